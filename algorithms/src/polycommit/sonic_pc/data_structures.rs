@@ -15,7 +15,7 @@
 use super::{LabeledPolynomial, PolynomialInfo};
 use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use hashbrown::HashMap;
@@ -679,3 +679,26 @@ impl<E: PairingEngine> ToBytes for BatchLCProof<E> {
         CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
     }
 }
+
+/// The accumulated elements of a batch check, before the final pairing check.
+/// The elements of several batch checks can be combined, and checked with a single pairing product.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedBatchCheck<E: PairingEngine> {
+    /// The combined commitments, for each degree bound.
+    pub(crate) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined witness.
+    pub(crate) combined_witness: E::G1Projective,
+    /// The combined adjusted witness.
+    pub(crate) combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> PreparedBatchCheck<E> {
+    /// Adds the elements of the given batch check, scaled by the given randomizer.
+    pub(crate) fn add_scaled(&mut self, other: Self, randomizer: E::Fr) {
+        for (degree_bound, comm) in other.combined_comms {
+            *self.combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) += comm * randomizer;
+        }
+        self.combined_witness += other.combined_witness * randomizer;
+        self.combined_adjusted_witness += other.combined_adjusted_witness * randomizer;
+    }
+}
@@ -24,7 +24,7 @@ use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
 
-use anyhow::{bail, ensure, Result};
+use anyhow::{anyhow, bail, ensure, Result};
 use core::{convert::TryInto, marker::PhantomData, ops::Mul};
 use rand_core::{RngCore, SeedableRng};
 use std::{
@@ -348,6 +348,22 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let prepared = Self::prepare_batch_check(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_elems(vk, prepared)
+    }
+
+    /// Accumulates the elements of a batch check, without performing the final pairing check.
+    pub fn prepare_batch_check<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PreparedBatchCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -404,9 +420,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result.map_err(Into::into)
+        Ok(PreparedBatchCheck { combined_comms, combined_witness, combined_adjusted_witness })
     }
 
     pub fn open_combinations<'a>(
@@ -482,6 +497,31 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let prepared = Self::prepare_check_combinations(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+        )?;
+        Self::check_elems(vk, prepared)
+    }
+
+    /// Accumulates the elements of the check of `check_combinations`, without performing the final pairing check.
+    pub fn prepare_check_combinations<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PreparedBatchCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -539,7 +579,24 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::prepare_batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+
+    /// Checks the given prepared batch checks with a single pairing product.
+    /// The batch checks are combined with random scalars sampled from `rng`, which must be unpredictable to the prover.
+    pub fn check_prepared<R: RngCore>(
+        vk: &UniversalVerifier<E>,
+        prepared: impl IntoIterator<Item = PreparedBatchCheck<E>>,
+        rng: &mut R,
+    ) -> Result<bool> {
+        let mut prepared = prepared.into_iter();
+        let mut combined = prepared.next().ok_or(anyhow!("There are no batch checks to combine"))?;
+        for batch_check in prepared {
+            // A 128-bit randomizer is sufficient for soundness.
+            let randomizer = E::Fr::from(((rng.next_u64() as u128) << 64) | rng.next_u64() as u128);
+            combined.add_scaled(batch_check, randomizer);
+        }
+        Self::check_elems(vk, combined)
     }
 }
 
@@ -633,12 +690,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Ok(())
     }
 
-    fn check_elems(
-        vk: &UniversalVerifier<E>,
-        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: E::G1Projective,
-        combined_adjusted_witness: E::G1Projective,
-    ) -> Result<bool> {
+    pub(crate) fn check_elems(vk: &UniversalVerifier<E>, prepared: PreparedBatchCheck<E>) -> Result<bool> {
+        let PreparedBatchCheck { combined_comms, combined_witness, combined_adjusted_witness } = prepared;
         let check_time = start_timer!(|| "Checking elems");
         let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
         let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
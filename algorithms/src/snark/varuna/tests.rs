@@ -315,7 +315,7 @@ mod varuna_hiding {
         ToBytes,
     };
 
-    use std::str::FromStr;
+    use std::{collections::BTreeMap, str::FromStr};
 
     type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
     type FS = PoseidonSponge<Fq, 2, 1>;
@@ -492,6 +492,51 @@ mod varuna_hiding {
         assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &new_vk, public_inputs, &proof).unwrap());
     }
 
+    #[test]
+    fn test_verify_prepared() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Prepare the checks of several proofs and certificates.
+        let mut prepared = Vec::new();
+        let mut invalid = None;
+        for _ in 0..3 {
+            let (circuit, public_inputs) = TestCircuit::gen_rand(2, 25, 25, rng);
+            let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+            let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+            let certificate = VarunaInst::prove_vk(universal_prover, &fs_parameters, &index_vk, &index_pk).unwrap();
+
+            let inputs = [public_inputs];
+            let keys_to_inputs = BTreeMap::from([(&index_vk, &inputs[..])]);
+            prepared.push(
+                VarunaInst::prepare_verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &proof)
+                    .unwrap()
+                    .unwrap(),
+            );
+            prepared.push(
+                VarunaInst::prepare_verify_vk(universal_verifier, &fs_parameters, &circuit, &index_vk, &certificate)
+                    .unwrap(),
+            );
+
+            // Prepare the check of the proof with the wrong public inputs.
+            let inputs = [vec![Fr::rand(rng), Fr::rand(rng)]];
+            let keys_to_inputs = BTreeMap::from([(&index_vk, &inputs[..])]);
+            invalid =
+                VarunaInst::prepare_verify_batch(universal_verifier, &fs_parameters, &keys_to_inputs, &proof).unwrap();
+        }
+
+        // Ensure the valid proofs and certificates verify together.
+        assert!(VarunaInst::verify_prepared(universal_verifier, prepared.clone(), rng).unwrap());
+        // Ensure a single invalid proof fails the combined check.
+        prepared.insert(1, invalid.unwrap());
+        assert!(!VarunaInst::verify_prepared(universal_verifier, prepared, rng).unwrap());
+    }
+
     #[test]
     fn test_srs_downloads() {
         let rng = &mut TestRng::default();
@@ -20,6 +20,7 @@ use crate::{
         CommitterUnionKey,
         Evaluations,
         LabeledCommitment,
+        PreparedBatchCheck,
         QuerySet,
         Randomness,
         SonicKZG10,
@@ -284,50 +285,10 @@ where
         verifying_key: &Self::VerifyingKey,
         certificate: &Self::Certificate,
     ) -> Result<bool> {
-        // Ensure the VerifyingKey encodes the expected circuit.
-        let circuit_id = &verifying_key.id;
-        let state = AHPForR1CS::<E::Fr, SM>::index_helper(circuit)?;
-        if state.index_info != verifying_key.circuit_info {
-            bail!(SNARKError::CircuitNotFound);
-        }
-        if state.id != *circuit_id {
-            bail!(SNARKError::CircuitNotFound);
-        }
-
-        // Initialize sponge.
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
-
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
-        let combiners = core::iter::once(E::Fr::one()).chain(challenges);
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let (lc, evaluation) =
-            AHPForR1CS::<E::Fr, SM>::evaluate_index_polynomials(state, circuit_id, point, combiners)?;
-
-        ensure!(verifying_key.circuit_commitments.len() == lc.terms.len());
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(lc.terms.keys())
-            .map(|(c, label)| LabeledCommitment::new(format!("{label:?}"), c, None))
-            .collect_vec();
-        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-
-        SonicKZG10::<E, FS>::check_combinations(
+        SonicKZG10::<E, FS>::check_elems(
             universal_verifier,
-            &[lc],
-            &commitments,
-            &query_set,
-            &evaluations,
-            &certificate.pc_proof,
-            &mut sponge,
+            Self::prepare_verify_vk(universal_verifier, fs_parameters, circuit, verifying_key, certificate)?,
         )
-        .map_err(Into::into)
     }
 
     /// This is the main entrypoint for creating proofs.
@@ -628,6 +589,30 @@ where
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool> {
+        match Self::prepare_verify_batch(universal_verifier, fs_parameters, keys_to_inputs, proof)? {
+            Some(prepared) => SonicKZG10::<E, FS>::check_elems(universal_verifier, prepared),
+            None => Ok(false),
+        }
+    }
+}
+
+impl<E: PairingEngine, FS, SM> VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    /// Verifies the given batch proof, up to the final pairing check, and returns the elements of the pairing check.
+    /// Returns `None` if the proof is invalid regardless of the pairing check.
+    ///
+    /// The elements of several proofs and certificates can be checked together with `Self::verify_prepared`.
+    pub fn prepare_verify_batch<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<Option<PreparedBatchCheck<E>>> {
         if keys_to_inputs.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -715,7 +700,7 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
@@ -888,8 +873,8 @@ where
         )?;
         end_timer!(lc_time);
 
-        let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let pc_time = start_timer!(|| "Preparing the check of the linear combinations with PC");
+        let prepared = SonicKZG10::<E, FS>::prepare_check_combinations(
             universal_verifier,
             lc_s.values(),
             &commitments,
@@ -900,15 +885,76 @@ where
         )?;
         end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
+        end_timer!(verifier_time);
+        Ok(Some(prepared))
+    }
+
+    /// Verifies the given certificate, up to the final pairing check, and returns the elements of the pairing check.
+    ///
+    /// The elements of several proofs and certificates can be checked together with `Self::verify_prepared`.
+    pub fn prepare_verify_vk<C: ConstraintSynthesizer<E::Fr>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        circuit: &C,
+        verifying_key: &CircuitVerifyingKey<E>,
+        certificate: &Certificate<E>,
+    ) -> Result<PreparedBatchCheck<E>> {
+        // Ensure the VerifyingKey encodes the expected circuit.
+        let circuit_id = &verifying_key.id;
+        let state = AHPForR1CS::<E::Fr, SM>::index_helper(circuit)?;
+        if state.index_info != verifying_key.circuit_info {
+            bail!(SNARKError::CircuitNotFound);
         }
+        if state.id != *circuit_id {
+            bail!(SNARKError::CircuitNotFound);
+        }
+
+        // Initialize sponge.
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, verifying_key)?;
+
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().ok_or(anyhow!("Failed to squeeze random element"))?;
+        let combiners = core::iter::once(E::Fr::one()).chain(challenges);
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let (lc, evaluation) =
+            AHPForR1CS::<E::Fr, SM>::evaluate_index_polynomials(state, circuit_id, point, combiners)?;
+
+        ensure!(verifying_key.circuit_commitments.len() == lc.terms.len());
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(lc.terms.keys())
+            .map(|(c, label)| LabeledCommitment::new(format!("{label:?}"), c, None))
+            .collect_vec();
+        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
 
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        SonicKZG10::<E, FS>::prepare_check_combinations(
+            universal_verifier,
+            &[lc],
+            &commitments,
+            &query_set,
+            &evaluations,
+            &certificate.pc_proof,
+            &mut sponge,
+        )
+        .map_err(Into::into)
+    }
+
+    /// Checks the elements of the given proofs and certificates with a single pairing product.
+    /// Returns `true` if and only if every proof and certificate is valid, except with negligible probability.
+    pub fn verify_prepared<R: Rng + CryptoRng>(
+        universal_verifier: &UniversalVerifier<E>,
+        prepared: impl IntoIterator<Item = PreparedBatchCheck<E>>,
+        rng: &mut R,
+    ) -> Result<bool> {
+        let verifier_time = start_timer!(|| "Varuna::VerifyPrepared");
+        let is_valid = SonicKZG10::<E, FS>::check_prepared(universal_verifier, prepared, rng)?;
+        end_timer!(verifier_time);
+        Ok(is_valid)
     }
 }
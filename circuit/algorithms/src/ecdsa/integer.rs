@@ -0,0 +1,313 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of limbs in an integer.
+const NUM_LIMBS: usize = 4;
+/// The number of bits in a limb.
+const LIMB_BITS: usize = 64;
+/// The number of bits in an integer.
+const INTEGER_BITS: usize = NUM_LIMBS * LIMB_BITS;
+/// The number of bits in the quotient of a modular relation.
+const QUOTIENT_BITS: usize = 264;
+/// The number of limbs in the quotient of a modular relation.
+const QUOTIENT_LIMBS: usize = 5;
+/// The number of columns in a modular relation.
+const NUM_COLUMNS: usize = QUOTIENT_LIMBS + NUM_LIMBS;
+/// The number of bits in a carry of a modular relation, including its sign offset.
+const CARRY_BITS: usize = 76;
+/// The maximum sum of the absolute coefficients of the products in a modular relation.
+const MAX_PRODUCT_COEFFICIENTS: u64 = 8;
+
+/// The modulus of a non-native field.
+#[derive(Clone)]
+pub(super) struct Modulus {
+    /// The modulus.
+    value: BigUint,
+    /// The limbs of the modulus.
+    limbs: Vec<u64>,
+    /// The little-endian bits of `modulus - 1`.
+    minus_one_bits_le: Vec<bool>,
+}
+
+impl Modulus {
+    /// Initializes a new modulus.
+    pub(super) fn new(value: BigUint) -> Self {
+        let minus_one = &value - 1u64;
+        Self {
+            limbs: to_limbs(&value),
+            minus_one_bits_le: (0..INTEGER_BITS).map(|i| minus_one.bit(i as u64)).collect(),
+            value,
+        }
+    }
+
+    /// Returns the modulus.
+    pub(super) const fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    /// Returns the little-endian bits of `modulus - 1`.
+    pub(super) fn minus_one_bits_le(&self) -> &[bool] {
+        &self.minus_one_bits_le
+    }
+
+    /// Returns `value^-1 mod modulus`, or `0` if the value is not invertible.
+    pub(super) fn inverse(&self, value: &BigUint) -> BigUint {
+        // As the modulus is prime, the inverse is `value^(modulus - 2)`.
+        value.modpow(&(&self.value - 2u64), &self.value)
+    }
+}
+
+/// A term of a modular relation.
+pub(super) enum Term<'a, E: Environment> {
+    /// The term `coefficient * lhs * rhs`.
+    Product(i64, &'a Uint256<E>, &'a Uint256<E>),
+    /// The term `coefficient * value`.
+    Linear(i64, &'a Uint256<E>),
+    /// The term `coefficient`.
+    Constant(i64),
+}
+
+/// A 256-bit unsigned integer, emulated as four 64-bit limbs in little-endian order.
+/// The limbs are range-checked to 64 bits whenever the integer is witnessed.
+#[derive(Clone)]
+pub(super) struct Uint256<E: Environment> {
+    limbs: Vec<Field<E>>,
+}
+
+impl<E: Environment> Uint256<E> {
+    /// Initializes a constant integer.
+    pub(super) fn constant(value: &BigUint) -> Self {
+        Self::new(Mode::Constant, value)
+    }
+
+    /// Initializes a new integer with the given mode.
+    pub(super) fn new(mode: Mode, value: &BigUint) -> Self {
+        Self::new_with_bits(mode, value).0
+    }
+
+    /// Initializes a new integer with the given mode, and returns it with its little-endian bits.
+    pub(super) fn new_with_bits(mode: Mode, value: &BigUint) -> (Self, Vec<Boolean<E>>) {
+        // Ensure the value is within range.
+        if value.bits() > INTEGER_BITS as u64 {
+            E::halt(format!("Attempted to initialize a {}-bit value as a 256-bit integer", value.bits()))
+        }
+        // Inject the bits, which range-checks the limbs.
+        let bits_le = (0..INTEGER_BITS).map(|i| Boolean::new(mode, value.bit(i as u64))).collect::<Vec<_>>();
+        (Self::from_bits_le(&bits_le), bits_le)
+    }
+
+    /// Initializes an integer from its little-endian bits.
+    pub(super) fn from_bits_le(bits_le: &[Boolean<E>]) -> Self {
+        // Ensure the number of bits is correct.
+        if bits_le.len() != INTEGER_BITS {
+            E::halt(format!("Attempted to initialize a 256-bit integer from {} bits", bits_le.len()))
+        }
+        Self { limbs: bits_le.chunks(LIMB_BITS).map(Field::from_bits_le).collect() }
+    }
+
+    /// Returns the little-endian bits of the integer.
+    pub(super) fn to_bits_le(&self) -> Vec<Boolean<E>> {
+        self.limbs.iter().flat_map(|limb| limb.to_lower_bits_le(LIMB_BITS)).collect()
+    }
+
+    /// Returns the value of the integer.
+    pub(super) fn value(&self) -> BigUint {
+        self.limbs.iter().rev().fold(BigUint::default(), |value, limb| (value << LIMB_BITS) + limb_value(limb))
+    }
+
+    /// Returns `true` if the integer is a constant.
+    pub(super) fn is_constant(&self) -> bool {
+        self.limbs.iter().all(|limb| limb.is_constant())
+    }
+
+    /// Returns `true` if the integer is zero.
+    pub(super) fn is_zero(&self) -> Boolean<E> {
+        self.limbs.iter().fold(Boolean::constant(true), |is_zero, limb| is_zero & limb.is_equal(&Field::zero()))
+    }
+
+    /// Returns `true` if the integers are equal.
+    pub(super) fn is_equal(&self, other: &Self) -> Boolean<E> {
+        self.limbs
+            .iter()
+            .zip_eq(&other.limbs)
+            .fold(Boolean::constant(true), |is_equal, (this, that)| is_equal & this.is_equal(that))
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(super) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            limbs: first
+                .limbs
+                .iter()
+                .zip_eq(&second.limbs)
+                .map(|(first, second)| Field::ternary(condition, first, second))
+                .collect(),
+        }
+    }
+
+    /// Returns the mode of a witness derived from the given integers.
+    pub(super) fn witness_mode(integers: &[&Self]) -> Mode {
+        match integers.iter().all(|integer| integer.is_constant()) {
+            true => Mode::Constant,
+            false => Mode::Private,
+        }
+    }
+
+    /// Returns the canonical representative of the integer modulo the given modulus, with its little-endian bits.
+    pub(super) fn canonicalize(&self, modulus: &Modulus) -> (Self, Vec<Boolean<E>>) {
+        // Inject the canonical representative.
+        let (canonical, bits_le) = Self::new_with_bits(Self::witness_mode(&[self]), &(self.value() % modulus.value()));
+        // Ensure the canonical representative is less than the modulus.
+        Boolean::assert_less_than_or_equal_constant(&bits_le, modulus.minus_one_bits_le());
+        // Ensure the integers are congruent.
+        Self::enforce_relation(&[Term::Linear(1, self), Term::Linear(-1, &canonical)], modulus);
+        (canonical, bits_le)
+    }
+
+    /// Enforces that the sum of the given terms is zero modulo the given modulus.
+    ///
+    /// The sum is offset by a multiple `K` of the modulus, so that it is nonnegative.
+    /// Then, a quotient `q` is witnessed, such that `sum + K - q * modulus = 0` over the integers.
+    /// This equation is enforced column by column, where each column is a weighted sum of
+    /// products of limbs, and each witnessed carry is range-checked.
+    pub(super) fn enforce_relation(terms: &[Term<E>], modulus: &Modulus) {
+        // Ensure the products are within the bounds of the quotient and carries.
+        let product_coefficients: u64 = terms
+            .iter()
+            .map(|term| match term {
+                Term::Product(coefficient, ..) => coefficient.unsigned_abs(),
+                _ => 0,
+            })
+            .sum();
+        if product_coefficients > MAX_PRODUCT_COEFFICIENTS {
+            E::halt(format!("Modular relation exceeds {MAX_PRODUCT_COEFFICIENTS} products"))
+        }
+
+        // Compute the offset `K`, as the least multiple of the modulus that exceeds the negative terms.
+        let max_integer = (BigUint::from(1u64) << INTEGER_BITS) - 1u64;
+        let negative_bound = terms.iter().fold(BigUint::default(), |bound, term| match term {
+            Term::Product(coefficient, ..) if *coefficient < 0 => {
+                bound + &max_integer * &max_integer * coefficient.unsigned_abs()
+            }
+            Term::Linear(coefficient, _) if *coefficient < 0 => bound + &max_integer * coefficient.unsigned_abs(),
+            Term::Constant(coefficient) if *coefficient < 0 => bound + coefficient.unsigned_abs(),
+            _ => bound,
+        });
+        let offset = (negative_bound / modulus.value() + 1u64) * modulus.value();
+
+        // Accumulate the columns of the terms, both in the circuit and natively.
+        let mut columns = vec![Field::<E>::zero(); NUM_COLUMNS];
+        let mut values = vec![BigInt::default(); NUM_COLUMNS];
+        let mut integers = Vec::with_capacity(2 * terms.len());
+        for term in terms {
+            match term {
+                Term::Product(coefficient, lhs, rhs) => {
+                    let factor = field_from_i64::<E>(*coefficient);
+                    for (i, a) in lhs.limbs.iter().enumerate() {
+                        for (j, b) in rhs.limbs.iter().enumerate() {
+                            columns[i + j] += &(a * b) * &factor;
+                            values[i + j] += BigInt::from(*coefficient) * limb_value(a) * limb_value(b);
+                        }
+                    }
+                    integers.extend([*lhs, *rhs]);
+                }
+                Term::Linear(coefficient, value) => {
+                    let factor = field_from_i64::<E>(*coefficient);
+                    for (i, a) in value.limbs.iter().enumerate() {
+                        columns[i] += a * &factor;
+                        values[i] += BigInt::from(*coefficient) * limb_value(a);
+                    }
+                    integers.push(*value);
+                }
+                Term::Constant(coefficient) => {
+                    columns[0] += field_from_i64::<E>(*coefficient);
+                    values[0] += BigInt::from(*coefficient);
+                }
+            }
+        }
+        // Accumulate the offset.
+        for (i, limb) in to_limbs_with_length(&offset, NUM_COLUMNS).into_iter().enumerate() {
+            columns[i] += Field::constant(console::Field::from_u64(limb));
+            values[i] += BigInt::from(limb);
+        }
+
+        // Compute the quotient natively.
+        let total =
+            values.iter().enumerate().fold(BigInt::default(), |total, (i, value)| total + (value << (i * LIMB_BITS)));
+        let quotient = total.to_biguint().unwrap_or_default() / modulus.value();
+
+        // Inject the quotient, which range-checks its limbs.
+        let mode = Self::witness_mode(&integers);
+        let quotient_bits = (0..QUOTIENT_BITS).map(|i| Boolean::new(mode, quotient.bit(i as u64))).collect::<Vec<_>>();
+        let quotient_limbs = quotient_bits.chunks(LIMB_BITS).map(Field::from_bits_le).collect::<Vec<_>>();
+        let quotient_values = to_limbs_with_length(&quotient, QUOTIENT_LIMBS);
+
+        // Subtract the product of the quotient and the modulus from the columns.
+        for (i, (q, q_value)) in quotient_limbs.iter().zip_eq(&quotient_values).enumerate() {
+            for (j, m) in modulus.limbs.iter().enumerate() {
+                columns[i + j] -= q * &Field::constant(console::Field::from_u64(*m));
+                values[i + j] -= BigInt::from(*q_value) * *m;
+            }
+        }
+
+        // Enforce that each column, with the carry from the previous column, is a multiple of `2^64`.
+        let carry_offset = BigInt::from(1u64) << (CARRY_BITS - 1);
+        let carry_offset_field = Field::constant(console::Field::from_u128(1u128 << (CARRY_BITS - 1)));
+        let limb_base = Field::constant(console::Field::from_u128(1u128 << LIMB_BITS));
+
+        let mut carry = Field::<E>::zero();
+        let mut carry_value = BigInt::default();
+        for (column, value) in columns.iter().zip_eq(&values).take(NUM_COLUMNS - 1) {
+            // Compute the next carry natively.
+            carry_value = (value + &carry_value) >> LIMB_BITS;
+            // Inject the next carry, offset to be nonnegative, which range-checks it.
+            let shifted = (&carry_value + &carry_offset).to_biguint().unwrap_or_default();
+            let carry_bits = (0..CARRY_BITS).map(|i| Boolean::new(mode, shifted.bit(i as u64))).collect::<Vec<_>>();
+            let next_carry = Field::from_bits_le(&carry_bits) - &carry_offset_field;
+            // Ensure `column + carry == next_carry * 2^64`.
+            E::assert_eq(column + &carry, &next_carry * &limb_base);
+            carry = next_carry;
+        }
+        // Ensure the last column, with the carry, is zero.
+        E::assert_eq(&columns[NUM_COLUMNS - 1] + &carry, Field::<E>::zero());
+    }
+}
+
+/// Returns the value of the given limb.
+fn limb_value<E: Environment>(limb: &Field<E>) -> u64 {
+    limb.eject_value().to_bits_le().iter().take(LIMB_BITS).rev().fold(0u64, |value, bit| (value << 1) | u64::from(*bit))
+}
+
+/// Returns the circuit constant of the given signed integer.
+fn field_from_i64<E: Environment>(value: i64) -> Field<E> {
+    let field = Field::constant(console::Field::from_u64(value.unsigned_abs()));
+    match value.is_negative() {
+        true => -field,
+        false => field,
+    }
+}
+
+/// Returns the limbs of the given 256-bit integer.
+fn to_limbs(value: &BigUint) -> Vec<u64> {
+    to_limbs_with_length(value, NUM_LIMBS)
+}
+
+/// Returns the given number of limbs of the given integer.
+fn to_limbs_with_length(value: &BigUint, length: usize) -> Vec<u64> {
+    let mut limbs = value.to_u64_digits();
+    limbs.resize(length, 0);
+    limbs
+}
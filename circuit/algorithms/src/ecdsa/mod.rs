@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod integer;
+mod point;
+mod verify;
+
+use integer::{Modulus, Term, Uint256};
+use point::Point;
+
+#[cfg(test)]
+use snarkvm_utilities::TestRng;
+
+use crate::{Hash, Keccak256};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, U8};
+
+use num_bigint::{BigInt, BigUint};
+
+/// The base field modulus `p` of secp256k1.
+const BASE_MODULUS: &str = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+/// The scalar field modulus `n` of secp256k1, which is the order of the curve.
+const SCALAR_MODULUS: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+/// The generator `G` of secp256k1.
+const GENERATOR: (&str, &str) = (
+    "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+);
+/// The offset point `H`, whose `x`-coordinate is `SHA-256("snarkVM.ECDSA.secp256k1.offset" || 0x00)`,
+/// and whose `y`-coordinate is even. The discrete logarithm of `H` with respect to `G` is unknown.
+const OFFSET: (&str, &str) = (
+    "cc1b7111b84eab13566051a747b8bb7b77c92e71e9f1b3a4b2db0c97b084a52f",
+    "41f4e44a90fa0d8ac233ca92ce197bd3303060db4b052d852f633ba12a3310e2",
+);
+/// The point `G + H`.
+const GENERATOR_PLUS_OFFSET: (&str, &str) = (
+    "a44a5edf950c9713d4563c49e639805b0ff4d2f555c58f188b5506028e359057",
+    "47efde3263e56d9f9ccefff2f7272517b7a60f6024375fcc608f84254ba01e24",
+);
+/// The point `-H`.
+const OFFSET_NEGATED: (&str, &str) = (
+    "cc1b7111b84eab13566051a747b8bb7b77c92e71e9f1b3a4b2db0c97b084a52f",
+    "be0b1bb56f05f2753dcc356d31e6842ccfcf9f24b4fad27ad09cc45dd5cceb4d",
+);
+/// The point `-2^256 * H`, which removes the offset from the accumulator of a scalar multiplication.
+const OFFSET_CORRECTION: (&str, &str) = (
+    "74f9d694c0c3fab3dd49745525ddbab98bb04a08c6b50177b10c4803f5b94274",
+    "4cce83c7a52632a279a911dda0082947dbde19f7ca7605c8abecf406d3c712a6",
+);
+
+/// The ECDSA signature scheme over the secp256k1 curve, as used by Bitcoin and Ethereum.
+///
+/// The curve arithmetic is emulated with 256-bit integers of four 64-bit limbs,
+/// and every modular relation is enforced with a witnessed quotient and carries.
+/// Points are added with the incomplete affine formulas. To avoid the exceptional cases,
+/// the accumulator of a scalar multiplication is initialized to the offset point `H`.
+/// Note: For a negligible set of adversarial inputs (e.g. a public key of `-G`), an exceptional case
+/// is reached, and the circuit is unsatisfiable. This can only be triggered by the prover's own inputs.
+#[derive(Clone)]
+pub struct ECDSA<E: Environment> {
+    /// The base field modulus `p`.
+    base_modulus: Modulus,
+    /// The scalar field modulus `n`.
+    scalar_modulus: Modulus,
+    /// The generator `G`.
+    generator: Point<E>,
+    /// The offset point `H`.
+    offset: Point<E>,
+    /// The point `G + H`.
+    generator_plus_offset: Point<E>,
+    /// The point `-H`.
+    offset_negated: Point<E>,
+    /// The point `-2^256 * H`.
+    offset_correction: Point<E>,
+    /// The Keccak-256 hash function.
+    keccak_256: Keccak256<E>,
+}
+
+impl<E: Environment> ECDSA<E> {
+    /// The size of a signature in bytes, as `r || s || v`.
+    pub const SIGNATURE_SIZE_IN_BYTES: usize = 65;
+    /// The size of a compressed (SEC1) public key in bytes, as `prefix || x`.
+    pub const PUBLIC_KEY_SIZE_IN_BYTES: usize = 33;
+    /// The size of a digest in bytes.
+    pub const DIGEST_SIZE_IN_BYTES: usize = 32;
+    /// The size of an Ethereum address in bytes.
+    pub const ETHEREUM_ADDRESS_SIZE_IN_BYTES: usize = 20;
+
+    /// Initializes a new instance of ECDSA over secp256k1.
+    pub fn new() -> Self {
+        let point = |(x, y): (&str, &str)| Point::constant(&from_hex(x), &from_hex(y));
+        Self {
+            base_modulus: Modulus::new(from_hex(BASE_MODULUS)),
+            scalar_modulus: Modulus::new(from_hex(SCALAR_MODULUS)),
+            generator: point(GENERATOR),
+            offset: point(OFFSET),
+            generator_plus_offset: point(GENERATOR_PLUS_OFFSET),
+            offset_negated: point(OFFSET_NEGATED),
+            offset_correction: point(OFFSET_CORRECTION),
+            keccak_256: Keccak256::new(),
+        }
+    }
+}
+
+impl<E: Environment> Default for ECDSA<E> {
+    /// Initializes a new instance of ECDSA over secp256k1.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Environment> ECDSA<E> {
+    /// Returns `u1 * G + u2 * Q`, for the given little-endian bits of the scalars `u1` and `u2`.
+    /// This method uses Shamir's trick, with the accumulator initialized to the offset point.
+    fn double_scalar_multiply(&self, u1_bits_le: &[Boolean<E>], u2_bits_le: &[Boolean<E>], q: &Point<E>) -> Point<E> {
+        let p = &self.base_modulus;
+
+        // Compute `G + Q` as `((G + H) + Q) - H`, which is well-defined when `Q = G`.
+        let g_plus_q = self.generator_plus_offset.add(q, p).add(&self.offset_negated, p);
+
+        // Initialize the accumulator to the offset point.
+        let mut accumulator = self.offset.clone();
+        // Iterate over the bits from the most significant bit.
+        for (bit_1, bit_2) in u1_bits_le.iter().rev().zip_eq(u2_bits_le.iter().rev()) {
+            accumulator = accumulator.double(p);
+            // Select the addend, which is `G` when neither bit is set (the sum is then discarded).
+            let addend = Point::ternary(bit_2, &Point::ternary(bit_1, &g_plus_q, q), &self.generator);
+            let sum = accumulator.add(&addend, p);
+            accumulator = Point::ternary(&(bit_1 | bit_2), &sum, &accumulator);
+        }
+        // Remove the offset from the accumulator.
+        accumulator.add(&self.offset_correction, p)
+    }
+
+    /// Returns the scalars `r` and `s` of the given 64-byte signature, and `true` if `0 < r < n` and `0 < s < n`.
+    /// If the signature is invalid, the returned scalars are `1`.
+    fn decode_signature(&self, signature: &[U8<E>]) -> (Uint256<E>, Uint256<E>, Boolean<E>) {
+        let one = Uint256::constant(&BigUint::from(1u64));
+        let decode = |bytes: &[U8<E>]| {
+            let bits_le = bits_le_from_bytes_be(bytes);
+            let scalar = Uint256::from_bits_le(&bits_le);
+            // Ensure `0 < scalar < n`.
+            let is_valid = !scalar.is_zero()
+                & Boolean::is_less_than_or_equal_constant(&bits_le, self.scalar_modulus.minus_one_bits_le());
+            (scalar, is_valid)
+        };
+        let (r, is_r_valid) = decode(&signature[..32]);
+        let (s, is_s_valid) = decode(&signature[32..64]);
+        let is_valid = is_r_valid & is_s_valid;
+        (Uint256::ternary(&is_valid, &r, &one), Uint256::ternary(&is_valid, &s, &one), is_valid)
+    }
+
+    /// Returns the point of the given compressed (SEC1) public key, and `true` if the public key is valid.
+    /// If the public key is invalid, the returned point is the generator.
+    fn decode_public_key(&self, public_key: &[U8<E>]) -> (Point<E>, Boolean<E>) {
+        // Ensure the prefix is `0x02` or `0x03`, where the lowest bit is the `y`-parity.
+        let prefix = public_key[0].to_bits_le();
+        let is_prefix_valid = prefix[2..].iter().fold(prefix[1].clone(), |is_valid, bit| is_valid & !bit);
+        // Ensure `x < p`.
+        let x_bits_le = bits_le_from_bytes_be(&public_key[1..]);
+        let is_x_valid = Boolean::is_less_than_or_equal_constant(&x_bits_le, self.base_modulus.minus_one_bits_le());
+        // Decompress the point.
+        let (point, is_on_curve) =
+            Point::decompress(&Uint256::from_bits_le(&x_bits_le), &prefix[0], &self.base_modulus);
+
+        let is_valid = is_prefix_valid & is_x_valid & is_on_curve;
+        (Point::ternary(&is_valid, &point, &self.generator), is_valid)
+    }
+}
+
+/// Returns the integer of the given hexadecimal string.
+fn from_hex(hex: &str) -> BigUint {
+    BigUint::parse_bytes(hex.as_bytes(), 16).expect("Invalid hexadecimal constant")
+}
+
+/// Returns the little-endian bits of the integer with the given big-endian bytes.
+fn bits_le_from_bytes_be<E: Environment>(bytes: &[U8<E>]) -> Vec<Boolean<E>> {
+    bytes.iter().rev().flat_map(|byte| byte.to_bits_le()).collect()
+}
+
+/// Returns the big-endian bytes, each as little-endian bits, of the integer with the given little-endian bits.
+fn bytes_be_from_bits_le<E: Environment>(bits_le: &[Boolean<E>]) -> Vec<Boolean<E>> {
+    bits_le.chunks(8).rev().flatten().cloned().collect()
+}
+
+/// Returns `true` if the given little-endian bits are equal to the given byte.
+fn is_byte_equal<E: Environment>(bits_le: &[Boolean<E>], byte: u8) -> Boolean<E> {
+    bits_le.iter().enumerate().fold(Boolean::constant(true), |is_equal, (i, bit)| match (byte >> i) & 1 == 1 {
+        true => is_equal & bit,
+        false => is_equal & !bit,
+    })
+}
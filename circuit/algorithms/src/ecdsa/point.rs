@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An affine point on secp256k1, whose coordinates are integers modulo the base field modulus `p`.
+/// The coordinates are not necessarily canonical.
+#[derive(Clone)]
+pub(super) struct Point<E: Environment> {
+    x: Uint256<E>,
+    y: Uint256<E>,
+}
+
+impl<E: Environment> Point<E> {
+    /// Initializes a constant point.
+    pub(super) fn constant(x: &BigUint, y: &BigUint) -> Self {
+        Self { x: Uint256::constant(x), y: Uint256::constant(y) }
+    }
+
+    /// Returns the `x`-coordinate of the point.
+    pub(super) const fn x(&self) -> &Uint256<E> {
+        &self.x
+    }
+
+    /// Returns the `y`-coordinate of the point.
+    pub(super) const fn y(&self) -> &Uint256<E> {
+        &self.y
+    }
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    pub(super) fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self {
+        Self {
+            x: Uint256::ternary(condition, &first.x, &second.x),
+            y: Uint256::ternary(condition, &first.y, &second.y),
+        }
+    }
+
+    /// Returns `self + other`.
+    /// This method enforces that the points have distinct `x`-coordinates.
+    pub(super) fn add(&self, other: &Self, p: &Modulus) -> Self {
+        let (x1, y1, x2, y2) = (&self.x, &self.y, &other.x, &other.y);
+        let mode = Uint256::witness_mode(&[x1, y1, x2, y2]);
+
+        // Compute the sum natively.
+        let modulus = p.value();
+        let (x1_value, y1_value) = (x1.value() % modulus, y1.value() % modulus);
+        let (x2_value, y2_value) = (x2.value() % modulus, y2.value() % modulus);
+        let inverse_value = p.inverse(&((&x2_value + modulus - &x1_value) % modulus));
+        let lambda_value = ((&y2_value + modulus - &y1_value) * &inverse_value) % modulus;
+        let x3_value = (&lambda_value * &lambda_value + modulus * 2u64 - &x1_value - &x2_value) % modulus;
+        let y3_value = (&lambda_value * ((&x1_value + modulus - &x3_value) % modulus) + modulus - &y1_value) % modulus;
+
+        // Inject the inverse of `x2 - x1`, the slope, and the sum.
+        let inverse = Uint256::new(mode, &inverse_value);
+        let lambda = Uint256::new(mode, &lambda_value);
+        let x3 = Uint256::new(mode, &x3_value);
+        let y3 = Uint256::new(mode, &y3_value);
+
+        // Ensure `x1 != x2`, by ensuring `inverse * (x2 - x1) == 1`.
+        Uint256::enforce_relation(
+            &[Term::Product(1, &inverse, x2), Term::Product(-1, &inverse, x1), Term::Constant(-1)],
+            p,
+        );
+        // Ensure `lambda * (x2 - x1) == y2 - y1`.
+        Uint256::enforce_relation(
+            &[Term::Product(1, &lambda, x2), Term::Product(-1, &lambda, x1), Term::Linear(-1, y2), Term::Linear(1, y1)],
+            p,
+        );
+        // Ensure `x3 == lambda^2 - x1 - x2`.
+        Uint256::enforce_relation(
+            &[Term::Product(1, &lambda, &lambda), Term::Linear(-1, x1), Term::Linear(-1, x2), Term::Linear(-1, &x3)],
+            p,
+        );
+        // Ensure `y3 == lambda * (x1 - x3) - y1`.
+        Uint256::enforce_relation(
+            &[
+                Term::Product(1, &lambda, x1),
+                Term::Product(-1, &lambda, &x3),
+                Term::Linear(-1, y1),
+                Term::Linear(-1, &y3),
+            ],
+            p,
+        );
+
+        Self { x: x3, y: y3 }
+    }
+
+    /// Returns `2 * self`.
+    /// As secp256k1 has no point of order 2, the doubling of a point on the curve is always well-defined.
+    pub(super) fn double(&self, p: &Modulus) -> Self {
+        let (x1, y1) = (&self.x, &self.y);
+        let mode = Uint256::witness_mode(&[x1, y1]);
+
+        // Compute the doubling natively.
+        let modulus = p.value();
+        let (x1_value, y1_value) = (x1.value() % modulus, y1.value() % modulus);
+        let lambda_value = (&x1_value * &x1_value * 3u64 * p.inverse(&(&y1_value * 2u64))) % modulus;
+        let x3_value = (&lambda_value * &lambda_value + modulus * 2u64 - &x1_value * 2u64) % modulus;
+        let y3_value = (&lambda_value * ((&x1_value + modulus - &x3_value) % modulus) + modulus - &y1_value) % modulus;
+
+        // Inject the slope and the doubling.
+        let lambda = Uint256::new(mode, &lambda_value);
+        let x3 = Uint256::new(mode, &x3_value);
+        let y3 = Uint256::new(mode, &y3_value);
+
+        // Ensure `lambda * 2 * y1 == 3 * x1^2`.
+        Uint256::enforce_relation(&[Term::Product(2, &lambda, y1), Term::Product(-3, x1, x1)], p);
+        // Ensure `x3 == lambda^2 - 2 * x1`.
+        Uint256::enforce_relation(
+            &[Term::Product(1, &lambda, &lambda), Term::Linear(-2, x1), Term::Linear(-1, &x3)],
+            p,
+        );
+        // Ensure `y3 == lambda * (x1 - x3) - y1`.
+        Uint256::enforce_relation(
+            &[
+                Term::Product(1, &lambda, x1),
+                Term::Product(-1, &lambda, &x3),
+                Term::Linear(-1, y1),
+                Term::Linear(-1, &y3),
+            ],
+            p,
+        );
+
+        Self { x: x3, y: y3 }
+    }
+
+    /// Returns the point with the given `x`-coordinate and `y`-parity, and `true` if the point is on the curve.
+    /// The returned `y`-coordinate is canonical. If the point is not on the curve, the returned point is unspecified.
+    pub(super) fn decompress(x: &Uint256<E>, is_y_odd: &Boolean<E>, p: &Modulus) -> (Self, Boolean<E>) {
+        let mode = match x.is_constant() && is_y_odd.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+
+        // Compute the decompression natively.
+        let modulus = p.value();
+        let x_value = x.value() % modulus;
+        let xx_value = (&x_value * &x_value) % modulus;
+        let w_value = (&xx_value * &x_value + 7u64) % modulus;
+        let negated_w_value = (modulus - &w_value) % modulus;
+        // As `p = 3 mod 4`, the value `w` is a square if `w^((p - 1) / 2) == 1`,
+        // in which case its square root is `w^((p + 1) / 4)`.
+        let is_square_value = w_value.modpow(&((modulus - 1u64) >> 1), modulus) == BigUint::from(1u64);
+        let root = match is_square_value {
+            true => w_value.modpow(&((modulus + 1u64) >> 2), modulus),
+            false => negated_w_value.modpow(&((modulus + 1u64) >> 2), modulus),
+        };
+        let y_value = match root.bit(0) == is_y_odd.eject_value() {
+            true => root,
+            false => (modulus - &root) % modulus,
+        };
+
+        // Inject the flag, `x^2`, `w = x^3 + 7`, `-w`, and the `y`-coordinate.
+        let is_on_curve = Boolean::new(mode, is_square_value);
+        let xx = Uint256::new(mode, &xx_value);
+        let w = Uint256::new(mode, &w_value);
+        let negated_w = Uint256::new(mode, &negated_w_value);
+        let (y, y_bits_le) = Uint256::new_with_bits(mode, &y_value);
+
+        // Ensure `xx == x^2`.
+        Uint256::enforce_relation(&[Term::Product(1, x, x), Term::Linear(-1, &xx)], p);
+        // Ensure `w == x^3 + 7`.
+        Uint256::enforce_relation(&[Term::Product(1, x, &xx), Term::Constant(7), Term::Linear(-1, &w)], p);
+        // Ensure `negated_w == -w`.
+        Uint256::enforce_relation(&[Term::Linear(1, &w), Term::Linear(1, &negated_w)], p);
+        // Ensure `y^2 == w` if the point is on the curve, and `y^2 == -w` otherwise.
+        // As `p = 3 mod 4`, `-1` is not a square, so exactly one of `w` and `-w` is a square (for a nonzero `w`).
+        let square = Uint256::ternary(&is_on_curve, &w, &negated_w);
+        Uint256::enforce_relation(&[Term::Product(1, &y, &y), Term::Linear(-1, &square)], p);
+        // Ensure `y` is canonical, and has the given parity.
+        Boolean::assert_less_than_or_equal_constant(&y_bits_le, p.minus_one_bits_le());
+        E::assert_eq(&y_bits_le[0], is_y_odd);
+
+        (Self { x: x.clone(), y }, is_on_curve)
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> ECDSA<E> {
+    /// Returns `true` if the signature is valid for the given compressed public key and 32-byte digest.
+    /// Note: The recovery ID of the signature is not used.
+    pub fn verify_digest(&self, signature: &[U8<E>], public_key: &[U8<E>], digest: &[U8<E>]) -> Boolean<E> {
+        // Ensure the inputs are of the expected size.
+        if signature.len() != Self::SIGNATURE_SIZE_IN_BYTES {
+            E::halt(format!("Invalid ECDSA signature size ({} bytes)", signature.len()))
+        }
+        if public_key.len() != Self::PUBLIC_KEY_SIZE_IN_BYTES {
+            E::halt(format!("Invalid ECDSA public key size ({} bytes)", public_key.len()))
+        }
+        if digest.len() != Self::DIGEST_SIZE_IN_BYTES {
+            E::halt(format!("Invalid ECDSA digest size ({} bytes)", digest.len()))
+        }
+
+        let n = &self.scalar_modulus;
+
+        // Decode the signature, public key, and digest.
+        let (r, s, is_signature_valid) = self.decode_signature(&signature[..64]);
+        let (public_key, is_public_key_valid) = self.decode_public_key(public_key);
+        let z = Uint256::from_bits_le(&bits_le_from_bytes_be(digest));
+
+        // Compute `u1 = z / s mod n` and `u2 = r / s mod n`.
+        let mode = Uint256::witness_mode(&[&r, &s, &z]);
+        let s_inverse_value = n.inverse(&s.value());
+        let s_inverse = Uint256::new(mode, &s_inverse_value);
+        let u1 = Uint256::new(mode, &((z.value() * &s_inverse_value) % n.value()));
+        let u2 = Uint256::new(mode, &((r.value() * &s_inverse_value) % n.value()));
+        // Ensure `s * s_inverse == 1`.
+        Uint256::enforce_relation(&[Term::Product(1, &s, &s_inverse), Term::Constant(-1)], n);
+        // Ensure `u1 == z * s_inverse`.
+        Uint256::enforce_relation(&[Term::Product(1, &z, &s_inverse), Term::Linear(-1, &u1)], n);
+        // Ensure `u2 == r * s_inverse`.
+        Uint256::enforce_relation(&[Term::Product(1, &r, &s_inverse), Term::Linear(-1, &u2)], n);
+
+        // Compute `R = u1 * G + u2 * Q`.
+        let point = self.double_scalar_multiply(&u1.to_bits_le(), &u2.to_bits_le(), &public_key);
+        // Reduce the `x`-coordinate of `R` modulo `p`, and then modulo `n`.
+        let (x, _) = point.x().canonicalize(&self.base_modulus);
+        let (x, _) = x.canonicalize(n);
+
+        // Ensure the `x`-coordinate of `R` matches `r`.
+        is_signature_valid & is_public_key_valid & x.is_equal(&r)
+    }
+
+    /// Returns `true` if the public key recovered from the signature on the Keccak-256 hash of the message
+    /// has the given Ethereum address. This is equivalent to `ecrecover(keccak256(message), v, r, s) == address`.
+    pub fn verify_keccak256(&self, signature: &[U8<E>], address: &[U8<E>], message: &[U8<E>]) -> Boolean<E> {
+        // Ensure the inputs are of the expected size.
+        if signature.len() != Self::SIGNATURE_SIZE_IN_BYTES {
+            E::halt(format!("Invalid ECDSA signature size ({} bytes)", signature.len()))
+        }
+        if address.len() != Self::ETHEREUM_ADDRESS_SIZE_IN_BYTES {
+            E::halt(format!("Invalid Ethereum address size ({} bytes)", address.len()))
+        }
+        if message.is_empty() {
+            E::halt("The message to verify must not be empty")
+        }
+
+        let n = &self.scalar_modulus;
+
+        // Decode the recovery ID, which is one of `0`, `1`, `27`, or `28`.
+        let v = signature[64].to_bits_le();
+        let is_v_one = is_byte_equal(&v, 1);
+        let is_v_twenty_eight = is_byte_equal(&v, 28);
+        let is_v_valid = is_byte_equal(&v, 0) | &is_v_one | is_byte_equal(&v, 27) | &is_v_twenty_eight;
+        let is_y_odd = is_v_one | is_v_twenty_eight;
+
+        // Decode the signature.
+        let (r, s, is_signature_valid) = self.decode_signature(&signature[..64]);
+        // Hash the message, and decode the digest.
+        let digest = self.keccak_256.hash(&message.iter().flat_map(|byte| byte.to_bits_le()).collect::<Vec<_>>());
+        let z = Uint256::from_bits_le(&bytes_be_from_bits_le(&digest));
+
+        // Decompress the point `R` from `r`, substituting the generator if `R` is not on the curve.
+        let (point, is_on_curve) = Point::decompress(&r, &is_y_odd, &self.base_modulus);
+        let point = Point::ternary(&is_on_curve, &point, &self.generator);
+
+        // Compute `u1 = -z / r mod n` and `u2 = s / r mod n`.
+        let mode = Uint256::witness_mode(&[&r, &s, &z]);
+        let r_inverse_value = n.inverse(&r.value());
+        let r_inverse = Uint256::new(mode, &r_inverse_value);
+        let u1 = Uint256::new(mode, &((n.value() - (z.value() * &r_inverse_value) % n.value()) % n.value()));
+        let u2 = Uint256::new(mode, &((s.value() * &r_inverse_value) % n.value()));
+        // Ensure `r * r_inverse == 1`.
+        Uint256::enforce_relation(&[Term::Product(1, &r, &r_inverse), Term::Constant(-1)], n);
+        // Ensure `u1 == -z * r_inverse`.
+        Uint256::enforce_relation(&[Term::Product(1, &z, &r_inverse), Term::Linear(1, &u1)], n);
+        // Ensure `u2 == s * r_inverse`.
+        Uint256::enforce_relation(&[Term::Product(1, &s, &r_inverse), Term::Linear(-1, &u2)], n);
+
+        // Recover the public key as `Q = u1 * G + u2 * R`.
+        let public_key = self.double_scalar_multiply(&u1.to_bits_le(), &u2.to_bits_le(), &point);
+        // Encode the public key as `x || y` in big-endian bytes.
+        let (_, x_bits_le) = public_key.x().canonicalize(&self.base_modulus);
+        let (_, y_bits_le) = public_key.y().canonicalize(&self.base_modulus);
+        let mut preimage = bytes_be_from_bits_le(&x_bits_le);
+        preimage.extend(bytes_be_from_bits_le(&y_bits_le));
+
+        // Compute the Ethereum address, as the last 20 bytes of the hash of the public key.
+        let hash = self.keccak_256.hash(&preimage);
+        let candidate = &hash[(32 - Self::ETHEREUM_ADDRESS_SIZE_IN_BYTES) * 8..];
+        // Ensure the address matches.
+        let is_address_equal = address
+            .iter()
+            .flat_map(|byte| byte.to_bits_le())
+            .zip_eq(candidate)
+            .fold(Boolean::constant(true), |is_equal, (this, that)| is_equal & this.is_equal(that));
+
+        is_v_valid & is_signature_valid & is_on_curve & is_address_equal
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Rng;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use k256::ecdsa::SigningKey;
+
+    const ITERATIONS: usize = 2;
+
+    /// Returns a random signing key, and a signature as `r || s || v` on the given digest.
+    fn sample_signature(digest: &[u8; 32], rng: &mut TestRng) -> (SigningKey, Vec<u8>) {
+        let signing_key = SigningKey::random(rng);
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(digest).unwrap();
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(recovery_id.to_byte());
+        (signing_key, bytes)
+    }
+
+    /// Returns the Keccak-256 hash of the given bytes.
+    fn keccak_256(preimage: &[u8]) -> Vec<u8> {
+        use console::Hash as H;
+
+        let hash = console::Keccak256::default().hash(&preimage.to_bits_le()).unwrap();
+        hash.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | *bit as u8)).collect()
+    }
+
+    /// Injects the given bytes in the given mode.
+    fn inject(mode: Mode, bytes: &[u8]) -> Vec<U8<Circuit>> {
+        bytes.iter().map(|byte| U8::new(mode, console::U8::new(*byte))).collect()
+    }
+
+    fn check_verify_digest(mode: Mode, signature: &[u8], public_key: &[u8], digest: &[u8]) {
+        let expected = console::ECDSA::verify_digest(signature, public_key, digest).unwrap();
+
+        let ecdsa = ECDSA::<Circuit>::new();
+        Circuit::scope(format!("ECDSA {mode}"), || {
+            let candidate =
+                ecdsa.verify_digest(&inject(mode, signature), &inject(mode, public_key), &inject(mode, digest));
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    fn check_verify_keccak256(mode: Mode, signature: &[u8], address: &[u8], message: &[u8]) {
+        let expected = console::ECDSA::verify_keccak256(signature, address, message).unwrap();
+
+        let ecdsa = ECDSA::<Circuit>::new();
+        Circuit::scope(format!("ECDSA {mode}"), || {
+            let candidate =
+                ecdsa.verify_keccak256(&inject(mode, signature), &inject(mode, address), &inject(mode, message));
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_verify_digest() {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Private] {
+            for _ in 0..ITERATIONS {
+                let digest: [u8; 32] = rng.gen();
+                let (signing_key, signature) = sample_signature(&digest, rng);
+                let public_key = signing_key.verifying_key().to_encoded_point(true);
+
+                // Check a valid signature.
+                check_verify_digest(mode, &signature, public_key.as_bytes(), &digest);
+
+                // Check a signature on a different digest.
+                let other_digest: [u8; 32] = rng.gen();
+                check_verify_digest(mode, &signature, public_key.as_bytes(), &other_digest);
+
+                // Check an invalid public key.
+                let mut invalid_key = public_key.as_bytes().to_vec();
+                invalid_key[0] = 0x04;
+                check_verify_digest(mode, &signature, &invalid_key, &digest);
+
+                // Check an invalid signature.
+                let mut invalid_signature = signature.clone();
+                invalid_signature[32..64].fill(0xff);
+                check_verify_digest(mode, &invalid_signature, public_key.as_bytes(), &digest);
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_keccak256() {
+        let rng = &mut TestRng::default();
+
+        for mode in [Mode::Constant, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let message = (0..(i + 1) * 50).map(|_| rng.gen()).collect::<Vec<u8>>();
+                let digest = keccak_256(&message).try_into().unwrap();
+                let (signing_key, mut signature) = sample_signature(&digest, rng);
+                let public_key = signing_key.verifying_key().to_encoded_point(false);
+                let address = keccak_256(&public_key.as_bytes()[1..])[12..].to_vec();
+
+                // Check a valid signature.
+                check_verify_keccak256(mode, &signature, &address, &message);
+
+                // Check a different address.
+                let mut other_address = address.clone();
+                other_address[19] ^= 1;
+                check_verify_keccak256(mode, &signature, &other_address, &message);
+
+                // Check the legacy Ethereum recovery ID.
+                signature[64] += 27;
+                check_verify_keccak256(mode, &signature, &address, &message);
+
+                // Check an invalid recovery ID.
+                signature[64] = 2;
+                check_verify_keccak256(mode, &signature, &address, &message);
+            }
+        }
+    }
+}
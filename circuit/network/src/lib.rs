@@ -19,7 +19,7 @@ pub mod v0;
 pub use v0::*;
 
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
-use snarkvm_circuit_types::{environment::Environment, Boolean, Field, Group, Scalar};
+use snarkvm_circuit_types::{environment::Environment, Boolean, Field, Group, Scalar, U8};
 
 pub trait Aleo: Environment {
     /// The maximum number of field elements in data (must not exceed u16::MAX).
@@ -163,6 +163,13 @@ pub trait Aleo: Environment {
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
     fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Scalar<Self>;
 
+    /// Returns `true` if the secp256k1 ECDSA signature is valid for the given compressed public key and digest.
+    fn ecdsa_verify_digest(signature: &[U8<Self>], public_key: &[U8<Self>], digest: &[U8<Self>]) -> Boolean<Self>;
+
+    /// Returns `true` if the secp256k1 ECDSA signature on the Keccak-256 hash of the message
+    /// recovers a public key with the given Ethereum address.
+    fn ecdsa_verify_keccak256(signature: &[U8<Self>], address: &[U8<Self>], message: &[U8<Self>]) -> Boolean<Self>;
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     #[allow(clippy::ptr_arg)]
     fn verify_merkle_path_bhp<const DEPTH: u8>(
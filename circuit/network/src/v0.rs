@@ -36,6 +36,7 @@ use snarkvm_circuit_algorithms::{
     BHP256,
     BHP512,
     BHP768,
+    ECDSA,
 };
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{
@@ -44,6 +45,7 @@ use snarkvm_circuit_types::{
     Field,
     Group,
     Scalar,
+    U8,
 };
 
 use core::fmt;
@@ -70,6 +72,9 @@ thread_local! {
     /// The BHP hash function, which can take an input of up to 1024 bits.
     static BHP_1024: BHP1024<AleoV0> = BHP1024::<AleoV0>::constant(console::BHP_1024.clone());
 
+    /// The ECDSA signature scheme over the secp256k1 curve.
+    static ECDSA_SECP256K1: ECDSA<AleoV0> = ECDSA::<AleoV0>::new();
+
     /// The Keccak hash function, which outputs 256 bits.
     static KECCAK_256: Keccak256<AleoV0> = Keccak256::<AleoV0>::new();
     /// The Keccak hash function, which outputs 384 bits.
@@ -337,6 +342,17 @@ impl Aleo for AleoV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash_to_scalar(input))
     }
 
+    /// Returns `true` if the secp256k1 ECDSA signature is valid for the given compressed public key and digest.
+    fn ecdsa_verify_digest(signature: &[U8<Self>], public_key: &[U8<Self>], digest: &[U8<Self>]) -> Boolean<Self> {
+        ECDSA_SECP256K1.with(|ecdsa| ecdsa.verify_digest(signature, public_key, digest))
+    }
+
+    /// Returns `true` if the secp256k1 ECDSA signature on the Keccak-256 hash of the message
+    /// recovers a public key with the given Ethereum address.
+    fn ecdsa_verify_keccak256(signature: &[U8<Self>], address: &[U8<Self>], message: &[U8<Self>]) -> Boolean<Self> {
+        ECDSA_SECP256K1.with(|ecdsa| ecdsa.verify_keccak256(signature, address, message))
+    }
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     fn verify_merkle_path_bhp<const DEPTH: u8>(
         path: &MerklePath<Self, DEPTH>,
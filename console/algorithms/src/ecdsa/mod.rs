@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+use snarkvm_console_types::environment::prelude::*;
+
+use k256::ecdsa::{signature::hazmat::PrehashVerifier, RecoveryId, Signature, VerifyingKey};
+use tiny_keccak::{Hasher, Keccak as TinyKeccak};
+
+/// The ECDSA signature scheme over the secp256k1 curve, as used by Bitcoin and Ethereum.
+///
+/// A signature is 65 bytes, comprising the 32-byte big-endian scalars `r` and `s`,
+/// followed by the recovery ID `v`, which is one of `0`, `1`, `27`, or `28`.
+/// A signature is only valid if `0 < r < n` and `0 < s < n`, where `n` is the order of the curve.
+/// Note: High-`s` signatures are accepted, as in the `ecrecover` precompile of Ethereum.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ECDSA;
+
+impl ECDSA {
+    /// The size of a signature in bytes, as `r || s || v`.
+    pub const SIGNATURE_SIZE_IN_BYTES: usize = 65;
+    /// The size of a compressed (SEC1) public key in bytes, as `prefix || x`.
+    pub const PUBLIC_KEY_SIZE_IN_BYTES: usize = 33;
+    /// The size of a digest in bytes.
+    pub const DIGEST_SIZE_IN_BYTES: usize = 32;
+    /// The size of an Ethereum address in bytes.
+    pub const ETHEREUM_ADDRESS_SIZE_IN_BYTES: usize = 20;
+
+    /// Returns the `y`-parity of the given recovery ID, if it is valid.
+    /// The recovery ID is `0` or `1`, or `27` or `28` as in Ethereum's legacy transactions.
+    const fn recovery_id_parity(v: u8) -> Option<bool> {
+        match v {
+            0 | 27 => Some(false),
+            1 | 28 => Some(true),
+            _ => None,
+        }
+    }
+
+    /// Returns the Ethereum address of the given public key,
+    /// which is the last 20 bytes of the Keccak-256 hash of the uncompressed public key (without its prefix).
+    fn ethereum_address(verifying_key: &VerifyingKey) -> [u8; 20] {
+        // Encode the uncompressed public key, as `0x04 || x || y`.
+        let public_key = verifying_key.to_encoded_point(false);
+        // Hash the coordinates of the public key.
+        let hash = keccak_256_native(&public_key.as_bytes()[1..]);
+        // Return the last 20 bytes of the hash.
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[12..]);
+        address
+    }
+}
+
+/// Computes the Keccak-256 hash of the given preimage as bytes.
+fn keccak_256_native(preimage: &[u8]) -> [u8; 32] {
+    let mut keccak = TinyKeccak::v256();
+    keccak.update(preimage);
+
+    let mut hash = [0u8; 32];
+    keccak.finalize(&mut hash);
+    hash
+}
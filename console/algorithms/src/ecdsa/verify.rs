@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl ECDSA {
+    /// Returns `true` if the signature is valid for the given compressed public key and 32-byte digest.
+    /// Note: The recovery ID of the signature is not used.
+    pub fn verify_digest(signature: &[u8], public_key: &[u8], digest: &[u8]) -> Result<bool> {
+        // Ensure the inputs are of the expected size.
+        ensure!(signature.len() == Self::SIGNATURE_SIZE_IN_BYTES, "Invalid ECDSA signature size");
+        ensure!(public_key.len() == Self::PUBLIC_KEY_SIZE_IN_BYTES, "Invalid ECDSA public key size");
+        ensure!(digest.len() == Self::DIGEST_SIZE_IN_BYTES, "Invalid ECDSA digest size");
+
+        // Decode the public key.
+        let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(public_key) else {
+            return Ok(false);
+        };
+        // Decode the signature, ensuring `0 < r < n` and `0 < s < n`.
+        let Ok(signature) = Signature::from_slice(&signature[..64]) else {
+            return Ok(false);
+        };
+        // Verify the signature.
+        Ok(verifying_key.verify_prehash(digest, &signature).is_ok())
+    }
+
+    /// Returns `true` if the public key recovered from the signature on the Keccak-256 hash of the message
+    /// has the given Ethereum address. This is equivalent to `ecrecover(keccak256(message), v, r, s) == address`.
+    pub fn verify_keccak256(signature: &[u8], address: &[u8], message: &[u8]) -> Result<bool> {
+        // Ensure the inputs are of the expected size.
+        ensure!(signature.len() == Self::SIGNATURE_SIZE_IN_BYTES, "Invalid ECDSA signature size");
+        ensure!(address.len() == Self::ETHEREUM_ADDRESS_SIZE_IN_BYTES, "Invalid Ethereum address size");
+        ensure!(!message.is_empty(), "The message to verify must not be empty");
+
+        // Decode the recovery ID.
+        let Some(is_y_odd) = Self::recovery_id_parity(signature[64]) else {
+            return Ok(false);
+        };
+        // Decode the signature, ensuring `0 < r < n` and `0 < s < n`.
+        let Ok(signature) = Signature::from_slice(&signature[..64]) else {
+            return Ok(false);
+        };
+        // Hash the message.
+        let digest = keccak_256_native(message);
+        // Recover the public key.
+        let Ok(verifying_key) =
+            VerifyingKey::recover_from_prehash(&digest, &signature, RecoveryId::new(is_y_odd, false))
+        else {
+            return Ok(false);
+        };
+        // Ensure the address of the public key matches.
+        Ok(Self::ethereum_address(&verifying_key) == address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+    use k256::ecdsa::SigningKey;
+
+    const ITERATIONS: usize = 100;
+
+    /// Returns a random signing key, and a signature as `r || s || v` on the given digest.
+    fn sample_signature(digest: &[u8; 32], rng: &mut TestRng) -> (SigningKey, Vec<u8>) {
+        let signing_key = SigningKey::random(rng);
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(digest).unwrap();
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(recovery_id.to_byte());
+        (signing_key, bytes)
+    }
+
+    #[test]
+    fn test_verify_digest() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let digest: [u8; 32] = rng.gen();
+            let (signing_key, signature) = sample_signature(&digest, rng);
+            let public_key = signing_key.verifying_key().to_encoded_point(true);
+
+            // Ensure the signature is valid.
+            assert!(ECDSA::verify_digest(&signature, public_key.as_bytes(), &digest).unwrap());
+
+            // Ensure the signature is invalid for a different digest.
+            let other_digest: [u8; 32] = rng.gen();
+            assert!(!ECDSA::verify_digest(&signature, public_key.as_bytes(), &other_digest).unwrap());
+
+            // Ensure the signature is invalid for a different public key.
+            let other_key = SigningKey::random(rng).verifying_key().to_encoded_point(true);
+            assert!(!ECDSA::verify_digest(&signature, other_key.as_bytes(), &digest).unwrap());
+
+            // Ensure an invalid public key is rejected.
+            let mut invalid_key = public_key.as_bytes().to_vec();
+            invalid_key[0] = 0x04;
+            assert!(!ECDSA::verify_digest(&signature, &invalid_key, &digest).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_verify_keccak256() {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            let message = (0..(i + 1)).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let (signing_key, mut signature) = sample_signature(&keccak_256_native(&message), rng);
+            let address = ECDSA::ethereum_address(signing_key.verifying_key());
+
+            // Ensure the signature is valid.
+            assert!(ECDSA::verify_keccak256(&signature, &address, &message).unwrap());
+
+            // Ensure the signature is invalid for a different message.
+            let mut other_message = message.clone();
+            other_message[0] ^= 1;
+            assert!(!ECDSA::verify_keccak256(&signature, &address, &other_message).unwrap());
+
+            // Ensure the signature is invalid for a different address.
+            let mut other_address = address;
+            other_address[19] ^= 1;
+            assert!(!ECDSA::verify_keccak256(&signature, &other_address, &message).unwrap());
+
+            // Ensure the legacy Ethereum recovery ID is accepted.
+            signature[64] += 27;
+            assert!(ECDSA::verify_keccak256(&signature, &address, &message).unwrap());
+
+            // Ensure an invalid recovery ID is rejected.
+            signature[64] = 2;
+            assert!(!ECDSA::verify_keccak256(&signature, &address, &message).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_invalid_scalars() {
+        let rng = &mut TestRng::default();
+
+        let digest: [u8; 32] = rng.gen();
+        let (signing_key, signature) = sample_signature(&digest, rng);
+        let public_key = signing_key.verifying_key().to_encoded_point(true);
+
+        // Ensure a zero `r` is rejected.
+        let mut invalid = signature.clone();
+        invalid[..32].fill(0);
+        assert!(!ECDSA::verify_digest(&invalid, public_key.as_bytes(), &digest).unwrap());
+
+        // Ensure an `s` greater than the curve order is rejected.
+        let mut invalid = signature;
+        invalid[32..64].fill(0xff);
+        assert!(!ECDSA::verify_digest(&invalid, public_key.as_bytes(), &digest).unwrap());
+    }
+
+    #[test]
+    fn test_verify_rejects_invalid_sizes() {
+        assert!(ECDSA::verify_digest(&[0u8; 64], &[0u8; 33], &[0u8; 32]).is_err());
+        assert!(ECDSA::verify_digest(&[0u8; 65], &[0u8; 65], &[0u8; 32]).is_err());
+        assert!(ECDSA::verify_digest(&[0u8; 65], &[0u8; 33], &[0u8; 31]).is_err());
+        assert!(ECDSA::verify_keccak256(&[0u8; 65], &[0u8; 32], &[0u8; 1]).is_err());
+        assert!(ECDSA::verify_keccak256(&[0u8; 65], &[0u8; 20], &[]).is_err());
+    }
+}
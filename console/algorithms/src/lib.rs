@@ -25,6 +25,9 @@ pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
+mod ecdsa;
+pub use ecdsa::ECDSA;
+
 mod elligator2;
 pub use elligator2::Elligator2;
 
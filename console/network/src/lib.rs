@@ -148,6 +148,9 @@ pub trait Network:
     const MAX_INSTRUCTIONS: usize = u16::MAX as usize;
     /// The maximum number of commands in finalize.
     const MAX_COMMANDS: usize = u16::MAX as usize;
+    /// The maximum number of iterations in a `repeat` instruction.
+    /// This is also the maximum number of iterations that a closure or function unrolls into, across nested `repeat`s.
+    const MAX_REPEAT_ITERATIONS: u32 = 1024;
     /// The maximum number of write commands in finalize.
     const MAX_WRITES: u16 = 16;
 
@@ -332,6 +335,13 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
     fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
+    /// Returns `true` if the secp256k1 ECDSA signature is valid for the given compressed public key and digest.
+    fn ecdsa_verify_digest(signature: &[u8], public_key: &[u8], digest: &[u8]) -> Result<bool>;
+
+    /// Returns `true` if the secp256k1 ECDSA signature on the Keccak-256 hash of the message
+    /// recovers a public key with the given Ethereum address.
+    fn ecdsa_verify_keccak256(signature: &[u8], address: &[u8], message: &[u8]) -> Result<bool>;
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>>;
 
@@ -30,6 +30,7 @@ use snarkvm_console_algorithms::{
     BHP256,
     BHP512,
     BHP768,
+    ECDSA,
 };
 
 lazy_static! {
@@ -452,6 +453,17 @@ impl Network for Testnet3 {
         POSEIDON_8.hash_to_scalar(input)
     }
 
+    /// Returns `true` if the secp256k1 ECDSA signature is valid for the given compressed public key and digest.
+    fn ecdsa_verify_digest(signature: &[u8], public_key: &[u8], digest: &[u8]) -> Result<bool> {
+        ECDSA::verify_digest(signature, public_key, digest)
+    }
+
+    /// Returns `true` if the secp256k1 ECDSA signature on the Keccak-256 hash of the message
+    /// recovers a public key with the given Ethereum address.
+    fn ecdsa_verify_keccak256(signature: &[u8], address: &[u8], message: &[u8]) -> Result<bool> {
+        ECDSA::verify_keccak256(signature, address, message)
+    }
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
         MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
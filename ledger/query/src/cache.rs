@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{ProgramQueryTrait, QueryTrait};
+use console::{
+    network::prelude::*,
+    program::{ProgramID, StatePath},
+    types::Field,
+};
+use synthesizer_program::Program;
+
+use std::{collections::HashMap, sync::RwLock};
+
+/// A query backend, which caches the programs and state paths of the given backend.
+///
+/// The current state root is always fetched from the backend. A state path is only returned from
+/// the cache if its global state root is the current state root, as the state paths of a transaction
+/// must share one global state root. When the state root changes, the older state paths are evicted.
+pub struct CachedQuery<N: Network, Q: ProgramQueryTrait<N>> {
+    /// The underlying query backend.
+    query: Q,
+    /// The cached programs.
+    programs: RwLock<HashMap<ProgramID<N>, Program<N>>>,
+    /// The cached state paths, for each global state root and commitment.
+    #[allow(clippy::type_complexity)]
+    state_paths: RwLock<HashMap<(N::StateRoot, Field<N>), StatePath<N>>>,
+}
+
+impl<N: Network, Q: ProgramQueryTrait<N>> CachedQuery<N, Q> {
+    /// Initializes a new caching wrapper around the given query backend.
+    pub fn new(query: Q) -> Self {
+        Self { query, programs: Default::default(), state_paths: Default::default() }
+    }
+
+    /// Returns the underlying query backend.
+    pub const fn inner(&self) -> &Q {
+        &self.query
+    }
+
+    /// Returns the number of cached programs.
+    pub fn num_programs(&self) -> usize {
+        self.programs.read().map_or(0, |programs| programs.len())
+    }
+
+    /// Returns the number of cached state paths.
+    pub fn num_state_paths(&self) -> usize {
+        self.state_paths.read().map_or(0, |state_paths| state_paths.len())
+    }
+
+    /// Removes the cached state paths.
+    pub fn clear_state_paths(&self) {
+        if let Ok(mut state_paths) = self.state_paths.write() {
+            state_paths.clear();
+        }
+    }
+
+    /// Returns the cached program, if it exists.
+    fn cached_program(&self, program_id: &ProgramID<N>) -> Option<Program<N>> {
+        self.programs.read().ok()?.get(program_id).cloned()
+    }
+
+    /// Caches the given program.
+    fn cache_program(&self, program: &Program<N>) {
+        if let Ok(mut programs) = self.programs.write() {
+            programs.insert(*program.id(), program.clone());
+        }
+    }
+
+    /// Returns the cached state path as of the given global state root, if it exists.
+    fn cached_state_path(&self, state_root: N::StateRoot, commitment: &Field<N>) -> Option<StatePath<N>> {
+        self.state_paths.read().ok()?.get(&(state_root, *commitment)).cloned()
+    }
+
+    /// Caches the given state path, evicting the state paths of other global state roots.
+    fn cache_state_path(&self, commitment: &Field<N>, state_path: &StatePath<N>) {
+        if let Ok(mut state_paths) = self.state_paths.write() {
+            let state_root = state_path.global_state_root();
+            state_paths.retain(|(root, _), _| *root == state_root);
+            state_paths.insert((state_root, *commitment), state_path.clone());
+        }
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network, Q: ProgramQueryTrait<N>> QueryTrait<N> for CachedQuery<N, Q> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        self.query.current_state_root()
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        self.query.current_state_root_async().await
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        let state_root = self.query.current_state_root()?;
+        if let Some(state_path) = self.cached_state_path(state_root, commitment) {
+            return Ok(state_path);
+        }
+        let state_path = self.query.get_state_path_for_commitment(commitment)?;
+        self.cache_state_path(commitment, &state_path);
+        Ok(state_path)
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        let state_root = self.query.current_state_root_async().await?;
+        if let Some(state_path) = self.cached_state_path(state_root, commitment) {
+            return Ok(state_path);
+        }
+        let state_path = self.query.get_state_path_for_commitment_async(commitment).await?;
+        self.cache_state_path(commitment, &state_path);
+        Ok(state_path)
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network, Q: ProgramQueryTrait<N>> ProgramQueryTrait<N> for CachedQuery<N, Q> {
+    /// Returns the program for the given program ID.
+    fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        if let Some(program) = self.cached_program(program_id) {
+            return Ok(program);
+        }
+        let program = self.query.get_program(program_id)?;
+        self.cache_program(&program);
+        Ok(program)
+    }
+
+    /// Returns the program for the given program ID.
+    #[cfg(feature = "async")]
+    async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        if let Some(program) = self.cached_program(program_id) {
+            return Ok(program);
+        }
+        let program = self.query.get_program_async(program_id).await?;
+        self.cache_program(&program);
+        Ok(program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockQuery;
+    use console::{network::Testnet3, program::test_helpers::sample_global_state_path};
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type CurrentNetwork = Testnet3;
+
+    /// A query backend, whose state can be updated, and which counts the state path lookups.
+    struct UpdatableQuery {
+        mock: RwLock<MockQuery<CurrentNetwork>>,
+        num_state_path_lookups: AtomicUsize,
+    }
+
+    impl UpdatableQuery {
+        fn update(&self, f: impl FnOnce(MockQuery<CurrentNetwork>) -> MockQuery<CurrentNetwork>) {
+            let mut mock = self.mock.write().unwrap();
+            *mock = f(mock.clone());
+        }
+    }
+
+    #[cfg_attr(feature = "async", async_trait(?Send))]
+    impl QueryTrait<CurrentNetwork> for UpdatableQuery {
+        fn current_state_root(&self) -> Result<<CurrentNetwork as Network>::StateRoot> {
+            self.mock.read().unwrap().current_state_root()
+        }
+
+        #[cfg(feature = "async")]
+        async fn current_state_root_async(&self) -> Result<<CurrentNetwork as Network>::StateRoot> {
+            self.current_state_root()
+        }
+
+        fn get_state_path_for_commitment(
+            &self,
+            commitment: &Field<CurrentNetwork>,
+        ) -> Result<StatePath<CurrentNetwork>> {
+            self.num_state_path_lookups.fetch_add(1, Ordering::SeqCst);
+            self.mock.read().unwrap().get_state_path_for_commitment(commitment)
+        }
+
+        #[cfg(feature = "async")]
+        async fn get_state_path_for_commitment_async(
+            &self,
+            commitment: &Field<CurrentNetwork>,
+        ) -> Result<StatePath<CurrentNetwork>> {
+            self.get_state_path_for_commitment(commitment)
+        }
+    }
+
+    #[cfg_attr(feature = "async", async_trait(?Send))]
+    impl ProgramQueryTrait<CurrentNetwork> for UpdatableQuery {
+        fn get_program(&self, program_id: &ProgramID<CurrentNetwork>) -> Result<Program<CurrentNetwork>> {
+            self.mock.read().unwrap().get_program(program_id)
+        }
+
+        #[cfg(feature = "async")]
+        async fn get_program_async(&self, program_id: &ProgramID<CurrentNetwork>) -> Result<Program<CurrentNetwork>> {
+            self.get_program(program_id)
+        }
+    }
+
+    #[test]
+    fn test_cached_query() {
+        let rng = &mut TestRng::default();
+
+        // Prepare a state path and a program.
+        let commitment = Field::rand(rng);
+        let state_path = sample_global_state_path::<CurrentNetwork>(Some(commitment), rng).unwrap();
+        let program = Program::<CurrentNetwork>::credits().unwrap();
+
+        // Initialize the cache, over a preloaded mock.
+        let mock = MockQuery::new(state_path.global_state_root())
+            .with_state_path(commitment, state_path.clone())
+            .with_program(program.clone());
+        let query = CachedQuery::new(mock);
+        assert_eq!(query.num_programs(), 0);
+        assert_eq!(query.num_state_paths(), 0);
+
+        // Ensure the lookups are cached.
+        assert_eq!(query.current_state_root().unwrap(), state_path.global_state_root());
+        assert_eq!(query.get_state_path_for_commitment(&commitment).unwrap(), state_path);
+        assert_eq!(query.get_program(program.id()).unwrap(), program);
+        assert_eq!(query.num_programs(), 1);
+        assert_eq!(query.num_state_paths(), 1);
+
+        // Ensure failed lookups are not cached.
+        assert!(query.get_state_path_for_commitment(&Field::rand(rng)).is_err());
+        assert!(query.get_program(&ProgramID::from_str("unknown.aleo").unwrap()).is_err());
+        assert_eq!(query.num_programs(), 1);
+        assert_eq!(query.num_state_paths(), 1);
+
+        // Ensure the state paths can be cleared.
+        query.clear_state_paths();
+        assert_eq!(query.num_state_paths(), 0);
+        assert_eq!(query.get_state_path_for_commitment(&commitment).unwrap(), state_path);
+    }
+
+    #[test]
+    fn test_cached_query_state_root_change() {
+        let rng = &mut TestRng::default();
+
+        // Prepare two state paths for the same commitment, as of different global state roots.
+        let commitment = Field::rand(rng);
+        let state_path_1 = sample_global_state_path::<CurrentNetwork>(Some(commitment), rng).unwrap();
+        let state_path_2 = sample_global_state_path::<CurrentNetwork>(Some(commitment), rng).unwrap();
+        assert_ne!(state_path_1.global_state_root(), state_path_2.global_state_root());
+
+        // Initialize the cache, over a backend at the first state root.
+        let mock = MockQuery::new(state_path_1.global_state_root()).with_state_path(commitment, state_path_1.clone());
+        let query = CachedQuery::new(UpdatableQuery { mock: RwLock::new(mock), num_state_path_lookups: 0.into() });
+
+        // Ensure the state path is cached while the state root is unchanged.
+        assert_eq!(query.get_state_path_for_commitment(&commitment).unwrap(), state_path_1);
+        assert_eq!(query.get_state_path_for_commitment(&commitment).unwrap(), state_path_1);
+        assert_eq!(query.inner().num_state_path_lookups.load(Ordering::SeqCst), 1);
+
+        // Advance the backend to the second state root.
+        query.inner().update(|mock| {
+            mock.with_state_root(state_path_2.global_state_root()).with_state_path(commitment, state_path_2.clone())
+        });
+
+        // Ensure the state path of the previous state root is not returned, and is evicted.
+        assert_eq!(query.get_state_path_for_commitment(&commitment).unwrap(), state_path_2);
+        assert_eq!(query.inner().num_state_path_lookups.load(Ordering::SeqCst), 2);
+        assert_eq!(query.num_state_paths(), 1);
+    }
+}
@@ -18,10 +18,25 @@
 #[cfg_attr(feature = "async", macro_use)]
 extern crate async_trait;
 
+#[cfg(feature = "query")]
+mod cache;
+#[cfg(feature = "query")]
+pub use cache::*;
+
+#[cfg(feature = "query")]
+mod mock;
+#[cfg(feature = "query")]
+pub use mock::*;
+
 #[cfg(feature = "query")]
 mod query;
 #[cfg(feature = "query")]
 pub use query::*;
 
+#[cfg(feature = "query")]
+mod rest;
+#[cfg(feature = "query")]
+pub use rest::*;
+
 mod traits;
 pub use traits::*;
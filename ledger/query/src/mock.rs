@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{ProgramQueryTrait, QueryTrait};
+use console::{
+    network::prelude::*,
+    program::{ProgramID, StatePath},
+    types::Field,
+};
+use synthesizer_program::Program;
+
+use std::collections::HashMap;
+
+/// An in-memory query backend, which is preloaded with a state root, state paths, and programs.
+#[derive(Clone)]
+pub struct MockQuery<N: Network> {
+    /// The current state root.
+    state_root: N::StateRoot,
+    /// The state paths, for each commitment.
+    state_paths: HashMap<Field<N>, StatePath<N>>,
+    /// The programs, for each program ID.
+    programs: HashMap<ProgramID<N>, Program<N>>,
+}
+
+impl<N: Network> MockQuery<N> {
+    /// Initializes a new mock query, with the given current state root.
+    pub fn new(state_root: N::StateRoot) -> Self {
+        Self { state_root, state_paths: Default::default(), programs: Default::default() }
+    }
+
+    /// Sets the current state root.
+    pub fn with_state_root(mut self, state_root: N::StateRoot) -> Self {
+        self.state_root = state_root;
+        self
+    }
+
+    /// Adds the state path for the given `commitment`.
+    pub fn with_state_path(mut self, commitment: Field<N>, state_path: StatePath<N>) -> Self {
+        self.state_paths.insert(commitment, state_path);
+        self
+    }
+
+    /// Adds the given program.
+    pub fn with_program(mut self, program: Program<N>) -> Self {
+        self.programs.insert(*program.id(), program);
+        self
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for MockQuery<N> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(self.state_root)
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        self.current_state_root()
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.state_paths.get(commitment).cloned().ok_or_else(|| anyhow!("State path for '{commitment}' not found"))
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_state_path_for_commitment(commitment)
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> ProgramQueryTrait<N> for MockQuery<N> {
+    /// Returns the program for the given program ID.
+    fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.programs.get(program_id).cloned().ok_or_else(|| anyhow!("Program '{program_id}' not found"))
+    }
+
+    /// Returns the program for the given program ID.
+    #[cfg(feature = "async")]
+    async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.get_program(program_id)
+    }
+}
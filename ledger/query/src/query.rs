@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::QueryTrait;
+use crate::{ProgramQueryTrait, QueryTrait, RestQuery};
 use console::{
     network::prelude::*,
     program::{ProgramID, StatePath},
@@ -21,12 +21,23 @@ use console::{
 use ledger_store::{BlockStorage, BlockStore};
 use synthesizer_program::Program;
 
+use std::sync::Arc;
+
 #[derive(Clone)]
 pub enum Query<N: Network, B: BlockStorage<N>> {
     /// The block store from the VM.
     VM(BlockStore<N, B>),
     /// The base URL of the node.
     REST(String),
+    /// A custom query backend.
+    Custom(Arc<dyn ProgramQueryTrait<N> + Send + Sync>),
+}
+
+impl<N: Network, B: BlockStorage<N>> Query<N, B> {
+    /// Initializes a new query from the given custom query backend.
+    pub fn custom(query: impl ProgramQueryTrait<N> + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(query))
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> From<BlockStore<N, B>> for Query<N, B> {
@@ -59,16 +70,20 @@ impl<N: Network, B: BlockStorage<N>> From<&str> for Query<N, B> {
     }
 }
 
+impl<N: Network, B: BlockStorage<N>> From<RestQuery<N>> for Query<N, B> {
+    fn from(query: RestQuery<N>) -> Self {
+        Self::custom(query)
+    }
+}
+
 #[cfg_attr(feature = "async", async_trait(?Send))]
 impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     /// Returns the current state root.
     fn current_state_root(&self) -> Result<N::StateRoot> {
         match self {
             Self::VM(block_store) => Ok(block_store.current_state_root()),
-            Self::REST(url) => match N::ID {
-                3 => Ok(Self::get_request(&format!("{url}/testnet3/latest/stateRoot"))?.into_json()?),
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => RestQuery::<N>::new(url).current_state_root(),
+            Self::Custom(query) => query.current_state_root(),
         }
     }
 
@@ -77,10 +92,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     async fn current_state_root_async(&self) -> Result<N::StateRoot> {
         match self {
             Self::VM(block_store) => Ok(block_store.current_state_root()),
-            Self::REST(url) => match N::ID {
-                3 => Ok(Self::get_request_async(&format!("{url}/testnet3/latest/stateRoot")).await?.json().await?),
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => RestQuery::<N>::new(url).current_state_root_async().await,
+            Self::Custom(query) => query.current_state_root_async().await,
         }
     }
 
@@ -88,10 +101,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         match self {
             Self::VM(block_store) => block_store.get_state_path_for_commitment(commitment),
-            Self::REST(url) => match N::ID {
-                3 => Ok(Self::get_request(&format!("{url}/testnet3/statePath/{commitment}"))?.into_json()?),
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => RestQuery::<N>::new(url).get_state_path_for_commitment(commitment),
+            Self::Custom(query) => query.get_state_path_for_commitment(commitment),
         }
     }
 
@@ -100,54 +111,34 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         match self {
             Self::VM(block_store) => block_store.get_state_path_for_commitment(commitment),
-            Self::REST(url) => match N::ID {
-                3 => {
-                    Ok(Self::get_request_async(&format!("{url}/testnet3/statePath/{commitment}")).await?.json().await?)
-                }
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => RestQuery::<N>::new(url).get_state_path_for_commitment_async(commitment).await,
+            Self::Custom(query) => query.get_state_path_for_commitment_async(commitment).await,
         }
     }
 }
 
-impl<N: Network, B: BlockStorage<N>> Query<N, B> {
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network, B: BlockStorage<N>> ProgramQueryTrait<N> for Query<N, B> {
     /// Returns the program for the given program ID.
-    pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+    fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
         match self {
             Self::VM(block_store) => {
                 block_store.get_program(program_id)?.ok_or_else(|| anyhow!("Program {program_id} not found in storage"))
             }
-            Self::REST(url) => match N::ID {
-                3 => Ok(Self::get_request(&format!("{url}/testnet3/program/{program_id}"))?.into_json()?),
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => RestQuery::<N>::new(url).get_program(program_id),
+            Self::Custom(query) => query.get_program(program_id),
         }
     }
 
     /// Returns the program for the given program ID.
     #[cfg(feature = "async")]
-    pub async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+    async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
         match self {
             Self::VM(block_store) => {
                 block_store.get_program(program_id)?.ok_or_else(|| anyhow!("Program {program_id} not found in storage"))
             }
-            Self::REST(url) => match N::ID {
-                3 => Ok(Self::get_request_async(&format!("{url}/testnet3/program/{program_id}")).await?.json().await?),
-                _ => bail!("Unsupported network ID in inclusion query"),
-            },
+            Self::REST(url) => RestQuery::<N>::new(url).get_program_async(program_id).await,
+            Self::Custom(query) => query.get_program_async(program_id).await,
         }
     }
-
-    /// Performs a GET request to the given URL.
-    fn get_request(url: &str) -> Result<ureq::Response> {
-        let response = ureq::get(url).call()?;
-        if response.status() == 200 { Ok(response) } else { bail!("Failed to fetch from {url}") }
-    }
-
-    /// Performs a GET request to the given URL.
-    #[cfg(feature = "async")]
-    async fn get_request_async(url: &str) -> Result<reqwest::Response> {
-        let response = reqwest::get(url).await?;
-        if response.status() == 200 { Ok(response) } else { bail!("Failed to fetch from {url}") }
-    }
 }
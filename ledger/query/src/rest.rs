@@ -0,0 +1,266 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{ProgramQueryTrait, QueryTrait};
+use console::{
+    network::prelude::*,
+    program::{ProgramID, StatePath},
+    types::Field,
+};
+use synthesizer_program::Program;
+
+use core::marker::PhantomData;
+use std::{collections::BTreeMap, time::Duration};
+
+/// The path segments of the REST endpoints, for each network ID.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UrlScheme {
+    /// The mapping of network IDs to their path segment.
+    networks: BTreeMap<u16, String>,
+}
+
+impl Default for UrlScheme {
+    /// Returns the URL scheme for the known networks.
+    fn default() -> Self {
+        Self::empty().with_network(3, "testnet3")
+    }
+}
+
+impl UrlScheme {
+    /// Returns a URL scheme without any networks.
+    pub fn empty() -> Self {
+        Self { networks: BTreeMap::new() }
+    }
+
+    /// Sets the path segment for the given network ID.
+    pub fn with_network(mut self, network_id: u16, segment: impl Into<String>) -> Self {
+        self.networks.insert(network_id, segment.into().trim_matches('/').to_string());
+        self
+    }
+
+    /// Returns the path segment for the given network ID.
+    pub fn network(&self, network_id: u16) -> Result<&str> {
+        match self.networks.get(&network_id) {
+            Some(segment) => Ok(segment),
+            None => bail!("Unsupported network ID '{network_id}' in inclusion query"),
+        }
+    }
+
+    /// Returns the URL of the given endpoint, for the given base URL and network ID.
+    pub fn url(&self, base_url: &str, network_id: u16, endpoint: &str) -> Result<String> {
+        Ok(format!("{}/{}/{}", base_url.trim_end_matches('/'), self.network(network_id)?, endpoint))
+    }
+}
+
+/// The policy to retry failed requests with, using an exponential backoff.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of retries, after the first attempt.
+    max_retries: u32,
+    /// The delay before the first retry.
+    initial_backoff: Duration,
+    /// The maximum delay between retries.
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_retries: 3, initial_backoff: Duration::from_millis(500), max_backoff: Duration::from_secs(8) }
+    }
+}
+
+impl RetryPolicy {
+    /// Initializes a new retry policy.
+    pub const fn new(max_retries: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self { max_retries, initial_backoff, max_backoff }
+    }
+
+    /// Returns a retry policy, which does not retry.
+    pub const fn none() -> Self {
+        Self::new(0, Duration::ZERO, Duration::ZERO)
+    }
+
+    /// Returns the maximum number of retries, after the first attempt.
+    pub const fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the delay before the given retry, starting from `0`.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff.saturating_mul(2u32.saturating_pow(retry)).min(self.max_backoff)
+    }
+}
+
+/// A query backend for the REST API of a node.
+#[derive(Clone, Debug)]
+pub struct RestQuery<N: Network> {
+    /// The base URL of the node.
+    base_url: String,
+    /// The path segments of the REST endpoints, for each network ID.
+    scheme: UrlScheme,
+    /// The policy to retry failed requests with.
+    retry_policy: RetryPolicy,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> RestQuery<N> {
+    /// Initializes a new REST query for the given base URL, with the default URL scheme and retry policy.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            scheme: UrlScheme::default(),
+            retry_policy: RetryPolicy::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Sets the URL scheme of the REST endpoints.
+    pub fn with_url_scheme(mut self, scheme: UrlScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Sets the policy to retry failed requests with.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Returns the base URL of the node.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the URL of the given endpoint.
+    pub fn url(&self, endpoint: &str) -> Result<String> {
+        self.scheme.url(&self.base_url, N::ID, endpoint)
+    }
+
+    /// Performs a GET request to the given endpoint, retrying on transport errors and server errors.
+    fn get_request<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T> {
+        let url = self.url(endpoint)?;
+        let mut retry = 0;
+        loop {
+            match ureq::get(&url).call() {
+                Ok(response) => return Ok(response.into_json()?),
+                Err(error) => {
+                    // Determine if the request may succeed on a retry.
+                    let is_retryable = match &error {
+                        ureq::Error::Status(status, _) => *status == 429 || *status >= 500,
+                        ureq::Error::Transport(_) => true,
+                    };
+                    if !is_retryable || retry >= self.retry_policy.max_retries() {
+                        bail!("Failed to fetch from {url} - {error}");
+                    }
+                    std::thread::sleep(self.retry_policy.backoff(retry));
+                    retry += 1;
+                }
+            }
+        }
+    }
+
+    /// Performs a GET request to the given endpoint.
+    /// Note: Asynchronous requests are not retried, as the backoff requires an async runtime.
+    #[cfg(feature = "async")]
+    async fn get_request_async<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T> {
+        let url = self.url(endpoint)?;
+        let response = reqwest::get(&url).await?;
+        if response.status() == 200 {
+            Ok(response.json().await?)
+        } else {
+            bail!("Failed to fetch from {url}")
+        }
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for RestQuery<N> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        self.get_request("latest/stateRoot")
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        self.get_request_async("latest/stateRoot").await
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_request(&format!("statePath/{commitment}"))
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_request_async(&format!("statePath/{commitment}")).await
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> ProgramQueryTrait<N> for RestQuery<N> {
+    /// Returns the program for the given program ID.
+    fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.get_request(&format!("program/{program_id}"))
+    }
+
+    /// Returns the program for the given program ID.
+    #[cfg(feature = "async")]
+    async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.get_request_async(&format!("program/{program_id}")).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_url_scheme() {
+        let query = RestQuery::<CurrentNetwork>::new("http://localhost:3030/");
+        assert_eq!(query.url("latest/stateRoot").unwrap(), "http://localhost:3030/testnet3/latest/stateRoot");
+
+        // Ensure the network segment is configurable.
+        let query = query.with_url_scheme(UrlScheme::empty().with_network(3, "/devnet/"));
+        assert_eq!(query.url("program/credits.aleo").unwrap(), "http://localhost:3030/devnet/program/credits.aleo");
+
+        // Ensure an unknown network is rejected.
+        let query = query.with_url_scheme(UrlScheme::empty().with_network(1, "mainnet"));
+        assert!(query.url("latest/stateRoot").is_err());
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(100), Duration::from_millis(500));
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(400));
+        assert_eq!(policy.backoff(3), Duration::from_millis(500));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(500));
+        assert_eq!(RetryPolicy::none().max_retries(), 0);
+    }
+
+    #[test]
+    fn test_unreachable_node() {
+        // Ensure the request fails after its retries, on an unreachable node.
+        let policy = RetryPolicy::new(2, Duration::from_millis(1), Duration::from_millis(1));
+        let query = RestQuery::<CurrentNetwork>::new("http://127.0.0.1:1").with_retry_policy(policy);
+        assert!(query.current_state_root().is_err());
+    }
+}
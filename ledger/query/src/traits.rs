@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "query")]
+use console::program::ProgramID;
 use console::{network::Network, prelude::Result, program::StatePath, types::Field};
+#[cfg(feature = "query")]
+use synthesizer_program::Program;
 
 #[cfg_attr(feature = "async", async_trait(?Send))]
 pub trait QueryTrait<N: Network> {
@@ -30,3 +34,15 @@ pub trait QueryTrait<N: Network> {
     #[cfg(feature = "async")]
     async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>>;
 }
+
+/// A query backend, which can also look up deployed programs.
+#[cfg(feature = "query")]
+#[cfg_attr(feature = "async", async_trait(?Send))]
+pub trait ProgramQueryTrait<N: Network>: QueryTrait<N> {
+    /// Returns the program for the given program ID.
+    fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>>;
+
+    /// Returns the program for the given program ID.
+    #[cfg(feature = "async")]
+    async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>>;
+}
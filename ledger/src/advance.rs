@@ -92,9 +92,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Drop the write lock on the current block.
         drop(current_block);
 
-        // Update the cached committee from storage.
+        // Update the cached committee from storage, retaining the previous committee.
+        let mut previous_committee = None;
         if let Ok(current_committee) = self.vm.finalize_store().committee_store().current_committee() {
-            *self.current_committee.write() = Some(current_committee);
+            previous_committee = self.current_committee.write().replace(current_committee);
         }
 
         // If the block is the start of a new epoch, or the epoch challenge has not been set, update the current epoch challenge.
@@ -103,8 +104,119 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(block.height()).ok());
         }
 
+        // Emit the ledger events for the block.
+        self.emit_events(block, previous_committee);
+
         Ok(())
     }
+
+    /// Delivers the ledger events for the given block to the registered sinks.
+    /// The committee is reported as changed if it differs from the given previous committee.
+    fn emit_events(&self, block: &Block<N>, previous_committee: Option<Committee<N>>) {
+        // Return early if there are no event sinks.
+        if self.event_sinks.read().is_empty() {
+            return;
+        }
+
+        // Prepare the events.
+        let events = match LedgerEvent::from_block(block, self.changed_committee(previous_committee).as_ref()) {
+            Ok(events) => events,
+            Err(error) => {
+                warn!("Failed to prepare the ledger events for block {} - {error}", block.height());
+                return;
+            }
+        };
+        // Deliver the events.
+        self.deliver_events(block.height(), &events);
+    }
+
+    /// Delivers the ledger events for the given reverted blocks, as (`height`, `block hash`), to the registered sinks.
+    /// The committee is reported as changed if it differs from the given previous committee.
+    fn emit_revert_events(&self, reverted_blocks: &[(u32, N::BlockHash)], previous_committee: Option<Committee<N>>) {
+        // Return early if there are no event sinks.
+        if self.event_sinks.read().is_empty() {
+            return;
+        }
+
+        // Prepare the events, in the order the blocks were reverted.
+        let mut events = reverted_blocks
+            .iter()
+            .map(|(height, block_hash)| LedgerEvent::BlockReverted(*height, *block_hash))
+            .collect::<Vec<_>>();
+        // Append the committee change, if one occurred.
+        let height = self.latest_height();
+        if let Some(committee) = self.changed_committee(previous_committee) {
+            events.push(LedgerEvent::CommitteeChanged(height, committee));
+        }
+        // Deliver the events.
+        self.deliver_events(height, &events);
+    }
+
+    /// Returns the current committee, if it differs from the given previous committee.
+    /// Note: The starting round is not compared, as it does not change the members or their stake.
+    fn changed_committee(&self, previous_committee: Option<Committee<N>>) -> Option<Committee<N>> {
+        let current_committee = self.current_committee.read().clone();
+        current_committee.filter(|current| match &previous_committee {
+            Some(previous) => previous.members() != current.members(),
+            None => true,
+        })
+    }
+
+    /// Delivers the given events to each registered sink.
+    fn deliver_events(&self, height: u32, events: &[LedgerEvent<N>]) {
+        // Retrieve the event sinks.
+        let event_sinks = self.event_sinks.read().clone();
+        // Deliver the events to each sink.
+        for sink in event_sinks {
+            if let Err(error) = sink.send(events) {
+                warn!("Failed to deliver the ledger events for block {height} - {error}");
+            }
+        }
+    }
+
+    /// Reverts the ledger to the given block height, removing every subsequent block,
+    /// along with its transactions, transitions, and finalize operations.
+    /// Only the blocks within the revert depth of the storage can be reverted.
+    pub fn revert_to_height(&self, height: u32) -> Result<()> {
+        // Acquire the write lock on the current block.
+        let mut current_block = self.current_block.write();
+        // Ensure the given height is not ahead of the current block.
+        ensure!(height <= current_block.height(), "Cannot revert to a future block height {height}");
+        // Retrieve the blocks to revert, as (`height`, `block hash`), in descending order of height.
+        let reverted_blocks = (height.saturating_add(1)..=current_block.height())
+            .rev()
+            .map(|block_height| Ok((block_height, self.get_hash(block_height)?)))
+            .collect::<Result<Vec<_>>>()?;
+        // Update the VM.
+        let revert_result = self.vm.revert_to_height(height);
+        // Retrieve the latest block height from storage.
+        // Note: If the revert failed partway, only some of the blocks were removed, so the ledger is
+        // updated to the latest block in storage, instead of the given height.
+        let latest_height = match self.vm.block_store().heights().max() {
+            Some(latest_height) => *latest_height,
+            None => bail!("Failed to revert to block {height} - the block store is empty"),
+        };
+        // Update the current block.
+        *current_block = self.get_block(latest_height)?;
+        // Drop the write lock on the current block.
+        drop(current_block);
+
+        // Update the cached committee from storage, retaining the previous committee.
+        let current_committee = self.vm.finalize_store().committee_store().current_committee().ok();
+        let previous_committee = std::mem::replace(&mut *self.current_committee.write(), current_committee);
+        // Update the current epoch challenge.
+        self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(latest_height).ok());
+
+        // Emit the ledger events for the reverted blocks.
+        let reverted_blocks =
+            reverted_blocks.into_iter().filter(|(block_height, _)| *block_height > latest_height).collect::<Vec<_>>();
+        if !reverted_blocks.is_empty() {
+            self.emit_revert_events(&reverted_blocks, previous_committee);
+        }
+
+        // Return the result of the revert.
+        revert_result
+    }
 }
 
 /// Splits candidate solutions into a collection of accepted ones and aborted ones.
@@ -271,6 +383,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             next_round,
             next_height,
+            next_timestamp,
             next_cumulative_weight,
             next_cumulative_proof_target,
             previous_block.hash(),
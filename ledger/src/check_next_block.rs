@@ -14,8 +14,6 @@
 
 use super::*;
 
-use rand::{rngs::StdRng, SeedableRng};
-
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
     pub fn check_next_block<R: CryptoRng + Rng>(&self, block: &Block<N>, rng: &mut R) -> Result<()> {
@@ -41,12 +39,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
 
         // Ensure each transaction is well-formed and unique.
-        let transactions = block.transactions();
-        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
-        cfg_iter!(transactions).zip(rngs).try_for_each(|(transaction, mut rng)| {
-            self.check_transaction_basic(transaction, transaction.to_rejected_id()?, &mut rng)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-        })?;
+        // Note: The proofs and certificates of all of the transactions are checked with a single pairing product.
+        let transactions = block
+            .transactions()
+            .iter()
+            .map(|transaction| Ok((transaction.deref(), transaction.to_rejected_id()?)))
+            .collect::<Result<Vec<_>>>()?;
+        self.vm
+            .check_transactions(&transactions, rng)
+            .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))?;
 
         // TODO (howardwu): Remove this after moving the total supply into credits.aleo.
         {
@@ -75,6 +76,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -0,0 +1,280 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Ledger;
+use console::{
+    account::{Address, PrivateKey},
+    network::prelude::*,
+};
+use ledger_block::{Block, Transaction};
+use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
+use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
+use synthesizer::vm::VM;
+
+use aleo_std::StorageMode;
+use anyhow::Result;
+use indexmap::IndexMap;
+use parking_lot::Mutex;
+use std::{sync::Arc, thread::JoinHandle, time::Duration};
+
+/// The minimum number of committee members, including the block producer.
+const NUM_COMMITTEE_MEMBERS: usize = 4;
+
+/// The configuration of a local development network.
+#[derive(Clone)]
+pub struct DevnetConfig<N: Network> {
+    /// The private key of the block producer, which receives the remaining supply.
+    private_key: PrivateKey<N>,
+    /// The committee members besides the block producer, with their stake in microcredits.
+    validators: IndexMap<Address<N>, u64>,
+    /// The funded accounts, with their public balance in microcredits.
+    balances: IndexMap<Address<N>, u64>,
+    /// Whether the genesis transactions are proven, and the submitted transactions are verified.
+    prove: bool,
+}
+
+impl<N: Network> DevnetConfig<N> {
+    /// Initializes a new configuration, for the given block producer.
+    pub fn new(private_key: PrivateKey<N>) -> Self {
+        Self { private_key, validators: IndexMap::new(), balances: IndexMap::new(), prove: true }
+    }
+
+    /// Adds the given committee member, with the given stake in microcredits.
+    pub fn with_validator(mut self, address: Address<N>, stake: u64) -> Self {
+        self.validators.insert(address, stake);
+        self
+    }
+
+    /// Funds the given account, with the given public balance in microcredits.
+    pub fn with_balance(mut self, address: Address<N>, balance: u64) -> Self {
+        self.balances.insert(address, balance);
+        self
+    }
+
+    /// Sets whether the genesis transactions are proven, and the submitted transactions are verified.
+    pub fn with_proofs(mut self, prove: bool) -> Self {
+        self.prove = prove;
+        self
+    }
+
+    /// Returns the private key of the block producer.
+    pub const fn private_key(&self) -> &PrivateKey<N> {
+        &self.private_key
+    }
+
+    /// Returns `true` if the genesis transactions are proven, and the submitted transactions are verified.
+    pub const fn prove(&self) -> bool {
+        self.prove
+    }
+}
+
+/// An in-memory development network, with a single block producer and a memory pool.
+pub struct Devnet<N: Network> {
+    /// The ledger.
+    ledger: Ledger<N, ConsensusMemory<N>>,
+    /// The private key of the block producer.
+    private_key: PrivateKey<N>,
+    /// Whether the submitted transactions are verified.
+    prove: bool,
+    /// The memory pool of unconfirmed transactions.
+    mempool: Mutex<IndexMap<N::TransactionID, Transaction<N>>>,
+    /// The lock to produce one block at a time.
+    block_lock: Mutex<()>,
+}
+
+impl<N: Network> Devnet<N> {
+    /// Initializes a new development network, from a genesis block for the given configuration.
+    pub fn new<R: Rng + CryptoRng>(config: DevnetConfig<N>, rng: &mut R) -> Result<Self> {
+        let DevnetConfig { private_key, validators: members, balances, prove } = config;
+        let producer = Address::try_from(&private_key)?;
+
+        // Construct the committee, which includes the block producer, and is padded to the minimum size.
+        let mut validators = IndexMap::from([(producer, MIN_VALIDATOR_STAKE)]);
+        validators.extend(members);
+        while validators.len() < NUM_COMMITTEE_MEMBERS {
+            validators.insert(Address::try_from(PrivateKey::<N>::new(rng)?)?, MIN_VALIDATOR_STAKE);
+        }
+        let committee = Committee::new_genesis(validators.into_iter().map(|(a, stake)| (a, (stake, true))).collect())?;
+
+        // Compute the remaining supply, for the block producer.
+        let funded_supply = balances
+            .values()
+            .try_fold(committee.total_stake(), |acc, balance| acc.checked_add(*balance))
+            .ok_or_else(|| anyhow!("The devnet supply overflowed"))?;
+        let remaining_supply = N::STARTING_SUPPLY
+            .checked_sub(funded_supply)
+            .ok_or_else(|| anyhow!("The stake and balances exceed the starting supply of {}", N::STARTING_SUPPLY))?;
+        // Ensure the block producer can fund the genesis transactions.
+        let genesis_amount = MIN_VALIDATOR_STAKE * Block::<N>::NUM_GENESIS_TRANSACTIONS as u64;
+        ensure!(
+            remaining_supply.saturating_add(*balances.get(&producer).unwrap_or(&0)) >= genesis_amount,
+            "The block producer must retain at least {genesis_amount} microcredits for the genesis transactions"
+        );
+        // Construct the public balances.
+        let mut public_balances = balances;
+        *public_balances.entry(producer).or_default() += remaining_supply;
+
+        // Initialize the genesis block.
+        let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+        let genesis = match prove {
+            true => vm.genesis_quorum(&private_key, committee, public_balances, rng)?,
+            false => vm.genesis_quorum_unproven(&private_key, committee, public_balances, rng)?,
+        };
+        // Initialize the ledger.
+        let ledger = Ledger::load(genesis, StorageMode::Production)?;
+
+        Ok(Self { ledger, private_key, prove, mempool: Default::default(), block_lock: Default::default() })
+    }
+
+    /// Returns the ledger.
+    pub const fn ledger(&self) -> &Ledger<N, ConsensusMemory<N>> {
+        &self.ledger
+    }
+
+    /// Returns the address of the block producer.
+    pub fn producer(&self) -> Result<Address<N>> {
+        Address::try_from(&self.private_key)
+    }
+
+    /// Returns `true` if the submitted transactions are verified.
+    pub const fn prove(&self) -> bool {
+        self.prove
+    }
+
+    /// Returns the unconfirmed transactions in the memory pool.
+    pub fn mempool(&self) -> Vec<Transaction<N>> {
+        self.mempool.lock().values().cloned().collect()
+    }
+
+    /// Adds the given transaction to the memory pool, after verifying it (if proofs are enabled).
+    pub fn submit_transaction<R: Rng + CryptoRng>(
+        &self,
+        transaction: Transaction<N>,
+        rng: &mut R,
+    ) -> Result<N::TransactionID> {
+        let transaction_id = transaction.id();
+        // Ensure the transaction is not already in the memory pool or the ledger.
+        ensure!(
+            !self.mempool.lock().contains_key(&transaction_id),
+            "Transaction '{transaction_id}' is already pending"
+        );
+        ensure!(
+            !self.ledger.contains_transaction_id(&transaction_id)?,
+            "Transaction '{transaction_id}' already exists in the ledger"
+        );
+        // Verify the transaction.
+        if self.prove {
+            self.ledger.check_transaction_basic(&transaction, None, rng)?;
+        }
+        self.mempool.lock().insert(transaction_id, transaction);
+        Ok(transaction_id)
+    }
+
+    /// Produces the next block, from the transactions in the memory pool, and adds it to the ledger.
+    pub fn advance<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Block<N>> {
+        // Acquire the block lock, to produce one block at a time.
+        let _block_lock = self.block_lock.lock();
+        // Take the transactions from the memory pool.
+        let transactions = std::mem::take(&mut *self.mempool.lock()).into_values().collect::<Vec<_>>();
+
+        // Construct the next block.
+        let block =
+            self.ledger.prepare_advance_to_next_beacon_block(&self.private_key, vec![], vec![], transactions, rng)?;
+        // Add the block to the ledger.
+        self.ledger.advance_to_next_block(&block)?;
+        Ok(block)
+    }
+
+    /// Spawns a thread, which produces the next block on every interval.
+    pub fn spawn_block_producer(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let devnet = self.clone();
+        std::thread::spawn(move || loop {
+            std::thread::sleep(interval);
+            if let Err(error) = devnet.advance(&mut rand::thread_rng()) {
+                warn!("Failed to produce the next devnet block - {error}");
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        network::Testnet3,
+        program::{Identifier, Literal, Plaintext, ProgramID, Value},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_devnet_without_proofs() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the devnet, with a funded account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let recipient = Address::try_from(&recipient_private_key).unwrap();
+        let config = DevnetConfig::new(private_key).with_balance(recipient, 1_000_000).with_proofs(false);
+        let devnet = Devnet::new(config, rng).unwrap();
+        assert_eq!(devnet.ledger().latest_height(), 0);
+        assert_eq!(devnet.ledger().latest_committee().unwrap().members().len(), NUM_COMMITTEE_MEMBERS);
+
+        // Ensure the funded account has its balance.
+        let credits = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+        let account = Identifier::from_str("account").unwrap();
+        let balance = |address: Address<CurrentNetwork>| {
+            devnet
+                .ledger()
+                .vm()
+                .finalize_store()
+                .get_value_confirmed(credits, account, &Plaintext::from(Literal::Address(address)))
+                .unwrap()
+        };
+        assert_eq!(balance(recipient), Some(Value::from_str("1_000_000u64").unwrap()));
+
+        // Submit an unproven transfer, and advance the devnet.
+        let authorization = devnet
+            .ledger()
+            .vm()
+            .authorize(
+                &recipient_private_key,
+                "credits.aleo",
+                "transfer_public",
+                [devnet.producer().unwrap().to_string(), "1_000u64".to_string()].iter(),
+                rng,
+            )
+            .unwrap();
+        let execution = ledger_block::Execution::from(
+            authorization.transitions().into_values(),
+            devnet.ledger().latest_state_root(),
+            None,
+        )
+        .unwrap();
+        let transaction = Transaction::from_execution(execution, None).unwrap();
+        let transaction_id = devnet.submit_transaction(transaction.clone(), rng).unwrap();
+        assert_eq!(devnet.mempool().len(), 1);
+        assert!(devnet.submit_transaction(transaction, rng).is_err());
+
+        let block = devnet.advance(rng).unwrap();
+        assert_eq!(block.height(), 1);
+        assert!(block.transactions().get(&transaction_id).is_some());
+        assert!(devnet.mempool().is_empty());
+        assert_eq!(balance(recipient), Some(Value::from_str("999_000u64").unwrap()));
+
+        // Advance the devnet with an empty block.
+        assert_eq!(devnet.advance(rng).unwrap().height(), 2);
+        assert_eq!(devnet.ledger().latest_height(), 2);
+    }
+}
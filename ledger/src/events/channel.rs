@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use parking_lot::Mutex;
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+/// A sink that delivers ledger events to an in-process channel.
+pub struct ChannelSink<N: Network> {
+    /// The sending half of the channel.
+    sender: Mutex<Sender<LedgerEvent<N>>>,
+}
+
+impl<N: Network> ChannelSink<N> {
+    /// Initializes a new channel sink, returning the sink and the receiving half of the channel.
+    pub fn new() -> (Self, Receiver<LedgerEvent<N>>) {
+        let (sender, receiver) = channel();
+        (Self { sender: Mutex::new(sender) }, receiver)
+    }
+}
+
+impl<N: Network> LedgerEventSink<N> for ChannelSink<N> {
+    /// Delivers the given events to the channel.
+    fn send(&self, events: &[LedgerEvent<N>]) -> Result<()> {
+        let sender = self.sender.lock();
+        for event in events {
+            if sender.send(event.clone()).is_err() {
+                bail!("Failed to send a ledger event - the receiver was dropped")
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,594 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use parking_lot::Mutex;
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    sync::mpsc::{sync_channel, SyncSender, TrySendError},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The Kafka API key for a produce request.
+const PRODUCE_API_KEY: i16 = 0;
+/// The Kafka API version for a produce request (the first version to carry v2 record batches).
+const PRODUCE_API_VERSION: i16 = 3;
+/// The record batch format version.
+const RECORD_BATCH_MAGIC: i8 = 2;
+/// The maximum size of a produce response, in bytes.
+const MAX_RESPONSE_SIZE: usize = 1 << 20;
+/// The maximum delay between attempts to publish a record batch.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// A Kafka record, as (`key`, `value`, `type`).
+type KafkaRecord = (Vec<u8>, Vec<u8>, &'static str);
+
+/// A sink that publishes ledger events to a topic partition of a Kafka broker.
+///
+/// Each call to `send` enqueues a single record batch, with one record per event, and returns immediately.
+/// A background thread publishes the record batches in order, and retries a failed record batch with an
+/// exponential backoff until it is published. If the queue is full, `send` returns an error.
+/// The record key is the block height, the record value is the JSON-encoded event,
+/// and the record carries a `type` header with the name of the event.
+pub struct KafkaSink {
+    /// The producer, which is cloned into the background thread.
+    producer: KafkaProducer,
+    /// The delay before the first retry of a failed record batch.
+    retry_delay: Duration,
+    /// The maximum number of record batches waiting to be published.
+    queue_capacity: usize,
+    /// The queue of record batches, which starts the background thread on the first call to `send`.
+    queue: Mutex<Option<SyncSender<Vec<KafkaRecord>>>>,
+}
+
+impl KafkaSink {
+    /// Initializes a new Kafka sink for the given broker address and topic.
+    pub fn new(address: impl Into<String>, topic: impl Into<String>) -> Self {
+        Self {
+            producer: KafkaProducer {
+                address: address.into(),
+                topic: topic.into(),
+                partition: 0,
+                client_id: "snarkvm".to_string(),
+                acks: 1,
+                timeout: Duration::from_secs(10),
+            },
+            retry_delay: Duration::from_secs(1),
+            queue_capacity: 1024,
+            queue: Default::default(),
+        }
+    }
+
+    /// Sets the topic partition.
+    pub fn with_partition(mut self, partition: i32) -> Self {
+        self.producer.partition = partition;
+        self
+    }
+
+    /// Sets the client ID.
+    pub fn with_client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.producer.client_id = client_id.into();
+        self
+    }
+
+    /// Sets the number of acknowledgments the broker must receive (`0`, `1`, or `-1` for all replicas).
+    pub fn with_acks(mut self, acks: i16) -> Self {
+        self.producer.acks = acks;
+        self
+    }
+
+    /// Sets the network timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.producer.timeout = timeout;
+        self
+    }
+
+    /// Sets the delay before the first retry of a failed record batch, which doubles on each subsequent retry.
+    pub fn with_retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Sets the maximum number of record batches waiting to be published.
+    pub fn with_queue_capacity(mut self, queue_capacity: usize) -> Self {
+        self.queue_capacity = queue_capacity;
+        self
+    }
+
+    /// Enqueues the given records to be published by the background thread, starting it if necessary.
+    fn enqueue(&self, records: Vec<KafkaRecord>) -> Result<()> {
+        let mut queue = self.queue.lock();
+        // Start the background thread, if it is not running.
+        let sender = match queue.as_ref() {
+            Some(sender) => sender,
+            None => queue.insert(self.start()?),
+        };
+        match sender.try_send(records) {
+            Ok(()) => Ok(()),
+            Err(TrySendError::Full(_)) => bail!("The queue of the Kafka sink is full"),
+            Err(TrySendError::Disconnected(_)) => {
+                // Reset the queue, so that the next call restarts the background thread.
+                *queue = None;
+                bail!("The background thread of the Kafka sink stopped")
+            }
+        }
+    }
+
+    /// Starts the background thread, which publishes the record batches from the returned queue in order.
+    fn start(&self) -> Result<SyncSender<Vec<KafkaRecord>>> {
+        let (sender, receiver) = sync_channel::<Vec<KafkaRecord>>(self.queue_capacity);
+        let producer = self.producer.clone();
+        let retry_delay = self.retry_delay;
+        thread::Builder::new().name("kafka-sink".to_string()).spawn(move || {
+            // Initialize the connection to the broker, and the correlation ID of the next request.
+            let mut stream = None;
+            let mut correlation_id = 0i32;
+            // Publish each record batch, until the sink is dropped.
+            for records in receiver {
+                let mut delay = retry_delay;
+                loop {
+                    let result = producer.produce(&mut stream, correlation_id, &records);
+                    correlation_id = correlation_id.wrapping_add(1);
+                    match result {
+                        Ok(()) => break,
+                        Err(error) => {
+                            warn!(
+                                "Failed to publish {} ledger events to Kafka, retrying in {delay:?} - {error}",
+                                records.len()
+                            );
+                            thread::sleep(delay);
+                            delay = delay.saturating_mul(2).min(MAX_RETRY_DELAY);
+                        }
+                    }
+                }
+            }
+        })?;
+        Ok(sender)
+    }
+}
+
+impl<N: Network> LedgerEventSink<N> for KafkaSink {
+    /// Enqueues the given events to be published to the broker, as a single record batch.
+    fn send(&self, events: &[LedgerEvent<N>]) -> Result<()> {
+        // Return early if there are no events.
+        if events.is_empty() {
+            return Ok(());
+        }
+        // Prepare the records.
+        let records = events
+            .iter()
+            .map(|event| Ok((event.height().to_string().into_bytes(), serde_json::to_vec(event)?, event.name())))
+            .collect::<Result<Vec<_>>>()?;
+        // Enqueue the records.
+        self.enqueue(records)
+    }
+}
+
+/// The configuration for publishing record batches to a topic partition of a Kafka broker.
+#[derive(Clone)]
+struct KafkaProducer {
+    /// The broker address, as `host:port`.
+    address: String,
+    /// The topic name.
+    topic: String,
+    /// The topic partition.
+    partition: i32,
+    /// The client ID.
+    client_id: String,
+    /// The number of acknowledgments the broker must receive (`0`, `1`, or `-1` for all replicas).
+    acks: i16,
+    /// The network timeout.
+    timeout: Duration,
+}
+
+impl KafkaProducer {
+    /// Publishes the given records to the broker, over the given connection, opening it if necessary.
+    fn produce(&self, stream: &mut Option<TcpStream>, correlation_id: i32, records: &[KafkaRecord]) -> Result<()> {
+        // Prepare the request.
+        let request = self.encode_produce_request(correlation_id, records)?;
+
+        // Open the connection, if necessary.
+        if stream.is_none() {
+            let connection = TcpStream::connect(&self.address)?;
+            connection.set_read_timeout(Some(self.timeout))?;
+            connection.set_write_timeout(Some(self.timeout))?;
+            connection.set_nodelay(true)?;
+            *stream = Some(connection);
+        }
+        let connection = stream.as_mut().ok_or_else(|| anyhow!("Missing the connection to the Kafka broker"))?;
+
+        // Send the request, and await the response.
+        let result = (|| {
+            connection.write_all(&request)?;
+            connection.flush()?;
+            // The broker does not respond when no acknowledgments are requested.
+            if self.acks == 0 {
+                return Ok(());
+            }
+            // Read the response.
+            let mut size = [0u8; 4];
+            connection.read_exact(&mut size)?;
+            let size = usize::try_from(i32::from_be_bytes(size))?;
+            ensure!(size <= MAX_RESPONSE_SIZE, "The Kafka produce response is too large ({size} bytes)");
+            let mut response = vec![0u8; size];
+            connection.read_exact(&mut response)?;
+            decode_produce_response(&response, correlation_id)
+        })();
+
+        // Drop the connection on failure, so that the next request reconnects.
+        if result.is_err() {
+            *stream = None;
+        }
+        result
+    }
+
+    /// Returns the size-prefixed produce request for the given records.
+    fn encode_produce_request(&self, correlation_id: i32, records: &[KafkaRecord]) -> Result<Vec<u8>> {
+        // Prepare the record batch.
+        let record_batch = encode_record_batch(records, current_timestamp()?)?;
+
+        let mut request = Vec::with_capacity(record_batch.len() + 64);
+        // Write the request header.
+        request.extend_from_slice(&PRODUCE_API_KEY.to_be_bytes());
+        request.extend_from_slice(&PRODUCE_API_VERSION.to_be_bytes());
+        request.extend_from_slice(&correlation_id.to_be_bytes());
+        write_string(&mut request, &self.client_id)?;
+        // Write the transactional ID, which is null.
+        request.extend_from_slice(&(-1i16).to_be_bytes());
+        // Write the acks and the timeout.
+        request.extend_from_slice(&self.acks.to_be_bytes());
+        request.extend_from_slice(&i32::try_from(self.timeout.as_millis())?.to_be_bytes());
+        // Write the topic data, with a single topic and a single partition.
+        request.extend_from_slice(&1i32.to_be_bytes());
+        write_string(&mut request, &self.topic)?;
+        request.extend_from_slice(&1i32.to_be_bytes());
+        request.extend_from_slice(&self.partition.to_be_bytes());
+        request.extend_from_slice(&i32::try_from(record_batch.len())?.to_be_bytes());
+        request.extend_from_slice(&record_batch);
+
+        // Prefix the request with its size.
+        let mut message = Vec::with_capacity(request.len() + 4);
+        message.extend_from_slice(&i32::try_from(request.len())?.to_be_bytes());
+        message.extend_from_slice(&request);
+        Ok(message)
+    }
+}
+
+/// Returns the current time, in milliseconds since the Unix epoch.
+fn current_timestamp() -> Result<i64> {
+    Ok(i64::try_from(SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis())?)
+}
+
+/// Returns the v2 record batch for the given records, as (`key`, `value`, `type`).
+fn encode_record_batch(records: &[KafkaRecord], timestamp: i64) -> Result<Vec<u8>> {
+    // Write the batch body, which is covered by the checksum.
+    let mut body = Vec::new();
+    // Write the attributes, which carry no compression, no transaction, and create-time timestamps.
+    body.extend_from_slice(&0i16.to_be_bytes());
+    // Write the last offset delta.
+    body.extend_from_slice(&(i32::try_from(records.len())? - 1).to_be_bytes());
+    // Write the first and maximum timestamps.
+    body.extend_from_slice(&timestamp.to_be_bytes());
+    body.extend_from_slice(&timestamp.to_be_bytes());
+    // Write the producer ID, producer epoch, and base sequence, which are unset.
+    body.extend_from_slice(&(-1i64).to_be_bytes());
+    body.extend_from_slice(&(-1i16).to_be_bytes());
+    body.extend_from_slice(&(-1i32).to_be_bytes());
+    // Write the records.
+    body.extend_from_slice(&i32::try_from(records.len())?.to_be_bytes());
+    for (offset_delta, (key, value, kind)) in records.iter().enumerate() {
+        let mut record = Vec::with_capacity(key.len() + value.len() + 32);
+        // Write the attributes, which are unused.
+        record.push(0u8);
+        // Write the timestamp delta and the offset delta.
+        write_varint(&mut record, 0);
+        write_varint(&mut record, i64::try_from(offset_delta)?);
+        // Write the key and the value.
+        write_varint(&mut record, i64::try_from(key.len())?);
+        record.extend_from_slice(key);
+        write_varint(&mut record, i64::try_from(value.len())?);
+        record.extend_from_slice(value);
+        // Write the `type` header.
+        write_varint(&mut record, 1);
+        write_varint(&mut record, 4);
+        record.extend_from_slice(b"type");
+        write_varint(&mut record, i64::try_from(kind.len())?);
+        record.extend_from_slice(kind.as_bytes());
+        // Write the size-prefixed record.
+        write_varint(&mut body, i64::try_from(record.len())?);
+        body.extend_from_slice(&record);
+    }
+
+    let mut batch = Vec::with_capacity(body.len() + 21);
+    // Write the base offset, which is assigned by the broker.
+    batch.extend_from_slice(&0i64.to_be_bytes());
+    // Write the batch length, which counts every byte after this field.
+    batch.extend_from_slice(&i32::try_from(body.len() + 9)?.to_be_bytes());
+    // Write the partition leader epoch, which is assigned by the broker.
+    batch.extend_from_slice(&(-1i32).to_be_bytes());
+    // Write the magic byte.
+    batch.extend_from_slice(&RECORD_BATCH_MAGIC.to_be_bytes());
+    // Write the checksum of the body.
+    batch.extend_from_slice(&crc32c(&body).to_be_bytes());
+    batch.extend_from_slice(&body);
+    Ok(batch)
+}
+
+/// Checks the produce response for the given correlation ID, returning an error if the broker rejected the batch.
+fn decode_produce_response(response: &[u8], expected_correlation_id: i32) -> Result<()> {
+    let mut reader = response;
+    // Check the correlation ID.
+    let correlation_id = read_i32(&mut reader)?;
+    ensure!(
+        correlation_id == expected_correlation_id,
+        "Mismatching Kafka correlation ID (expected {expected_correlation_id}, found {correlation_id})"
+    );
+    // Check the error code of each partition.
+    for _ in 0..read_i32(&mut reader)? {
+        let topic = read_string(&mut reader)?;
+        for _ in 0..read_i32(&mut reader)? {
+            let partition = read_i32(&mut reader)?;
+            let error_code = read_i16(&mut reader)?;
+            // Read the base offset and the log append time.
+            read_bytes::<16>(&mut reader)?;
+            if error_code != 0 {
+                bail!("The Kafka broker rejected the batch for '{topic}-{partition}' (error code {error_code})")
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes the given string, prefixed with its 16-bit length.
+fn write_string(buffer: &mut Vec<u8>, string: &str) -> Result<()> {
+    buffer.extend_from_slice(&i16::try_from(string.len())?.to_be_bytes());
+    buffer.extend_from_slice(string.as_bytes());
+    Ok(())
+}
+
+/// Writes the given integer as a zigzag-encoded variable-length integer.
+#[allow(clippy::cast_possible_truncation)]
+fn write_varint(buffer: &mut Vec<u8>, value: i64) {
+    let mut value = ((value << 1) ^ (value >> 63)) as u64;
+    while value >= 0x80 {
+        buffer.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+/// Reads the next `NUM_BYTES` bytes.
+fn read_bytes<const NUM_BYTES: usize>(reader: &mut &[u8]) -> Result<[u8; NUM_BYTES]> {
+    let mut bytes = [0u8; NUM_BYTES];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Reads a big-endian 16-bit integer.
+fn read_i16(reader: &mut &[u8]) -> Result<i16> {
+    Ok(i16::from_be_bytes(read_bytes(reader)?))
+}
+
+/// Reads a big-endian 32-bit integer.
+fn read_i32(reader: &mut &[u8]) -> Result<i32> {
+    Ok(i32::from_be_bytes(read_bytes(reader)?))
+}
+
+/// Reads a string, prefixed with its 16-bit length.
+fn read_string(reader: &mut &[u8]) -> Result<String> {
+    let length = usize::try_from(read_i16(reader)?)?;
+    ensure!(length <= reader.len(), "Invalid string length in the Kafka response");
+    let (string, remaining) = reader.split_at(length);
+    *reader = remaining;
+    Ok(String::from_utf8(string.to_vec())?)
+}
+
+/// Returns the CRC-32C (Castagnoli) checksum of the given bytes.
+fn crc32c(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0x82f6_3b78 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+    use std::{net::TcpListener, thread};
+
+    type CurrentNetwork = Testnet3;
+
+    /// A decoded record, as (`key`, `value`, `type`).
+    type DecodedRecord = (Vec<u8>, Vec<u8>, Vec<u8>);
+
+    /// Reads a zigzag-encoded variable-length integer.
+    fn read_varint(reader: &mut &[u8]) -> i64 {
+        let (mut value, mut shift) = (0u64, 0);
+        loop {
+            let byte = read_bytes::<1>(reader).unwrap()[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                break;
+            }
+            shift += 7;
+        }
+        (value >> 1) as i64 ^ -((value & 1) as i64)
+    }
+
+    /// Reads a varint-prefixed byte string.
+    fn read_varint_bytes(reader: &mut &[u8]) -> Vec<u8> {
+        let length = usize::try_from(read_varint(reader)).unwrap();
+        let (bytes, remaining) = reader.split_at(length);
+        *reader = remaining;
+        bytes.to_vec()
+    }
+
+    /// Handles a single produce request as a mock broker, returning the topic and the decoded records.
+    fn handle_produce_request(stream: &mut TcpStream, error_code: i16) -> (String, Vec<DecodedRecord>) {
+        let mut size = [0u8; 4];
+        stream.read_exact(&mut size).unwrap();
+        let mut request = vec![0u8; i32::from_be_bytes(size) as usize];
+        stream.read_exact(&mut request).unwrap();
+        let mut reader = &request[..];
+
+        // Check the request header.
+        assert_eq!(read_i16(&mut reader).unwrap(), PRODUCE_API_KEY);
+        assert_eq!(read_i16(&mut reader).unwrap(), PRODUCE_API_VERSION);
+        let correlation_id = read_i32(&mut reader).unwrap();
+        assert_eq!(read_string(&mut reader).unwrap(), "snarkvm");
+        // Check the request body.
+        assert_eq!(read_i16(&mut reader).unwrap(), -1);
+        assert_eq!(read_i16(&mut reader).unwrap(), 1);
+        read_i32(&mut reader).unwrap();
+        assert_eq!(read_i32(&mut reader).unwrap(), 1);
+        let topic = read_string(&mut reader).unwrap();
+        assert_eq!(read_i32(&mut reader).unwrap(), 1);
+        assert_eq!(read_i32(&mut reader).unwrap(), 0);
+        let batch_size = read_i32(&mut reader).unwrap() as usize;
+        assert_eq!(batch_size, reader.len());
+
+        // Check the record batch header.
+        assert_eq!(i64::from_be_bytes(read_bytes(&mut reader).unwrap()), 0);
+        assert_eq!(read_i32(&mut reader).unwrap() as usize, reader.len());
+        assert_eq!(read_i32(&mut reader).unwrap(), -1);
+        assert_eq!(read_bytes::<1>(&mut reader).unwrap()[0] as i8, RECORD_BATCH_MAGIC);
+        let checksum = u32::from_be_bytes(read_bytes(&mut reader).unwrap());
+        assert_eq!(checksum, crc32c(reader));
+        assert_eq!(read_i16(&mut reader).unwrap(), 0);
+        let last_offset_delta = read_i32(&mut reader).unwrap();
+        read_bytes::<16>(&mut reader).unwrap();
+        assert_eq!(i64::from_be_bytes(read_bytes(&mut reader).unwrap()), -1);
+        assert_eq!(read_i16(&mut reader).unwrap(), -1);
+        assert_eq!(read_i32(&mut reader).unwrap(), -1);
+        let num_records = read_i32(&mut reader).unwrap();
+        assert_eq!(last_offset_delta, num_records - 1);
+
+        // Decode the records.
+        let mut records = Vec::new();
+        for offset_delta in 0..num_records {
+            let mut record = &read_varint_bytes(&mut reader)[..];
+            assert_eq!(read_bytes::<1>(&mut record).unwrap()[0], 0);
+            assert_eq!(read_varint(&mut record), 0);
+            assert_eq!(read_varint(&mut record), offset_delta as i64);
+            let key = read_varint_bytes(&mut record);
+            let value = read_varint_bytes(&mut record);
+            assert_eq!(read_varint(&mut record), 1);
+            assert_eq!(read_varint_bytes(&mut record), b"type");
+            let kind = read_varint_bytes(&mut record);
+            assert!(record.is_empty());
+            records.push((key, value, kind));
+        }
+        assert!(reader.is_empty());
+
+        // Write the response.
+        let mut response = Vec::new();
+        response.extend_from_slice(&correlation_id.to_be_bytes());
+        response.extend_from_slice(&1i32.to_be_bytes());
+        write_string(&mut response, &topic).unwrap();
+        response.extend_from_slice(&1i32.to_be_bytes());
+        response.extend_from_slice(&0i32.to_be_bytes());
+        response.extend_from_slice(&error_code.to_be_bytes());
+        response.extend_from_slice(&0i64.to_be_bytes());
+        response.extend_from_slice(&(-1i64).to_be_bytes());
+        response.extend_from_slice(&0i32.to_be_bytes());
+        stream.write_all(&i32::try_from(response.len()).unwrap().to_be_bytes()).unwrap();
+        stream.write_all(&response).unwrap();
+
+        (topic, records)
+    }
+
+    #[test]
+    fn test_crc32c() {
+        // The check value of CRC-32C.
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+        assert_eq!(crc32c(b""), 0);
+    }
+
+    #[test]
+    fn test_varint() {
+        for value in [0i64, 1, -1, 63, -64, 64, 300, -300, i32::MAX as i64, i64::MIN, i64::MAX] {
+            let mut buffer = Vec::new();
+            write_varint(&mut buffer, value);
+            assert_eq!(read_varint(&mut &buffer[..]), value);
+        }
+        // Check the zigzag encoding against the Kafka specification.
+        let mut buffer = Vec::new();
+        write_varint(&mut buffer, -1);
+        write_varint(&mut buffer, 150);
+        assert_eq!(buffer, [0x01, 0xac, 0x02]);
+    }
+
+    #[test]
+    fn test_kafka_sink() {
+        // Start the mock broker.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let broker = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let first = handle_produce_request(&mut stream, 0);
+            let rejected = handle_produce_request(&mut stream, 6);
+            // The sink reconnects after a failure, and retries the rejected record batch.
+            let (mut stream, _) = listener.accept().unwrap();
+            let retried = handle_produce_request(&mut stream, 0);
+            (first, rejected, retried)
+        });
+
+        // Prepare the events.
+        let events = vec![
+            LedgerEvent::<CurrentNetwork>::BlockAccepted(7, Default::default()),
+            LedgerEvent::SerialNumberSpent(7, Field::from_u64(5)),
+        ];
+
+        // Publish the events twice. The second record batch is rejected by the broker once, and retried.
+        let sink = KafkaSink::new(address.to_string(), "ledger-events").with_retry_delay(Duration::from_millis(10));
+        LedgerEventSink::<CurrentNetwork>::send(&sink, &events).unwrap();
+        LedgerEventSink::<CurrentNetwork>::send(&sink, &events[..1]).unwrap();
+
+        // Check the records received by the broker.
+        let ((topic, records), (_, rejected), (_, retried)) = broker.join().unwrap();
+        assert_eq!(topic, "ledger-events");
+        assert_eq!(records.len(), 2);
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(retried, rejected);
+        for ((key, value, kind), event) in records.iter().zip_eq(&events) {
+            assert_eq!(key, b"7");
+            assert_eq!(*value, serde_json::to_vec(event).unwrap());
+            assert_eq!(kind, event.name().as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_kafka_sink_queue_is_bounded() {
+        // Start a mock broker, which accepts a connection but never responds.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let broker = thread::spawn(move || listener.accept().unwrap());
+
+        // Ensure sending does not block on the broker, and fails once the queue is full.
+        let events = vec![LedgerEvent::<CurrentNetwork>::BlockAccepted(7, Default::default())];
+        let sink = KafkaSink::new(address.to_string(), "ledger-events").with_queue_capacity(1);
+        let results = (0..3).map(|_| LedgerEventSink::<CurrentNetwork>::send(&sink, &events)).collect::<Vec<_>>();
+        assert!(results[0].is_ok());
+        assert!(results[2].is_err());
+        drop(broker.join().unwrap());
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod channel;
+pub use channel::*;
+
+mod file;
+pub use file::*;
+
+mod kafka;
+pub use kafka::*;
+
+mod serialize;
+
+use console::{
+    network::prelude::*,
+    program::{Ciphertext, Record},
+    types::Field,
+};
+use ledger_block::Block;
+use ledger_committee::Committee;
+use synthesizer::program::FinalizeOperation;
+
+use anyhow::Result;
+
+/// A change to the ledger, emitted after a block is added to or reverted from the ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LedgerEvent<N: Network> {
+    /// A block was accepted, as (`height`, `block hash`).
+    BlockAccepted(u32, N::BlockHash),
+    /// A transaction was confirmed as accepted, as (`height`, `transaction ID`).
+    TransactionConfirmed(u32, N::TransactionID),
+    /// A transaction was confirmed as rejected, as (`height`, `unconfirmed transaction ID`).
+    TransactionRejected(u32, N::TransactionID),
+    /// A record was created, as (`height`, `commitment`, `record`).
+    RecordCreated(u32, Field<N>, Record<N, Ciphertext<N>>),
+    /// A serial number was spent, as (`height`, `serial number`).
+    SerialNumberSpent(u32, Field<N>),
+    /// A mapping key was inserted, updated, or removed, as (`height`, `transaction ID`, `finalize operation`).
+    MappingKeyUpdated(u32, N::TransactionID, FinalizeOperation<N>),
+    /// The committee changed, as (`height`, `committee`).
+    CommitteeChanged(u32, Committee<N>),
+    /// A block was reverted, as (`height`, `block hash`).
+    BlockReverted(u32, N::BlockHash),
+}
+
+impl<N: Network> LedgerEvent<N> {
+    /// Returns the events for the given block, in order.
+    /// If a committee is given, a `CommitteeChanged` event is appended for it.
+    pub fn from_block(block: &Block<N>, committee: Option<&Committee<N>>) -> Result<Vec<Self>> {
+        // Retrieve the block height.
+        let height = block.height();
+        // Initialize the events with the accepted block.
+        let mut events = vec![Self::BlockAccepted(height, block.hash())];
+
+        // Iterate over the confirmed transactions.
+        for confirmed in block.transactions().iter() {
+            // Retrieve the transaction ID.
+            let transaction_id = confirmed.transaction().id();
+            // Append the transaction event.
+            match confirmed.is_accepted() {
+                true => events.push(Self::TransactionConfirmed(height, transaction_id)),
+                false => events.push(Self::TransactionRejected(height, confirmed.to_unconfirmed_transaction_id()?)),
+            }
+            // Append the spent serial numbers and the created records.
+            for transition in confirmed.transaction().transitions() {
+                for serial_number in transition.serial_numbers() {
+                    events.push(Self::SerialNumberSpent(height, *serial_number));
+                }
+                for (commitment, record) in transition.records() {
+                    events.push(Self::RecordCreated(height, *commitment, record.clone()));
+                }
+            }
+            // Append the mapping key updates.
+            for operation in confirmed.finalize_operations() {
+                if matches!(
+                    operation,
+                    FinalizeOperation::InsertKeyValue(..)
+                        | FinalizeOperation::UpdateKeyValue(..)
+                        | FinalizeOperation::RemoveKeyValue(..)
+                ) {
+                    events.push(Self::MappingKeyUpdated(height, transaction_id, *operation));
+                }
+            }
+        }
+
+        // Append the committee change, if one occurred.
+        if let Some(committee) = committee {
+            events.push(Self::CommitteeChanged(height, committee.clone()));
+        }
+
+        Ok(events)
+    }
+
+    /// Returns the block height at which the event occurred.
+    pub const fn height(&self) -> u32 {
+        match self {
+            Self::BlockAccepted(height, ..)
+            | Self::TransactionConfirmed(height, ..)
+            | Self::TransactionRejected(height, ..)
+            | Self::RecordCreated(height, ..)
+            | Self::SerialNumberSpent(height, ..)
+            | Self::MappingKeyUpdated(height, ..)
+            | Self::CommitteeChanged(height, ..)
+            | Self::BlockReverted(height, ..) => *height,
+        }
+    }
+
+    /// Returns the name of the event type.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::BlockAccepted(..) => "block_accepted",
+            Self::TransactionConfirmed(..) => "transaction_confirmed",
+            Self::TransactionRejected(..) => "transaction_rejected",
+            Self::RecordCreated(..) => "record_created",
+            Self::SerialNumberSpent(..) => "serial_number_spent",
+            Self::MappingKeyUpdated(..) => "mapping_key_updated",
+            Self::CommitteeChanged(..) => "committee_changed",
+            Self::BlockReverted(..) => "block_reverted",
+        }
+    }
+}
+
+/// A destination for ledger events.
+pub trait LedgerEventSink<N: Network>: Send + Sync {
+    /// Delivers the given events, in order.
+    fn send(&self, events: &[LedgerEvent<N>]) -> Result<()>;
+}
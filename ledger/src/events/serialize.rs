@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for LedgerEvent<N> {
+    /// Serializes the ledger event to a JSON-string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::BlockAccepted(height, block_hash) | Self::BlockReverted(height, block_hash) => {
+                let mut event = serializer.serialize_struct("LedgerEvent", 3)?;
+                event.serialize_field("type", self.name())?;
+                event.serialize_field("height", height)?;
+                event.serialize_field("block_hash", block_hash)?;
+                event.end()
+            }
+            Self::TransactionConfirmed(height, transaction_id) | Self::TransactionRejected(height, transaction_id) => {
+                let mut event = serializer.serialize_struct("LedgerEvent", 3)?;
+                event.serialize_field("type", self.name())?;
+                event.serialize_field("height", height)?;
+                event.serialize_field("transaction_id", transaction_id)?;
+                event.end()
+            }
+            Self::RecordCreated(height, commitment, record) => {
+                let mut event = serializer.serialize_struct("LedgerEvent", 4)?;
+                event.serialize_field("type", self.name())?;
+                event.serialize_field("height", height)?;
+                event.serialize_field("commitment", commitment)?;
+                event.serialize_field("record", record)?;
+                event.end()
+            }
+            Self::SerialNumberSpent(height, serial_number) => {
+                let mut event = serializer.serialize_struct("LedgerEvent", 3)?;
+                event.serialize_field("type", self.name())?;
+                event.serialize_field("height", height)?;
+                event.serialize_field("serial_number", serial_number)?;
+                event.end()
+            }
+            Self::MappingKeyUpdated(height, transaction_id, operation) => {
+                let mut event = serializer.serialize_struct("LedgerEvent", 4)?;
+                event.serialize_field("type", self.name())?;
+                event.serialize_field("height", height)?;
+                event.serialize_field("transaction_id", transaction_id)?;
+                event.serialize_field("operation", operation)?;
+                event.end()
+            }
+            Self::CommitteeChanged(height, committee) => {
+                let mut event = serializer.serialize_struct("LedgerEvent", 3)?;
+                event.serialize_field("type", self.name())?;
+                event.serialize_field("height", height)?;
+                event.serialize_field("committee", committee)?;
+                event.end()
+            }
+        }
+    }
+}
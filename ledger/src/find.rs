@@ -51,12 +51,84 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.transition_store().find_transition_id(id)
     }
 
+    /// Returns the commitment for the given record `nonce`.
+    pub fn find_commitment_from_nonce(&self, nonce: &Group<N>) -> Result<Option<Field<N>>> {
+        self.vm.transition_store().find_commitment_from_nonce(nonce)
+    }
+
+    /// Returns the serial number for the given record `tag`.
+    pub fn find_serial_number_from_tag(&self, tag: &Field<N>) -> Result<Option<Field<N>>> {
+        self.vm.transition_store().find_serial_number_from_tag(tag)
+    }
+
+    /// Returns the transition IDs that publicly reference the given `address`.
+    /// Note: This method requires the secondary indexes to be enabled.
+    pub fn find_transition_ids_from_address(&self, address: &Address<N>) -> Result<Vec<N::TransitionID>> {
+        self.vm.transition_store().find_transition_ids_from_address(address)
+    }
+
+    /// Returns the transaction IDs that publicly reference the given `address`.
+    /// Note: This method requires the secondary indexes to be enabled.
+    pub fn find_transaction_ids_from_address(&self, address: &Address<N>) -> Result<Vec<N::TransactionID>> {
+        self.find_transaction_ids_from_transition_ids(self.find_transition_ids_from_address(address)?)
+    }
+
+    /// Returns the transaction IDs of the executions that called the given `program ID`.
+    /// Note: This method requires the secondary indexes to be enabled.
+    pub fn find_execution_ids_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Vec<N::TransactionID>> {
+        self.find_transaction_ids_from_transition_ids(
+            self.vm.transition_store().find_transition_ids_from_program_id(program_id)?,
+        )
+    }
+
+    /// Returns `true` if the secondary indexes are enabled.
+    pub fn indexes_enabled(&self) -> Result<bool> {
+        self.vm.transition_store().indexes_enabled()
+    }
+
+    /// Enables the secondary indexes, and builds them from the existing transitions.
+    /// If the indexes are already enabled, this method is a no-op.
+    pub fn enable_indexes(&self) -> Result<()> {
+        self.vm.transition_store().enable_indexes()
+    }
+
+    /// Rebuilds the secondary indexes from the existing transitions.
+    /// This is used to repair the indexes of an existing database.
+    pub fn rebuild_indexes(&self) -> Result<()> {
+        self.vm.transition_store().rebuild_indexes()
+    }
+
+    /// Returns the unique transaction IDs that contain the given `transition IDs`.
+    fn find_transaction_ids_from_transition_ids(
+        &self,
+        transition_ids: Vec<N::TransitionID>,
+    ) -> Result<Vec<N::TransactionID>> {
+        let mut transaction_ids = IndexSet::with_capacity(transition_ids.len());
+        for transition_id in transition_ids {
+            match self.find_transaction_id_from_transition_id(&transition_id)? {
+                Some(transaction_id) => transaction_ids.insert(transaction_id),
+                None => bail!("Failed to find the transaction ID for transition '{transition_id}'"),
+            };
+        }
+        Ok(transaction_ids.into_iter().collect())
+    }
+
     /// Returns the record ciphertexts that belong to the given view key.
     pub fn find_record_ciphertexts<'a>(
         &'a self,
         view_key: &'a ViewKey<N>,
         filter: RecordsFilter<N>,
     ) -> Result<impl '_ + Iterator<Item = (Field<N>, Cow<'_, Record<N, Ciphertext<N>>>)>> {
+        self.filter_record_ciphertexts(view_key, filter, self.records())
+    }
+
+    /// Returns the given record ciphertexts, as `(commitment, record)`, that belong to the given view key.
+    pub(crate) fn filter_record_ciphertexts<'a>(
+        &'a self,
+        view_key: &'a ViewKey<N>,
+        filter: RecordsFilter<N>,
+        records: impl 'a + Iterator<Item = (Cow<'a, Field<N>>, Cow<'a, Record<N, Ciphertext<N>>>)>,
+    ) -> Result<impl 'a + Iterator<Item = (Field<N>, Cow<'a, Record<N, Ciphertext<N>>>)>> {
         // Derive the x-coordinate of the address corresponding to the given view key.
         let address_x_coordinate = view_key.to_address().to_x_coordinate();
         // Derive the `sk_tag` from the graph key.
@@ -65,7 +137,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
         };
 
-        Ok(self.records().flat_map(move |cow| {
+        Ok(records.flat_map(move |cow| {
             // Retrieve the commitment and record.
             let (commitment, record) = match cow {
                 (Cow::Borrowed(commitment), record) => (*commitment, record),
@@ -247,6 +247,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 mod tests {
     use super::*;
     use crate::test_helpers::CurrentLedger;
+    use aleo_std::StorageMode;
     use console::network::Testnet3;
 
     type CurrentNetwork = Testnet3;
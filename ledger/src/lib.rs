@@ -31,9 +31,21 @@ pub use crate::block::*;
 #[cfg(feature = "test-helpers")]
 pub use ledger_test_helpers;
 
+mod devnet;
+pub use devnet::*;
+
+mod events;
+pub use events::*;
+
 mod helpers;
 pub use helpers::*;
 
+mod snapshot;
+pub use snapshot::*;
+
+mod scanner;
+pub use scanner::*;
+
 mod advance;
 mod check_next_block;
 mod check_transaction_basic;
@@ -56,19 +68,16 @@ use ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSo
 use ledger_committee::Committee;
 use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
 use ledger_query::Query;
-use ledger_store::{ConsensusStorage, ConsensusStore};
+use ledger_store::{ConsensusStorage, ConsensusStore, StorageOptions};
 use synthesizer::{
     program::{FinalizeGlobalState, Program},
     vm::VM,
 };
 
-use aleo_std::{
-    prelude::{finish, lap, timer},
-    StorageMode,
-};
+use aleo_std::prelude::{finish, lap, timer};
 use anyhow::Result;
 use core::ops::Range;
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use rand::{prelude::IteratorRandom, rngs::OsRng};
 use std::{borrow::Cow, sync::Arc};
@@ -107,17 +116,19 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The ledger event sinks.
+    event_sinks: Arc<RwLock<Vec<Arc<dyn LedgerEventSink<N>>>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
-    /// Loads the ledger from storage.
-    pub fn load(genesis_block: Block<N>, storage_mode: StorageMode) -> Result<Self> {
+    /// Loads the ledger from storage, with the given storage mode or storage options.
+    pub fn load<S: Clone + Into<StorageOptions>>(genesis_block: Block<N>, storage: S) -> Result<Self> {
         let timer = timer!("Ledger::load");
 
         // Retrieve the genesis hash.
         let genesis_hash = genesis_block.hash();
         // Initialize the ledger.
-        let ledger = Self::load_unchecked(genesis_block, storage_mode)?;
+        let ledger = Self::load_unchecked(genesis_block, storage)?;
 
         // Ensure the ledger contains the correct genesis block.
         if !ledger.contains_block_hash(&genesis_hash)? {
@@ -133,7 +144,12 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let block_heights: Vec<u32> =
             (0..=latest_height).choose_multiple(&mut OsRng, (latest_height as usize).min(NUM_BLOCKS));
         cfg_into_iter!(block_heights).try_for_each(|height| {
-            ledger.get_block(height)?;
+            // If the block has been pruned, only check its header.
+            if ledger.vm.block_store().is_block_pruned(height)? {
+                ledger.get_header(height)?;
+            } else {
+                ledger.get_block(height)?;
+            }
             Ok::<_, Error>(())
         })?;
         lap!(timer, "Check existence of {NUM_BLOCKS} random blocks");
@@ -143,17 +159,28 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     }
 
     /// Loads the ledger from storage, without performing integrity checks.
-    pub fn load_unchecked(genesis_block: Block<N>, storage_mode: StorageMode) -> Result<Self> {
+    pub fn load_unchecked<S: Clone + Into<StorageOptions>>(genesis_block: Block<N>, storage: S) -> Result<Self> {
         let timer = timer!("Ledger::load_unchecked");
 
         info!("Loading the ledger from storage...");
         // Initialize the consensus store.
-        let store = match ConsensusStore::<N, C>::open(storage_mode) {
+        let store = match ConsensusStore::<N, C>::open(storage) {
             Ok(store) => store,
             Err(e) => bail!("Failed to load ledger (run 'snarkos clean' and try again)\n\n{e}\n"),
         };
         lap!(timer, "Load consensus store");
 
+        // Initialize the ledger.
+        let ledger = Self::from_store_unchecked(genesis_block, store)?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+
+    /// Initializes the ledger from the given consensus store, without performing integrity checks.
+    fn from_store_unchecked(genesis_block: Block<N>, store: ConsensusStore<N, C>) -> Result<Self> {
+        let timer = timer!("Ledger::from_store_unchecked");
+
         // Initialize a new VM.
         let vm = VM::from(store)?;
         lap!(timer, "Initialize a new VM");
@@ -169,6 +196,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             current_epoch_challenge: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            event_sinks: Default::default(),
         };
 
         // If the block store is empty, initialize the genesis block.
@@ -193,7 +221,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Set the current epoch challenge.
         ledger.current_epoch_challenge = Arc::new(RwLock::new(Some(ledger.get_epoch_challenge(latest_height)?)));
 
-        finish!(timer, "Initialize ledger");
+        finish!(timer, "Initialize ledger from store");
         Ok(ledger)
     }
 
@@ -207,6 +235,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         &self.coinbase_puzzle
     }
 
+    /// Registers a sink, which receives the ledger events of every subsequent block.
+    pub fn add_event_sink(&self, sink: Arc<dyn LedgerEventSink<N>>) {
+        self.event_sinks.write().push(sink);
+    }
+
     /// Returns the latest committee.
     pub fn latest_committee(&self) -> Result<Committee<N>> {
         match self.current_committee.read().as_ref() {
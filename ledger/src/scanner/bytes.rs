@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ScannerCheckpoint<N> {
+    /// Reads the scanner checkpoint from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != CHECKPOINT_VERSION {
+            return Err(error("Invalid scanner checkpoint version"));
+        }
+        // Read the address.
+        let address = Address::read_le(&mut reader)?;
+        // Read the last scanned block.
+        let last_scanned = match bool::read_le(&mut reader)? {
+            true => Some((u32::read_le(&mut reader)?, N::BlockHash::read_le(&mut reader)?)),
+            false => None,
+        };
+        // Read the number of unspent records.
+        let num_records = u32::read_le(&mut reader)?;
+        // Read the unspent records, along with their tags.
+        let mut unspent_records = IndexMap::with_capacity(num_records as usize);
+        let mut unspent_tags = IndexMap::with_capacity(num_records as usize);
+        for _ in 0..num_records {
+            let tag = Field::read_le(&mut reader)?;
+            let commitment = Field::read_le(&mut reader)?;
+            let record = Record::read_le(&mut reader)?;
+            unspent_tags.insert(tag, commitment);
+            unspent_records.insert(commitment, record);
+        }
+        // Return the scanner checkpoint.
+        Ok(Self { address, last_scanned, unspent_records, unspent_tags })
+    }
+}
+
+impl<N: Network> ToBytes for ScannerCheckpoint<N> {
+    /// Writes the scanner checkpoint to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        CHECKPOINT_VERSION.write_le(&mut writer)?;
+        // Write the address.
+        self.address.write_le(&mut writer)?;
+        // Write the last scanned block.
+        match &self.last_scanned {
+            Some((height, hash)) => {
+                true.write_le(&mut writer)?;
+                height.write_le(&mut writer)?;
+                hash.write_le(&mut writer)?;
+            }
+            None => false.write_le(&mut writer)?,
+        }
+        // Write the number of unspent records.
+        u32::try_from(self.unspent_tags.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the unspent records, along with their tags.
+        for (tag, commitment) in &self.unspent_tags {
+            let Some(record) = self.unspent_records.get(commitment) else {
+                return Err(error(format!("Missing the unspent record '{commitment}'")));
+            };
+            tag.write_le(&mut writer)?;
+            commitment.write_le(&mut writer)?;
+            record.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,306 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use crate::{Ledger, RecordMap, RecordsFilter};
+use console::{
+    account::{Address, GraphKey, ViewKey},
+    network::prelude::*,
+    program::{Ciphertext, Plaintext, Record},
+    types::Field,
+};
+use ledger_block::{Block, Input, Output, Transition};
+use ledger_store::ConsensusStorage;
+
+use anyhow::Result;
+use core::ops::Range;
+use indexmap::IndexMap;
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The version of the record scanner checkpoint format.
+const CHECKPOINT_VERSION: u8 = 1;
+/// The number of blocks that are fetched and scanned in parallel, between two checkpoints.
+const SCAN_BATCH_SIZE: u32 = 64;
+
+/// The records that changed over a range of scanned blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanUpdate<N: Network> {
+    /// The block heights that were scanned.
+    heights: Range<u32>,
+    /// The records that were created in the scanned blocks, and are still spendable.
+    new_records: RecordMap<N>,
+    /// The records that were spent in the scanned blocks.
+    spent_records: RecordMap<N>,
+}
+
+impl<N: Network> ScanUpdate<N> {
+    /// Returns the block heights that were scanned.
+    pub fn heights(&self) -> Range<u32> {
+        self.heights.clone()
+    }
+
+    /// Returns the records that were created in the scanned blocks, and are still spendable.
+    pub const fn new_records(&self) -> &RecordMap<N> {
+        &self.new_records
+    }
+
+    /// Returns the records that were spent in the scanned blocks.
+    /// This includes the records that were both created and spent in the scanned blocks.
+    pub const fn spent_records(&self) -> &RecordMap<N> {
+        &self.spent_records
+    }
+
+    /// Returns `true` if no records changed in the scanned blocks.
+    pub fn is_empty(&self) -> bool {
+        self.new_records.is_empty() && self.spent_records.is_empty()
+    }
+}
+
+/// The persisted state of a record scanner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ScannerCheckpoint<N: Network> {
+    /// The address of the scanned account.
+    address: Address<N>,
+    /// The height and hash of the last scanned block, if any block has been scanned.
+    last_scanned: Option<(u32, N::BlockHash)>,
+    /// The spendable records of the account, as a map of `commitment` to `record`.
+    unspent_records: RecordMap<N>,
+    /// The tags of the spendable records, as a map of `tag` to `commitment`.
+    unspent_tags: IndexMap<Field<N>, Field<N>>,
+}
+
+/// A record found in a block, in the order it appears in the block.
+enum BlockRecord<N: Network> {
+    /// A record input, which spends the record with the given tag.
+    Spent(Field<N>),
+    /// A record output that belongs to the account, as `(tag, commitment, record)`.
+    Created(Field<N>, Field<N>, Record<N, Plaintext<N>>),
+}
+
+/// An incremental scanner for the records of a single account.
+///
+/// The scanner scans blocks in ascending order of height, tracks the spendable records of the account,
+/// and determines whether a record is spent using the tag derived from the graph key of the account.
+/// The records of the account are found with the record filtering of `Ledger::find_record_ciphertexts`,
+/// applied to the record outputs of each scanned block, instead of to every record in the ledger.
+/// If the scanner is opened with a checkpoint path, its state is persisted after every scanned batch,
+/// so that a restarted scanner resumes from the last scanned block.
+#[derive(Clone)]
+pub struct RecordScanner<N: Network> {
+    /// The view key of the account.
+    view_key: ViewKey<N>,
+    /// The `sk_tag` of the graph key of the account.
+    sk_tag: Field<N>,
+    /// The scanner state.
+    checkpoint: ScannerCheckpoint<N>,
+    /// The path of the checkpoint file, if the scanner state is persisted.
+    path: Option<PathBuf>,
+}
+
+impl<N: Network> RecordScanner<N> {
+    /// Initializes a new record scanner for the given view key, which starts from the genesis block.
+    pub fn new(view_key: ViewKey<N>) -> Result<Self> {
+        // Derive the `sk_tag` from the graph key.
+        let sk_tag = match GraphKey::try_from(&view_key) {
+            Ok(graph_key) => graph_key.sk_tag(),
+            Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+        };
+        // Derive the address.
+        let address = view_key.to_address();
+        Ok(Self {
+            view_key,
+            sk_tag,
+            checkpoint: ScannerCheckpoint {
+                address,
+                last_scanned: None,
+                unspent_records: IndexMap::new(),
+                unspent_tags: IndexMap::new(),
+            },
+            path: None,
+        })
+    }
+
+    /// Opens a record scanner for the given view key, which persists its state to the given checkpoint file.
+    /// If the checkpoint file exists, the scanner resumes from the last scanned block.
+    pub fn open<P: AsRef<Path>>(view_key: ViewKey<N>, path: P) -> Result<Self> {
+        let path = path.as_ref();
+        // Initialize the scanner.
+        let mut scanner = Self::new(view_key)?;
+        // Load the checkpoint, if it exists.
+        if path.exists() {
+            let checkpoint = ScannerCheckpoint::<N>::from_bytes_le(&std::fs::read(path)?)?;
+            // Ensure the checkpoint belongs to the same account.
+            ensure!(
+                checkpoint.address == scanner.checkpoint.address,
+                "The checkpoint '{}' belongs to a different account ('{}')",
+                path.display(),
+                checkpoint.address
+            );
+            scanner.checkpoint = checkpoint;
+        }
+        scanner.path = Some(path.to_path_buf());
+        Ok(scanner)
+    }
+
+    /// Returns the address of the scanned account.
+    pub const fn address(&self) -> Address<N> {
+        self.checkpoint.address
+    }
+
+    /// Returns the height of the last scanned block, if any block has been scanned.
+    pub fn last_scanned_height(&self) -> Option<u32> {
+        self.checkpoint.last_scanned.map(|(height, _)| height)
+    }
+
+    /// Returns the spendable records of the account, as of the last scanned block.
+    pub const fn unspent_records(&self) -> &RecordMap<N> {
+        &self.checkpoint.unspent_records
+    }
+
+    /// Scans the blocks up to the latest block in the given ledger, and returns the records that changed.
+    pub fn scan<C: ConsensusStorage<N>>(&mut self, ledger: &Ledger<N, C>) -> Result<ScanUpdate<N>> {
+        self.scan_to(ledger, ledger.latest_height())
+    }
+
+    /// Scans the blocks up to (and including) the given height, and returns the records that changed.
+    pub fn scan_to<C: ConsensusStorage<N>>(&mut self, ledger: &Ledger<N, C>, end_height: u32) -> Result<ScanUpdate<N>> {
+        // Ensure the end height is not beyond the latest block.
+        ensure!(
+            end_height <= ledger.latest_height(),
+            "Cannot scan to block {end_height}, as the latest block is {}",
+            ledger.latest_height()
+        );
+        // Determine the first height to scan, and ensure the last scanned block is still in the ledger.
+        let start_height = match self.checkpoint.last_scanned {
+            Some((height, hash)) => {
+                ensure!(
+                    height <= ledger.latest_height() && ledger.get_hash(height)? == hash,
+                    "The last scanned block {height} ('{hash}') is no longer in the ledger, the records must be rescanned"
+                );
+                height + 1
+            }
+            None => 0,
+        };
+
+        let mut update = ScanUpdate {
+            heights: start_height..start_height.max(end_height.saturating_add(1)),
+            new_records: IndexMap::new(),
+            spent_records: IndexMap::new(),
+        };
+
+        // Scan the blocks in batches.
+        for batch_start in update.heights.clone().step_by(SCAN_BATCH_SIZE as usize) {
+            let batch_end = batch_start.saturating_add(SCAN_BATCH_SIZE).min(update.heights.end);
+            // Retrieve the blocks.
+            let blocks = ledger.get_blocks(batch_start..batch_end)?;
+            // Find the records of each block, in parallel.
+            let block_records =
+                cfg_iter!(blocks).map(|block| self.find_block_records(ledger, block)).collect::<Result<Vec<_>>>()?;
+
+            // Apply the records of each block, in order.
+            for records in block_records {
+                for record in records {
+                    match record {
+                        BlockRecord::Spent(tag) => {
+                            if let Some(commitment) = self.checkpoint.unspent_tags.shift_remove(&tag) {
+                                if let Some(record) = self.checkpoint.unspent_records.shift_remove(&commitment) {
+                                    update.new_records.shift_remove(&commitment);
+                                    update.spent_records.insert(commitment, record);
+                                }
+                            }
+                        }
+                        BlockRecord::Created(tag, commitment, record) => {
+                            update.new_records.insert(commitment, record.clone());
+                            self.checkpoint.unspent_tags.insert(tag, commitment);
+                            self.checkpoint.unspent_records.insert(commitment, record);
+                        }
+                    }
+                }
+            }
+
+            // Update the last scanned block, and persist the checkpoint.
+            if let Some(block) = blocks.last() {
+                self.checkpoint.last_scanned = Some((block.height(), block.hash()));
+            }
+            self.save()?;
+        }
+
+        Ok(update)
+    }
+
+    /// Returns the record inputs and the owned record outputs of the given block, in order.
+    fn find_block_records<C: ConsensusStorage<N>>(
+        &self,
+        ledger: &Ledger<N, C>,
+        block: &Block<N>,
+    ) -> Result<Vec<BlockRecord<N>>> {
+        // Retrieve the record outputs of the block.
+        let outputs = block.transitions().flat_map(Transition::outputs).filter_map(|output| match output {
+            Output::Record(commitment, _, Some(record)) => Some((Cow::Borrowed(commitment), Cow::Borrowed(record))),
+            _ => None,
+        });
+        // Find the record outputs that belong to the account.
+        let mut owned_records = ledger
+            .filter_record_ciphertexts(&self.view_key, RecordsFilter::All, outputs)?
+            .collect::<IndexMap<_, _>>();
+
+        let mut records = Vec::new();
+        for transition in block.transitions() {
+            // Collect the tags of the spent records.
+            for input in transition.inputs() {
+                if let Input::Record(_, tag) = input {
+                    records.push(BlockRecord::Spent(*tag));
+                }
+            }
+            // Collect the records that belong to the account.
+            for output in transition.outputs() {
+                if let Output::Record(commitment, _, _) = output {
+                    if let Some(record) = owned_records.shift_remove(commitment) {
+                        match self.to_created_record(commitment, &record) {
+                            Ok(record) => records.push(record),
+                            Err(e) => warn!("Failed to scan the record '{commitment}' in block {}: {e}", block.height()),
+                        }
+                    }
+                }
+            }
+        }
+        Ok(records)
+    }
+
+    /// Returns the created record, for the given record that belongs to the account.
+    fn to_created_record(&self, commitment: &Field<N>, record: &Record<N, Ciphertext<N>>) -> Result<BlockRecord<N>> {
+        // Derive the tag of the record.
+        let tag = Record::<N, Plaintext<N>>::tag(self.sk_tag, *commitment)?;
+        // Decrypt the record.
+        Ok(BlockRecord::Created(tag, *commitment, record.decrypt(&self.view_key)?))
+    }
+
+    /// Persists the scanner state to the checkpoint file, if the scanner was opened with one.
+    fn save(&self) -> Result<()> {
+        if let Some(path) = &self.path {
+            // Write to a temporary file first, so that an interrupted write does not corrupt the checkpoint.
+            let temporary_path = path.with_extension("tmp");
+            std::fs::write(&temporary_path, self.checkpoint.to_bytes_le()?)?;
+            std::fs::rename(temporary_path, path)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,553 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod serialize;
+
+use crate::Ledger;
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
+};
+use ledger_block::Block;
+use ledger_committee::Committee;
+use ledger_store::{ConsensusStorage, ConsensusStore, FinalizeRevert, StorageOptions};
+use synthesizer::program::{FinalizeGlobalState, FinalizeStoreTrait};
+
+use aleo_std::prelude::{finish, lap, timer};
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+/// The version of the snapshot format.
+const SNAPSHOT_VERSION: u16 = 1;
+/// The file name of the snapshot manifest.
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+/// The file name of the blocks section, which also restores the transaction and transition stores.
+const BLOCKS_FILE_NAME: &str = "blocks.bin";
+/// The file name of the finalize section.
+const FINALIZE_FILE_NAME: &str = "finalize.bin";
+/// The file name of the committees section.
+const COMMITTEES_FILE_NAME: &str = "committees.bin";
+/// The file name of the revert section, which holds the prior state of the entries finalized at the snapshot height.
+const REVERT_FILE_NAME: &str = "revert.bin";
+
+/// The manifest of a ledger snapshot, which describes the state of the ledger at the snapshot height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotManifest<N: Network> {
+    /// The version of the snapshot format.
+    version: u16,
+    /// The network ID.
+    network: u16,
+    /// The block height of the snapshot.
+    height: u32,
+    /// The block hash at the snapshot height.
+    block_hash: N::BlockHash,
+    /// The state root at the snapshot height.
+    state_root: N::StateRoot,
+    /// The finalize root of the block at the snapshot height.
+    finalize_root: Field<N>,
+    /// The checksum of the finalize storage at the snapshot height.
+    finalize_checksum: Field<N>,
+    /// The sections of the snapshot.
+    sections: Vec<SnapshotSection>,
+}
+
+impl<N: Network> SnapshotManifest<N> {
+    /// Returns the version of the snapshot format.
+    pub const fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the network ID.
+    pub const fn network(&self) -> u16 {
+        self.network
+    }
+
+    /// Returns the block height of the snapshot.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the block hash at the snapshot height.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the state root at the snapshot height.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the finalize root of the block at the snapshot height.
+    pub const fn finalize_root(&self) -> Field<N> {
+        self.finalize_root
+    }
+
+    /// Returns the checksum of the finalize storage at the snapshot height.
+    pub const fn finalize_checksum(&self) -> Field<N> {
+        self.finalize_checksum
+    }
+
+    /// Returns the sections of the snapshot.
+    pub fn sections(&self) -> &[SnapshotSection] {
+        &self.sections
+    }
+
+    /// Returns the section with the given file name.
+    fn get_section(&self, file_name: &str) -> Result<&SnapshotSection> {
+        match self.sections.iter().find(|section| section.file_name == file_name) {
+            Some(section) => Ok(section),
+            None => bail!("The snapshot manifest is missing the '{file_name}' section"),
+        }
+    }
+}
+
+/// A file of a ledger snapshot, along with its size and SHA-256 checksum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotSection {
+    /// The file name of the section.
+    file_name: String,
+    /// The size of the section in bytes.
+    size: u64,
+    /// The SHA-256 checksum of the section, as a hex string.
+    checksum: String,
+}
+
+impl SnapshotSection {
+    /// Returns the file name of the section.
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    /// Returns the size of the section in bytes.
+    pub const fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the SHA-256 checksum of the section, as a hex string.
+    pub fn checksum(&self) -> &str {
+        &self.checksum
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Exports a snapshot of the ledger at the latest block height into the given directory,
+    /// and returns its manifest.
+    ///
+    /// The snapshot contains every block (which restores the block, transaction, and transition stores),
+    /// the finalize storage, the program editions, the committee of every block height, and the finalize revert
+    /// of the latest block. The remaining finalize revert journal and the mapping history are not included,
+    /// so an imported ledger cannot revert below the snapshot height.
+    pub fn export_snapshot<P: AsRef<Path>>(&self, directory: P) -> Result<SnapshotManifest<N>> {
+        let timer = timer!("Ledger::export_snapshot");
+
+        let directory = directory.as_ref();
+        // Ensure the directory does not already contain a snapshot.
+        ensure!(
+            !directory.join(MANIFEST_FILE_NAME).exists(),
+            "The directory '{}' already contains a snapshot",
+            directory.display()
+        );
+        std::fs::create_dir_all(directory)?;
+
+        // Retrieve the latest block.
+        let block = self.latest_block();
+        let height = block.height();
+
+        // Write the blocks, in ascending order of height.
+        let blocks = write_section(directory, BLOCKS_FILE_NAME, |writer| {
+            (height + 1).write_le(&mut *writer)?;
+            for block_height in 0..=height {
+                self.get_block(block_height)?.write_le(&mut *writer)?;
+            }
+            Ok(())
+        })?;
+        lap!(timer, "Write {} blocks", height + 1);
+
+        // Retrieve the finalize store.
+        let finalize_store = self.vm.finalize_store();
+        // Compute the checksum of the finalize storage.
+        let finalize_checksum = finalize_store.get_checksum_confirmed()?;
+
+        // Write the mappings of each program, in storage order.
+        let finalize = write_section(directory, FINALIZE_FILE_NAME, |writer| {
+            let program_ids = finalize_store.program_ids().map(|program_id| *program_id).collect::<Vec<_>>();
+            u32::try_from(program_ids.len())?.write_le(&mut *writer)?;
+            for program_id in program_ids {
+                let Some(mapping_names) = finalize_store.get_mapping_names_confirmed(&program_id)? else {
+                    bail!("Missing the mapping names for '{program_id}'")
+                };
+                program_id.write_le(&mut *writer)?;
+                u32::try_from(mapping_names.len())?.write_le(&mut *writer)?;
+                for mapping_name in mapping_names {
+                    let entries = finalize_store.get_mapping_confirmed(program_id, mapping_name)?;
+                    mapping_name.write_le(&mut *writer)?;
+                    u32::try_from(entries.len())?.write_le(&mut *writer)?;
+                    for (key, value) in entries {
+                        key.write_le(&mut *writer)?;
+                        value.write_le(&mut *writer)?;
+                    }
+                }
+            }
+            // Write the editions of each program.
+            let editions = finalize_store.editions().collect::<Vec<_>>();
+            u32::try_from(editions.len())?.write_le(&mut *writer)?;
+            for (program_id, edition, block_height) in editions {
+                program_id.write_le(&mut *writer)?;
+                edition.write_le(&mut *writer)?;
+                block_height.write_le(&mut *writer)?;
+            }
+            Ok(())
+        })?;
+        lap!(timer, "Write the finalize storage");
+
+        // Write the prior state of the entries finalized at the snapshot height.
+        let revert = write_section(directory, REVERT_FILE_NAME, |writer| {
+            let Some(revert) = finalize_store.get_revert_confirmed(height)? else {
+                bail!("Missing the finalize revert for block {height}")
+            };
+            write_revert(writer, &revert)
+        })?;
+        lap!(timer, "Write the finalize revert");
+
+        // Write the committee of each block height.
+        let committees = write_section(directory, COMMITTEES_FILE_NAME, |writer| {
+            let committee_store = finalize_store.committee_store();
+            let committees = (0..=height)
+                .filter_map(|block_height| match committee_store.get_committee(block_height) {
+                    Ok(committee) => committee.map(|committee| Ok((block_height, committee))),
+                    Err(error) => Some(Err(error)),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            u32::try_from(committees.len())?.write_le(&mut *writer)?;
+            for (block_height, committee) in committees {
+                block_height.write_le(&mut *writer)?;
+                committee.write_le(&mut *writer)?;
+            }
+            Ok(())
+        })?;
+        lap!(timer, "Write the committees");
+
+        // Ensure the ledger did not advance while the snapshot was written.
+        ensure!(
+            self.latest_hash() == block.hash(),
+            "The ledger advanced while exporting the snapshot at block {height}, please try again"
+        );
+
+        // Construct the manifest.
+        let manifest = SnapshotManifest {
+            version: SNAPSHOT_VERSION,
+            network: N::ID,
+            height,
+            block_hash: block.hash(),
+            state_root: self.latest_state_root(),
+            finalize_root: block.finalize_root(),
+            finalize_checksum,
+            sections: vec![blocks, finalize, committees, revert],
+        };
+        // Write the manifest last, so that an interrupted export is never mistaken for a snapshot.
+        std::fs::write(directory.join(MANIFEST_FILE_NAME), serde_json::to_string_pretty(&manifest)?)?;
+
+        finish!(timer, "Exported the snapshot at block {height}");
+        Ok(manifest)
+    }
+
+    /// Imports the snapshot in the given directory into empty storage, and loads the ledger from it.
+    ///
+    /// The checksum of every section is verified before it is read. The reconstructed state root
+    /// is verified against the header of every block, and against the manifest. The finalize storage
+    /// is rewound to the block before the snapshot height, and the block at the snapshot height is
+    /// finalized on top of it, which ensures the recomputed finalize root matches the block header.
+    /// The resulting finalize storage checksum is verified against the manifest.
+    /// Note: If the import fails, the storage must be cleared before trying again.
+    pub fn import_snapshot<S: Clone + Into<StorageOptions>, P: AsRef<Path>>(
+        genesis_block: Block<N>,
+        storage: S,
+        directory: P,
+    ) -> Result<Self> {
+        let timer = timer!("Ledger::import_snapshot");
+
+        let directory = directory.as_ref();
+        // Read the manifest.
+        let manifest: SnapshotManifest<N> =
+            serde_json::from_str(&std::fs::read_to_string(directory.join(MANIFEST_FILE_NAME))?)?;
+        let height = manifest.height;
+        // Ensure the manifest is compatible.
+        ensure!(manifest.version == SNAPSHOT_VERSION, "Unsupported snapshot version {}", manifest.version);
+        ensure!(manifest.network == N::ID, "The snapshot is for network {}, expected {}", manifest.network, N::ID);
+
+        // Verify the checksum of every section.
+        for file_name in [BLOCKS_FILE_NAME, FINALIZE_FILE_NAME, COMMITTEES_FILE_NAME, REVERT_FILE_NAME] {
+            verify_section(directory, manifest.get_section(file_name)?)?;
+        }
+        lap!(timer, "Verify the snapshot checksums");
+
+        // Initialize the consensus store.
+        let store = ConsensusStore::<N, C>::open(storage)?;
+        // Ensure the storage is empty.
+        ensure!(store.block_store().heights().max().is_none(), "Cannot import a snapshot into non-empty storage");
+
+        // Restore the blocks before the snapshot height, ensuring they form a chain from the genesis block.
+        // Note: The block at the snapshot height is finalized once the ledger is loaded.
+        let mut reader = BufReader::new(File::open(directory.join(BLOCKS_FILE_NAME))?);
+        let num_blocks = u32::read_le(&mut reader)?;
+        ensure!(num_blocks == height + 1, "The snapshot contains {num_blocks} blocks, expected {}", height + 1);
+        let mut previous_hash = None;
+        let mut block = None;
+        for block_height in 0..=height {
+            let next_block = Block::<N>::read_le(&mut reader)?;
+            let block = block.insert(next_block);
+            ensure!(block.height() == block_height, "Expected block {block_height}, found block {}", block.height());
+            match previous_hash {
+                // Ensure the first block is the genesis block.
+                None => ensure!(block.hash() == genesis_block.hash(), "The snapshot has an incorrect genesis block"),
+                // Ensure the block follows the previous block, and commits to the reconstructed state root.
+                Some(previous_hash) => {
+                    ensure!(
+                        block.previous_hash() == previous_hash,
+                        "Block {block_height} has an incorrect previous hash"
+                    );
+                    ensure!(
+                        block.previous_state_root() == store.block_store().current_state_root(),
+                        "Block {block_height} has an incorrect previous state root"
+                    );
+                }
+            }
+            if block_height < height {
+                store.block_store().insert(block)?;
+            }
+            previous_hash = Some(block.hash());
+        }
+        let Some(block) = block else { bail!("The snapshot does not contain block {height}") };
+        // Ensure the block at the snapshot height matches the manifest.
+        ensure!(block.hash() == manifest.block_hash, "The snapshot has an incorrect block hash at block {height}");
+        lap!(timer, "Restore {height} blocks");
+
+        // Restore the finalize storage, the editions, the committees, and the finalize revert in a single atomic batch.
+        let finalize_store = store.finalize_store();
+        finalize_store.start_atomic();
+        let result = (|| {
+            let mut reader = BufReader::new(File::open(directory.join(FINALIZE_FILE_NAME))?);
+            for _ in 0..u32::read_le(&mut reader)? {
+                let program_id = ProgramID::<N>::read_le(&mut reader)?;
+                for _ in 0..u32::read_le(&mut reader)? {
+                    let mapping_name = Identifier::<N>::read_le(&mut reader)?;
+                    finalize_store.initialize_mapping(program_id, mapping_name)?;
+                    for _ in 0..u32::read_le(&mut reader)? {
+                        let key = Plaintext::<N>::read_le(&mut reader)?;
+                        let value = Value::<N>::read_le(&mut reader)?;
+                        finalize_store.insert_key_value(program_id, mapping_name, key, value)?;
+                    }
+                }
+            }
+            for _ in 0..u32::read_le(&mut reader)? {
+                let program_id = ProgramID::<N>::read_le(&mut reader)?;
+                let edition = u16::read_le(&mut reader)?;
+                let block_height = u32::read_le(&mut reader)?;
+                finalize_store.record_edition(program_id, edition, block_height)?;
+            }
+
+            let mut reader = BufReader::new(File::open(directory.join(COMMITTEES_FILE_NAME))?);
+            for _ in 0..u32::read_le(&mut reader)? {
+                let block_height = u32::read_le(&mut reader)?;
+                let committee = Committee::<N>::read_le(&mut reader)?;
+                finalize_store.committee_store().insert(block_height, committee)?;
+            }
+
+            let mut reader = BufReader::new(File::open(directory.join(REVERT_FILE_NAME))?);
+            finalize_store.insert_revert(height, read_revert(&mut reader)?)?;
+            Ok::<_, Error>(())
+        })();
+        match result {
+            Ok(()) => finalize_store.finish_atomic()?,
+            Err(error) => {
+                finalize_store.abort_atomic();
+                return Err(error);
+            }
+        }
+        lap!(timer, "Restore the finalize storage and committees");
+
+        // Ensure the restored finalize storage matches the manifest.
+        ensure!(
+            finalize_store.get_checksum_confirmed()? == manifest.finalize_checksum,
+            "The restored finalize storage does not match the snapshot manifest"
+        );
+        // Rewind the finalize storage to the block before the snapshot height.
+        finalize_store.revert(height)?;
+        lap!(timer, "Rewind the finalize storage to block {}", height.saturating_sub(1));
+
+        // Initialize the ledger.
+        // Note: If the snapshot height is the genesis height, this finalizes the genesis block.
+        let ledger = Self::from_store_unchecked(genesis_block, store)?;
+        if height > 0 {
+            // Construct the finalize state of the block at the snapshot height.
+            let state = FinalizeGlobalState::new::<N>(
+                block.round(),
+                block.height(),
+                block.timestamp(),
+                block.cumulative_weight(),
+                block.cumulative_proof_target(),
+                block.previous_hash(),
+            )?;
+            // Recompute the finalize root of the block, by speculating over the restored finalize storage.
+            let ratified_finalize_operations =
+                ledger.vm.check_speculate(state, block.ratifications(), block.solutions(), block.transactions())?;
+            ensure!(
+                block.transactions().to_finalize_root(ratified_finalize_operations)? == block.finalize_root(),
+                "The restored finalize storage does not match the finalize root of block {height}"
+            );
+            // Finalize the block at the snapshot height.
+            ledger.advance_to_next_block(&block)?;
+        }
+        lap!(timer, "Finalize block {height}");
+
+        // Ensure the loaded ledger matches the manifest.
+        ensure!(ledger.latest_hash() == manifest.block_hash, "Failed to load the ledger at the snapshot height");
+        ensure!(
+            ledger.latest_state_root() == manifest.state_root,
+            "The reconstructed state root does not match the snapshot manifest"
+        );
+        ensure!(
+            ledger.vm.finalize_store().get_checksum_confirmed()? == manifest.finalize_checksum,
+            "The finalized storage does not match the snapshot manifest"
+        );
+
+        finish!(timer, "Imported the snapshot at block {height}");
+        Ok(ledger)
+    }
+}
+
+/// A writer that computes the size and SHA-256 checksum of the bytes written to it.
+struct ChecksumWriter<W: Write> {
+    /// The inner writer.
+    writer: W,
+    /// The hasher.
+    hasher: Sha256,
+    /// The number of bytes written.
+    size: u64,
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let num_bytes = self.writer.write(buf)?;
+        self.hasher.update(&buf[..num_bytes]);
+        self.size += num_bytes as u64;
+        Ok(num_bytes)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Writes a section with the given file name into the directory, and returns its description.
+fn write_section(
+    directory: &Path,
+    file_name: &str,
+    write: impl FnOnce(&mut ChecksumWriter<BufWriter<File>>) -> Result<()>,
+) -> Result<SnapshotSection> {
+    let file = File::create(directory.join(file_name))?;
+    let mut writer = ChecksumWriter { writer: BufWriter::new(file), hasher: Sha256::new(), size: 0 };
+    write(&mut writer)?;
+    writer.flush()?;
+    Ok(SnapshotSection {
+        file_name: file_name.to_string(),
+        size: writer.size,
+        checksum: hex::encode(writer.hasher.finalize()),
+    })
+}
+
+/// Writes the given finalize revert.
+fn write_revert<N: Network>(writer: &mut impl Write, revert: &FinalizeRevert<N>) -> Result<()> {
+    let (program_entries, key_value_entries) = revert;
+    u32::try_from(program_entries.len())?.write_le(&mut *writer)?;
+    for (program_id, mapping_names) in program_entries {
+        program_id.write_le(&mut *writer)?;
+        mapping_names.is_some().write_le(&mut *writer)?;
+        if let Some(mapping_names) = mapping_names {
+            u32::try_from(mapping_names.len())?.write_le(&mut *writer)?;
+            for mapping_name in mapping_names {
+                mapping_name.write_le(&mut *writer)?;
+            }
+        }
+    }
+    u32::try_from(key_value_entries.len())?.write_le(&mut *writer)?;
+    for ((program_id, mapping_name), key, value) in key_value_entries {
+        program_id.write_le(&mut *writer)?;
+        mapping_name.write_le(&mut *writer)?;
+        key.write_le(&mut *writer)?;
+        value.is_some().write_le(&mut *writer)?;
+        if let Some(value) = value {
+            value.write_le(&mut *writer)?;
+        }
+    }
+    Ok(())
+}
+
+/// Reads a finalize revert.
+fn read_revert<N: Network>(reader: &mut impl Read) -> Result<FinalizeRevert<N>> {
+    let mut program_entries = Vec::new();
+    for _ in 0..u32::read_le(&mut *reader)? {
+        let program_id = ProgramID::<N>::read_le(&mut *reader)?;
+        let mapping_names = match bool::read_le(&mut *reader)? {
+            true => Some(
+                (0..u32::read_le(&mut *reader)?)
+                    .map(|_| Ok(Identifier::<N>::read_le(&mut *reader)?))
+                    .collect::<Result<_>>()?,
+            ),
+            false => None,
+        };
+        program_entries.push((program_id, mapping_names));
+    }
+    let mut key_value_entries = Vec::new();
+    for _ in 0..u32::read_le(&mut *reader)? {
+        let program_id = ProgramID::<N>::read_le(&mut *reader)?;
+        let mapping_name = Identifier::<N>::read_le(&mut *reader)?;
+        let key = Plaintext::<N>::read_le(&mut *reader)?;
+        let value = match bool::read_le(&mut *reader)? {
+            true => Some(Value::<N>::read_le(&mut *reader)?),
+            false => None,
+        };
+        key_value_entries.push(((program_id, mapping_name), key, value));
+    }
+    Ok((program_entries, key_value_entries))
+}
+
+/// Ensures the size and SHA-256 checksum of the given section match its file in the directory.
+fn verify_section(directory: &Path, section: &SnapshotSection) -> Result<()> {
+    let mut reader = BufReader::new(File::open(directory.join(&section.file_name))?);
+    let mut hasher = Sha256::new();
+    let mut size = 0u64;
+    let mut buffer = vec![0u8; 1 << 16];
+    loop {
+        let num_bytes = reader.read(&mut buffer)?;
+        if num_bytes == 0 {
+            break;
+        }
+        hasher.update(&buffer[..num_bytes]);
+        size += num_bytes as u64;
+    }
+    ensure!(size == section.size, "The '{}' section has {size} bytes, expected {}", section.file_name, section.size);
+    ensure!(
+        hex::encode(hasher.finalize()) == section.checksum,
+        "The '{}' section does not match its checksum",
+        section.file_name
+    );
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for SnapshotManifest<N> {
+    /// Serializes the snapshot manifest to a JSON-string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut manifest = serializer.serialize_struct("SnapshotManifest", 8)?;
+        manifest.serialize_field("version", &self.version)?;
+        manifest.serialize_field("network", &self.network)?;
+        manifest.serialize_field("height", &self.height)?;
+        manifest.serialize_field("block_hash", &self.block_hash)?;
+        manifest.serialize_field("state_root", &self.state_root)?;
+        manifest.serialize_field("finalize_root", &self.finalize_root)?;
+        manifest.serialize_field("finalize_checksum", &self.finalize_checksum)?;
+        manifest.serialize_field("sections", &self.sections)?;
+        manifest.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for SnapshotManifest<N> {
+    /// Deserializes the snapshot manifest from a JSON-string.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut manifest = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self {
+            version: DeserializeExt::take_from_value::<D>(&mut manifest, "version")?,
+            network: DeserializeExt::take_from_value::<D>(&mut manifest, "network")?,
+            height: DeserializeExt::take_from_value::<D>(&mut manifest, "height")?,
+            block_hash: DeserializeExt::take_from_value::<D>(&mut manifest, "block_hash")?,
+            state_root: DeserializeExt::take_from_value::<D>(&mut manifest, "state_root")?,
+            finalize_root: DeserializeExt::take_from_value::<D>(&mut manifest, "finalize_root")?,
+            finalize_checksum: DeserializeExt::take_from_value::<D>(&mut manifest, "finalize_checksum")?,
+            sections: DeserializeExt::take_from_value::<D>(&mut manifest, "sections")?,
+        })
+    }
+}
+
+impl Serialize for SnapshotSection {
+    /// Serializes the snapshot section to a JSON-string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut section = serializer.serialize_struct("SnapshotSection", 3)?;
+        section.serialize_field("file_name", &self.file_name)?;
+        section.serialize_field("size", &self.size)?;
+        section.serialize_field("checksum", &self.checksum)?;
+        section.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SnapshotSection {
+    /// Deserializes the snapshot section from a JSON-string.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut section = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self {
+            file_name: DeserializeExt::take_from_value::<D>(&mut section, "file_name")?,
+            size: DeserializeExt::take_from_value::<D>(&mut section, "size")?,
+            checksum: DeserializeExt::take_from_value::<D>(&mut section, "checksum")?,
+        })
+    }
+}
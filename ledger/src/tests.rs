@@ -15,11 +15,15 @@
 use crate::{
     advance::split_candidate_solutions,
     test_helpers::{CurrentLedger, CurrentNetwork},
+    ChannelSink,
+    FileSink,
+    LedgerEvent,
+    RecordScanner,
     RecordsFilter,
 };
 use aleo_std::StorageMode;
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, ViewKey},
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
 };
@@ -1238,6 +1242,366 @@ finalize foo2:
     assert!(ledger.vm.block_store().contains_rejected_or_aborted_transaction_id(&deployment_2_id).unwrap());
 }
 
+#[test]
+fn test_revert_to_height() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve the genesis state.
+    let genesis = ledger.latest_block();
+    let genesis_checksum = ledger.vm().finalize_store().get_checksum_confirmed().unwrap();
+    let genesis_committee = ledger.latest_committee().unwrap();
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+mapping counter:
+    key as u8.public;
+    value as u8.public;
+function foo:
+    input r0 as u8.private;
+    async foo r0 into r1;
+    output r1 as {program_id}/foo.future;
+finalize foo:
+    input r0 as u8.public;
+    set r0 into counter[r0];",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let deploy_block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&deploy_block).unwrap();
+    assert!(ledger.vm().contains_program(&program_id));
+
+    // Transfer credits to a new address.
+    let recipient_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let inputs = [Value::from_str(&format!("{recipient_address}")).unwrap(), Value::from_str("185000u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+    let transfer_block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&transfer_block).unwrap();
+    assert_eq!(ledger.latest_height(), 2);
+
+    // Retrieve the account balance of the given address.
+    let balance = |address: Address<CurrentNetwork>| {
+        ledger
+            .vm()
+            .finalize_store()
+            .get_value_confirmed(
+                ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("account").unwrap(),
+                &Plaintext::from(Literal::Address(address)),
+            )
+            .unwrap()
+    };
+    assert!(balance(recipient_address).is_some());
+
+    // Ensure reverting to a future height fails.
+    assert!(ledger.revert_to_height(3).is_err());
+
+    // Revert to the deployment block.
+    ledger.revert_to_height(1).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+    assert_eq!(ledger.latest_hash(), deploy_block.hash());
+    assert!(balance(recipient_address).is_none());
+    assert!(!ledger.contains_transaction_id(&transaction_id).unwrap());
+    assert!(ledger.vm().contains_program(&program_id));
+
+    // Revert to the genesis block.
+    ledger.revert_to_height(0).unwrap();
+    assert_eq!(ledger.latest_block(), genesis);
+    assert_eq!(ledger.latest_committee().unwrap(), genesis_committee);
+    assert_eq!(ledger.vm().finalize_store().get_checksum_confirmed().unwrap(), genesis_checksum);
+    assert!(!ledger.vm().contains_program(&program_id));
+    assert!(ledger.vm().finalize_store().get_mapping_names_confirmed(&program_id).unwrap().is_none());
+    assert!(balance(address).is_some());
+
+    // Ensure the reverted blocks can be re-added.
+    ledger.advance_to_next_block(&deploy_block).unwrap();
+    ledger.advance_to_next_block(&transfer_block).unwrap();
+    assert_eq!(ledger.latest_height(), 2);
+    assert!(balance(recipient_address).is_some());
+}
+
+#[test]
+fn test_event_sinks() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Register a channel sink and a file sink.
+    let (channel_sink, receiver) = ChannelSink::new();
+    ledger.add_event_sink(std::sync::Arc::new(channel_sink));
+    let path = std::env::temp_dir().join(format!("ledger-events-{}.jsonl", rng.gen::<u64>()));
+    ledger.add_event_sink(std::sync::Arc::new(FileSink::open(&path).unwrap()));
+
+    // Transfer credits to a new address.
+    let recipient_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let inputs = [Value::from_str(&format!("{recipient_address}")).unwrap(), Value::from_str("185000u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+    let previous_committee = ledger.latest_committee().unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Check the events received by the channel.
+    let events = receiver.try_iter().collect::<Vec<_>>();
+    let committee = ledger.latest_committee().unwrap();
+    let changed_committee = (committee.members() != previous_committee.members()).then_some(committee);
+    assert_eq!(events, LedgerEvent::from_block(&block, changed_committee.as_ref()).unwrap());
+    assert_eq!(events[0], LedgerEvent::BlockAccepted(1, block.hash()));
+    assert_eq!(events[1], LedgerEvent::TransactionConfirmed(1, transaction_id));
+    assert!(
+        events.iter().any(|event| matches!(event, LedgerEvent::MappingKeyUpdated(1, id, _) if *id == transaction_id))
+    );
+    assert!(events.iter().all(|event| event.height() == 1));
+
+    // Check the events written to the file.
+    let lines = std::fs::read_to_string(&path).unwrap();
+    let lines = lines.lines().map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()).collect::<Vec<_>>();
+    assert_eq!(lines.len(), events.len());
+    for (line, event) in lines.iter().zip_eq(&events) {
+        assert_eq!(line["type"], event.name());
+        assert_eq!(line["height"], 1);
+    }
+
+    // Bond additional stake to the validator, which changes its stake but not the committee members.
+    let address = Address::try_from(&private_key).unwrap();
+    let previous_committee = ledger.latest_committee().unwrap();
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1000000000u64").unwrap()];
+    let transaction =
+        ledger.vm.execute(&private_key, ("credits.aleo", "bond_public"), inputs.iter(), None, 0, None, rng).unwrap();
+    let bond_block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&bond_block).unwrap();
+
+    // Ensure the stake change is reported as a committee change.
+    let committee = ledger.latest_committee().unwrap();
+    assert_eq!(committee.members().keys().collect::<Vec<_>>(), previous_committee.members().keys().collect::<Vec<_>>());
+    assert!(committee.get_stake(address) > previous_committee.get_stake(address));
+    let events = receiver.try_iter().collect::<Vec<_>>();
+    assert_eq!(events.last(), Some(&LedgerEvent::CommitteeChanged(2, committee)));
+
+    // Revert the bond block, and ensure the revert and the committee change are reported.
+    ledger.revert_to_height(1).unwrap();
+    let events = receiver.try_iter().collect::<Vec<_>>();
+    assert_eq!(events, vec![
+        LedgerEvent::BlockReverted(2, bond_block.hash()),
+        LedgerEvent::CommitteeChanged(1, ledger.latest_committee().unwrap())
+    ]);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_snapshot_export_import() {
+    let rng = &mut TestRng::default();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load_unchecked(genesis.clone(), StorageMode::Production).unwrap();
+
+    // Export the snapshot.
+    let directory = std::env::temp_dir().join(format!("ledger-snapshot-{}", rng.gen::<u64>()));
+    let manifest = ledger.export_snapshot(&directory).unwrap();
+    assert_eq!(manifest.height(), 0);
+    assert_eq!(manifest.block_hash(), genesis.hash());
+    assert_eq!(manifest.state_root(), ledger.latest_state_root());
+    assert_eq!(manifest.finalize_root(), genesis.finalize_root());
+    assert_eq!(manifest.sections().len(), 4);
+    // Ensure exporting into the same directory fails.
+    assert!(ledger.export_snapshot(&directory).is_err());
+
+    // Import the snapshot.
+    let imported = CurrentLedger::import_snapshot(genesis.clone(), StorageMode::Production, &directory).unwrap();
+    assert_eq!(imported.latest_block(), ledger.latest_block());
+    assert_eq!(imported.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(imported.latest_committee().unwrap(), ledger.latest_committee().unwrap());
+    assert_eq!(
+        imported.vm().finalize_store().get_checksum_confirmed().unwrap(),
+        ledger.vm().finalize_store().get_checksum_confirmed().unwrap()
+    );
+    for transaction_id in genesis.transaction_ids() {
+        assert!(imported.contains_transaction_id(transaction_id).unwrap());
+    }
+
+    // Ensure a corrupted snapshot is rejected.
+    let path = directory.join("finalize.bin");
+    let mut bytes = std::fs::read(&path).unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] ^= 1;
+    std::fs::write(&path, bytes).unwrap();
+    assert!(CurrentLedger::import_snapshot(genesis, StorageMode::Production, &directory).is_err());
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn test_snapshot_export_import_at_height() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+    let genesis = ledger.genesis_block.clone();
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+mapping counter:
+    key as u8.public;
+    value as u8.public;
+function foo:
+    input r0 as u8.private;
+    async foo r0 into r1;
+    output r1 as {program_id}/foo.future;
+finalize foo:
+    input r0 as u8.public;
+    set r0 into counter[r0];",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Execute the program, and transfer credits to a new address.
+    let recipient_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let inputs = [Value::from_str(&format!("{recipient_address}")).unwrap(), Value::from_str("185000u64").unwrap()];
+    let transactions = vec![
+        ledger
+            .vm
+            .execute(&private_key, (program_id, "foo"), [Value::from_str("7u8").unwrap()].iter(), None, 0, None, rng)
+            .unwrap(),
+        ledger
+            .vm
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+            .unwrap(),
+    ];
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], transactions, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 2);
+
+    // Export the snapshot.
+    let directory = std::env::temp_dir().join(format!("ledger-snapshot-{}", rng.gen::<u64>()));
+    let manifest = ledger.export_snapshot(&directory).unwrap();
+    assert_eq!(manifest.height(), 2);
+    assert_eq!(manifest.block_hash(), block.hash());
+    assert_eq!(manifest.sections().len(), 4);
+
+    // Import the snapshot.
+    let imported = CurrentLedger::import_snapshot(genesis.clone(), StorageMode::Production, &directory).unwrap();
+    assert_eq!(imported.latest_block(), ledger.latest_block());
+    assert_eq!(imported.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(imported.latest_committee().unwrap(), ledger.latest_committee().unwrap());
+    assert_eq!(
+        imported.vm().finalize_store().get_checksum_confirmed().unwrap(),
+        ledger.vm().finalize_store().get_checksum_confirmed().unwrap()
+    );
+    assert!(imported.vm().contains_program(&program_id));
+    let mapping_name = Identifier::from_str("counter").unwrap();
+    let key = Plaintext::from_str("7u8").unwrap();
+    assert_eq!(
+        imported.vm().finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap(),
+        Some(Value::from_str("7u8").unwrap())
+    );
+    // Ensure the imported ledger cannot revert past the snapshot height, as the snapshot has no revert
+    // journal entry for the prior blocks, and that the failed revert leaves the imported ledger unchanged.
+    assert!(imported.revert_to_height(0).is_err());
+    assert_eq!(imported.latest_height(), 2);
+    assert_eq!(imported.latest_block(), ledger.latest_block());
+    assert_eq!(*imported.vm().block_store().heights().max().unwrap(), 2);
+    assert_eq!(imported.latest_committee().unwrap(), ledger.latest_committee().unwrap());
+    assert_eq!(imported.latest_epoch_challenge().unwrap(), ledger.latest_epoch_challenge().unwrap());
+    assert!(imported.vm().finalize_store().get_revert_confirmed(2).unwrap().is_some());
+    // Ensure the imported ledger can revert the block at the snapshot height.
+    imported.revert_to_height(1).unwrap();
+    assert_eq!(imported.latest_height(), 1);
+
+    // Replace the finalize revert with an empty one, and update its section in the manifest.
+    let bytes = [0u8; 8];
+    std::fs::write(directory.join("revert.bin"), bytes).unwrap();
+    let path = directory.join("manifest.json");
+    let mut manifest = serde_json::from_str::<serde_json::Value>(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    for section in manifest["sections"].as_array_mut().unwrap() {
+        if section["file_name"] == "revert.bin" {
+            section["size"] = bytes.len().into();
+            section["checksum"] = hex::encode(<sha2::Sha256 as sha2::Digest>::digest(bytes)).into();
+        }
+    }
+    std::fs::write(&path, manifest.to_string()).unwrap();
+    // Ensure the snapshot is rejected, as the finalize storage does not match the block at the snapshot height.
+    assert!(CurrentLedger::import_snapshot(genesis, StorageMode::Production, &directory).is_err());
+    std::fs::remove_dir_all(directory).unwrap();
+}
+
+#[test]
+fn test_record_scanner() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Scan the genesis block, with a checkpoint.
+    let path = std::env::temp_dir().join(format!("record-scanner-{}.bin", rng.gen::<u64>()));
+    let mut scanner = RecordScanner::open(view_key, &path).unwrap();
+    let update = scanner.scan(&ledger).unwrap();
+    assert_eq!(update.heights(), 0..1);
+    assert_eq!(scanner.last_scanned_height(), Some(0));
+    assert!(update.spent_records().is_empty());
+
+    // Ensure the scanned records match the unspent records in the ledger.
+    let unspent = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().collect::<indexmap::IndexMap<_, _>>();
+    assert!(!unspent.is_empty());
+    assert_eq!(update.new_records(), &unspent);
+
+    // Spend a record, in a transfer to the same address.
+    let (commitment, record) = update.new_records().first().map(|(c, r)| (*c, r.clone())).unwrap();
+    let inputs =
+        [Value::Record(record), Value::from_str(&format!("{address}")).unwrap(), Value::from_str("100u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_private"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Resume the scan from the checkpoint.
+    let mut scanner = RecordScanner::open(view_key, &path).unwrap();
+    assert_eq!(scanner.last_scanned_height(), Some(0));
+    let update = scanner.scan(&ledger).unwrap();
+    assert_eq!(update.heights(), 1..2);
+    assert_eq!(update.spent_records().keys().copied().collect::<Vec<_>>(), vec![commitment]);
+    assert_eq!(update.new_records().len(), 2);
+
+    // Ensure the scanned records match the unspent records in the ledger.
+    let unspent = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().collect::<indexmap::IndexMap<_, _>>();
+    assert_eq!(scanner.unspent_records(), &unspent);
+
+    // Ensure scanning again is a no-op.
+    assert!(scanner.scan(&ledger).unwrap().is_empty());
+
+    // Ensure the checkpoint cannot be opened by a different account.
+    let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(RecordScanner::open(other_view_key, &path).is_err());
+    std::fs::remove_file(path).unwrap();
+}
+
 #[test]
 fn test_split_candidate_solutions() {
     let rng = &mut TestRng::default();
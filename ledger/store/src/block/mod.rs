@@ -62,6 +62,13 @@ pub enum ConfirmedTxType {
     RejectedExecute(u32),
 }
 
+/// The key of the pruning depth in the pruning map.
+const PRUNING_DEPTH_KEY: u8 = 0;
+/// The key of the pruned block height in the pruning map, below which all blocks have been pruned.
+const PRUNED_HEIGHT_KEY: u8 = 1;
+/// The maximum number of blocks that are pruned upon the insertion of a single block.
+const MAX_BLOCKS_PRUNED_PER_INSERT: u32 = 16;
+
 /// Separates the confirmed transaction into a tuple.
 #[allow(clippy::type_complexity)]
 fn to_confirmed_tuple<N: Network>(
@@ -202,6 +209,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of pruning configuration keys to their values.
+    type PruningMap: for<'a> Map<'a, u8, u32>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -240,6 +249,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the pruning map.
+    fn pruning_map(&self) -> &Self::PruningMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -270,6 +281,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.pruning_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -290,6 +302,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.pruning_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -310,6 +323,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.pruning_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -330,6 +344,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.pruning_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -350,6 +365,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.pruning_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -370,6 +386,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.pruning_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -390,6 +407,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.pruning_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -493,6 +511,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().insert(&transaction)?;
             }
 
+            // Prune the blocks that are now deeper than the pruning depth.
+            self.prune_blocks(block.height())?;
+
             Ok(())
         })
     }
@@ -529,14 +550,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         };
 
         // Retrieve the rejected transaction IDs, and the deployment or execution ID.
-        let rejected_transaction_ids_and_deployment_or_execution_id = match self.get_block_transactions(block_hash)? {
-            Some(transactions) => transactions
-                .iter()
-                .filter(|tx| tx.is_rejected())
-                .map(|tx| Ok((tx.to_unconfirmed_transaction_id()?, tx.to_rejected_id()?)))
-                .collect::<Result<Vec<_>>>()?,
-            None => Vec::new(),
-        };
+        let rejected_transaction_ids_and_deployment_or_execution_id =
+            match self.get_block_rejected_transactions(block_hash)? {
+                Some(transactions) => transactions
+                    .iter()
+                    .map(|tx| Ok((tx.to_unconfirmed_transaction_id()?, tx.to_rejected_id()?)))
+                    .collect::<Result<Vec<_>>>()?,
+                None => Vec::new(),
+            };
 
         // Determine the certificate IDs to remove.
         let certificate_ids_to_remove = match self.authority_map().get_confirmed(block_hash)? {
@@ -610,6 +631,104 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().remove(transaction_id)?;
             }
 
+            // Lower the pruned block height, if the removed block was pruned.
+            if self.pruned_height()? > block_height {
+                self.pruning_map().insert(PRUNED_HEIGHT_KEY, block_height)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Sets the pruning depth, or disables pruning if the given `depth` is `None`.
+    /// When enabled, the execution proofs, fee proofs, and deployment verifying keys of the accepted transactions
+    /// in blocks more than `depth` blocks below the latest block are dropped. Blocks already pruned remain pruned.
+    fn set_pruning_depth(&self, depth: Option<u32>) -> Result<()> {
+        // If the pruning depth is unchanged, return early.
+        if self.pruning_depth()? == depth {
+            return Ok(());
+        }
+        atomic_batch_scope!(self, {
+            match depth {
+                // Store the pruning depth.
+                Some(depth) => {
+                    // Ensure the pruning depth is non-zero.
+                    ensure!(depth > 0, "The pruning depth must be greater than zero");
+                    self.pruning_map().insert(PRUNING_DEPTH_KEY, depth)?
+                }
+                // Remove the pruning depth.
+                None => self.pruning_map().remove(&PRUNING_DEPTH_KEY)?,
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Returns the pruning depth, or `None` if pruning is disabled.
+    fn pruning_depth(&self) -> Result<Option<u32>> {
+        Ok(self.pruning_map().get_confirmed(&PRUNING_DEPTH_KEY)?.map(|depth| cow_to_copied!(depth)))
+    }
+
+    /// Returns the block height below which all blocks have been pruned.
+    fn pruned_height(&self) -> Result<u32> {
+        Ok(self.pruning_map().get_confirmed(&PRUNED_HEIGHT_KEY)?.map_or(0, |height| cow_to_copied!(height)))
+    }
+
+    /// Returns `true` if the block at the given `height` has been pruned.
+    fn is_block_pruned(&self, height: u32) -> Result<bool> {
+        Ok(height < self.pruned_height()?)
+    }
+
+    /// Prunes the blocks that are more than the pruning depth below the given `latest height`.
+    /// If pruning is disabled, this method is a no-op.
+    /// Note: At most `MAX_BLOCKS_PRUNED_PER_INSERT` blocks are pruned per call, so that enabling pruning
+    /// on an existing ledger spreads the work over the insertion of the next blocks.
+    fn prune_blocks(&self, latest_height: u32) -> Result<()> {
+        // Retrieve the pruning depth.
+        let Some(depth) = self.pruning_depth()? else { return Ok(()) };
+        // Determine the range of block heights to prune.
+        let start_height = self.pruned_height()?;
+        let end_height = latest_height
+            .saturating_add(1)
+            .saturating_sub(depth)
+            .min(start_height.saturating_add(MAX_BLOCKS_PRUNED_PER_INSERT));
+        // If there are no blocks to prune, return early.
+        if start_height >= end_height {
+            return Ok(());
+        }
+
+        atomic_batch_scope!(self, {
+            for height in start_height..end_height {
+                // Retrieve the block hash.
+                let Some(block_hash) = self.get_block_hash(height)? else {
+                    bail!("Failed to prune block {height}: missing block hash")
+                };
+                // Retrieve the transaction IDs.
+                let transaction_ids = match self.transactions_map().get_confirmed(&block_hash)? {
+                    Some(transaction_ids) => cow_to_cloned!(transaction_ids),
+                    None => bail!("Failed to prune block {height}: missing transactions for block '{block_hash}'"),
+                };
+                for transaction_id in transaction_ids {
+                    // Retrieve the confirmed transaction type.
+                    let confirmed_type = match self.confirmed_transactions_map().get_confirmed(&transaction_id)? {
+                        Some(confirmed_attributes) => confirmed_attributes.1,
+                        None => {
+                            bail!("Failed to prune block {height}: missing confirmed transaction '{transaction_id}'")
+                        }
+                    };
+                    // Prune the accepted transactions.
+                    // Note: Rejected transactions are retained, as they are required to remove the block.
+                    match confirmed_type {
+                        ConfirmedTxType::AcceptedDeploy(..) | ConfirmedTxType::AcceptedExecute(..) => {
+                            self.transaction_store().prune(&transaction_id)?
+                        }
+                        ConfirmedTxType::RejectedDeploy(..) | ConfirmedTxType::RejectedExecute(..) => (),
+                    }
+                }
+            }
+            // Store the pruned block height.
+            self.pruning_map().insert(PRUNED_HEIGHT_KEY, end_height)?;
+
             Ok(())
         })
     }
@@ -887,6 +1006,30 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             .collect::<Result<Option<Transactions<_>>>>()
     }
 
+    /// Returns the rejected block transactions for the given `block hash`.
+    /// Note: Unlike `get_block_transactions`, this method does not read the accepted transactions, which may be pruned.
+    fn get_block_rejected_transactions(&self, block_hash: &N::BlockHash) -> Result<Option<Transactions<N>>> {
+        // Retrieve the transaction IDs.
+        let transaction_ids = match self.transactions_map().get_confirmed(block_hash)? {
+            Some(transaction_ids) => transaction_ids,
+            None => return Ok(None),
+        };
+        // Retrieve the rejected transactions.
+        transaction_ids
+            .iter()
+            .filter_map(|transaction_id| match self.confirmed_transactions_map().get_confirmed(transaction_id) {
+                Ok(Some(confirmed_attributes)) => match confirmed_attributes.1 {
+                    ConfirmedTxType::RejectedDeploy(..) | ConfirmedTxType::RejectedExecute(..) => {
+                        Some(self.get_confirmed_transaction(*transaction_id))
+                    }
+                    ConfirmedTxType::AcceptedDeploy(..) | ConfirmedTxType::AcceptedExecute(..) => None,
+                },
+                Ok(None) => Some(Err(anyhow!("Missing confirmed transaction '{transaction_id}' in block storage"))),
+                Err(error) => Some(Err(error)),
+            })
+            .collect::<Result<Option<Transactions<_>>>>()
+    }
+
     /// Returns the block aborted transaction IDs for the given `block hash`.
     fn get_block_aborted_transaction_ids(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<N::TransactionID>>> {
         match self.aborted_transaction_ids_map().get_confirmed(block_hash)? {
@@ -900,7 +1043,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         // Check if the transaction was rejected or aborted.
         // Note: We can only retrieve accepted or rejected transactions. We cannot retrieve aborted transactions.
         match self.rejected_or_aborted_transaction_id_map().get_confirmed(transaction_id)? {
-            Some(block_hash) => match self.get_block_transactions(&block_hash)? {
+            Some(block_hash) => match self.get_block_rejected_transactions(&block_hash)? {
                 Some(transactions) => {
                     match transactions.find_confirmed_transaction_for_unconfirmed_transaction_id(transaction_id) {
                         Some(confirmed) => Ok(Some(confirmed.transaction().clone())),
@@ -943,7 +1086,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         // Check if the transaction was rejected or aborted.
         // Note: We can only retrieve accepted or rejected transactions. We cannot retrieve aborted transactions.
         match self.rejected_or_aborted_transaction_id_map().get_confirmed(transaction_id)? {
-            Some(block_hash) => match self.get_block_transactions(&block_hash)? {
+            Some(block_hash) => match self.get_block_rejected_transactions(&block_hash)? {
                 Some(transactions) => {
                     match transactions.find_confirmed_transaction_for_unconfirmed_transaction_id(transaction_id) {
                         Some(confirmed) => Ok(Some(confirmed.to_unconfirmed_transaction()?)),
@@ -969,6 +1112,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         if header.height() != height {
             bail!("Mismatching block height for block {height} ('{block_hash}')")
         }
+        // Ensure the block has not been pruned.
+        if self.is_block_pruned(height)? {
+            bail!("Block {height} ('{block_hash}') has been pruned from storage")
+        }
 
         // Retrieve the previous block hash.
         let Some(previous_hash) = self.get_previous_block_hash(height)? else {
@@ -1126,6 +1273,22 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Sets the pruning depth, or disables pruning if the given `depth` is `None`.
+    /// The pruning depth is persisted in storage, and applied upon the insertion of each subsequent block.
+    pub(crate) fn set_pruning_depth(&self, depth: Option<u32>) -> Result<()> {
+        self.storage.set_pruning_depth(depth)
+    }
+
+    /// Returns the pruning depth, or `None` if pruning is disabled.
+    pub fn pruning_depth(&self) -> Result<Option<u32>> {
+        self.storage.pruning_depth()
+    }
+
+    /// Returns `true` if the block at the given `height` has been pruned.
+    pub fn is_block_pruned(&self, height: u32) -> Result<bool> {
+        self.storage.is_block_pruned(height)
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
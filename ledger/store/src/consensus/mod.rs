@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod options;
+pub use options::*;
+
 use crate::{
     BlockStorage,
     BlockStore,
@@ -39,8 +42,8 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The transition storage.
     type TransitionStorage: TransitionStorage<N>;
 
-    /// Initializes the consensus storage.
-    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
+    /// Initializes the consensus storage, and applies the given storage options.
+    fn open<S: Clone + Into<StorageOptions>>(storage: S) -> Result<Self>;
 
     /// Returns the finalize storage.
     fn finalize_store(&self) -> &FinalizeStore<N, Self::FinalizeStorage>;
@@ -113,8 +116,8 @@ pub struct ConsensusStore<N: Network, C: ConsensusStorage<N>> {
 }
 
 impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
-    /// Initializes the consensus store.
-    pub fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+    /// Initializes the consensus store, and applies the given storage options.
+    pub fn open<S: Clone + Into<StorageOptions>>(storage: S) -> Result<Self> {
         // Initialize the consensus storage.
         let storage = C::open(storage.clone())?;
         // Return the consensus store.
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::DEFAULT_REVERT_DEPTH;
+
+use aleo_std_storage::StorageMode;
+use std::path::PathBuf;
+
+/// The storage mode of the consensus storage, along with the options of its stores.
+/// The options are applied each time the consensus storage is opened.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageOptions {
+    /// The storage mode.
+    mode: StorageMode,
+    /// The pruning depth, or `None` if pruning is disabled.
+    pruning_depth: Option<u32>,
+    /// The number of latest blocks that can be reverted.
+    revert_depth: u32,
+    /// Whether the history of key-value changes in the finalize store is recorded.
+    history: bool,
+}
+
+impl StorageOptions {
+    /// Initializes the storage options for the given storage mode, with all options disabled,
+    /// and the default revert depth.
+    pub fn new(mode: StorageMode) -> Self {
+        Self { mode, pruning_depth: None, revert_depth: DEFAULT_REVERT_DEPTH, history: false }
+    }
+
+    /// Enables pruning, which drops the execution proofs, fee proofs, and deployment verifying keys
+    /// of the accepted transactions in blocks that are more than `depth` blocks below the latest block.
+    pub fn with_pruning(mut self, depth: u32) -> Self {
+        self.pruning_depth = Some(depth);
+        self
+    }
+
+    /// Sets the number of latest blocks that can be reverted, which must be greater than zero.
+    /// The prior state of the finalize store is only retained for these blocks.
+    pub fn with_revert_depth(mut self, depth: u32) -> Self {
+        self.revert_depth = depth;
+        self
+    }
+
+    /// Enables the history of key-value changes in the finalize store, which is recorded from the next block onwards.
+    /// Note: Once enabled, the history is retained in storage, and is recorded from its original starting block height.
+    pub fn with_history(mut self) -> Self {
+        self.history = true;
+        self
+    }
+
+    /// Returns the storage mode.
+    pub const fn mode(&self) -> &StorageMode {
+        &self.mode
+    }
+
+    /// Returns the pruning depth, or `None` if pruning is disabled.
+    pub const fn pruning_depth(&self) -> Option<u32> {
+        self.pruning_depth
+    }
+
+    /// Returns the number of latest blocks that can be reverted.
+    pub const fn revert_depth(&self) -> u32 {
+        self.revert_depth
+    }
+
+    /// Returns `true` if the history of key-value changes is enabled.
+    pub const fn history(&self) -> bool {
+        self.history
+    }
+}
+
+impl From<StorageMode> for StorageOptions {
+    fn from(mode: StorageMode) -> Self {
+        Self::new(mode)
+    }
+}
+
+impl From<Option<u16>> for StorageOptions {
+    fn from(dev: Option<u16>) -> Self {
+        Self::new(StorageMode::from(dev))
+    }
+}
+
+impl From<u16> for StorageOptions {
+    fn from(id: u16) -> Self {
+        Self::new(StorageMode::from(id))
+    }
+}
+
+impl From<PathBuf> for StorageOptions {
+    fn from(path: PathBuf) -> Self {
+        Self::new(StorageMode::from(path))
+    }
+}
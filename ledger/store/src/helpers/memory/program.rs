@@ -18,11 +18,13 @@ use crate::{
     helpers::memory::{MemoryMap, NestedMemoryMap},
     CommitteeStorage,
     CommitteeStore,
+    FinalizeRevert,
     FinalizeStorage,
 };
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_committee::Committee;
 
@@ -38,6 +40,16 @@ pub struct FinalizeMemory<N: Network> {
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The revert map.
+    revert_map: MemoryMap<u32, FinalizeRevert<N>>,
+    /// The revert depth map.
+    revert_depth_map: MemoryMap<u8, u32>,
+    /// The history map.
+    history_map: NestedMemoryMap<Field<N>, u32, Option<Value<N>>>,
+    /// The history start map.
+    history_start_map: MemoryMap<u8, u32>,
+    /// The edition map.
+    edition_map: NestedMemoryMap<ProgramID<N>, u16, u32>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -47,6 +59,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type RevertMap = MemoryMap<u32, FinalizeRevert<N>>;
+    type RevertDepthMap = MemoryMap<u8, u32>;
+    type HistoryMap = NestedMemoryMap<Field<N>, u32, Option<Value<N>>>;
+    type HistoryStartMap = MemoryMap<u8, u32>;
+    type EditionMap = NestedMemoryMap<ProgramID<N>, u16, u32>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -57,6 +74,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             committee_store,
             program_id_map: MemoryMap::default(),
             key_value_map: NestedMemoryMap::default(),
+            revert_map: MemoryMap::default(),
+            revert_depth_map: MemoryMap::default(),
+            history_map: NestedMemoryMap::default(),
+            history_start_map: MemoryMap::default(),
+            edition_map: NestedMemoryMap::default(),
             storage_mode: storage.into(),
         })
     }
@@ -82,6 +104,31 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.key_value_map
     }
 
+    /// Returns the revert map.
+    fn revert_map(&self) -> &Self::RevertMap {
+        &self.revert_map
+    }
+
+    /// Returns the revert depth map.
+    fn revert_depth_map(&self) -> &Self::RevertDepthMap {
+        &self.revert_depth_map
+    }
+
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the history start map.
+    fn history_start_map(&self) -> &Self::HistoryStartMap {
+        &self.history_start_map
+    }
+
+    /// Returns the edition map.
+    fn edition_map(&self) -> &Self::EditionMap {
+        &self.edition_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{helpers::memory::MemoryMap, InputStorage, InputStore, OutputStorage, OutputStore, TransitionStorage};
+use crate::{
+    helpers::memory::{MemoryMap, NestedMemoryMap},
+    InputStorage,
+    InputStore,
+    OutputStorage,
+    OutputStore,
+    TransitionStorage,
+};
 use console::{
     prelude::*,
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
-    types::{Field, Group},
+    types::{Address, Field, Group},
 };
 
 use aleo_std_storage::StorageMode;
@@ -38,6 +45,12 @@ pub struct TransitionMemory<N: Network> {
     tcm_map: MemoryMap<N::TransitionID, Field<N>>,
     /// The reverse `tcm` map.
     reverse_tcm_map: MemoryMap<Field<N>, N::TransitionID>,
+    /// The address index map.
+    address_index_map: NestedMemoryMap<Address<N>, N::TransitionID, ()>,
+    /// The program index map.
+    program_index_map: NestedMemoryMap<ProgramID<N>, N::TransitionID, ()>,
+    /// The index status map.
+    index_status_map: MemoryMap<u8, bool>,
 }
 
 #[rustfmt::skip]
@@ -49,6 +62,9 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type ReverseTPKMap = MemoryMap<Group<N>, N::TransitionID>;
     type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = MemoryMap<Field<N>, N::TransitionID>;
+    type AddressIndexMap = NestedMemoryMap<Address<N>, N::TransitionID, ()>;
+    type ProgramIndexMap = NestedMemoryMap<ProgramID<N>, N::TransitionID, ()>;
+    type IndexStatusMap = MemoryMap<u8, bool>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -60,6 +76,9 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             reverse_tpk_map: MemoryMap::default(),
             tcm_map: MemoryMap::default(),
             reverse_tcm_map: MemoryMap::default(),
+            address_index_map: NestedMemoryMap::default(),
+            program_index_map: NestedMemoryMap::default(),
+            index_status_map: MemoryMap::default(),
         })
     }
 
@@ -97,6 +116,21 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
         &self.reverse_tcm_map
     }
+
+    /// Returns the address index map.
+    fn address_index_map(&self) -> &Self::AddressIndexMap {
+        &self.address_index_map
+    }
+
+    /// Returns the program index map.
+    fn program_index_map(&self) -> &Self::ProgramIndexMap {
+        &self.program_index_map
+    }
+
+    /// Returns the index status map.
+    fn index_status_map(&self) -> &Self::IndexStatusMap {
+        &self.index_status_map
+    }
 }
 
 /// An in-memory transition input storage.
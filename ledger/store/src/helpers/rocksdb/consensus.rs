@@ -17,11 +17,10 @@ use crate::{
     BlockStore,
     ConsensusStorage,
     FinalizeStore,
+    StorageOptions,
 };
 use console::prelude::*;
 
-use aleo_std_storage::StorageMode;
-
 /// An RocksDB consensus storage.
 #[derive(Clone)]
 pub struct ConsensusDB<N: Network> {
@@ -38,12 +37,22 @@ impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
     type TransactionStorage = TransactionDB<N>;
     type TransitionStorage = TransitionDB<N>;
 
-    /// Initializes the consensus storage.
-    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+    /// Initializes the consensus storage, and applies the given storage options.
+    fn open<S: Clone + Into<StorageOptions>>(storage: S) -> Result<Self> {
+        // Retrieve the storage options.
+        let options: StorageOptions = storage.into();
         // Initialize the finalize store.
-        let finalize_store = FinalizeStore::<N, FinalizeDB<N>>::open(storage.clone())?;
+        let finalize_store = FinalizeStore::<N, FinalizeDB<N>>::open(options.mode().clone())?;
         // Initialize the block store.
-        let block_store = BlockStore::<N, BlockDB<N>>::open(storage)?;
+        let block_store = BlockStore::<N, BlockDB<N>>::open(options.mode().clone())?;
+        // Apply the pruning depth.
+        block_store.set_pruning_depth(options.pruning_depth())?;
+        // Apply the revert depth.
+        finalize_store.set_revert_depth(options.revert_depth())?;
+        // If the history is enabled, record it from the next block onwards.
+        if options.history() {
+            finalize_store.enable_history(block_store.heights().max().map_or(0, |height| *height + 1))?;
+        }
         // Return the consensus storage.
         Ok(Self {
             finalize_store,
@@ -82,6 +82,7 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    Pruning = DataID::BlockPruningMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -171,6 +172,7 @@ pub enum TransitionOutputMap {
 #[repr(u16)]
 pub enum TransactionMap {
     ID = DataID::TransactionIDMap as u16,
+    Pruned = DataID::TransactionPrunedMap as u16,
 }
 
 /// The RocksDB map prefix for transition-related entries.
@@ -184,6 +186,9 @@ pub enum TransitionMap {
     ReverseTPK = DataID::TransitionReverseTPKMap as u16,
     TCM = DataID::TransitionTCMMap as u16,
     ReverseTCM = DataID::TransitionReverseTCMMap as u16,
+    AddressIndex = DataID::TransitionAddressIndexMap as u16,
+    ProgramIndex = DataID::TransitionProgramIndexMap as u16,
+    IndexStatus = DataID::TransitionIndexStatusMap as u16,
 }
 
 /// The RocksDB map prefix for program-related entries.
@@ -194,6 +199,11 @@ pub enum TransitionMap {
 pub enum ProgramMap {
     ProgramID = DataID::ProgramIDMap as u16,
     KeyValueID = DataID::KeyValueMap as u16,
+    Revert = DataID::ProgramRevertMap as u16,
+    History = DataID::ProgramHistoryMap as u16,
+    HistoryStart = DataID::ProgramHistoryStartMap as u16,
+    Edition = DataID::ProgramEditionMap as u16,
+    RevertDepth = DataID::ProgramRevertDepthMap as u16,
 }
 
 /// The RocksDB map prefix for test-related entries.
@@ -285,6 +295,16 @@ enum DataID {
     // TODO (howardwu): For mainnet - Reorder this up above.
     BlockRejectedDeploymentOrExecutionMap,
     BFTTransmissionsMap,
+    ProgramRevertMap,
+    ProgramHistoryMap,
+    ProgramHistoryStartMap,
+    BlockPruningMap,
+    TransactionPrunedMap,
+    TransitionAddressIndexMap,
+    TransitionProgramIndexMap,
+    TransitionIndexStatusMap,
+    ProgramEditionMap,
+    ProgramRevertDepthMap,
 
     // Testing
     #[cfg(test)]
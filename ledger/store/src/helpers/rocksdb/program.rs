@@ -18,11 +18,13 @@ use crate::{
     helpers::rocksdb::{self, CommitteeMap, DataMap, Database, MapID, NestedDataMap, ProgramMap},
     CommitteeStorage,
     CommitteeStore,
+    FinalizeRevert,
     FinalizeStorage,
 };
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_committee::Committee;
 
@@ -38,6 +40,16 @@ pub struct FinalizeDB<N: Network> {
     program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The revert map.
+    revert_map: DataMap<u32, FinalizeRevert<N>>,
+    /// The revert depth map.
+    revert_depth_map: DataMap<u8, u32>,
+    /// The history map.
+    history_map: NestedDataMap<Field<N>, u32, Option<Value<N>>>,
+    /// The history start map.
+    history_start_map: DataMap<u8, u32>,
+    /// The edition map.
+    edition_map: NestedDataMap<ProgramID<N>, u16, u32>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -47,6 +59,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type CommitteeStorage = CommitteeDB<N>;
     type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type RevertMap = DataMap<u32, FinalizeRevert<N>>;
+    type RevertDepthMap = DataMap<u8, u32>;
+    type HistoryMap = NestedDataMap<Field<N>, u32, Option<Value<N>>>;
+    type HistoryStartMap = DataMap<u8, u32>;
+    type EditionMap = NestedDataMap<ProgramID<N>, u16, u32>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -57,6 +74,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::KeyValueID))?,
+            revert_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::Revert))?,
+            revert_depth_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::RevertDepth))?,
+            history_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::History))?,
+            history_start_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::HistoryStart))?,
+            edition_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::Edition))?,
             storage_mode: storage.into(),
         })
     }
@@ -71,6 +93,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            revert_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::Revert))?,
+            revert_depth_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::RevertDepth))?,
+            history_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::History))?,
+            history_start_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::HistoryStart))?,
+            edition_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::Edition))?,
             storage_mode: dev.into(),
         })
     }
@@ -90,6 +117,31 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.key_value_map
     }
 
+    /// Returns the revert map.
+    fn revert_map(&self) -> &Self::RevertMap {
+        &self.revert_map
+    }
+
+    /// Returns the revert depth map.
+    fn revert_depth_map(&self) -> &Self::RevertDepthMap {
+        &self.revert_depth_map
+    }
+
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the history start map.
+    fn history_start_map(&self) -> &Self::HistoryStartMap {
+        &self.history_start_map
+    }
+
+    /// Returns the edition map.
+    fn edition_map(&self) -> &Self::EditionMap {
+        &self.edition_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
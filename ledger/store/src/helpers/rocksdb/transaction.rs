@@ -46,6 +46,8 @@ use synthesizer_snark::{Certificate, Proof, VerifyingKey};
 pub struct TransactionDB<N: Network> {
     /// The mapping of `transaction ID` to `transaction type`.
     id_map: DataMap<N::TransactionID, TransactionType>,
+    /// The mapping of pruned `transaction ID`s.
+    pruned_map: DataMap<N::TransactionID, ()>,
     /// The deployment store.
     deployment_store: DeploymentStore<N, DeploymentDB<N>>,
     /// The execution store.
@@ -57,6 +59,7 @@ pub struct TransactionDB<N: Network> {
 #[rustfmt::skip]
 impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
     type IDMap = DataMap<N::TransactionID, TransactionType>;
+    type PrunedMap = DataMap<N::TransactionID, ()>;
     type DeploymentStorage = DeploymentDB<N>;
     type ExecutionStorage = ExecutionDB<N>;
     type FeeStorage = FeeDB<N>;
@@ -71,7 +74,7 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionDB<N>>::open(fee_store.clone())?;
         // Return the transaction storage.
-        Ok(Self { id_map: rocksdb::RocksDB::open_map(N::ID, execution_store.storage_mode().clone(), MapID::Transaction(TransactionMap::ID))?, deployment_store, execution_store, fee_store })
+        Ok(Self { id_map: rocksdb::RocksDB::open_map(N::ID, execution_store.storage_mode().clone(), MapID::Transaction(TransactionMap::ID))?, pruned_map: rocksdb::RocksDB::open_map(N::ID, execution_store.storage_mode().clone(), MapID::Transaction(TransactionMap::Pruned))?, deployment_store, execution_store, fee_store })
     }
 
     /// Returns the ID map.
@@ -79,6 +82,11 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
         &self.id_map
     }
 
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap {
+        &self.pruned_map
+    }
+
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
         &self.deployment_store
@@ -13,7 +13,16 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{self, DataMap, Database, MapID, TransitionInputMap, TransitionMap, TransitionOutputMap},
+    helpers::rocksdb::{
+        self,
+        DataMap,
+        Database,
+        MapID,
+        NestedDataMap,
+        TransitionInputMap,
+        TransitionMap,
+        TransitionOutputMap,
+    },
     InputStorage,
     InputStore,
     OutputStorage,
@@ -23,7 +32,7 @@ use crate::{
 use console::{
     prelude::*,
     program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
-    types::{Field, Group},
+    types::{Address, Field, Group},
 };
 
 use aleo_std_storage::StorageMode;
@@ -45,6 +54,12 @@ pub struct TransitionDB<N: Network> {
     tcm_map: DataMap<N::TransitionID, Field<N>>,
     /// The reverse `tcm` map.
     reverse_tcm_map: DataMap<Field<N>, N::TransitionID>,
+    /// The address index map.
+    address_index_map: NestedDataMap<Address<N>, N::TransitionID, ()>,
+    /// The program index map.
+    program_index_map: NestedDataMap<ProgramID<N>, N::TransitionID, ()>,
+    /// The index status map.
+    index_status_map: DataMap<u8, bool>,
 }
 
 #[rustfmt::skip]
@@ -56,6 +71,9 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     type ReverseTPKMap = DataMap<Group<N>, N::TransitionID>;
     type TCMMap = DataMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = DataMap<Field<N>, N::TransitionID>;
+    type AddressIndexMap = NestedDataMap<Address<N>, N::TransitionID, ()>;
+    type ProgramIndexMap = NestedDataMap<ProgramID<N>, N::TransitionID, ()>;
+    type IndexStatusMap = DataMap<u8, bool>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -66,7 +84,10 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
             tpk_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::TPK))?,
             reverse_tpk_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::ReverseTPK))?,
             tcm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::TCM))?,
-            reverse_tcm_map: rocksdb::RocksDB::open_map(N::ID, storage,  MapID::Transition(TransitionMap::ReverseTCM))?,
+            reverse_tcm_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::ReverseTCM))?,
+            address_index_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::AddressIndex))?,
+            program_index_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Transition(TransitionMap::ProgramIndex))?,
+            index_status_map: rocksdb::RocksDB::open_map(N::ID, storage, MapID::Transition(TransitionMap::IndexStatus))?,
         })
     }
 
@@ -104,6 +125,21 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
         &self.reverse_tcm_map
     }
+
+    /// Returns the address index map.
+    fn address_index_map(&self) -> &Self::AddressIndexMap {
+        &self.address_index_map
+    }
+
+    /// Returns the program index map.
+    fn program_index_map(&self) -> &Self::ProgramIndexMap {
+        &self.program_index_map
+    }
+
+    /// Returns the index status map.
+    fn index_status_map(&self) -> &Self::IndexStatusMap {
+        &self.index_status_map
+    }
 }
 
 /// An database transition input storage.
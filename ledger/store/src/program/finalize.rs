@@ -29,7 +29,66 @@ use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use core::marker::PhantomData;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
+use std::borrow::Cow;
+
+/// The prior state of the program ID and key-value entries that were modified when finalizing a block.
+///
+/// A `None` denotes that the entry did not exist prior to the block.
+pub type FinalizeRevert<N> = (
+    Vec<(ProgramID<N>, Option<IndexSet<Identifier<N>>>)>,
+    Vec<((ProgramID<N>, Identifier<N>), Plaintext<N>, Option<Value<N>>)>,
+);
+
+/// The key of the starting block height in the history start map.
+const HISTORY_START_KEY: u8 = 0;
+/// The key of the revert depth in the revert depth map.
+const REVERT_DEPTH_KEY: u8 = 0;
+
+/// The default number of latest blocks that can be reverted.
+pub const DEFAULT_REVERT_DEPTH: u32 = 100;
+
+/// A change to a key-value entry in a mapping, which is pending in the current atomic batch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingDiff<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The mapping name.
+    mapping_name: Identifier<N>,
+    /// The key.
+    key: Plaintext<N>,
+    /// The confirmed value of the key, or `None` if the key does not exist.
+    previous_value: Option<Value<N>>,
+    /// The pending value of the key, or `None` if the key is removed.
+    value: Option<Value<N>>,
+}
+
+impl<N: Network> MappingDiff<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the mapping name.
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        &self.mapping_name
+    }
+
+    /// Returns the key.
+    pub const fn key(&self) -> &Plaintext<N> {
+        &self.key
+    }
+
+    /// Returns the confirmed value of the key, or `None` if the key does not exist.
+    pub const fn previous_value(&self) -> Option<&Value<N>> {
+        self.previous_value.as_ref()
+    }
+
+    /// Returns the pending value of the key, or `None` if the key is removed.
+    pub const fn value(&self) -> Option<&Value<N>> {
+        self.value.as_ref()
+    }
+}
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
@@ -77,6 +136,16 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
     type KeyValueMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    /// The mapping of `block height` to the prior state of the entries finalized in that block.
+    type RevertMap: for<'a> Map<'a, u32, FinalizeRevert<N>>;
+    /// The mapping of `0u8` to the number of latest blocks that can be reverted.
+    type RevertDepthMap: for<'a> Map<'a, u8, u32>;
+    /// The mapping of `key ID` to `[(block height, value)]`, for every change to the key.
+    type HistoryMap: for<'a> NestedMap<'a, Field<N>, u32, Option<Value<N>>>;
+    /// The mapping of `0u8` to the block height from which the history is recorded.
+    type HistoryStartMap: for<'a> Map<'a, u8, u32>;
+    /// The mapping of `program ID` to `[(edition, block height)]`.
+    type EditionMap: for<'a> NestedMap<'a, ProgramID<N>, u16, u32>;
 
     /// Initializes the program state storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -91,6 +160,16 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
     fn key_value_map(&self) -> &Self::KeyValueMap;
+    /// Returns the revert map.
+    fn revert_map(&self) -> &Self::RevertMap;
+    /// Returns the revert depth map.
+    fn revert_depth_map(&self) -> &Self::RevertDepthMap;
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap;
+    /// Returns the history start map.
+    fn history_start_map(&self) -> &Self::HistoryStartMap;
+    /// Returns the edition map.
+    fn edition_map(&self) -> &Self::EditionMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode;
@@ -100,6 +179,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
+        self.revert_map().start_atomic();
+        self.revert_depth_map().start_atomic();
+        self.history_map().start_atomic();
+        self.history_start_map().start_atomic();
+        self.edition_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -107,6 +191,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
+            || self.revert_map().is_atomic_in_progress()
+            || self.revert_depth_map().is_atomic_in_progress()
+            || self.history_map().is_atomic_in_progress()
+            || self.history_start_map().is_atomic_in_progress()
+            || self.edition_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -114,6 +203,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_checkpoint();
         self.program_id_map().atomic_checkpoint();
         self.key_value_map().atomic_checkpoint();
+        self.revert_map().atomic_checkpoint();
+        self.revert_depth_map().atomic_checkpoint();
+        self.history_map().atomic_checkpoint();
+        self.history_start_map().atomic_checkpoint();
+        self.edition_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -121,6 +215,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
+        self.revert_map().clear_latest_checkpoint();
+        self.revert_depth_map().clear_latest_checkpoint();
+        self.history_map().clear_latest_checkpoint();
+        self.history_start_map().clear_latest_checkpoint();
+        self.edition_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -128,6 +227,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
+        self.revert_map().atomic_rewind();
+        self.revert_depth_map().atomic_rewind();
+        self.history_map().atomic_rewind();
+        self.history_start_map().atomic_rewind();
+        self.edition_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -135,13 +239,23 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
+        self.revert_map().abort_atomic();
+        self.revert_depth_map().abort_atomic();
+        self.history_map().abort_atomic();
+        self.history_start_map().abort_atomic();
+        self.edition_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.committee_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
-        self.key_value_map().finish_atomic()
+        self.key_value_map().finish_atomic()?;
+        self.revert_map().finish_atomic()?;
+        self.revert_depth_map().finish_atomic()?;
+        self.history_map().finish_atomic()?;
+        self.history_start_map().finish_atomic()?;
+        self.edition_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -342,6 +456,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Update the mapping names.
             self.program_id_map().remove(program_id)?;
+            // Remove the edition history.
+            self.edition_map().remove_map(program_id)?;
 
             // Remove each mapping.
             for mapping_name in mapping_names.iter() {
@@ -352,11 +468,292 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Records that the given `edition` of the `program ID` was finalized at the given `block height`.
+    /// If the `edition` is already recorded, an error is returned.
+    fn record_edition(&self, program_id: ProgramID<N>, edition: u16, block_height: u32) -> Result<()> {
+        // Ensure the edition is not already recorded.
+        if self.edition_map().contains_key_speculative(&program_id, &edition)? {
+            bail!("Illegal operation: edition {edition} of program '{program_id}' is already recorded in storage.")
+        }
+
+        atomic_batch_scope!(self, {
+            // Store the block height of the edition.
+            self.edition_map().insert(program_id, edition, block_height)?;
+
+            Ok(())
+        })
+    }
+
+    /// Records the prior state of every entry that is pending in the current atomic batch,
+    /// so that the finalize operations for the given `block height` can later be reverted.
+    /// The prior state of the blocks that are beyond the revert depth is removed.
+    fn record_revert(&self, block_height: u32) -> Result<()> {
+        // Ensure an atomic batch is in progress.
+        ensure!(self.is_atomic_in_progress(), "Cannot record the revert for block {block_height} outside of a batch");
+
+        // Retrieve the prior mapping names for each pending program ID.
+        let mut program_entries = IndexMap::new();
+        for (program_id, _) in self.program_id_map().iter_pending() {
+            let program_id = cow_to_copied!(program_id);
+            if !program_entries.contains_key(&program_id) {
+                let mapping_names =
+                    self.program_id_map().get_confirmed(&program_id)?.map(|names| cow_to_cloned!(names));
+                program_entries.insert(program_id, mapping_names);
+            }
+        }
+
+        // Retrieve the pending keys, indexed by their byte representation.
+        let mut keys = IndexMap::new();
+        for (mapping, key, _) in self.key_value_map().iter_pending() {
+            let mapping = cow_to_copied!(mapping);
+            match key {
+                Some(key) => {
+                    let key = cow_to_cloned!(key);
+                    keys.insert((mapping, key.to_bytes_le()?), key);
+                }
+                // If the entire mapping is removed, every confirmed key in the mapping is affected.
+                None => {
+                    for (key, _) in self.key_value_map().get_map_confirmed(&mapping)? {
+                        keys.insert((mapping, key.to_bytes_le()?), key);
+                    }
+                }
+            }
+        }
+        // Retrieve the prior value for each pending key.
+        let key_value_entries = keys
+            .into_iter()
+            .map(|((mapping, _), key)| {
+                let value =
+                    self.key_value_map().get_value_confirmed(&mapping, &key)?.map(|value| cow_to_cloned!(value));
+                Ok((mapping, key, value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the block heights whose prior state is beyond the revert depth.
+        let revert_depth = self.revert_depth()?;
+        let expired_heights = self
+            .revert_map()
+            .keys_confirmed()
+            .map(|height| cow_to_copied!(height))
+            .filter(|height| height.saturating_add(revert_depth) <= block_height)
+            .collect::<Vec<_>>();
+
+        atomic_batch_scope!(self, {
+            // Store the prior state for the block height.
+            self.revert_map().insert(block_height, (program_entries.into_iter().collect(), key_value_entries))?;
+            // Remove the prior state of the blocks that can no longer be reverted.
+            for height in expired_heights {
+                self.revert_map().remove(&height)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Sets the revert depth, which is the number of latest blocks whose prior state is retained,
+    /// so that they can be reverted. The prior state of older blocks is removed as new blocks are finalized.
+    fn set_revert_depth(&self, depth: u32) -> Result<()> {
+        // Ensure the revert depth is non-zero.
+        ensure!(depth > 0, "The revert depth must be greater than zero");
+        // If the revert depth is unchanged, return early.
+        if self.revert_depth()? == depth {
+            return Ok(());
+        }
+        atomic_batch_scope!(self, {
+            // Store the revert depth.
+            self.revert_depth_map().insert(REVERT_DEPTH_KEY, depth)?;
+
+            Ok(())
+        })
+    }
+
+    /// Returns the revert depth, which defaults to `DEFAULT_REVERT_DEPTH`.
+    fn revert_depth(&self) -> Result<u32> {
+        Ok(self
+            .revert_depth_map()
+            .get_confirmed(&REVERT_DEPTH_KEY)?
+            .map_or(DEFAULT_REVERT_DEPTH, |depth| cow_to_copied!(depth)))
+    }
+
+    /// Enables the history of key-value changes, which is recorded from the given `block height` onwards.
+    /// If the history is already enabled, its starting block height is retained.
+    fn enable_history(&self, block_height: u32) -> Result<()> {
+        // If the history is already enabled, return early.
+        if self.history_start_map().contains_key_confirmed(&HISTORY_START_KEY)? {
+            return Ok(());
+        }
+        atomic_batch_scope!(self, {
+            // Store the starting block height of the history.
+            self.history_start_map().insert(HISTORY_START_KEY, block_height)?;
+
+            Ok(())
+        })
+    }
+
+    /// Returns the block height from which the history is recorded, or `None` if the history is disabled.
+    fn history_start_height(&self) -> Result<Option<u32>> {
+        Ok(self.history_start_map().get_confirmed(&HISTORY_START_KEY)?.map(|height| cow_to_copied!(height)))
+    }
+
+    /// Returns the key-value changes that are pending in the current atomic batch, in the order they were first made.
+    /// Keys whose pending value is equal to their confirmed value are omitted.
+    fn get_pending_mapping_diffs(&self) -> Result<Vec<MappingDiff<N>>> {
+        // Retrieve the latest pending value for each pending key, indexed by its byte representation.
+        let mut changes = IndexMap::new();
+        for (mapping, key, value) in self.key_value_map().iter_pending() {
+            let mapping = cow_to_copied!(mapping);
+            match key {
+                Some(key) => {
+                    let key = cow_to_cloned!(key);
+                    changes.insert((mapping, key.to_bytes_le()?), (key, value.map(|value| cow_to_cloned!(value))));
+                }
+                // If the entire mapping is removed, every key in the mapping is removed.
+                None => {
+                    for ((change_mapping, _), (_, change_value)) in changes.iter_mut() {
+                        if *change_mapping == mapping {
+                            *change_value = None;
+                        }
+                    }
+                    for (key, _) in self.key_value_map().get_map_confirmed(&mapping)? {
+                        changes.insert((mapping, key.to_bytes_le()?), (key, None));
+                    }
+                }
+            }
+        }
+
+        // Retrieve the confirmed value of each pending key.
+        let mut diffs = Vec::with_capacity(changes.len());
+        for (((program_id, mapping_name), _), (key, value)) in changes {
+            let previous_value = self
+                .key_value_map()
+                .get_value_confirmed(&(program_id, mapping_name), &key)?
+                .map(|value| cow_to_cloned!(value));
+            if previous_value != value {
+                diffs.push(MappingDiff { program_id, mapping_name, key, previous_value, value });
+            }
+        }
+        Ok(diffs)
+    }
+
+    /// Records the pending key-value changes in the history, for the given `block height`.
+    /// If the history is disabled, or starts after the given `block height`, this method is a no-op.
+    /// Note: This method must be called from within an atomic batch, after the finalize operations of the block.
+    fn record_history(&self, block_height: u32) -> Result<()> {
+        // Retrieve the starting block height of the history.
+        let start_height = match self.history_start_height()? {
+            Some(start_height) if start_height <= block_height => start_height,
+            _ => return Ok(()),
+        };
+        // Ensure an atomic batch is in progress.
+        ensure!(self.is_atomic_in_progress(), "Cannot record the history for block {block_height} outside of a batch");
+
+        // Retrieve the pending key-value changes, indexed by the key ID.
+        let mut changes = Vec::new();
+        // For each key without a history, retrieve the prior value as its baseline,
+        // so that the value of the key is known from the starting block height onwards.
+        let mut baselines = Vec::new();
+        for diff in self.get_pending_mapping_diffs()? {
+            let key_id = to_key_id(&diff.program_id, &diff.mapping_name, &diff.key)?;
+            if start_height > 0 && self.history_map().get_map_confirmed(&key_id)?.is_empty() {
+                baselines.push((key_id, diff.previous_value));
+            }
+            changes.push((key_id, diff.value));
+        }
+
+        atomic_batch_scope!(self, {
+            // Store the baseline of each new key, as of the block before the history starts.
+            for (key_id, value) in baselines {
+                self.history_map().insert(key_id, start_height - 1, value)?;
+            }
+            // Store the change of each key.
+            for (key_id, value) in changes {
+                self.history_map().insert(key_id, block_height, value)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Reverts the finalize operations for the given `block height`, restoring the prior state
+    /// of every program ID and key-value entry, and removing the committee for the block height.
+    /// Note: Blocks must be reverted in descending order of their height.
+    fn revert(&self, block_height: u32) -> Result<()> {
+        // Retrieve the prior state for the block height.
+        let (program_entries, key_value_entries) = match self.revert_map().get_confirmed(&block_height)? {
+            Some(revert) => cow_to_cloned!(revert),
+            None => bail!("Failed to revert block {block_height}: missing finalize revert in storage"),
+        };
+        // Determine whether a committee was stored for the block height.
+        let is_committee_stored = self.committee_store().get_committee(block_height)?.is_some();
+        // Retrieve the editions that were finalized at the block height.
+        let editions = self
+            .edition_map()
+            .iter_confirmed()
+            .filter(|(_, _, height)| **height == block_height)
+            .map(|(program_id, edition, _)| (cow_to_copied!(program_id), cow_to_copied!(edition)))
+            .collect::<Vec<_>>();
+        // If the history is enabled, retrieve the history entries to remove for each key.
+        // Note: If the block height holds the only recorded change of a key, its baseline is removed as well.
+        let mut history_entries = Vec::new();
+        if let Some(start_height) = self.history_start_height()? {
+            for ((program_id, mapping_name), key, _) in &key_value_entries {
+                let key_id = to_key_id(program_id, mapping_name, key)?;
+                let heights = self.history_map().get_map_confirmed(&key_id)?.into_iter().map(|(height, _)| height);
+                let heights = heights.filter(|height| *height != block_height).collect::<Vec<_>>();
+                if heights.iter().all(|height| *height < start_height) {
+                    history_entries.extend(heights.into_iter().map(|height| (key_id, height)));
+                }
+                history_entries.push((key_id, block_height));
+            }
+        }
+
+        atomic_batch_scope!(self, {
+            // Restore the mapping names for each program ID.
+            for (program_id, mapping_names) in program_entries {
+                match mapping_names {
+                    Some(mapping_names) => self.program_id_map().insert(program_id, mapping_names)?,
+                    None => self.program_id_map().remove(&program_id)?,
+                }
+            }
+            // Restore the value for each key.
+            for (mapping, key, value) in key_value_entries {
+                match value {
+                    Some(value) => self.key_value_map().insert(mapping, key, value)?,
+                    None => self.key_value_map().remove_key(&mapping, &key)?,
+                }
+            }
+            // Remove the history for the block height.
+            for (key_id, height) in &history_entries {
+                self.history_map().remove_key(key_id, height)?;
+            }
+            // Remove the editions that were finalized at the block height.
+            for (program_id, edition) in &editions {
+                self.edition_map().remove_key(program_id, edition)?;
+            }
+            // Remove the committee for the block height.
+            if is_committee_stored {
+                self.committee_store().remove(block_height)?;
+            }
+            // Remove the prior state for the block height.
+            self.revert_map().remove(&block_height)?;
+
+            Ok(())
+        })
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key_confirmed(program_id)
     }
 
+    /// Returns the confirmed `(edition, block height)` of every finalized edition of the given `program ID`,
+    /// in ascending order of edition.
+    fn get_edition_history_confirmed(&self, program_id: &ProgramID<N>) -> Result<Vec<(u16, u32)>> {
+        let mut editions = self.edition_map().get_map_confirmed(program_id)?;
+        editions.sort_unstable_by_key(|(edition, _)| *edition);
+        Ok(editions)
+    }
+
     /// Returns `true` if the given `program ID` and `mapping name` exist.
     fn contains_mapping_confirmed(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<bool> {
         Ok(self.program_id_map().get_confirmed(program_id)?.map_or(false, |m| m.contains(mapping_name)))
@@ -451,6 +848,50 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the confirmed history for the given `program ID`, `mapping name`, and `key`,
+    /// as `(block height, value)` pairs in ascending order of block height, where a `None` value denotes a removal.
+    /// Note: This includes the baseline value of the key, as of the block before the history starts.
+    fn get_history_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Vec<(u32, Option<Value<N>>)>> {
+        // Retrieve the history for the key.
+        let mut history = self.history_map().get_map_confirmed(&to_key_id(&program_id, &mapping_name, key)?)?;
+        // Sort the history by block height.
+        history.sort_unstable_by_key(|(block_height, _)| *block_height);
+        Ok(history)
+    }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the given `block height`.
+    fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Ensure the history is available for the block height.
+        match self.history_start_height()? {
+            Some(start_height) => ensure!(
+                block_height >= start_height,
+                "The mapping history is only available from block {start_height} (requested block {block_height})"
+            ),
+            None => bail!("The mapping history is not enabled in storage"),
+        }
+        // Retrieve the history for the key.
+        let history = self.get_history_confirmed(program_id, mapping_name, key)?;
+        // Return the latest value at or before the block height.
+        match history.iter().rev().find(|(height, _)| *height <= block_height) {
+            Some((_, value)) => Ok(value.clone()),
+            // If the key has no history, its value has not changed since the history started.
+            None if history.is_empty() => self.get_value_confirmed(program_id, mapping_name, key),
+            // Otherwise, the key did not exist at the block height.
+            None => Ok(None),
+        }
+    }
+
     /// Returns the confirmed checksum of the finalize storage.
     fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         // Compute all mapping checksums.
@@ -696,6 +1137,63 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     pub fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
         self.storage.remove_program(program_id)
     }
+
+    /// Records that the given `edition` of the `program ID` was finalized at the given `block height`.
+    pub fn record_edition(&self, program_id: ProgramID<N>, edition: u16, block_height: u32) -> Result<()> {
+        self.storage.record_edition(program_id, edition, block_height)
+    }
+
+    /// Records the prior state of every entry that is pending in the current atomic batch,
+    /// so that the finalize operations for the given `block height` can later be reverted.
+    /// The prior state of the blocks that are beyond the revert depth is removed.
+    pub fn record_revert(&self, block_height: u32) -> Result<()> {
+        self.storage.record_revert(block_height)
+    }
+
+    /// Sets the number of latest blocks that can be reverted.
+    pub(crate) fn set_revert_depth(&self, depth: u32) -> Result<()> {
+        self.storage.set_revert_depth(depth)
+    }
+
+    /// Returns the number of latest blocks that can be reverted.
+    pub fn revert_depth(&self) -> Result<u32> {
+        self.storage.revert_depth()
+    }
+
+    /// Reverts the finalize operations for the given `block height`.
+    /// Note: Blocks must be reverted in descending order of their height.
+    pub fn revert(&self, block_height: u32) -> Result<()> {
+        self.storage.revert(block_height)
+    }
+
+    /// Stores the prior state of the entries finalized in the given `block height`,
+    /// so that the block can be reverted (e.g. when restoring the state of a block from a snapshot).
+    pub fn insert_revert(&self, block_height: u32, revert: FinalizeRevert<N>) -> Result<()> {
+        self.storage.revert_map().insert(block_height, revert)
+    }
+
+    /// Enables the history of key-value changes, which is recorded from the given `block height` onwards.
+    /// If the history is already enabled, its starting block height is retained.
+    pub fn enable_history(&self, block_height: u32) -> Result<()> {
+        self.storage.enable_history(block_height)
+    }
+
+    /// Returns the block height from which the history is recorded, or `None` if the history is disabled.
+    pub fn history_start_height(&self) -> Result<Option<u32>> {
+        self.storage.history_start_height()
+    }
+
+    /// Records the pending key-value changes in the history, for the given `block height`.
+    /// Note: This method must be called from within an atomic batch, after the finalize operations of the block.
+    pub fn record_history(&self, block_height: u32) -> Result<()> {
+        self.storage.record_history(block_height)
+    }
+
+    /// Returns the key-value changes that are pending in the current atomic batch, in the order they were first made.
+    /// Keys whose pending value is equal to their confirmed value are omitted.
+    pub fn get_pending_mapping_diffs(&self) -> Result<Vec<MappingDiff<N>>> {
+        self.storage.get_pending_mapping_diffs()
+    }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
@@ -704,6 +1202,15 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.contains_program_confirmed(program_id)
     }
 
+    /// Returns `true` if the given `program ID` and `mapping name` exist, including pending changes.
+    pub fn contains_mapping_speculative(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<bool> {
+        self.storage.contains_mapping_speculative(program_id, mapping_name)
+    }
+
     /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
     pub fn contains_key_confirmed(
         &self,
@@ -716,6 +1223,12 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns the confirmed `(edition, block height)` of every finalized edition of the given `program ID`,
+    /// in ascending order of edition.
+    pub fn get_edition_history_confirmed(&self, program_id: &ProgramID<N>) -> Result<Vec<(u16, u32)>> {
+        self.storage.get_edition_history_confirmed(program_id)
+    }
+
     /// Returns the confirmed mapping names for the given `program ID`.
     pub fn get_mapping_names_confirmed(&self, program_id: &ProgramID<N>) -> Result<Option<IndexSet<Identifier<N>>>> {
         self.storage.get_mapping_names_confirmed(program_id)
@@ -759,10 +1272,57 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns the confirmed prior state of the entries finalized in the given `block height`.
+    pub fn get_revert_confirmed(&self, block_height: u32) -> Result<Option<FinalizeRevert<N>>> {
+        Ok(self.storage.revert_map().get_confirmed(&block_height)?.map(|revert| cow_to_cloned!(revert)))
+    }
+
     /// Returns the confirmed checksum of the finalize store.
     pub fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         self.storage.get_checksum_confirmed()
     }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, as of the given `block height`.
+    pub fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.storage.get_value_at_height(program_id, mapping_name, key, block_height)
+    }
+
+    /// Returns an iterator over the changes to the given `program ID`, `mapping name`, and `key`,
+    /// as `(block height, value)` pairs in ascending order of block height, where a `None` value denotes a removal.
+    pub fn history_iter(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<impl Iterator<Item = (u32, Option<Value<N>>)>> {
+        // Retrieve the starting block height of the history.
+        let Some(start_height) = self.storage.history_start_height()? else {
+            bail!("The mapping history is not enabled in storage")
+        };
+        // Retrieve the history, skipping the baseline value of the key.
+        let history = self.storage.get_history_confirmed(program_id, mapping_name, key)?;
+        Ok(history.into_iter().filter(move |(block_height, _)| *block_height >= start_height))
+    }
+}
+
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns an iterator over the confirmed program IDs, for all programs with mappings.
+    pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        self.storage.program_id_map().keys_confirmed()
+    }
+
+    /// Returns an iterator over the confirmed `(program ID, edition, block height)` of every finalized edition.
+    pub fn editions(&self) -> impl '_ + Iterator<Item = (ProgramID<N>, u16, u32)> {
+        self.storage.edition_map().iter_confirmed().map(|(program_id, edition, block_height)| {
+            (cow_to_copied!(program_id), cow_to_copied!(edition), cow_to_copied!(block_height))
+        })
+    }
 }
 
 #[cfg(test)]
@@ -1205,6 +1765,286 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_record_and_revert() {
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Check the record and revert.
+        check_record_and_revert(&finalize_store);
+    }
+
+    #[test]
+    #[cfg(feature = "rocks")]
+    fn test_record_and_revert_rocksdb() {
+        // Initialize a new finalize store.
+        let temp_dir = tempfile::tempdir().expect("Failed to open temporary directory").into_path();
+        let program_rocksdb = crate::helpers::rocksdb::FinalizeDB::open_testing(temp_dir, None).unwrap();
+        let finalize_store = FinalizeStore::from(program_rocksdb).unwrap();
+        // Check the record and revert.
+        check_record_and_revert(&finalize_store);
+    }
+
+    fn check_record_and_revert<F: FinalizeStorage<CurrentNetwork>>(finalize_store: &FinalizeStore<CurrentNetwork, F>) {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let other_mapping_name = Identifier::from_str("other").unwrap();
+
+        // Prepare the keys and values.
+        let key_0 = Plaintext::from_str("0field").unwrap();
+        let key_1 = Plaintext::from_str("1field").unwrap();
+        let value_0 = Value::from_str("0u64").unwrap();
+        let value_1 = Value::from_str("1u64").unwrap();
+
+        // Ensure recording a revert outside of an atomic batch fails.
+        assert!(finalize_store.record_revert(0).is_err());
+
+        // Finalize the first block, which initializes the mapping and inserts a key-value.
+        finalize_store.start_atomic();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        finalize_store.insert_key_value(program_id, mapping_name, key_0.clone(), value_0.clone()).unwrap();
+        finalize_store.record_revert(1).unwrap();
+        finalize_store.finish_atomic().unwrap();
+        // Retrieve the checksum after the first block.
+        let checksum = finalize_store.get_checksum_confirmed().unwrap();
+
+        // Finalize the second block, which updates, inserts, and replaces entries.
+        finalize_store.start_atomic();
+        finalize_store.initialize_mapping(program_id, other_mapping_name).unwrap();
+        finalize_store.update_key_value(program_id, mapping_name, key_0.clone(), value_1.clone()).unwrap();
+        finalize_store.insert_key_value(program_id, mapping_name, key_1.clone(), value_1.clone()).unwrap();
+        finalize_store.replace_mapping(program_id, mapping_name, vec![(key_1.clone(), value_0.clone())]).unwrap();
+        finalize_store.record_revert(2).unwrap();
+        finalize_store.finish_atomic().unwrap();
+        // Ensure the second block is finalized.
+        assert!(finalize_store.contains_mapping_confirmed(&program_id, &other_mapping_name).unwrap());
+        assert!(finalize_store.get_value_confirmed(program_id, mapping_name, &key_0).unwrap().is_none());
+        assert_eq!(
+            finalize_store.get_value_confirmed(program_id, mapping_name, &key_1).unwrap(),
+            Some(value_0.clone())
+        );
+
+        // Revert the second block.
+        finalize_store.revert(2).unwrap();
+        // Ensure the state matches the first block.
+        assert!(!finalize_store.contains_mapping_confirmed(&program_id, &other_mapping_name).unwrap());
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key_0).unwrap(), Some(value_0));
+        assert!(finalize_store.get_value_confirmed(program_id, mapping_name, &key_1).unwrap().is_none());
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), checksum);
+        // Ensure the second block cannot be reverted twice.
+        assert!(finalize_store.revert(2).is_err());
+
+        // Revert the first block.
+        finalize_store.revert(1).unwrap();
+        // Ensure the program no longer exists.
+        assert!(!finalize_store.contains_program_confirmed(&program_id).unwrap());
+        assert!(!finalize_store.contains_key_confirmed(program_id, mapping_name, &key_0).unwrap());
+    }
+
+    #[test]
+    fn test_revert_depth() {
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Check the revert depth.
+        check_revert_depth(&finalize_store);
+    }
+
+    #[test]
+    #[cfg(feature = "rocks")]
+    fn test_revert_depth_rocksdb() {
+        // Initialize a new finalize store.
+        let temp_dir = tempfile::tempdir().expect("Failed to open temporary directory").into_path();
+        let program_rocksdb = crate::helpers::rocksdb::FinalizeDB::open_testing(temp_dir, None).unwrap();
+        let finalize_store = FinalizeStore::from(program_rocksdb).unwrap();
+        // Check the revert depth.
+        check_revert_depth(&finalize_store);
+    }
+
+    fn check_revert_depth<F: FinalizeStorage<CurrentNetwork>>(finalize_store: &FinalizeStore<CurrentNetwork, F>) {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+
+        // Ensure the revert depth defaults to `DEFAULT_REVERT_DEPTH`.
+        assert_eq!(finalize_store.revert_depth().unwrap(), DEFAULT_REVERT_DEPTH);
+        // Ensure the revert depth must be non-zero.
+        assert!(finalize_store.set_revert_depth(0).is_err());
+        // Set the revert depth to 3 blocks.
+        finalize_store.set_revert_depth(3).unwrap();
+        assert_eq!(finalize_store.revert_depth().unwrap(), 3);
+
+        // Finalize 6 blocks, which each update the key.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        for block_height in 0..6u32 {
+            finalize_store.start_atomic();
+            let value = Value::from_str(&format!("{block_height}u64")).unwrap();
+            finalize_store.update_key_value(program_id, mapping_name, key.clone(), value).unwrap();
+            finalize_store.record_revert(block_height).unwrap();
+            finalize_store.finish_atomic().unwrap();
+        }
+        // Ensure the prior state is only retained for the latest 3 blocks.
+        for block_height in 0..3 {
+            assert!(finalize_store.get_revert_confirmed(block_height).unwrap().is_none());
+        }
+        for block_height in 3..6 {
+            assert!(finalize_store.get_revert_confirmed(block_height).unwrap().is_some());
+        }
+
+        // Ensure the retained blocks can be reverted.
+        for block_height in (3..6).rev() {
+            finalize_store.revert(block_height).unwrap();
+        }
+        assert_eq!(
+            finalize_store.get_value_confirmed(program_id, mapping_name, &key).unwrap(),
+            Some(Value::from_str("2u64").unwrap())
+        );
+        // Ensure the block beyond the revert depth cannot be reverted.
+        assert!(finalize_store.revert(2).is_err());
+
+        // Lower the revert depth to 1 block, and finalize another block.
+        finalize_store.set_revert_depth(1).unwrap();
+        finalize_store.start_atomic();
+        finalize_store
+            .update_key_value(program_id, mapping_name, key.clone(), Value::from_str("7u64").unwrap())
+            .unwrap();
+        finalize_store.record_revert(3).unwrap();
+        finalize_store.finish_atomic().unwrap();
+        // Ensure only the prior state of the latest block is retained.
+        assert_eq!(finalize_store.storage.revert_map().keys_confirmed().count(), 1);
+        assert!(finalize_store.get_revert_confirmed(3).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_history() {
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        // Check the history.
+        check_history(&finalize_store);
+    }
+
+    #[test]
+    #[cfg(feature = "rocks")]
+    fn test_history_rocksdb() {
+        // Initialize a new finalize store.
+        let temp_dir = tempfile::tempdir().expect("Failed to open temporary directory").into_path();
+        let program_rocksdb = crate::helpers::rocksdb::FinalizeDB::open_testing(temp_dir, None).unwrap();
+        let finalize_store = FinalizeStore::from(program_rocksdb).unwrap();
+        // Check the history.
+        check_history(&finalize_store);
+    }
+
+    fn check_history<F: FinalizeStorage<CurrentNetwork>>(finalize_store: &FinalizeStore<CurrentNetwork, F>) {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Prepare the keys and values.
+        let key_0 = Plaintext::from_str("0field").unwrap();
+        let key_1 = Plaintext::from_str("1field").unwrap();
+        let value_0 = Value::from_str("0u64").unwrap();
+        let value_1 = Value::from_str("1u64").unwrap();
+
+        // Finalizes a block with the given operations.
+        let finalize = |block_height: u32, operations: &dyn Fn()| {
+            finalize_store.start_atomic();
+            operations();
+            finalize_store.record_revert(block_height).unwrap();
+            finalize_store.record_history(block_height).unwrap();
+            finalize_store.finish_atomic().unwrap();
+        };
+
+        // Finalize the first block, before the history is enabled.
+        finalize(1, &|| {
+            finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key_0.clone(), value_0.clone()).unwrap();
+        });
+        // Ensure the history is disabled.
+        assert!(finalize_store.history_start_height().unwrap().is_none());
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key_0, 1).is_err());
+        assert!(finalize_store.history_iter(program_id, mapping_name, &key_0).is_err());
+
+        // Enable the history from the third block.
+        finalize_store.enable_history(3).unwrap();
+        // Ensure re-enabling the history retains the starting block height.
+        finalize_store.enable_history(5).unwrap();
+        assert_eq!(finalize_store.history_start_height().unwrap(), Some(3));
+
+        // Finalize the second block, which is not recorded.
+        finalize(2, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key_0.clone(), value_1.clone()).unwrap();
+        });
+        // Finalize the third block, which updates the first key and inserts the second key.
+        finalize(3, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key_0.clone(), value_0.clone()).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key_1.clone(), value_0.clone()).unwrap();
+        });
+        // Finalize the fourth block, which does not modify any keys.
+        finalize(4, &|| {});
+        // Finalize the fifth block, which updates the second key twice, leaving it unchanged.
+        finalize(5, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key_1.clone(), value_1.clone()).unwrap();
+            finalize_store.update_key_value(program_id, mapping_name, key_1.clone(), value_0.clone()).unwrap();
+        });
+        // Finalize the sixth block, which removes the first key.
+        finalize(6, &|| {
+            finalize_store.remove_key_value(program_id, mapping_name, &key_0).unwrap();
+        });
+        // Finalize the seventh block, which removes the mapping.
+        finalize(7, &|| {
+            finalize_store.remove_mapping(program_id, mapping_name).unwrap();
+        });
+
+        // Ensure the history is not available before the starting block height.
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key_0, 2).is_err());
+
+        // Ensure the values are correct at each block height.
+        let value_at = |key: &Plaintext<CurrentNetwork>, block_height: u32| {
+            finalize_store.get_value_at_height(program_id, mapping_name, key, block_height).unwrap()
+        };
+        assert_eq!(value_at(&key_0, 3), Some(value_0.clone()));
+        assert_eq!(value_at(&key_0, 5), Some(value_0.clone()));
+        assert_eq!(value_at(&key_0, 6), None);
+        assert_eq!(value_at(&key_1, 3), Some(value_0.clone()));
+        assert_eq!(value_at(&key_1, 4), Some(value_0.clone()));
+        assert_eq!(value_at(&key_1, 5), Some(value_0.clone()));
+        assert_eq!(value_at(&key_1, 6), Some(value_0.clone()));
+        assert_eq!(value_at(&key_1, 7), None);
+        assert_eq!(value_at(&key_1, 100), None);
+
+        // Ensure the change log of each key is correct.
+        let history = |key: &Plaintext<CurrentNetwork>| {
+            finalize_store.history_iter(program_id, mapping_name, key).unwrap().collect::<Vec<_>>()
+        };
+        assert_eq!(history(&key_0), vec![(3, Some(value_0.clone())), (6, None)]);
+        assert_eq!(history(&key_1), vec![(3, Some(value_0.clone())), (7, None)]);
+
+        // Revert the seventh and sixth blocks.
+        finalize_store.revert(7).unwrap();
+        finalize_store.revert(6).unwrap();
+        // Ensure the history is reverted.
+        assert_eq!(history(&key_0), vec![(3, Some(value_0.clone()))]);
+        assert_eq!(history(&key_1), vec![(3, Some(value_0.clone()))]);
+        assert_eq!(value_at(&key_0, 7), Some(value_0.clone()));
+        assert_eq!(value_at(&key_1, 7), Some(value_0));
+        assert_eq!(value_at(&key_0, 3), finalize_store.get_value_confirmed(program_id, mapping_name, &key_0).unwrap());
+
+        // Revert the remaining blocks in the history.
+        for block_height in (3..=5).rev() {
+            finalize_store.revert(block_height).unwrap();
+        }
+        // Ensure the history entries of each key are removed, including their baselines.
+        for key in [&key_0, &key_1] {
+            assert!(finalize_store.storage.get_history_confirmed(program_id, mapping_name, key).unwrap().is_empty());
+        }
+        assert!(finalize_store.storage.history_map().iter_confirmed().next().is_none());
+        assert_eq!(value_at(&key_0, 3), Some(value_1));
+        assert_eq!(value_at(&key_1, 3), None);
+    }
+
     #[test]
     fn test_must_initialize_first() {
         // Initialize a program ID and mapping name.
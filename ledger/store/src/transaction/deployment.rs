@@ -182,6 +182,13 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the program ID.
         let program_id = *program.id();
 
+        // If the program is already deployed, ensure the edition is the next edition of the program.
+        if let Some(latest_edition) = self.get_edition(&program_id)? {
+            if latest_edition.checked_add(1) != Some(edition) {
+                bail!("Failed to insert edition {edition} of program '{program_id}' (latest edition {latest_edition})")
+            }
+        }
+
         atomic_batch_scope!(self, {
             // Store the program ID.
             self.id_map().insert(*transaction_id, program_id)?;
@@ -211,6 +218,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     }
 
     /// Removes the deployment transaction for the given `transaction ID`.
+    /// If the transaction deployed an upgrade, the program is restored to its previous edition.
     fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
         // Retrieve the program ID.
         let program_id = match self.get_program_id(transaction_id)? {
@@ -218,10 +226,22 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.find_edition_from_transaction_id(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to locate the edition for program '{program_id}'"),
         };
+        // Ensure the edition is the latest edition, as later editions must be removed first.
+        if self.get_edition(&program_id)? != Some(edition) {
+            bail!("Failed to remove edition {edition} of program '{program_id}' before its later editions")
+        }
+        // Retrieve the previous edition, if the transaction deployed an upgrade.
+        let previous_edition = match edition.checked_sub(1) {
+            Some(previous) => match self.reverse_id_map().contains_key_confirmed(&(program_id, previous))? {
+                true => Some(previous),
+                false => None,
+            },
+            None => None,
+        };
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -231,8 +251,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Restore the previous edition, or remove the edition if this is the original deployment.
+            match previous_edition {
+                Some(previous_edition) => self.edition_map().insert(program_id, previous_edition)?,
+                None => self.edition_map().remove(&program_id)?,
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -256,6 +279,40 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the verifying keys, certificates, and fee proof for the given `transaction ID`.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = match self.get_program_id(transaction_id)? {
+            Some(program_id) => program_id,
+            None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
+        };
+        // Retrieve the edition.
+        let edition = match self.find_edition_from_transaction_id(transaction_id)? {
+            Some(edition) => edition,
+            None => bail!("Failed to locate the edition for program '{program_id}'"),
+        };
+        // Retrieve the program.
+        let program = match self.program_map().get_confirmed(&(program_id, edition))? {
+            Some(program) => cow_to_cloned!(program),
+            None => bail!("Failed to locate program '{program_id}' for transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Drop the verifying keys and certificates.
+            for function_name in program.functions().keys() {
+                // Remove the verifying key.
+                self.verifying_key_map().remove(&(program_id, *function_name, edition))?;
+                // Remove the certificate.
+                self.certificate_map().remove(&(program_id, *function_name, edition))?;
+            }
+
+            // Drop the fee proof.
+            self.fee_store().prune(transaction_id)?;
+
+            Ok(())
+        })
+    }
+
     /// Returns the transaction ID that contains the given `program ID`.
     fn find_transaction_id_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
         // Check if the program ID is for 'credits.aleo'.
@@ -294,6 +351,41 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the edition deployed by the given `transaction ID`.
+    fn find_edition_from_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        // Retrieve the program ID.
+        let program_id = match self.get_program_id(transaction_id)? {
+            Some(program_id) => program_id,
+            None => return Ok(None),
+        };
+        // Find the edition that was deployed by the transaction.
+        Ok(self
+            .get_edition_history(&program_id)?
+            .into_iter()
+            .find(|(_, id)| id == transaction_id)
+            .map(|(edition, _)| edition))
+    }
+
+    /// Returns the `(edition, transaction ID)` of every deployed edition of the given `program ID`,
+    /// in ascending order of edition.
+    fn get_edition_history(&self, program_id: &ProgramID<N>) -> Result<Vec<(u16, N::TransactionID)>> {
+        // Retrieve the latest edition.
+        let Some(latest_edition) = self.get_edition(program_id)? else {
+            return Ok(vec![]);
+        };
+
+        // Walk back from the latest edition, as the editions of a program are contiguous.
+        let mut history = Vec::new();
+        for edition in (0..=latest_edition).rev() {
+            match self.reverse_id_map().get_confirmed(&(*program_id, edition))? {
+                Some(transaction_id) => history.push((edition, cow_to_copied!(transaction_id))),
+                None => break,
+            }
+        }
+        history.reverse();
+        Ok(history)
+    }
+
     /// Returns the edition for the given `program ID`.
     fn get_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
         // Check if the program ID is for 'credits.aleo'.
@@ -330,6 +422,14 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the program for the given `program ID` and `edition`.
+    fn get_program_for_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<Program<N>>> {
+        match self.program_map().get_confirmed(&(*program_id, edition))? {
+            Some(program) => Ok(Some(cow_to_cloned!(program))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the verifying key for the given `program ID` and `function name`.
     fn get_verifying_key(
         &self,
@@ -390,7 +490,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => return Ok(None),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.find_edition_from_transaction_id(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to get the edition for program '{program_id}'"),
         };
@@ -428,7 +528,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.fee_store().get_fee(transaction_id)
     }
 
-    /// Returns the owner for the given `program ID`.
+    /// Returns the owner of the latest edition for the given `program ID`.
     fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
@@ -437,7 +537,6 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             return Ok(None);
         }
 
-        // TODO (raychu86): Consider program upgrades and edition changes.
         // Retrieve the edition.
         let edition = match self.get_edition(program_id)? {
             Some(edition) => edition,
@@ -464,9 +563,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the fee for transaction '{transaction_id}'"),
         };
 
-        // Retrieve the owner.
-        let owner = match self.get_owner(deployment.program_id())? {
-            Some(owner) => owner,
+        // Retrieve the owner of the deployed edition.
+        let owner = match self.owner_map().get_confirmed(&(*deployment.program_id(), deployment.edition()))? {
+            Some(owner) => cow_to_copied!(owner),
             None => bail!("Failed to get the owner for transaction '{transaction_id}'"),
         };
 
@@ -513,6 +612,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the verifying keys, certificates, and fee proof for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -570,6 +674,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_edition(program_id)
     }
 
+    /// Returns the `(edition, transaction ID)` of every deployed edition of the given `program ID`.
+    pub fn get_edition_history(&self, program_id: &ProgramID<N>) -> Result<Vec<(u16, N::TransactionID)>> {
+        self.storage.get_edition_history(program_id)
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     pub fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         self.storage.get_program_id(transaction_id)
@@ -580,6 +689,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_program(program_id)
     }
 
+    /// Returns the program for the given `program ID` and `edition`.
+    pub fn get_program_for_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<Program<N>>> {
+        self.storage.get_program_for_edition(program_id, edition)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
@@ -602,6 +716,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         self.storage.get_fee(transaction_id)
     }
+
+    /// Returns the owner of the latest edition for the given `program ID`.
+    pub fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.get_owner(program_id)
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
@@ -610,6 +729,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.find_transaction_id_from_program_id(program_id)
     }
 
+    /// Returns the edition deployed by the given `transaction ID`.
+    pub fn find_edition_from_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        self.storage.find_edition_from_transaction_id(transaction_id)
+    }
+
     /// Returns the transaction ID that deployed the given `transition ID`.
     pub fn find_transaction_id_from_transition_id(
         &self,
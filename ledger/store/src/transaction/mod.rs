@@ -55,6 +55,8 @@ pub enum TransactionType {
 pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// The mapping of `transaction ID` to `transaction type`.
     type IDMap: for<'a> Map<'a, N::TransactionID, TransactionType>;
+    /// The mapping of pruned `transaction ID`s.
+    type PrunedMap: for<'a> Map<'a, N::TransactionID, ()>;
     /// The deployment storage.
     type DeploymentStorage: DeploymentStorage<N, FeeStorage = Self::FeeStorage>;
     /// The execution storage.
@@ -69,6 +71,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
+    /// Returns the pruned map.
+    fn pruned_map(&self) -> &Self::PrunedMap;
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage>;
     /// Returns the execution store.
@@ -90,6 +94,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.id_map().start_atomic();
+        self.pruned_map().start_atomic();
         self.deployment_store().start_atomic();
         self.execution_store().start_atomic();
         self.fee_store().start_atomic();
@@ -98,6 +103,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
         self.id_map().is_atomic_in_progress()
+            || self.pruned_map().is_atomic_in_progress()
             || self.deployment_store().is_atomic_in_progress()
             || self.execution_store().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
@@ -106,6 +112,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Checkpoints the atomic batch.
     fn atomic_checkpoint(&self) {
         self.id_map().atomic_checkpoint();
+        self.pruned_map().atomic_checkpoint();
         self.deployment_store().atomic_checkpoint();
         self.execution_store().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
@@ -114,6 +121,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Clears the latest atomic batch checkpoint.
     fn clear_latest_checkpoint(&self) {
         self.id_map().clear_latest_checkpoint();
+        self.pruned_map().clear_latest_checkpoint();
         self.deployment_store().clear_latest_checkpoint();
         self.execution_store().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
@@ -122,6 +130,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Rewinds the atomic batch to the previous checkpoint.
     fn atomic_rewind(&self) {
         self.id_map().atomic_rewind();
+        self.pruned_map().atomic_rewind();
         self.deployment_store().atomic_rewind();
         self.execution_store().atomic_rewind();
         self.fee_store().atomic_rewind();
@@ -130,6 +139,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Aborts an atomic batch write operation.
     fn abort_atomic(&self) {
         self.id_map().abort_atomic();
+        self.pruned_map().abort_atomic();
         self.deployment_store().abort_atomic();
         self.execution_store().abort_atomic();
         self.fee_store().abort_atomic();
@@ -138,6 +148,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.id_map().finish_atomic()?;
+        self.pruned_map().finish_atomic()?;
         self.deployment_store().finish_atomic()?;
         self.execution_store().finish_atomic()?;
         self.fee_store().finish_atomic()
@@ -181,6 +192,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Remove the transaction type.
             self.id_map().remove(transaction_id)?;
+            // Remove the pruned marker.
+            self.pruned_map().remove(transaction_id)?;
             // Remove the transaction.
             match transaction_type {
                 // Remove the deployment transaction.
@@ -194,6 +207,41 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the proofs and verifying keys for the given `transaction ID`.
+    ///
+    /// The transitions are retained, so that the commitments, serial numbers, and tags remain available.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Return early if the transaction is already pruned.
+        if self.is_pruned(transaction_id)? {
+            return Ok(());
+        }
+        // Retrieve the transaction type.
+        let transaction_type = match self.id_map().get_confirmed(transaction_id)? {
+            Some(transaction_type) => cow_to_copied!(transaction_type),
+            None => bail!("Failed to get the type for transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            // Prune the transaction.
+            match transaction_type {
+                // Prune the deployment transaction.
+                TransactionType::Deploy => self.deployment_store().prune(transaction_id)?,
+                // Prune the execution transaction.
+                TransactionType::Execute => self.execution_store().prune(transaction_id)?,
+                // Fee transactions belong to rejected transactions, which are not pruned.
+                TransactionType::Fee => bail!("Cannot prune fee transaction '{transaction_id}'"),
+            }
+            // Mark the transaction as pruned.
+            self.pruned_map().insert(*transaction_id, ())?;
+            Ok(())
+        })
+    }
+
+    /// Returns `true` if the given transaction ID has been pruned.
+    fn is_pruned(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        self.pruned_map().contains_key_confirmed(transaction_id)
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -214,6 +262,10 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
             Some(transaction_type) => cow_to_copied!(transaction_type),
             None => return Ok(None),
         };
+        // Ensure the transaction has not been pruned.
+        if self.is_pruned(transaction_id)? {
+            bail!("Transaction '{transaction_id}' has been pruned from storage")
+        }
         // Retrieve the transaction.
         match transaction_type {
             // Return the deployment transaction.
@@ -262,6 +314,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the proofs and verifying keys for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the deployment store.
     pub fn deployment_store(&self) -> &DeploymentStore<N, T::DeploymentStorage> {
         self.storage.deployment_store()
@@ -326,6 +383,10 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
             Some(transaction_type) => cow_to_copied!(transaction_type),
             None => bail!("Failed to get the type for transaction '{transaction_id}'"),
         };
+        // Ensure the deployment has not been pruned.
+        if self.storage.is_pruned(transaction_id)? {
+            bail!("Deployment transaction '{transaction_id}' has been pruned from storage")
+        }
         // Retrieve the deployment.
         match transaction_type {
             // Return the deployment.
@@ -344,6 +405,10 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
             Some(transaction_type) => cow_to_copied!(transaction_type),
             None => bail!("Failed to get the type for transaction '{transaction_id}'"),
         };
+        // Ensure the execution has not been pruned.
+        if self.storage.is_pruned(transaction_id)? {
+            bail!("Execution transaction '{transaction_id}' has been pruned from storage")
+        }
         // Retrieve the execution.
         match transaction_type {
             // Throw an error.
@@ -365,12 +430,10 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         // Retrieve the edition.
         match transaction_type {
             TransactionType::Deploy => {
-                // Retrieve the program ID.
-                let program_id = self.storage.deployment_store().get_program_id(transaction_id)?;
-                // Return the edition.
-                match program_id {
-                    Some(program_id) => self.storage.deployment_store().get_edition(&program_id),
-                    None => bail!("Failed to get the program ID for deployment transaction '{transaction_id}'"),
+                // Retrieve the edition deployed by the transaction.
+                match self.storage.deployment_store().find_edition_from_transaction_id(transaction_id)? {
+                    Some(edition) => Ok(Some(edition)),
+                    None => bail!("Failed to get the edition for deployment transaction '{transaction_id}'"),
                 }
             }
             // Return 'None'.
@@ -390,12 +453,25 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().get_program(program_id)
     }
 
+    /// Returns the program for the given `program ID` and `edition`.
+    pub fn get_program_for_edition(&self, program_id: &ProgramID<N>, edition: u16) -> Result<Option<Program<N>>> {
+        self.storage.deployment_store().get_program_for_edition(program_id, edition)
+    }
+
+    /// Returns the `(edition, transaction ID)` of every deployed edition of the given `program ID`,
+    /// in ascending order of edition.
+    pub fn get_edition_history(&self, program_id: &ProgramID<N>) -> Result<Vec<(u16, N::TransactionID)>> {
+        self.storage.deployment_store().get_edition_history(program_id)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
         program_id: &ProgramID<N>,
         function_name: &Identifier<N>,
     ) -> Result<Option<VerifyingKey<N>>> {
+        // Ensure the verifying key has not been pruned.
+        self.ensure_program_is_not_pruned(program_id)?;
         self.storage.deployment_store().get_verifying_key(program_id, function_name)
     }
 
@@ -405,8 +481,20 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         program_id: &ProgramID<N>,
         function_name: &Identifier<N>,
     ) -> Result<Option<Certificate<N>>> {
+        // Ensure the certificate has not been pruned.
+        self.ensure_program_is_not_pruned(program_id)?;
         self.storage.deployment_store().get_certificate(program_id, function_name)
     }
+
+    /// Ensures the deployment of the given `program ID` has not been pruned.
+    fn ensure_program_is_not_pruned(&self, program_id: &ProgramID<N>) -> Result<()> {
+        if let Some(transaction_id) = self.storage.deployment_store().find_transaction_id_from_program_id(program_id)? {
+            if self.storage.is_pruned(&transaction_id)? {
+                bail!("The deployment of '{program_id}' has been pruned from storage (transaction '{transaction_id}')")
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
@@ -430,6 +518,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.transaction_ids.contains_key_confirmed(transaction_id)
     }
 
+    /// Returns `true` if the given transaction ID has been pruned.
+    pub fn is_pruned(&self, transaction_id: &N::TransactionID) -> Result<bool> {
+        self.storage.is_pruned(transaction_id)
+    }
+
     /// Returns `true` if the given program ID exists.
     pub fn contains_program_id(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.storage.deployment_store().contains_program_id(program_id)
@@ -568,4 +661,71 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_prune() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions.
+        for transaction in [
+            ledger_test_helpers::sample_deployment_transaction(true, rng),
+            ledger_test_helpers::sample_deployment_transaction(false, rng),
+            ledger_test_helpers::sample_execution_transaction_with_fee(true, rng),
+            ledger_test_helpers::sample_execution_transaction_with_fee(false, rng),
+        ] {
+            let transaction_id = transaction.id();
+
+            // Initialize a new transition store.
+            let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+            // Initialize a new transaction store.
+            let transaction_store = TransactionStore::<_, TransactionMemory<_>>::open(transition_store).unwrap();
+
+            // Insert the transaction.
+            transaction_store.insert(&transaction).unwrap();
+            assert!(!transaction_store.is_pruned(&transaction_id).unwrap());
+
+            // Prune the transaction, twice to ensure pruning is idempotent.
+            transaction_store.prune(&transaction_id).unwrap();
+            transaction_store.prune(&transaction_id).unwrap();
+            assert!(transaction_store.is_pruned(&transaction_id).unwrap());
+
+            // Ensure the pruned transaction can no longer be retrieved.
+            let error = transaction_store.get_transaction(&transaction_id).unwrap_err();
+            assert!(error.to_string().contains("pruned"));
+
+            // Ensure the transaction ID and transitions are retained.
+            assert!(transaction_store.contains_transaction_id(&transaction_id).unwrap());
+            for transition_id in transaction.transition_ids() {
+                assert!(transaction_store.transition_store().contains_transition_id(transition_id).unwrap());
+            }
+
+            match &transaction {
+                Transaction::Deploy(_, _, deployment, _) => {
+                    // Ensure the deployment, verifying keys, and certificates are pruned.
+                    assert!(transaction_store.get_deployment(&transaction_id).is_err());
+                    let program_id = deployment.program_id();
+                    for function_name in deployment.program().functions().keys() {
+                        assert!(transaction_store.get_verifying_key(program_id, function_name).is_err());
+                        assert!(transaction_store.get_certificate(program_id, function_name).is_err());
+                    }
+                    assert_eq!(transaction_store.verifying_keys().count(), 0);
+                    // Ensure the program is retained.
+                    assert_eq!(transaction_store.get_program(program_id).unwrap().as_ref(), Some(deployment.program()));
+                }
+                Transaction::Execute(..) => {
+                    // Ensure the execution is pruned.
+                    assert!(transaction_store.get_execution(&transaction_id).is_err());
+                }
+                Transaction::Fee(..) => unreachable!(),
+            }
+
+            // Remove the transaction.
+            transaction_store.remove(&transaction_id).unwrap();
+
+            // Ensure the transaction does not exist.
+            assert!(!transaction_store.is_pruned(&transaction_id).unwrap());
+            let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
+            assert_eq!(None, candidate);
+        }
+    }
 }
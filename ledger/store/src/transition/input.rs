@@ -14,6 +14,7 @@
 
 use crate::{
     atomic_batch_scope,
+    cow_to_copied,
     helpers::{Map, MapRead},
 };
 use console::{
@@ -421,6 +422,13 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     }
 }
 
+impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
+    /// Returns the serial number for the given `tag`.
+    pub fn find_serial_number_from_tag(&self, tag: &Field<N>) -> Result<Option<Field<N>>> {
+        Ok(self.record_tag.get_confirmed(tag)?.map(|serial_number| cow_to_copied!(serial_number)))
+    }
+}
+
 impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     /// Returns an iterator over the input IDs, for all transition inputs.
     pub fn input_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
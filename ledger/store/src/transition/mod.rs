@@ -22,19 +22,72 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
 };
 use console::{
     network::prelude::*,
-    program::{Ciphertext, Identifier, Plaintext, ProgramID, Record},
-    types::{Field, Group},
+    program::{Argument, Ciphertext, Future, Identifier, Literal, Owner, Plaintext, ProgramID, Record},
+    types::{Address, Field, Group},
 };
 use ledger_block::{Input, Output, Transition};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
+use indexmap::IndexSet;
 use std::borrow::Cow;
 
+/// The key of the enabled flag in the index status map.
+const INDEXES_ENABLED_KEY: u8 = 0;
+
+/// Returns the addresses that are publicly visible in the given `transition`,
+/// from its public inputs, public outputs, public record owners, and future arguments.
+fn to_public_addresses<N: Network>(transition: &Transition<N>) -> IndexSet<Address<N>> {
+    // Collects the addresses in the given plaintext.
+    fn plaintext_addresses<N: Network>(plaintext: &Plaintext<N>, addresses: &mut IndexSet<Address<N>>) {
+        match plaintext {
+            Plaintext::Literal(Literal::Address(address), _) => {
+                addresses.insert(*address);
+            }
+            Plaintext::Literal(..) => (),
+            Plaintext::Struct(members, _) => {
+                members.values().for_each(|member| plaintext_addresses(member, addresses));
+            }
+            Plaintext::Array(elements, _) => {
+                elements.iter().for_each(|element| plaintext_addresses(element, addresses));
+            }
+        }
+    }
+    // Collects the addresses in the given future.
+    fn future_addresses<N: Network>(future: &Future<N>, addresses: &mut IndexSet<Address<N>>) {
+        for argument in future.arguments() {
+            match argument {
+                Argument::Plaintext(plaintext) => plaintext_addresses(plaintext, addresses),
+                Argument::Future(future) => future_addresses(future, addresses),
+            }
+        }
+    }
+
+    let mut addresses = IndexSet::new();
+    for input in transition.inputs() {
+        if let Input::Public(_, Some(plaintext)) = input {
+            plaintext_addresses(plaintext, &mut addresses);
+        }
+    }
+    for output in transition.outputs() {
+        match output {
+            Output::Public(_, Some(plaintext)) => plaintext_addresses(plaintext, &mut addresses),
+            Output::Record(_, _, Some(record)) => {
+                if let Owner::Public(address) = record.owner() {
+                    addresses.insert(*address);
+                }
+            }
+            Output::Future(_, Some(future)) => future_addresses(future, &mut addresses),
+            _ => (),
+        }
+    }
+    addresses
+}
+
 /// A trait for transition storage.
 pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     /// The transition program IDs and function names.
@@ -51,6 +104,12 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type TCMMap: for<'a> Map<'a, N::TransitionID, Field<N>>;
     /// The mapping of `transition commitment` to `transition ID`.
     type ReverseTCMMap: for<'a> Map<'a, Field<N>, N::TransitionID>;
+    /// The mapping of `address` to the `transition ID`s that publicly reference it.
+    type AddressIndexMap: for<'a> NestedMap<'a, Address<N>, N::TransitionID, ()>;
+    /// The mapping of `program ID` to the `transition ID`s of the executions that called it.
+    type ProgramIndexMap: for<'a> NestedMap<'a, ProgramID<N>, N::TransitionID, ()>;
+    /// The mapping of index status keys to their values.
+    type IndexStatusMap: for<'a> Map<'a, u8, bool>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -69,6 +128,12 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn tcm_map(&self) -> &Self::TCMMap;
     /// Returns the reverse `tcm` map.
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
+    /// Returns the address index map.
+    fn address_index_map(&self) -> &Self::AddressIndexMap;
+    /// Returns the program index map.
+    fn program_index_map(&self) -> &Self::ProgramIndexMap;
+    /// Returns the index status map.
+    fn index_status_map(&self) -> &Self::IndexStatusMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
@@ -85,6 +150,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().start_atomic();
         self.tcm_map().start_atomic();
         self.reverse_tcm_map().start_atomic();
+        self.address_index_map().start_atomic();
+        self.program_index_map().start_atomic();
+        self.index_status_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -96,6 +164,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             || self.reverse_tpk_map().is_atomic_in_progress()
             || self.tcm_map().is_atomic_in_progress()
             || self.reverse_tcm_map().is_atomic_in_progress()
+            || self.address_index_map().is_atomic_in_progress()
+            || self.program_index_map().is_atomic_in_progress()
+            || self.index_status_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -107,6 +178,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().atomic_checkpoint();
         self.tcm_map().atomic_checkpoint();
         self.reverse_tcm_map().atomic_checkpoint();
+        self.address_index_map().atomic_checkpoint();
+        self.program_index_map().atomic_checkpoint();
+        self.index_status_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -118,6 +192,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().clear_latest_checkpoint();
         self.tcm_map().clear_latest_checkpoint();
         self.reverse_tcm_map().clear_latest_checkpoint();
+        self.address_index_map().clear_latest_checkpoint();
+        self.program_index_map().clear_latest_checkpoint();
+        self.index_status_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -129,6 +206,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().atomic_rewind();
         self.tcm_map().atomic_rewind();
         self.reverse_tcm_map().atomic_rewind();
+        self.address_index_map().atomic_rewind();
+        self.program_index_map().atomic_rewind();
+        self.index_status_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -140,6 +220,9 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().abort_atomic();
         self.tcm_map().abort_atomic();
         self.reverse_tcm_map().abort_atomic();
+        self.address_index_map().abort_atomic();
+        self.program_index_map().abort_atomic();
+        self.index_status_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -150,7 +233,10 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tpk_map().finish_atomic()?;
         self.reverse_tpk_map().finish_atomic()?;
         self.tcm_map().finish_atomic()?;
-        self.reverse_tcm_map().finish_atomic()
+        self.reverse_tcm_map().finish_atomic()?;
+        self.address_index_map().finish_atomic()?;
+        self.program_index_map().finish_atomic()?;
+        self.index_status_map().finish_atomic()
     }
 
     /// Stores the given `transition` into storage.
@@ -172,6 +258,10 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.tcm_map().insert(transition_id, *transition.tcm())?;
             // Store the reverse `tcm` entry.
             self.reverse_tcm_map().insert(*transition.tcm(), transition_id)?;
+            // Store the secondary index entries.
+            if self.indexes_enabled()? {
+                self.insert_index_entries(transition)?;
+            }
 
             Ok(())
         })
@@ -189,6 +279,11 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             Some(tcm) => cow_to_copied!(tcm),
             None => return Ok(()),
         };
+        // Retrieve the transition, if the secondary index entries need to be removed.
+        let transition = match self.indexes_enabled()? {
+            true => self.get(transition_id)?,
+            false => None,
+        };
 
         atomic_batch_scope!(self, {
             // Remove the program ID and function name.
@@ -205,11 +300,110 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.tcm_map().remove(transition_id)?;
             // Remove the reverse `tcm` entry.
             self.reverse_tcm_map().remove(&tcm)?;
+            // Remove the secondary index entries.
+            if let Some(transition) = transition {
+                self.remove_index_entries(&transition)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Returns `true` if the secondary indexes are enabled.
+    fn indexes_enabled(&self) -> Result<bool> {
+        Ok(self
+            .index_status_map()
+            .get_confirmed(&INDEXES_ENABLED_KEY)?
+            .map_or(false, |enabled| cow_to_copied!(enabled)))
+    }
+
+    /// Enables the secondary indexes, and builds them from the existing transitions.
+    /// If the indexes are already enabled, this method is a no-op.
+    fn enable_indexes(&self) -> Result<()> {
+        // If the indexes are already enabled, return early.
+        if self.indexes_enabled()? {
+            return Ok(());
+        }
+        atomic_batch_scope!(self, {
+            // Build the secondary indexes.
+            self.rebuild_indexes()?;
+            // Store the enabled flag.
+            self.index_status_map().insert(INDEXES_ENABLED_KEY, true)?;
 
             Ok(())
         })
     }
 
+    /// Rebuilds the secondary indexes from the existing transitions.
+    fn rebuild_indexes(&self) -> Result<()> {
+        // Retrieve the indexed addresses and program IDs.
+        let addresses = self.address_index_map().keys_confirmed().map(|(address, _)| cow_to_copied!(address));
+        let addresses = addresses.collect::<IndexSet<_>>();
+        let program_ids = self.program_index_map().keys_confirmed().map(|(program_id, _)| cow_to_copied!(program_id));
+        let program_ids = program_ids.collect::<IndexSet<_>>();
+        // Retrieve the transition IDs.
+        let transition_ids = self.locator_map().keys_confirmed().map(|id| cow_to_copied!(id)).collect::<Vec<_>>();
+
+        atomic_batch_scope!(self, {
+            // Clear the secondary indexes.
+            for address in addresses {
+                self.address_index_map().remove_map(&address)?;
+            }
+            for program_id in program_ids {
+                self.program_index_map().remove_map(&program_id)?;
+            }
+            // Index the transitions.
+            for transition_id in transition_ids {
+                match self.get(&transition_id)? {
+                    Some(transition) => self.insert_index_entries(&transition)?,
+                    None => bail!("Failed to index the missing transition '{transition_id}'"),
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Stores the secondary index entries for the given `transition`.
+    fn insert_index_entries(&self, transition: &Transition<N>) -> Result<()> {
+        let transition_id = *transition.id();
+        // Store the address index entries.
+        for address in to_public_addresses(transition) {
+            self.address_index_map().insert(address, transition_id, ())?;
+        }
+        // Store the program index entry.
+        // Note: Fee transitions are not indexed, as they do not belong to the executions that called the program.
+        if !transition.is_fee_private() && !transition.is_fee_public() {
+            self.program_index_map().insert(*transition.program_id(), transition_id, ())?;
+        }
+        Ok(())
+    }
+
+    /// Removes the secondary index entries for the given `transition`.
+    fn remove_index_entries(&self, transition: &Transition<N>) -> Result<()> {
+        let transition_id = transition.id();
+        // Remove the address index entries.
+        for address in to_public_addresses(transition) {
+            self.address_index_map().remove_key(&address, transition_id)?;
+        }
+        // Remove the program index entry.
+        self.program_index_map().remove_key(transition.program_id(), transition_id)
+    }
+
+    /// Returns the transition IDs that publicly reference the given `address`.
+    fn find_transition_ids_from_address(&self, address: &Address<N>) -> Result<Vec<N::TransitionID>> {
+        // Ensure the secondary indexes are enabled.
+        ensure!(self.indexes_enabled()?, "The transition indexes are not enabled");
+        Ok(self.address_index_map().get_map_confirmed(address)?.into_iter().map(|(id, _)| id).collect())
+    }
+
+    /// Returns the transition IDs of the executions that called the given `program ID`.
+    fn find_transition_ids_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Vec<N::TransitionID>> {
+        // Ensure the secondary indexes are enabled.
+        ensure!(self.indexes_enabled()?, "The transition indexes are not enabled");
+        Ok(self.program_index_map().get_map_confirmed(program_id)?.into_iter().map(|(id, _)| id).collect())
+    }
+
     /// Returns the transition for the given `transition ID`.
     fn get(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
         // Retrieve the program ID and function name.
@@ -311,6 +505,24 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Returns `true` if the secondary indexes are enabled.
+    pub fn indexes_enabled(&self) -> Result<bool> {
+        self.storage.indexes_enabled()
+    }
+
+    /// Enables the secondary indexes, and builds them from the existing transitions.
+    /// If the indexes are already enabled, this method is a no-op.
+    pub fn enable_indexes(&self) -> Result<()> {
+        self.storage.enable_indexes()
+    }
+
+    /// Rebuilds the secondary indexes from the existing transitions.
+    pub fn rebuild_indexes(&self) -> Result<()> {
+        // Ensure the secondary indexes are enabled.
+        ensure!(self.storage.indexes_enabled()?, "The transition indexes are not enabled");
+        self.storage.rebuild_indexes()
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -366,6 +578,28 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         // Throw an error.
         bail!("Failed to find the transition ID for the given input or output ID '{id}'")
     }
+
+    /// Returns the commitment for the given record `nonce`.
+    pub fn find_commitment_from_nonce(&self, nonce: &Group<N>) -> Result<Option<Field<N>>> {
+        self.outputs.find_commitment_from_nonce(nonce)
+    }
+
+    /// Returns the serial number for the given record `tag`.
+    pub fn find_serial_number_from_tag(&self, tag: &Field<N>) -> Result<Option<Field<N>>> {
+        self.inputs.find_serial_number_from_tag(tag)
+    }
+
+    /// Returns the transition IDs that publicly reference the given `address`.
+    /// Note: This method requires the secondary indexes to be enabled.
+    pub fn find_transition_ids_from_address(&self, address: &Address<N>) -> Result<Vec<N::TransitionID>> {
+        self.storage.find_transition_ids_from_address(address)
+    }
+
+    /// Returns the transition IDs of the executions that called the given `program ID`.
+    /// Note: This method requires the secondary indexes to be enabled.
+    pub fn find_transition_ids_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Vec<N::TransitionID>> {
+        self.storage.find_transition_ids_from_program_id(program_id)
+    }
 }
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
@@ -706,4 +940,58 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_indexes() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transitions.
+        let transaction = ledger_test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let transitions = transaction.transitions().cloned().collect::<Vec<_>>();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionMemory::open(None).unwrap();
+
+        // Insert the first transition, before the indexes are enabled.
+        let (first, rest) = transitions.split_first().unwrap();
+        transition_store.insert(first).unwrap();
+
+        // Ensure the indexes are not enabled.
+        assert!(!transition_store.indexes_enabled().unwrap());
+        assert!(transition_store.find_transition_ids_from_program_id(first.program_id()).is_err());
+
+        // Enable the indexes, and insert the remaining transitions.
+        transition_store.enable_indexes().unwrap();
+        assert!(transition_store.indexes_enabled().unwrap());
+        for transition in rest {
+            transition_store.insert(transition).unwrap();
+        }
+
+        // Ensure every transition is indexed, including the one inserted before the indexes were enabled.
+        let check_indexed = |transition: &Transition<_>, expected: bool| {
+            let transition_id = transition.id();
+            for address in to_public_addresses(transition) {
+                let candidate = transition_store.find_transition_ids_from_address(&address).unwrap();
+                assert_eq!(expected, candidate.contains(transition_id));
+            }
+            let candidate = transition_store.find_transition_ids_from_program_id(transition.program_id()).unwrap();
+            let is_fee = transition.is_fee_private() || transition.is_fee_public();
+            assert_eq!(expected && !is_fee, candidate.contains(transition_id));
+        };
+        for transition in transitions.iter() {
+            check_indexed(transition, true);
+        }
+
+        // Ensure rebuilding the indexes is idempotent.
+        transition_store.rebuild_indexes().unwrap();
+        for transition in transitions.iter() {
+            check_indexed(transition, true);
+        }
+
+        // Remove every transition, and ensure it is no longer indexed.
+        for transition in transitions.iter() {
+            transition_store.remove(transition.id()).unwrap();
+            check_indexed(transition, false);
+        }
+    }
 }
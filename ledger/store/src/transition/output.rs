@@ -14,6 +14,7 @@
 
 use crate::{
     atomic_batch_scope,
+    cow_to_copied,
     helpers::{Map, MapRead},
 };
 use console::{
@@ -465,6 +466,13 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     }
 }
 
+impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
+    /// Returns the commitment for the given record `nonce`.
+    pub fn find_commitment_from_nonce(&self, nonce: &Group<N>) -> Result<Option<Field<N>>> {
+        Ok(self.record_nonce.get_confirmed(nonce)?.map(|commitment| cow_to_copied!(commitment)))
+    }
+}
+
 impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
     /// Returns an iterator over the output IDs, for all transition outputs.
     pub fn output_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, Field<N>>> {
@@ -25,6 +25,9 @@ pub enum ParameterError {
     #[error("{}", _0)]
     Message(String),
 
+    #[error("'{}' is missing in {}, and remote fetches are disabled in offline mode", _0, _1)]
+    Offline(String, String),
+
     #[error("Remote fetch is disabled, enable compiler flag for feature")]
     RemoteFetchDisabled,
 
@@ -30,6 +30,11 @@ pub mod macros;
 pub mod errors;
 pub use errors::*;
 
+#[cfg(not(target_family = "wasm"))]
+pub mod manager;
+#[cfg(not(target_family = "wasm"))]
+pub use manager::*;
+
 pub mod testnet3;
 
 pub mod prelude {
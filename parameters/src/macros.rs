@@ -188,14 +188,18 @@ macro_rules! impl_load_bytes_logic_remote {
                 );
             }
 
-            // Construct the URL.
-            let url = format!("{}/{}", $remote_url, $filename);
-
             // Load remote file
             cfg_if::cfg_if! {
                 if #[cfg(not(feature = "wasm"))] {
-                    let mut buffer = vec![];
-                    Self::remote_fetch(&mut buffer, &url)?;
+                    // Resolve the source of the file, from the mirror and offline mode configuration.
+                    let buffer = match $crate::ParameterConfig::current().resolve($remote_url, $filename, &file_path)? {
+                        $crate::ParameterSource::File(path) => std::fs::read(path)?,
+                        $crate::ParameterSource::Url(url) => {
+                            let mut buffer = vec![];
+                            Self::remote_fetch(&mut buffer, &url)?;
+                            buffer
+                        }
+                    };
 
                     // Ensure the checksum matches.
                     let candidate_checksum = checksum!(&buffer);
@@ -214,6 +218,8 @@ macro_rules! impl_load_bytes_logic_remote {
                         }
                     }
                 } else if #[cfg(feature = "wasm")] {
+                    // Construct the URL.
+                    let url = format!("{}/{}", $remote_url, $filename);
                     let buffer = Self::remote_fetch(&url)?;
 
                     // Ensure the checksum matches.
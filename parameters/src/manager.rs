@@ -0,0 +1,427 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+
+use parking_lot::RwLock;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The environment variable, which sets the mirror to fetch the parameter files from.
+pub const PARAMETERS_MIRROR_ENV: &str = "SNARKVM_PARAMETERS_MIRROR";
+/// The environment variable, which enables the strict offline mode.
+pub const PARAMETERS_OFFLINE_ENV: &str = "SNARKVM_PARAMETERS_OFFLINE";
+
+/// The file name of the manifest in a parameter bundle.
+const BUNDLE_MANIFEST: &str = "manifest.json";
+
+lazy_static! {
+    /// The global parameter configuration, which is initialized from the environment.
+    static ref PARAMETER_CONFIG: RwLock<ParameterConfig> = RwLock::new(ParameterConfig::from_env());
+}
+
+/// The configuration of where missing parameter files are fetched from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParameterConfig {
+    /// The mirror to fetch from, as a local directory, a `file://` URL, or an HTTP(S) base URL.
+    mirror: Option<String>,
+    /// If `true`, missing parameter files are never fetched over the network.
+    offline: bool,
+}
+
+/// The source to load a missing parameter file from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParameterSource {
+    /// A file on the local filesystem.
+    File(PathBuf),
+    /// A remote URL.
+    Url(String),
+}
+
+impl ParameterConfig {
+    /// Initializes the configuration from the `SNARKVM_PARAMETERS_MIRROR` and `SNARKVM_PARAMETERS_OFFLINE` environment variables.
+    pub fn from_env() -> Self {
+        let mirror = std::env::var(PARAMETERS_MIRROR_ENV).ok().filter(|mirror| !mirror.is_empty());
+        let offline = std::env::var(PARAMETERS_OFFLINE_ENV)
+            .map_or(false, |offline| !matches!(offline.to_lowercase().as_str(), "" | "0" | "false" | "no" | "off"));
+        Self { mirror, offline }
+    }
+
+    /// Returns the global configuration.
+    pub fn current() -> Self {
+        PARAMETER_CONFIG.read().clone()
+    }
+
+    /// Sets the global configuration, for all subsequent parameter loads.
+    pub fn set_current(config: Self) {
+        *PARAMETER_CONFIG.write() = config;
+    }
+
+    /// Sets the mirror, as a local directory, a `file://` URL, or an HTTP(S) base URL.
+    pub fn with_mirror(mut self, mirror: Option<String>) -> Self {
+        self.mirror = mirror;
+        self
+    }
+
+    /// Sets the strict offline mode.
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Returns the mirror, if one is set.
+    pub fn mirror(&self) -> Option<&str> {
+        self.mirror.as_deref()
+    }
+
+    /// Returns `true` if the strict offline mode is enabled.
+    pub const fn offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Returns the local directory of the mirror, if the mirror is on the local filesystem.
+    pub fn local_mirror(&self) -> Option<PathBuf> {
+        let mirror = self.mirror.as_deref()?;
+        match mirror.strip_prefix("file://") {
+            Some(path) => Some(PathBuf::from(path)),
+            None if mirror.contains("://") => None,
+            None => Some(PathBuf::from(mirror)),
+        }
+    }
+
+    /// Returns the source of the given parameter file, which is missing at the given local path.
+    pub fn resolve(
+        &self,
+        remote_url: &str,
+        filename: &str,
+        local_path: &Path,
+    ) -> Result<ParameterSource, ParameterError> {
+        match (self.local_mirror(), &self.mirror) {
+            // Load the file from the local mirror.
+            (Some(directory), _) => Ok(ParameterSource::File(directory.join(filename))),
+            // Fail fast in offline mode, instead of trying the network.
+            (None, _) if self.offline => {
+                Err(ParameterError::Offline(filename.to_string(), format!("{}", local_path.display())))
+            }
+            // Fetch the file from the remote mirror.
+            (None, Some(mirror)) => Ok(ParameterSource::Url(format!("{}/{filename}", mirror.trim_end_matches('/')))),
+            // Fetch the file from the default remote URL.
+            (None, None) => Ok(ParameterSource::Url(format!("{remote_url}/{filename}"))),
+        }
+    }
+}
+
+/// A parameter file, which is fetched on first use.
+#[derive(Clone)]
+pub struct ParameterFile {
+    /// The name of the parameter file, e.g. `powers-of-beta-17`.
+    name: &'static str,
+    /// The type of the parameter file, e.g. `usrs` or `prover`.
+    file_type: &'static str,
+    /// The metadata of the parameter file, with its checksum and size.
+    metadata: &'static str,
+    /// The function to load the parameter file with, fetching it if it is missing.
+    load: fn() -> Result<Vec<u8>, ParameterError>,
+}
+
+/// The status of a parameter file in the local directory.
+#[derive(Debug)]
+pub enum ParameterStatus {
+    /// The file exists, and matches its metadata.
+    Valid,
+    /// The file does not exist.
+    Missing,
+    /// The file exists, but does not match its metadata.
+    Invalid(ParameterError),
+}
+
+impl ParameterFile {
+    /// Initializes a new parameter file.
+    pub const fn new(
+        name: &'static str,
+        file_type: &'static str,
+        metadata: &'static str,
+        load: fn() -> Result<Vec<u8>, ParameterError>,
+    ) -> Self {
+        Self { name, file_type, metadata, load }
+    }
+
+    /// Returns the name of the parameter file.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the type of the parameter file.
+    pub const fn file_type(&self) -> &'static str {
+        self.file_type
+    }
+
+    /// Returns the expected checksum of the parameter file.
+    pub fn checksum(&self) -> Result<String, ParameterError> {
+        let key = match self.file_type {
+            "usrs" => "checksum".to_string(),
+            file_type => format!("{file_type}_checksum"),
+        };
+        self.metadata()?[key.as_str()]
+            .as_str()
+            .map(|checksum| checksum.to_string())
+            .ok_or_else(|| ParameterError::Message(format!("Missing the checksum of '{}'", self.name)))
+    }
+
+    /// Returns the expected size of the parameter file, in bytes.
+    pub fn size(&self) -> Result<usize, ParameterError> {
+        let key = match self.file_type {
+            "usrs" => "size".to_string(),
+            file_type => format!("{file_type}_size"),
+        };
+        self.metadata()?[key.as_str()]
+            .as_u64()
+            .map(|size| size as usize)
+            .ok_or_else(|| ParameterError::Message(format!("Missing the size of '{}'", self.name)))
+    }
+
+    /// Returns the versioned file name, as stored in the local directory and on the remote.
+    pub fn filename(&self) -> Result<String, ParameterError> {
+        let checksum = self.checksum()?;
+        Ok(match checksum.get(0..7) {
+            Some(sum) => format!("{}.{}.{sum}", self.name, self.file_type),
+            None => format!("{}.{}", self.name, self.file_type),
+        })
+    }
+
+    /// Ensures the given bytes match the size and checksum of the parameter file.
+    pub fn check(&self, bytes: &[u8]) -> Result<(), ParameterError> {
+        let expected_size = self.size()?;
+        if expected_size != bytes.len() {
+            return Err(ParameterError::SizeMismatch(expected_size, bytes.len()));
+        }
+        let expected_checksum = self.checksum()?;
+        let candidate_checksum = checksum!(bytes);
+        if expected_checksum != candidate_checksum {
+            return checksum_error!(expected_checksum, candidate_checksum);
+        }
+        Ok(())
+    }
+
+    /// Returns the status of the parameter file in the given directory.
+    /// If `verify_checksum` is `false`, only the size of the file is checked.
+    pub fn status(&self, directory: &Path, verify_checksum: bool) -> Result<ParameterStatus, ParameterError> {
+        let path = directory.join(self.filename()?);
+        if !path.exists() {
+            return Ok(ParameterStatus::Missing);
+        }
+        let result = match verify_checksum {
+            true => self.check(&fs::read(&path)?),
+            false => match (self.size()?, fs::metadata(&path)?.len() as usize) {
+                (expected, candidate) if expected == candidate => Ok(()),
+                (expected, candidate) => Err(ParameterError::SizeMismatch(expected, candidate)),
+            },
+        };
+        Ok(match result {
+            Ok(()) => ParameterStatus::Valid,
+            Err(error) => ParameterStatus::Invalid(error),
+        })
+    }
+
+    /// Returns the parsed metadata.
+    fn metadata(&self) -> Result<serde_json::Value, ParameterError> {
+        serde_json::from_str(self.metadata).map_err(|error| {
+            ParameterError::Crate("serde_json", format!("Invalid metadata of '{}': {error}", self.name))
+        })
+    }
+}
+
+/// A manager for the parameter files that are fetched on first use.
+#[derive(Clone)]
+pub struct ParameterManager {
+    /// The local directory of the parameter files.
+    directory: PathBuf,
+    /// The parameter files.
+    files: Vec<ParameterFile>,
+}
+
+impl ParameterManager {
+    /// Initializes a new manager for the given parameter files, in the given local directory.
+    pub fn new(directory: PathBuf, files: Vec<ParameterFile>) -> Self {
+        Self { directory, files }
+    }
+
+    /// Initializes a new manager for the testnet3 parameter files, in the default local directory.
+    pub fn testnet3() -> Self {
+        Self::new(aleo_std::aleo_dir().join("resources"), crate::testnet3::remote_parameters())
+    }
+
+    /// Returns the local directory of the parameter files.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the parameter files.
+    pub fn files(&self) -> &[ParameterFile] {
+        &self.files
+    }
+
+    /// Returns the status of each parameter file, by checking its existence and size.
+    pub fn list(&self) -> Result<Vec<(&ParameterFile, ParameterStatus)>, ParameterError> {
+        self.files.iter().map(|file| Ok((file, file.status(&self.directory, false)?))).collect()
+    }
+
+    /// Returns the status of each parameter file, by checking its existence, size, and checksum.
+    pub fn verify(&self) -> Result<Vec<(&ParameterFile, ParameterStatus)>, ParameterError> {
+        self.files.iter().map(|file| Ok((file, file.status(&self.directory, true)?))).collect()
+    }
+
+    /// Fetches each missing parameter file, and returns the number of fetched files.
+    /// Note: The files are fetched according to the current `ParameterConfig`.
+    pub fn fetch_all(&self) -> Result<usize, ParameterError> {
+        let mut num_fetched = 0;
+        for file in &self.files {
+            if let ParameterStatus::Valid = file.status(&self.directory, false)? {
+                continue;
+            }
+            (file.load)()?;
+            num_fetched += 1;
+        }
+        Ok(num_fetched)
+    }
+
+    /// Exports the parameter files into a bundle at the given directory, and returns the number of exported files.
+    /// The bundle can be imported with `import_bundle`, or used directly as a local mirror.
+    pub fn export_bundle(&self, bundle: &Path) -> Result<usize, ParameterError> {
+        fs::create_dir_all(bundle)?;
+        let mut manifest = Vec::with_capacity(self.files.len());
+        for file in &self.files {
+            let filename = file.filename()?;
+            // Ensure the file is valid, before it is exported.
+            let bytes = fs::read(self.directory.join(&filename)).map_err(|_| {
+                ParameterError::Message(format!("'{filename}' is missing, fetch it before exporting the bundle"))
+            })?;
+            file.check(&bytes)?;
+            fs::write(bundle.join(&filename), bytes)?;
+            manifest
+                .push(serde_json::json!({ "filename": filename, "checksum": file.checksum()?, "size": file.size()? }));
+        }
+        let manifest = serde_json::to_string_pretty(&serde_json::json!({ "files": manifest }))
+            .map_err(|error| ParameterError::Crate("serde_json", error.to_string()))?;
+        fs::write(bundle.join(BUNDLE_MANIFEST), manifest)?;
+        Ok(self.files.len())
+    }
+
+    /// Imports the parameter files from the bundle at the given directory, and returns the number of imported files.
+    pub fn import_bundle(&self, bundle: &Path) -> Result<usize, ParameterError> {
+        if !bundle.join(BUNDLE_MANIFEST).exists() {
+            return Err(ParameterError::Message(format!("'{}' is not a parameter bundle", bundle.display())));
+        }
+        fs::create_dir_all(&self.directory)?;
+        let mut num_imported = 0;
+        for file in &self.files {
+            let filename = file.filename()?;
+            let path = bundle.join(&filename);
+            if !path.exists() {
+                continue;
+            }
+            // Ensure the file is valid, before it is imported.
+            let bytes = fs::read(&path)?;
+            file.check(&bytes)?;
+            fs::write(self.directory.join(&filename), bytes)?;
+            num_imported += 1;
+        }
+        Ok(num_imported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENTS: &[u8] = b"parameters";
+
+    fn sample_file() -> ParameterFile {
+        let metadata =
+            format!("{{\"prover_checksum\": \"{}\", \"prover_size\": {}}}", checksum!(CONTENTS), CONTENTS.len());
+        ParameterFile::new("sample", "prover", Box::leak(metadata.into_boxed_str()), || Ok(CONTENTS.to_vec()))
+    }
+
+    fn temporary_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("snarkvm-parameters-{name}-{}", rand::random::<u64>()));
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_resolve() {
+        let local_path = Path::new("/tmp/missing");
+
+        // Ensure the default remote URL is used.
+        let config = ParameterConfig::default();
+        let source = config.resolve("https://example.com", "file.prover", local_path).unwrap();
+        assert_eq!(source, ParameterSource::Url("https://example.com/file.prover".to_string()));
+
+        // Ensure a local mirror is used, as a directory or a `file://` URL.
+        for mirror in ["/mirror", "file:///mirror"] {
+            let config = ParameterConfig::default().with_mirror(Some(mirror.to_string())).with_offline(true);
+            let source = config.resolve("https://example.com", "file.prover", local_path).unwrap();
+            assert_eq!(source, ParameterSource::File(PathBuf::from("/mirror/file.prover")));
+        }
+
+        // Ensure a remote mirror is used.
+        let config = ParameterConfig::default().with_mirror(Some("https://mirror.com/".to_string()));
+        let source = config.resolve("https://example.com", "file.prover", local_path).unwrap();
+        assert_eq!(source, ParameterSource::Url("https://mirror.com/file.prover".to_string()));
+
+        // Ensure the offline mode fails fast, without a local mirror.
+        let config = config.with_offline(true);
+        let error = config.resolve("https://example.com", "file.prover", local_path).unwrap_err();
+        assert!(matches!(error, ParameterError::Offline(..)));
+    }
+
+    #[test]
+    fn test_bundle() {
+        let file = sample_file();
+        let filename = file.filename().unwrap();
+        assert_eq!(filename, format!("sample.prover.{}", &checksum!(CONTENTS)[0..7]));
+
+        // Ensure a missing file is listed as missing.
+        let source = temporary_directory("source");
+        let manager = ParameterManager::new(source.clone(), vec![file.clone()]);
+        assert!(matches!(manager.list().unwrap()[0].1, ParameterStatus::Missing));
+        assert!(manager.export_bundle(&temporary_directory("empty")).is_err());
+
+        // Ensure a valid file is exported.
+        fs::write(source.join(&filename), CONTENTS).unwrap();
+        assert!(matches!(manager.verify().unwrap()[0].1, ParameterStatus::Valid));
+        let bundle = temporary_directory("bundle");
+        assert_eq!(manager.export_bundle(&bundle).unwrap(), 1);
+
+        // Ensure the bundle is imported.
+        let target = temporary_directory("target");
+        let manager = ParameterManager::new(target.clone(), vec![file.clone()]);
+        assert_eq!(manager.import_bundle(&bundle).unwrap(), 1);
+        assert!(matches!(manager.verify().unwrap()[0].1, ParameterStatus::Valid));
+
+        // Ensure a corrupt file is detected, and is not imported.
+        fs::write(bundle.join(&filename), b"corrupted!").unwrap();
+        assert!(matches!(manager.verify().unwrap()[0].1, ParameterStatus::Valid));
+        assert!(manager.import_bundle(&bundle).is_err());
+        fs::write(target.join(&filename), b"corrupted!").unwrap();
+        assert!(matches!(manager.list().unwrap()[0].1, ParameterStatus::Valid));
+        assert!(matches!(manager.verify().unwrap()[0].1, ParameterStatus::Invalid(_)));
+
+        for directory in [source, bundle, target] {
+            fs::remove_dir_all(directory).unwrap();
+        }
+    }
+}
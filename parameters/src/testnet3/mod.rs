@@ -158,6 +158,56 @@ lazy_static! {
         InclusionVerifier::load_bytes().expect("Failed to load inclusion verifying key");
 }
 
+/// Returns the parameter files, which are fetched on first use.
+#[cfg(not(target_family = "wasm"))]
+pub fn remote_parameters() -> Vec<crate::ParameterFile> {
+    macro_rules! remote_parameter {
+        ($name: ident, $fname: tt, $ftype: tt) => {
+            crate::ParameterFile::new($fname, $ftype, $name::METADATA, $name::load_bytes)
+        };
+    }
+
+    vec![
+        remote_parameter!(Degree17, "powers-of-beta-17", "usrs"),
+        remote_parameter!(Degree18, "powers-of-beta-18", "usrs"),
+        remote_parameter!(Degree19, "powers-of-beta-19", "usrs"),
+        remote_parameter!(Degree20, "powers-of-beta-20", "usrs"),
+        remote_parameter!(Degree21, "powers-of-beta-21", "usrs"),
+        remote_parameter!(Degree22, "powers-of-beta-22", "usrs"),
+        remote_parameter!(Degree23, "powers-of-beta-23", "usrs"),
+        remote_parameter!(Degree24, "powers-of-beta-24", "usrs"),
+        remote_parameter!(Degree25, "powers-of-beta-25", "usrs"),
+        remote_parameter!(Degree26, "powers-of-beta-26", "usrs"),
+        remote_parameter!(Degree27, "powers-of-beta-27", "usrs"),
+        remote_parameter!(Degree28, "powers-of-beta-28", "usrs"),
+        remote_parameter!(ShiftedDegree17, "shifted-powers-of-beta-17", "usrs"),
+        remote_parameter!(ShiftedDegree18, "shifted-powers-of-beta-18", "usrs"),
+        remote_parameter!(ShiftedDegree19, "shifted-powers-of-beta-19", "usrs"),
+        remote_parameter!(ShiftedDegree20, "shifted-powers-of-beta-20", "usrs"),
+        remote_parameter!(ShiftedDegree21, "shifted-powers-of-beta-21", "usrs"),
+        remote_parameter!(ShiftedDegree22, "shifted-powers-of-beta-22", "usrs"),
+        remote_parameter!(ShiftedDegree23, "shifted-powers-of-beta-23", "usrs"),
+        remote_parameter!(ShiftedDegree24, "shifted-powers-of-beta-24", "usrs"),
+        remote_parameter!(ShiftedDegree25, "shifted-powers-of-beta-25", "usrs"),
+        remote_parameter!(ShiftedDegree26, "shifted-powers-of-beta-26", "usrs"),
+        remote_parameter!(ShiftedDegree27, "shifted-powers-of-beta-27", "usrs"),
+        remote_parameter!(BondPublicProver, "bond_public", "prover"),
+        remote_parameter!(UnbondPublicProver, "unbond_public", "prover"),
+        remote_parameter!(UnbondDelegatorAsValidatorProver, "unbond_delegator_as_validator", "prover"),
+        remote_parameter!(ClaimUnbondPublicProver, "claim_unbond_public", "prover"),
+        remote_parameter!(SetValidatorStateProver, "set_validator_state", "prover"),
+        remote_parameter!(TransferPrivateProver, "transfer_private", "prover"),
+        remote_parameter!(TransferPublicProver, "transfer_public", "prover"),
+        remote_parameter!(TransferPrivateToPublicProver, "transfer_private_to_public", "prover"),
+        remote_parameter!(TransferPublicToPrivateProver, "transfer_public_to_private", "prover"),
+        remote_parameter!(JoinProver, "join", "prover"),
+        remote_parameter!(SplitProver, "split", "prover"),
+        remote_parameter!(FeePrivateProver, "fee_private", "prover"),
+        remote_parameter!(FeePublicProver, "fee_public", "prover"),
+        remote_parameter!(InclusionProver, "inclusion", "prover"),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -15,7 +15,7 @@
 use super::*;
 
 impl<N: Network> Process<N> {
-    /// Deploys the given program ID, if it does not exist.
+    /// Deploys the given program, or an upgrade of the program if it already exists.
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -25,7 +25,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::deploy");
 
         // Compute the stack.
-        let stack = Stack::new(self, program)?;
+        let stack = self.initialize_stack(program)?;
         lap!(timer, "Compute the stack");
 
         // Return the deployment.
@@ -37,14 +37,16 @@ impl<N: Network> Process<N> {
         deployment
     }
 
-    /// Adds the newly-deployed program.
+    /// Adds the newly-deployed program, or upgrades the program if it already exists.
     /// This method assumes the given deployment **is valid**.
     #[inline]
     pub fn load_deployment(&mut self, deployment: &Deployment<N>) -> Result<()> {
         let timer = timer!("Process::load_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.initialize_stack(deployment.program())?;
+        // Ensure the edition of the stack matches the deployment.
+        ensure!(stack.edition() == deployment.edition(), "Deployed the wrong edition of '{}'", deployment.program_id());
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -54,10 +56,18 @@ impl<N: Network> Process<N> {
         lap!(timer, "Insert the verifying keys");
 
         // Add the stack to the process.
-        self.add_stack(stack);
+        self.add_stack(stack)?;
 
         finish!(timer);
 
         Ok(())
     }
+
+    /// Restores the earlier edition of a program from its deployment, replacing the later editions.
+    /// This method is used to revert an upgrade, and assumes the given deployment **is valid**.
+    #[inline]
+    pub fn restore_deployment(&mut self, deployment: &Deployment<N>) -> Result<()> {
+        // Compute the program stack, and add it to the process.
+        self.add_stack(Stack::restore(self, deployment)?)
+    }
 }
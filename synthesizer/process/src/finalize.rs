@@ -14,7 +14,16 @@
 
 use super::*;
 use console::program::{Future, Register};
-use synthesizer_program::{Await, FinalizeRegistersState, Operand};
+use synthesizer_program::{
+    Await,
+    CommandTrait,
+    FinalizeRegistersState,
+    Invoke,
+    Mapping,
+    MappingLocator,
+    Operand,
+    StackMatches,
+};
 use utilities::handle_halting;
 
 impl<N: Network> Process<N> {
@@ -31,8 +40,12 @@ impl<N: Network> Process<N> {
     ) -> Result<(Stack<N>, Vec<FinalizeOperation<N>>)> {
         let timer = timer!("Process::finalize_deployment");
 
+        // Determine if the deployment is an upgrade of an existing program.
+        let is_upgrade = self.contains_program(deployment.program_id());
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.initialize_stack(deployment.program())?;
+        // Ensure the edition of the stack matches the deployment.
+        ensure!(stack.edition() == deployment.edition(), "Deployed the wrong edition of '{}'", deployment.program_id());
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -41,6 +54,12 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Insert the verifying keys");
 
+        // If this is an upgrade, ensure the stacks that import the program can be refreshed with the new edition.
+        if is_upgrade {
+            self.clone().add_stack(stack.clone())?;
+            lap!(timer, "Refresh the dependent stacks");
+        }
+
         // Initialize the mappings, and store their finalize operations.
         atomic_batch_scope!(store, {
             // Initialize a list for the finalize operations.
@@ -51,7 +70,7 @@ impl<N: Network> Process<N> {
             // Retrieve the fee stack.
             let fee_stack = self.get_stack(fee.program_id())?;
             // Finalize the fee transition.
-            finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+            finalize_operations.extend(finalize_fee_transition(self, state, store, fee_stack, fee, None)?);
             lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
 
             /* Finalize the deployment. */
@@ -60,10 +79,18 @@ impl<N: Network> Process<N> {
             let program_id = deployment.program_id();
             // Iterate over the mappings.
             for mapping in deployment.program().mappings().values() {
+                // If this is an upgrade, skip the mappings that were initialized by a previous edition.
+                if is_upgrade && store.contains_mapping_speculative(program_id, mapping.name())? {
+                    continue;
+                }
                 // Initialize the mapping.
                 finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
             }
-            finish!(timer, "Initialize the program mappings");
+            lap!(timer, "Initialize the program mappings");
+
+            // Record the block height of the edition.
+            store.record_edition(*program_id, deployment.edition(), state.block_height())?;
+            finish!(timer, "Record the edition");
 
             // Return the stack and finalize operations.
             Ok((stack, finalize_operations))
@@ -80,6 +107,38 @@ impl<N: Network> Process<N> {
         store: &FinalizeStore<N, P>,
         execution: &Execution<N>,
         fee: Option<&Fee<N>>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        self.finalize_execution_with_trace(state, store, execution, fee, None)
+    }
+
+    /// Finalizes the execution and fee, and returns a trace of the evaluated finalize commands.
+    /// The trace contains the finalize operations if finalize succeeds, and the abort reason otherwise.
+    /// This method assumes the given execution **is valid**.
+    /// Note: The caller is responsible for aborting the atomic batch, if the state should not be committed.
+    #[inline]
+    pub fn trace_finalize_execution<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        execution: &Execution<N>,
+        fee: Option<&Fee<N>>,
+    ) -> FinalizeTrace<N> {
+        // Initialize the trace.
+        let mut trace = FinalizeTrace::new();
+        // Finalize the execution, and record the outcome.
+        let outcome = self.finalize_execution_with_trace(state, store, execution, fee, Some(&mut trace));
+        trace.set_outcome(outcome);
+        trace
+    }
+
+    /// Finalizes the execution and fee, recording the evaluated finalize commands in the given trace.
+    fn finalize_execution_with_trace<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        execution: &Execution<N>,
+        fee: Option<&Fee<N>>,
+        trace: Option<&mut FinalizeTrace<N>>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("Program::finalize_execution");
 
@@ -104,10 +163,14 @@ impl<N: Network> Process<N> {
         let call_graph = self.construct_call_graph(execution)?;
 
         atomic_batch_scope!(store, {
+            // Move the trace into this scope.
+            let mut trace = trace;
+
             // Finalize the root transition.
             // Note that this will result in all the remaining transitions being finalized, since the number
             // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            let mut finalize_operations =
+                finalize_transition(self, state, store, stack, transition, call_graph, trace.as_deref_mut())?;
 
             /* Finalize the fee. */
 
@@ -115,7 +178,7 @@ impl<N: Network> Process<N> {
                 // Retrieve the fee stack.
                 let fee_stack = self.get_stack(fee.program_id())?;
                 // Finalize the fee transition.
-                finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+                finalize_operations.extend(finalize_fee_transition(self, state, store, fee_stack, fee, trace)?);
                 lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             }
 
@@ -141,7 +204,7 @@ impl<N: Network> Process<N> {
             // Retrieve the stack.
             let stack = self.get_stack(fee.program_id())?;
             // Finalize the fee transition.
-            let result = finalize_fee_transition(state, store, stack, fee);
+            let result = finalize_fee_transition(self, state, store, stack, fee, None);
             finish!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             // Return the result.
             result
@@ -151,10 +214,12 @@ impl<N: Network> Process<N> {
 
 /// Finalizes the given fee transition.
 fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
     state: FinalizeGlobalState,
     store: &FinalizeStore<N, P>,
     stack: &Stack<N>,
     fee: &Fee<N>,
+    trace: Option<&mut FinalizeTrace<N>>,
 ) -> Result<Vec<FinalizeOperation<N>>> {
     // Construct the call graph.
     let mut call_graph = HashMap::new();
@@ -162,7 +227,7 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     call_graph.insert(*fee.transition_id(), Vec::new());
 
     // Finalize the transition.
-    match finalize_transition(state, store, stack, fee, call_graph) {
+    match finalize_transition(process, state, store, stack, fee, call_graph, trace) {
         // If the evaluation succeeds, return the finalize operations.
         Ok(finalize_operations) => Ok(finalize_operations),
         // If the evaluation fails, bail and return the error.
@@ -172,11 +237,13 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
 
 /// Finalizes the given transition.
 fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
     state: FinalizeGlobalState,
     store: &FinalizeStore<N, P>,
     stack: &Stack<N>,
     transition: &Transition<N>,
     call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
+    mut trace: Option<&mut FinalizeTrace<N>>,
 ) -> Result<Vec<FinalizeOperation<N>>> {
     // Retrieve the program ID.
     let program_id = transition.program_id();
@@ -222,6 +289,10 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         while counter < finalize.commands().len() {
             // Retrieve the command.
             let command = &finalize.commands()[counter];
+            // If tracing is enabled, record the command before it is evaluated.
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.begin_step(begin_finalize_step(process, stack, store, &registers, counter, command));
+            }
             // Finalize the command.
             match &command {
                 Command::BranchEq(branch_eq) => {
@@ -237,6 +308,10 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         // If the evaluation fails, bail and return the error.
                         Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
                     }
+                    // If tracing is enabled, mark the command as evaluated.
+                    if let Some(trace) = trace.as_deref_mut() {
+                        trace.complete_step(Vec::new());
+                    }
                 }
                 Command::BranchNeq(branch_neq) => {
                     let result = handle_halting!(panic::AssertUnwindSafe(|| {
@@ -251,6 +326,10 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         // If the evaluation fails, bail and return the error.
                         Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
                     }
+                    // If tracing is enabled, mark the command as evaluated.
+                    if let Some(trace) = trace.as_deref_mut() {
+                        trace.complete_step(Vec::new());
+                    }
                 }
                 Command::Await(await_) => {
                     // Check that the `await` register's locator is greater than the last seen call locator.
@@ -288,6 +367,12 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
                     };
 
+                    // If tracing is enabled, mark the command as evaluated.
+                    // Note: The commands of the callee are recorded next.
+                    if let Some(trace) = trace.as_deref_mut() {
+                        trace.complete_step(Vec::new());
+                    }
+
                     // Set the last seen call locator.
                     recent_call_locator = Some(locator);
                     // Increment the call counter.
@@ -306,9 +391,51 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
 
                     break;
                 }
+                Command::Invoke(invoke) => {
+                    let callee_state = match handle_halting!(panic::AssertUnwindSafe(|| {
+                        // Set up the finalize state for the callback.
+                        setup_invoke(process, state, invoke, stack, &registers)
+                    })) {
+                        Ok(Ok(callee_state)) => callee_state,
+                        // If the evaluation fails, bail and return the error.
+                        Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                        // If the evaluation fails, bail and return the error.
+                        Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                    };
+
+                    // If tracing is enabled, mark the command as evaluated.
+                    // Note: The commands of the callback are recorded next.
+                    if let Some(trace) = trace.as_deref_mut() {
+                        trace.complete_step(Vec::new());
+                    }
+
+                    // Increment the counter.
+                    counter += 1;
+
+                    // Aggregate the caller state.
+                    let caller_state =
+                        FinalizeState { counter, finalize, registers, stack, call_counter, recent_call_locator };
+
+                    // Push the caller state onto the stack.
+                    states.push(caller_state);
+                    // Push the callee state onto the stack.
+                    states.push(callee_state);
+
+                    break;
+                }
                 _ => {
-                    let result =
-                        handle_halting!(panic::AssertUnwindSafe(|| { command.finalize(stack, store, &mut registers) }));
+                    let result = handle_halting!(panic::AssertUnwindSafe(|| match command {
+                        // If the command is a `repeat` instruction, we need to handle it separately.
+                        Command::Instruction(Instruction::Repeat(repeat)) => {
+                            RepeatTrait::finalize(repeat, stack, &mut registers).map(|()| None)
+                        }
+                        // Otherwise, finalize the command, resolving its mapping first if it is dynamic.
+                        _ => match resolve_dynamic_command(process, stack, &registers, command) {
+                            Ok(Some(resolved)) => resolved.finalize(stack, store, &mut registers),
+                            Ok(None) => command.finalize(stack, store, &mut registers),
+                            Err(error) => Err(error),
+                        },
+                    }));
                     match result {
                         // If the evaluation succeeds with an operation, add it to the list.
                         Ok(Ok(Some(finalize_operation))) => finalize_operations.push(finalize_operation),
@@ -319,6 +446,18 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                         // If the evaluation fails, bail and return the error.
                         Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
                     }
+                    // If tracing is enabled, mark the command as evaluated, with the values of its destinations.
+                    if let Some(trace) = trace.as_deref_mut() {
+                        let outputs = command
+                            .destinations()
+                            .into_iter()
+                            .filter_map(|register| {
+                                let value = registers.load(stack, &Operand::Register(register.clone())).ok()?;
+                                Some((register, value))
+                            })
+                            .collect();
+                        trace.complete_step(outputs);
+                    }
                     counter += 1;
                 }
             };
@@ -406,6 +545,194 @@ fn setup_await<'a, N: Network>(
     initialize_finalize_state(state, &future, stack, transition_id)
 }
 
+// A helper function that sets up the invoke operation, for the callback in the program whose address is in the register.
+#[inline]
+fn setup_invoke<'a, N: Network>(
+    process: &'a Process<N>,
+    state: FinalizeGlobalState,
+    invoke: &Invoke<N>,
+    stack: &Stack<N>,
+    registers: &FinalizeRegisters<N>,
+) -> Result<FinalizeState<'a, N>> {
+    // Load the program address from the register.
+    let address = match registers.load_literal(stack, &Operand::Register(invoke.register().clone()))? {
+        Literal::Address(address) => address,
+        _ => bail!("Register '{}' does not contain a program address", invoke.register()),
+    };
+    // Retrieve the stack of the program.
+    let callee_stack: &Stack<N> = process.get_stack_by_address(&address)?;
+    // Retrieve the callback.
+    let callback = callee_stack.program().get_callback_ref(invoke.callback_name())?;
+    let finalize = callback.logic();
+    // Ensure the number of inputs matches the callback, including the address of the invoking program.
+    ensure!(
+        finalize.inputs().len() == invoke.arguments().len() + 1,
+        "Callback '{}/{}' expects {} inputs, found {}",
+        callee_stack.program_id(),
+        callback.name(),
+        finalize.inputs().len(),
+        invoke.arguments().len() + 1
+    );
+
+    // Initialize the registers.
+    let mut callee_registers = FinalizeRegisters::new(
+        state,
+        *registers.transition_id(),
+        *callback.name(),
+        callee_stack.get_finalize_types(callback.name())?.clone(),
+    );
+    // Store the address of the invoking program, followed by the arguments.
+    // Note: The registers ensure each input matches the input type of the callback.
+    let caller = Value::from(Literal::Address(stack.program_id().to_address()?));
+    let arguments = invoke.arguments().iter().map(|operand| registers.load(stack, operand));
+    for (input, value) in finalize.inputs().iter().zip_eq(std::iter::once(Ok(caller)).chain(arguments)) {
+        callee_registers.store(callee_stack, input.register(), value?)?;
+    }
+
+    Ok(FinalizeState {
+        counter: 0,
+        finalize,
+        registers: callee_registers,
+        stack: callee_stack,
+        call_counter: 0,
+        recent_call_locator: None,
+    })
+}
+
+// A helper function that records a command in the finalize trace, before it is evaluated.
+fn begin_finalize_step<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
+    stack: &Stack<N>,
+    store: &FinalizeStore<N, P>,
+    registers: &FinalizeRegisters<N>,
+    counter: usize,
+    command: &Command<N>,
+) -> FinalizeStep<N> {
+    // Resolve the operands. Note: An operand that fails to resolve is recorded as `None`.
+    let operands = command.operands().iter().map(|operand| registers.load(stack, operand).ok()).collect();
+    // Resolve the mapping access.
+    let mapping_access = finalize_mapping_access(process, stack, store, registers, command).ok().flatten();
+    FinalizeStep::new(
+        *registers.transition_id(),
+        *stack.program_id(),
+        *registers.function_name(),
+        counter,
+        command.clone(),
+        operands,
+        mapping_access,
+    )
+}
+
+// A helper function that returns the mapping access of the command, if the command accesses a mapping.
+fn finalize_mapping_access<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
+    stack: &Stack<N>,
+    store: &FinalizeStore<N, P>,
+    registers: &FinalizeRegisters<N>,
+    command: &Command<N>,
+) -> Result<Option<MappingAccess<N>>> {
+    // If the command reads a dynamic mapping, resolve the mapping.
+    let resolved = resolve_dynamic_command(process, stack, registers, command)?;
+    let command = resolved.as_ref().unwrap_or(command);
+    // A helper to determine the program ID and mapping name of a mapping locator.
+    let locate = |mapping: &MappingLocator<N>| match mapping {
+        MappingLocator::Locator(locator) => Ok((*locator.program_id(), *locator.resource())),
+        MappingLocator::Resource(mapping_name) => Ok((*stack.program_id(), *mapping_name)),
+        MappingLocator::Dynamic(..) => bail!("The dynamic mapping '{mapping}' is not resolved"),
+    };
+    // A helper to read the value of a key from storage.
+    let read = |program_id: ProgramID<N>, mapping_name: Identifier<N>, key: &Operand<N>| -> Result<_> {
+        let key = registers.load_plaintext(stack, key)?;
+        let value = store.get_value_speculative(program_id, mapping_name, &key)?;
+        Ok(Some(MappingAccess::Read(program_id, mapping_name, key, value)))
+    };
+    match command {
+        Command::Contains(contains) => read(*stack.program_id(), *contains.mapping_name(), contains.key()),
+        Command::Get(get) => {
+            let (program_id, mapping_name) = locate(get.mapping())?;
+            read(program_id, mapping_name, get.key())
+        }
+        Command::GetOrUse(get_or_use) => {
+            let (program_id, mapping_name) = locate(get_or_use.mapping())?;
+            read(program_id, mapping_name, get_or_use.key())
+        }
+        Command::Set(set) => {
+            let key = registers.load_plaintext(stack, set.key())?;
+            let value = registers.load(stack, set.value())?;
+            Ok(Some(MappingAccess::Write(*stack.program_id(), *set.mapping_name(), key, value)))
+        }
+        Command::Remove(remove) => {
+            let key = registers.load_plaintext(stack, remove.key())?;
+            Ok(Some(MappingAccess::Remove(*stack.program_id(), *remove.mapping_name(), key)))
+        }
+        _ => Ok(None),
+    }
+}
+
+// A helper function that resolves a command that reads a dynamic mapping, to read the mapping in the resolved program.
+// Returns `None` if the command does not read a dynamic mapping.
+fn resolve_dynamic_command<N: Network>(
+    process: &Process<N>,
+    stack: &Stack<N>,
+    registers: &FinalizeRegisters<N>,
+    command: &Command<N>,
+) -> Result<Option<Command<N>>> {
+    match command {
+        Command::Get(get) if get.mapping().is_dynamic() => {
+            // Resolve the program and mapping.
+            let (external_stack, mapping) =
+                resolve_dynamic_mapping(process, stack, registers, get.mapping(), get.key())?;
+            // Ensure the declared value type matches the value type of the mapping.
+            let value_type = get.value_type().ok_or_else(|| anyhow!("'{command}' does not declare a value type"))?;
+            let mapping_value_type = mapping.value().plaintext_type();
+            ensure!(
+                value_type == mapping_value_type,
+                "Declared value type '{value_type}' does not match the value type in the mapping '{mapping_value_type}'"
+            );
+            Ok(Some(Command::Get(get.resolve(*external_stack.program_id())?)))
+        }
+        Command::GetOrUse(get_or_use) if get_or_use.mapping().is_dynamic() => {
+            // Resolve the program and mapping.
+            let (external_stack, mapping) =
+                resolve_dynamic_mapping(process, stack, registers, get_or_use.mapping(), get_or_use.key())?;
+            // Ensure the default value matches the value type of the mapping.
+            let default = registers.load_plaintext(stack, get_or_use.default())?;
+            external_stack.matches_plaintext(&default, mapping.value().plaintext_type())?;
+            Ok(Some(Command::GetOrUse(get_or_use.resolve(*external_stack.program_id())?)))
+        }
+        _ => Ok(None),
+    }
+}
+
+// A helper function that retrieves the stack and mapping of a dynamic mapping, using the address in its register.
+// Ensures the key matches the key type of the mapping.
+fn resolve_dynamic_mapping<'a, N: Network>(
+    process: &'a Process<N>,
+    stack: &Stack<N>,
+    registers: &FinalizeRegisters<N>,
+    mapping: &MappingLocator<N>,
+    key: &Operand<N>,
+) -> Result<(&'a Arc<Stack<N>>, Mapping<N>)> {
+    // Retrieve the register and mapping name.
+    let (register, mapping_name) = match mapping {
+        MappingLocator::Dynamic(register, mapping_name) => (register, mapping_name),
+        _ => bail!("The mapping '{mapping}' is not dynamic"),
+    };
+    // Load the program address from the register.
+    let address = match registers.load_literal(stack, &Operand::Register(register.clone()))? {
+        Literal::Address(address) => address,
+        _ => bail!("Register '{register}' does not contain a program address"),
+    };
+    // Retrieve the stack of the program.
+    let external_stack = process.get_stack_by_address(&address)?;
+    // Retrieve the mapping from the program.
+    let mapping = external_stack.program().get_mapping(mapping_name)?;
+    // Ensure the key matches the key type of the mapping.
+    let key = registers.load_plaintext(stack, key)?;
+    external_stack.matches_plaintext(&key, mapping.key().plaintext_type())?;
+    Ok((external_stack, mapping))
+}
+
 // A helper function that returns the index to branch to.
 #[inline]
 fn branch_to<N: Network, const VARIANT: u8>(
@@ -508,7 +835,7 @@ function compute:
         let (stack, _) =
             process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
         // Add the stack *manually* to the process.
-        process.add_stack(stack);
+        process.add_stack(stack).unwrap();
 
         // Ensure the program exists.
         assert!(process.contains_program(program.id()));
@@ -0,0 +1,260 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::marker::PhantomData;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The environment variable, which enables the key cache at the given directory.
+pub const KEY_CACHE_ENV: &str = "SNARKVM_KEY_CACHE";
+
+/// The file extension of a cached proving key.
+const PROVER_FILE_EXTENSION: &str = "prover";
+/// The file extension of a cached verifying key.
+const VERIFIER_FILE_EXTENSION: &str = "verifier";
+
+/// A content-addressed disk cache for the proving and verifying keys of programs.
+///
+/// The keys are stored at `{directory}/{program ID}/{checksum}/{function name}.{prover,verifier}`,
+/// where the checksum commits to the network, the edition and bytes of the program, and the bytes of its imports.
+/// Each edition of a program is stored under its own checksum, so switching between editions does not evict keys.
+/// Each key file carries a digest of the key, bound to the checksum and function name, which is checked on load.
+#[derive(Clone, Debug)]
+pub struct KeyCache<N: Network> {
+    /// The directory of the cache.
+    directory: PathBuf,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> KeyCache<N> {
+    /// Initializes a new key cache at the given directory.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into(), _phantom: PhantomData }
+    }
+
+    /// Initializes a new key cache from the `SNARKVM_KEY_CACHE` environment variable, if it is set.
+    pub fn from_env() -> Option<Self> {
+        match std::env::var_os(KEY_CACHE_ENV) {
+            Some(directory) if !directory.is_empty() => Some(Self::new(directory)),
+            _ => None,
+        }
+    }
+
+    /// Returns the directory of the cache.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the checksum of the given stack, which commits to its edition, program, and imports.
+    pub fn checksum(stack: &Stack<N>) -> Result<String> {
+        // Prepare the preimage, from the network ID, the edition, the program, and its imports.
+        let mut preimage = N::ID.to_bytes_le()?;
+        preimage.extend(stack.edition().to_bytes_le()?);
+        preimage.extend(stack.program().to_bytes_le()?);
+        for import in stack.program().imports().keys() {
+            preimage.extend(stack.get_external_program(import)?.to_bytes_le()?);
+        }
+        // Hash the preimage, and encode the hash in hexadecimal.
+        Ok(Self::hash(&preimage)?.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+
+    /// Returns the cached proving and verifying key for the given function, if they exist.
+    pub fn load(
+        &self,
+        stack: &Stack<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<Option<(ProvingKey<N>, VerifyingKey<N>)>> {
+        let directory = self.entry_directory(stack.program_id(), &Self::checksum(stack)?);
+        // Retrieve the file paths.
+        let prover_path = directory.join(format!("{function_name}.{PROVER_FILE_EXTENSION}"));
+        let verifier_path = directory.join(format!("{function_name}.{VERIFIER_FILE_EXTENSION}"));
+        if !prover_path.exists() || !verifier_path.exists() {
+            return Ok(None);
+        }
+        // Read the keys.
+        let checksum = Self::checksum(stack)?;
+        let proving_key = Self::read_key(&prover_path, &checksum, function_name)?;
+        let verifying_key = Self::read_key(&verifier_path, &checksum, function_name)?;
+        Ok(Some((proving_key, verifying_key)))
+    }
+
+    /// Stores the given proving and verifying key for the given function.
+    pub fn store(
+        &self,
+        stack: &Stack<N>,
+        function_name: &Identifier<N>,
+        proving_key: &ProvingKey<N>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<()> {
+        let checksum = Self::checksum(stack)?;
+        let directory = self.entry_directory(stack.program_id(), &checksum);
+        fs::create_dir_all(&directory)?;
+
+        // Write the keys.
+        Self::write_key(
+            &directory.join(format!("{function_name}.{PROVER_FILE_EXTENSION}")),
+            &checksum,
+            function_name,
+            proving_key,
+        )?;
+        Self::write_key(
+            &directory.join(format!("{function_name}.{VERIFIER_FILE_EXTENSION}")),
+            &checksum,
+            function_name,
+            verifying_key,
+        )
+    }
+
+    /// Returns the directory of the entries for the given program ID and checksum.
+    fn entry_directory(&self, program_id: &ProgramID<N>, checksum: &str) -> PathBuf {
+        self.directory.join(program_id.to_string()).join(checksum)
+    }
+
+    /// Reads the key from the given file, which is prefixed with its function name and digest.
+    fn read_key<K: FromBytes>(path: &Path, checksum: &str, function_name: &Identifier<N>) -> Result<K> {
+        let bytes = fs::read(path)?;
+        let mut reader = bytes.as_slice();
+        // Ensure the function name matches.
+        let candidate = Identifier::<N>::read_le(&mut reader)?;
+        ensure!(&candidate == function_name, "The cached key '{}' is for '{candidate}'", path.display());
+        // Ensure the digest matches the key.
+        let digest = <[u8; 32]>::read_le(&mut reader)?;
+        ensure!(
+            digest == Self::digest(checksum, function_name, reader)?,
+            "The cached key '{}' does not match its digest",
+            path.display()
+        );
+        // Read the key.
+        let key = K::read_le(&mut reader)?;
+        ensure!(reader.is_empty(), "The cached key '{}' contains trailing bytes", path.display());
+        Ok(key)
+    }
+
+    /// Writes the key to the given file, prefixed with its function name and digest.
+    fn write_key<K: ToBytes>(path: &Path, checksum: &str, function_name: &Identifier<N>, key: &K) -> Result<()> {
+        let key_bytes = key.to_bytes_le()?;
+        let mut bytes = function_name.to_bytes_le()?;
+        bytes.extend(Self::digest(checksum, function_name, &key_bytes)?);
+        bytes.extend(key_bytes);
+        // Write to a temporary file, and rename it, so that concurrent readers never observe a partial key.
+        let temporary_path = path.with_extension(format!("{}.tmp", std::process::id()));
+        fs::write(&temporary_path, bytes)?;
+        Ok(fs::rename(temporary_path, path)?)
+    }
+
+    /// Returns the digest of the given key bytes, which binds the key to the checksum and function name.
+    fn digest(checksum: &str, function_name: &Identifier<N>, key_bytes: &[u8]) -> Result<[u8; 32]> {
+        let mut preimage = checksum.as_bytes().to_vec();
+        preimage.extend(function_name.to_bytes_le()?);
+        preimage.extend(key_bytes);
+        Self::hash(&preimage)
+    }
+
+    /// Returns the SHA3-256 hash of the given bytes.
+    fn hash(preimage: &[u8]) -> Result<[u8; 32]> {
+        let hash = N::hash_sha3_256(&preimage.to_bits_le())?;
+        let bytes: Vec<u8> =
+            hash.chunks(8).map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | *bit as u8)).collect();
+        bytes.try_into().map_err(|_| anyhow!("The SHA3-256 hash must be 32 bytes"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_key_cache() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the cache.
+        let directory = std::env::temp_dir().join(format!("snarkvm-key-cache-{}", rng.gen::<u64>()));
+        let cache = KeyCache::<CurrentNetwork>::new(&directory);
+
+        // Initialize a program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program cache.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Synthesize the keys, with the cache.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.set_key_cache(Some(cache.clone()));
+        process.add_program(&program).unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+        assert!(cache.load(stack, &function_name).unwrap().is_none());
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+        let proving_key = process.get_proving_key(program.id(), function_name).unwrap();
+        let verifying_key = process.get_verifying_key(program.id(), function_name).unwrap();
+
+        // Ensure the keys are cached.
+        let (cached_proving_key, cached_verifying_key) = cache.load(stack, &function_name).unwrap().unwrap();
+        assert_eq!(cached_proving_key.to_bytes_le().unwrap(), proving_key.to_bytes_le().unwrap());
+        assert_eq!(cached_verifying_key, verifying_key);
+
+        // Ensure a new process loads the keys from the cache.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.set_key_cache(Some(cache.clone()));
+        process.add_program(&program).unwrap();
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+        assert_eq!(process.get_verifying_key(program.id(), function_name).unwrap(), verifying_key);
+
+        // Ensure a modified key is rejected, and is replaced on the next synthesis.
+        let verifier_path = directory
+            .join("cache.aleo")
+            .join(KeyCache::checksum(stack).unwrap())
+            .join(format!("{function_name}.{VERIFIER_FILE_EXTENSION}"));
+        let mut bytes = fs::read(&verifier_path).unwrap();
+        *bytes.last_mut().unwrap() ^= 1;
+        fs::write(&verifier_path, bytes).unwrap();
+        assert!(cache.load(stack, &function_name).is_err());
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.set_key_cache(Some(cache.clone()));
+        process.add_program(&program).unwrap();
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+        assert_eq!(process.get_verifying_key(program.id(), function_name).unwrap(), verifying_key);
+        assert!(cache.load(stack, &function_name).unwrap().is_some());
+
+        // Ensure a changed program is stored in a new entry, and the entry of the original program is retained.
+        let changed =
+            Program::<CurrentNetwork>::from_str(&program.to_string().replace("add r0 r0", "mul r0 r0")).unwrap();
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.set_key_cache(Some(cache.clone()));
+        process.add_program(&changed).unwrap();
+        let changed_stack = process.get_stack(changed.id()).unwrap();
+        assert!(cache.load(changed_stack, &function_name).unwrap().is_none());
+        process.synthesize_key::<CurrentAleo, _>(changed.id(), &function_name, rng).unwrap();
+        assert!(cache.load(changed_stack, &function_name).unwrap().is_some());
+        assert!(cache.load(stack, &function_name).unwrap().is_some());
+        assert_eq!(fs::read_dir(directory.join("cache.aleo")).unwrap().count(), 2);
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
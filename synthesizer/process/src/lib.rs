@@ -18,6 +18,9 @@
 // TODO (howardwu): Update the return type on `execute` after stabilizing the interface.
 #![allow(clippy::type_complexity)]
 
+mod key_cache;
+pub use key_cache::*;
+
 mod stack;
 pub use stack::*;
 
@@ -43,7 +46,7 @@ use console::{
     account::PrivateKey,
     network::prelude::*,
     program::{Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, Value},
-    types::{Field, U16, U64},
+    types::{Address, Field, U16, U64},
 };
 use ledger_block::{Deployment, Execution, Fee, Input, Transition};
 use ledger_store::{atomic_batch_scope, FinalizeStorage, FinalizeStore};
@@ -60,10 +63,10 @@ use synthesizer_program::{
     RegistersStore,
     StackProgram,
 };
-use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_snark::{PreparedCheck, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::{collections::HashMap, sync::Arc};
 
@@ -76,6 +79,10 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// The mapping of program addresses to program IDs.
+    program_addresses: IndexMap<Address<N>, ProgramID<N>>,
+    /// The disk cache for the proving and verifying keys, if it is enabled.
+    key_cache: Option<Arc<KeyCache<N>>>,
 }
 
 impl<N: Network> Process<N> {
@@ -85,7 +92,12 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            program_addresses: IndexMap::new(),
+            key_cache: KeyCache::from_env().map(Arc::new),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -104,7 +116,7 @@ impl<N: Network> Process<N> {
         lap!(timer, "Synthesize credits program keys");
 
         // Add the 'credits.aleo' stack to the process.
-        process.add_stack(stack);
+        process.add_stack(stack)?;
 
         finish!(timer);
         // Return the process.
@@ -119,17 +131,91 @@ impl<N: Network> Process<N> {
         let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
         // If the program is not 'credits.aleo', compute the program stack, and add it to the process.
         if program.id() != &credits_program_id {
-            self.add_stack(Stack::new(self, program)?);
+            self.add_stack(Stack::new(self, program)?)?;
         }
         Ok(())
     }
 
+    /// Upgrades an existing program in the process to its next edition.
+    /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
+    #[inline]
+    pub fn upgrade_program(&mut self, program: &Program<N>) -> Result<()> {
+        // Compute the program stack, and add it to the process.
+        self.add_stack(Stack::upgrade(self, program)?)
+    }
+
     /// Adds a new stack to the process.
+    /// If the stack replaces an edition of an existing program, the stacks that import the program are refreshed.
+    /// If a stack fails to be refreshed, the process is rolled back, and the error is returned.
+    /// If the address of the program cannot be computed, the stack is not added, and the error is returned.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
-    pub fn add_stack(&mut self, stack: Stack<N>) {
+    pub fn add_stack(&mut self, stack: Stack<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
+        // Compute the program address, so that the stack can be resolved from its address.
+        let address = program_id.to_address()?;
+        // If the stack replaces an existing edition of the program, retain the stacks to roll back to.
+        let previous_stacks = self.stacks.contains_key(&program_id).then(|| self.stacks.clone());
         // Add the stack to the process.
-        self.stacks.insert(*stack.program_id(), Arc::new(stack));
+        self.stacks.insert(program_id, Arc::new(stack));
+        // Record the program address.
+        self.program_addresses.insert(address, program_id);
+        // Refresh the stacks that import the program, so that they use the new edition.
+        if let Some(previous_stacks) = previous_stacks {
+            if let Err(error) = self.refresh_dependent_stacks(program_id) {
+                // Roll back the stacks to the previous edition of the program.
+                self.stacks = previous_stacks;
+                bail!("Failed to replace the stack for '{program_id}' - {error}")
+            }
+        }
+        Ok(())
+    }
+
+    /// Refreshes the stacks that directly or indirectly import the given program ID.
+    fn refresh_dependent_stacks(&mut self, program_id: ProgramID<N>) -> Result<()> {
+        // Initialize the set of refreshed program IDs.
+        let mut refreshed = IndexSet::from([program_id]);
+        // Note: The stacks are in the order they were added, so every import precedes the programs that import it.
+        for index in 0..self.stacks.len() {
+            // Retrieve the stack.
+            let Some(stack) = self.stacks.get_index(index).map(|(_, stack)| stack.clone()) else { continue };
+            // Skip the stack if it does not import a refreshed program.
+            if !stack.program().imports().keys().any(|import| refreshed.contains(import)) {
+                continue;
+            }
+            // Refresh the stack.
+            let refreshed_stack = Stack::refresh(self, &stack)
+                .map_err(|error| anyhow!("Failed to refresh the stack for '{}' - {error}", stack.program_id()))?;
+            refreshed.insert(*stack.program_id());
+            self.stacks.insert(*stack.program_id(), Arc::new(refreshed_stack));
+        }
+        Ok(())
+    }
+
+    /// Initializes the stack for the given program, as the next edition of the program if it already exists.
+    fn initialize_stack(&self, program: &Program<N>) -> Result<Stack<N>> {
+        match self.contains_program(program.id()) {
+            true => Stack::upgrade(self, program),
+            false => Stack::new(self, program),
+        }
+    }
+
+    /// Removes the program with the given program ID from the process.
+    /// Note: This method does not check if the program is imported by another program in the process.
+    #[inline]
+    pub fn remove_program(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::<N>::from_str("credits.aleo")?, "Cannot remove 'credits.aleo'");
+        // Remove the stack from the process.
+        match self.stacks.shift_remove(program_id) {
+            Some(_) => {
+                // Remove the program address.
+                self.program_addresses.retain(|_, id| id != program_id);
+                Ok(())
+            }
+            None => bail!("Program '{program_id}' does not exist"),
+        }
     }
 }
 
@@ -140,7 +226,12 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            program_addresses: IndexMap::new(),
+            key_cache: KeyCache::from_env().map(Arc::new),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -161,7 +252,7 @@ impl<N: Network> Process<N> {
         lap!(timer, "Load circuit keys");
 
         // Add the stack to the process.
-        process.add_stack(stack);
+        process.add_stack(stack)?;
 
         finish!(timer, "Process::load");
         // Return the process.
@@ -173,7 +264,12 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            program_addresses: IndexMap::new(),
+            key_cache: KeyCache::from_env().map(Arc::new),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -182,7 +278,7 @@ impl<N: Network> Process<N> {
         let stack = Stack::new(&process, &program)?;
 
         // Add the stack to the process.
-        process.add_stack(stack);
+        process.add_stack(stack)?;
 
         // Return the process.
         Ok(process)
@@ -194,6 +290,19 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the disk cache for the proving and verifying keys, if it is enabled.
+    #[inline]
+    pub const fn key_cache(&self) -> Option<&Arc<KeyCache<N>>> {
+        self.key_cache.as_ref()
+    }
+
+    /// Sets the disk cache for the proving and verifying keys, for the programs added after this call.
+    /// By default, the cache is enabled if the `SNARKVM_KEY_CACHE` environment variable is set.
+    #[inline]
+    pub fn set_key_cache(&mut self, key_cache: Option<KeyCache<N>>) {
+        self.key_cache = key_cache.map(Arc::new);
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -213,6 +322,16 @@ impl<N: Network> Process<N> {
         Ok(stack)
     }
 
+    /// Returns the stack for the program with the given address.
+    #[inline]
+    pub fn get_stack_by_address(&self, address: &Address<N>) -> Result<&Arc<Stack<N>>> {
+        // Retrieve the program ID.
+        let program_id =
+            self.program_addresses.get(address).ok_or_else(|| anyhow!("No program exists at address '{address}'"))?;
+        // Retrieve the stack.
+        self.get_stack(*program_id)
+    }
+
     /// Returns the program for the given program ID.
     #[inline]
     pub fn get_program(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Program<N>> {
@@ -17,7 +17,7 @@ use super::*;
 use rand::{rngs::StdRng, SeedableRng};
 
 impl<N: Network> Stack<N> {
-    /// Deploys the given program ID, if it does not exist.
+    /// Deploys the program of the stack, as the edition of the stack.
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Deployment<N>> {
         let timer = timer!("Stack::deploy");
@@ -50,7 +50,7 @@ impl<N: Network> Stack<N> {
         finish!(timer);
 
         // Return the deployment.
-        Deployment::new(N::EDITION, self.program.clone(), verifying_keys)
+        Deployment::new(self.edition, self.program.clone(), verifying_keys)
     }
 
     /// Checks each function in the program on the given verifying key and certificate.
@@ -62,14 +62,82 @@ impl<N: Network> Stack<N> {
     ) -> Result<()> {
         let timer = timer!("Stack::verify_deployment");
 
+        // Synthesize the circuit of each function.
+        let assignments = self.synthesize_deployment_assignments::<A, R>(deployment, rng)?;
+        lap!(timer, "Synthesize the circuits");
+
+        // Verify the certificates.
+        let program_id = self.program.id();
+        cfg_iter!(assignments).zip_eq(deployment.verifying_keys()).try_for_each(
+            |(assignment, (function_name, (verifying_key, certificate)))| {
+                // Ensure the certificate is valid.
+                match certificate.verify(&function_name.to_string(), assignment, verifying_key) {
+                    true => Ok(()),
+                    false => bail!("The certificate for function '{function_name}' is invalid in '{program_id}'"),
+                }
+            },
+        )?;
+
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Checks each function in the program on the given verifying key and certificate,
+    /// up to the final pairing check of each certificate, and returns the pairing checks,
+    /// to be checked with `PreparedCheck::verify_all`.
+    #[inline]
+    pub fn prepare_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<Vec<PreparedCheck<N>>> {
+        let timer = timer!("Stack::prepare_deployment");
+
+        // Synthesize the circuit of each function.
+        let assignments = self.synthesize_deployment_assignments::<A, R>(deployment, rng)?;
+        lap!(timer, "Synthesize the circuits");
+
+        // Prepare the pairing checks of the certificates.
+        let program_id = self.program.id();
+        let prepared = cfg_iter!(assignments)
+            .zip_eq(deployment.verifying_keys())
+            .map(|(assignment, (function_name, (verifying_key, certificate)))| {
+                certificate.prepare(&function_name.to_string(), assignment, verifying_key).map_err(|e| {
+                    anyhow!("The certificate for function '{function_name}' is invalid in '{program_id}': {e}")
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        finish!(timer);
+
+        Ok(prepared)
+    }
+
+    /// Synthesizes the circuit of each function in the deployment, and returns the assignments,
+    /// in the order of the verifying keys in the deployment.
+    fn synthesize_deployment_assignments<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<Vec<circuit::Assignment<N::Field>>> {
+        let timer = timer!("Stack::synthesize_deployment_assignments");
+
         // Sanity Checks //
 
         // Ensure the deployment is ordered.
         deployment.check_is_ordered()?;
         // Ensure the program in the stack and deployment matches.
         ensure!(&self.program == deployment.program(), "The stack program does not match the deployment program");
+        // Ensure the edition in the stack and deployment matches.
+        ensure!(
+            self.edition == deployment.edition(),
+            "The deployment edition ({}) does not match the stack edition ({})",
+            deployment.edition(),
+            self.edition
+        );
 
-        // Check Verifying Keys //
+        // Synthesize Circuits //
 
         let program_id = self.program.id();
 
@@ -117,30 +185,26 @@ impl<N: Network> Stack<N> {
             call_stacks.push((function.name(), call_stack, assignments));
         }
 
-        // Verify the certificates.
+        // Synthesize the circuits.
         let rngs = (0..call_stacks.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
-        cfg_iter!(call_stacks).zip_eq(deployment.verifying_keys()).zip_eq(rngs).try_for_each(
-            |(((function_name, call_stack, assignments), (_, (verifying_key, certificate))), mut rng)| {
+        let assignments = cfg_iter!(call_stacks)
+            .zip_eq(deployment.verifying_keys())
+            .zip_eq(rngs)
+            .map(|(((function_name, call_stack, assignments), _), mut rng)| {
                 // Synthesize the circuit.
                 if let Err(err) = self.execute_function::<A, _>(call_stack.clone(), None, &mut rng) {
                     bail!("Failed to synthesize the circuit for '{function_name}': {err}")
                 }
-                // Check the certificate.
+                // Retrieve the assignment.
                 match assignments.read().last() {
                     None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
-                    Some((assignment, _metrics)) => {
-                        // Ensure the certificate is valid.
-                        if !certificate.verify(&function_name.to_string(), assignment, verifying_key) {
-                            bail!("The certificate for function '{function_name}' is invalid in '{program_id}'")
-                        }
-                    }
-                };
-                Ok(())
-            },
-        )?;
+                    Some((assignment, _metrics)) => Ok(assignment.clone()),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         finish!(timer);
 
-        Ok(())
+        Ok(assignments)
     }
 }
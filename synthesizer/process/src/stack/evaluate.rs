@@ -54,9 +54,17 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
+        // Note: We handle the `repeat` instruction separately, as it requires special handling.
         for instruction in closure.instructions() {
+            // Evaluate the instruction.
+            let result = match instruction {
+                // If the instruction is a `repeat` instruction, we need to handle it separately.
+                Instruction::Repeat(repeat) => RepeatTrait::evaluate(repeat, self, &mut registers),
+                // Otherwise, evaluate the instruction normally.
+                _ => instruction.evaluate(self, &mut registers),
+            };
             // If the evaluation fails, bail and return the error.
-            if let Err(error) = instruction.evaluate(self, &mut registers) {
+            if let Err(error) = result {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
             }
         }
@@ -82,6 +90,12 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a closure scope."),
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => bail!("Cannot retrieve the block timestamp from a closure scope."),
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => bail!("Cannot retrieve the network ID from a closure scope."),
+                    // If the operand is the program address, throw an error.
+                    Operand::ProgramAddress => bail!("Cannot retrieve the program address from a closure scope."),
                 }
             })
             .collect();
@@ -174,12 +188,14 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Evaluate the instructions.
-        // Note: We handle the `call` instruction separately, as it requires special handling.
+        // Note: We handle the `call` and `repeat` instructions separately, as they require special handling.
         for instruction in function.instructions() {
             // Evaluate the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                // If the instruction is a `repeat` instruction, we need to handle it separately.
+                Instruction::Repeat(repeat) => RepeatTrait::evaluate(repeat, self, &mut registers),
                 // Otherwise, evaluate the instruction normally.
                 _ => instruction.evaluate(self, &mut registers),
             };
@@ -213,6 +229,12 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => bail!("Cannot retrieve the block timestamp from a function scope."),
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => bail!("Cannot retrieve the network ID from a function scope."),
+                    // If the operand is the program address, throw an error.
+                    Operand::ProgramAddress => bail!("Cannot retrieve the program address from a function scope."),
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -70,13 +70,25 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         for instruction in closure.instructions() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
+                // Evaluate the instruction.
+                let result = match instruction {
+                    // If the instruction is a `repeat` instruction, we need to handle it separately.
+                    Instruction::Repeat(repeat) => RepeatTrait::evaluate(repeat, self, &mut registers),
+                    // Otherwise, evaluate the instruction normally.
+                    _ => instruction.evaluate(self, &mut registers),
+                };
                 // If the evaluation fails, bail and return the error.
-                if let Err(error) = instruction.evaluate(self, &mut registers) {
+                if let Err(error) = result {
                     bail!("Failed to evaluate instruction ({instruction}): {error}");
                 }
             }
             // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            match instruction {
+                // If the instruction is a `repeat` instruction, we need to handle it separately.
+                Instruction::Repeat(repeat) => RepeatTrait::execute(repeat, self, &mut registers)?,
+                // Otherwise, execute the instruction normally.
+                _ => instruction.execute(self, &mut registers)?,
+            }
         }
         lap!(timer, "Execute the instructions");
 
@@ -115,6 +127,18 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a closure scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a closure scope")
+                    }
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => {
+                        bail!("Illegal operation: cannot retrieve the network ID in a closure scope")
+                    }
+                    // If the operand is the program address, throw an error.
+                    Operand::ProgramAddress => {
+                        bail!("Illegal operation: cannot retrieve the program address in a closure scope")
+                    }
                 }
             })
             .collect();
@@ -252,15 +276,19 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
+        // Initialize a list for the circuit metrics of the instructions.
+        let mut instruction_metrics = Vec::with_capacity(function.instructions().len());
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
                 let result = match instruction {
                     // If the instruction is a `call` instruction, we need to handle it separately.
                     Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                    // If the instruction is a `repeat` instruction, we need to handle it separately.
+                    Instruction::Repeat(repeat) => RepeatTrait::evaluate(repeat, self, &mut registers),
                     // Otherwise, evaluate the instruction normally.
                     _ => instruction.evaluate(self, &mut registers),
                 };
@@ -270,10 +298,15 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 }
             }
 
+            // Retrieve the circuit counts before the instruction.
+            let count = A::count();
+
             // Execute the instruction.
             let result = match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::execute(call, self, &mut registers, rng),
+                // If the instruction is a `repeat` instruction, we need to handle it separately.
+                Instruction::Repeat(repeat) => RepeatTrait::execute(repeat, self, &mut registers),
                 // Otherwise, execute the instruction normally.
                 _ => instruction.execute(self, &mut registers),
             };
@@ -282,13 +315,21 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 bail!("Failed to execute instruction ({instruction}): {error}");
             }
 
-            // If the instruction was a function call, then set the tracker to `true`.
+            // If the instruction was a function call, then set the tracker to `true`, and retrieve the callee.
+            let mut callee = None;
             if let Instruction::Call(call) = instruction {
                 // Check if the call is a function call.
                 if call.is_function_call(self)? {
                     contains_function_call = true;
+                    callee = Some(match call.operator() {
+                        CallOperator::Locator(locator) => *locator,
+                        CallOperator::Resource(resource) => Locator::new(*self.program_id(), *resource),
+                    });
                 }
             }
+
+            // Record the circuit metrics of the instruction.
+            instruction_metrics.push(InstructionMetrics::new(index, instruction.opcode(), callee, count, A::count()));
         }
         lap!(timer, "Execute the instructions");
 
@@ -322,6 +363,18 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a function scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a function scope")
+                    }
+                    // If the operand is the network ID, throw an error.
+                    Operand::NetworkID => {
+                        bail!("Illegal operation: cannot retrieve the network ID in a function scope")
+                    }
+                    // If the operand is the program address, throw an error.
+                    Operand::ProgramAddress => {
+                        bail!("Illegal operation: cannot retrieve the program address in a function scope")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -425,6 +478,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                instructions: instruction_metrics,
             };
             // Add the assignment to the assignments.
             assignments.write().push((assignment, metrics));
@@ -447,6 +501,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                instructions: instruction_metrics,
             };
 
             // Add the transition to the trace.
@@ -467,6 +522,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                instructions: instruction_metrics,
             };
             // Add the assignment to the assignments.
             assignments.write().push((assignment, metrics));
@@ -41,6 +41,16 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             Operand::BlockHeight => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_height())))));
             }
+            // If the operand is the block timestamp, load the block timestamp.
+            Operand::BlockTimestamp => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::I64(I64::new(self.state.block_timestamp())))));
+            }
+            // If the operand is the network ID, load the network ID.
+            Operand::NetworkID => return Ok(Value::Plaintext(Plaintext::from(Literal::U16(U16::new(N::ID))))),
+            // If the operand is the program address, load the address of the current program.
+            Operand::ProgramAddress => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::Address(stack.program_id().to_address()?))));
+            }
         };
 
         // Retrieve the value.
@@ -21,6 +21,7 @@ use synthesizer_program::{
     Contains,
     Get,
     GetOrUse,
+    Invoke,
     MappingLocator,
     RandChaCha,
     Remove,
@@ -81,6 +82,52 @@ impl<N: Network> FinalizeTypes<N> {
 
         Ok(finalize_types)
     }
+
+    /// Initializes a new instance of `FinalizeTypes` for the given closure.
+    /// Checks that the given closure is well-formed in a finalize context for the given stack.
+    #[inline]
+    pub(super) fn initialize_closure_types(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        closure: &Closure<N>,
+    ) -> Result<Self> {
+        // Initialize a map of registers to their types.
+        let mut finalize_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+
+        // Step 1. Check the inputs are well-formed.
+        for input in closure.inputs() {
+            // Ensure the input register type is a plaintext type.
+            let finalize_type = match input.register_type() {
+                RegisterType::Plaintext(plaintext_type) => FinalizeType::Plaintext(plaintext_type.clone()),
+                register_type => {
+                    bail!("Input type '{register_type}' in '{}' is not allowed in finalize", closure.name())
+                }
+            };
+            // Check the input register type.
+            finalize_types.check_input(stack, input.register(), &finalize_type)?;
+        }
+
+        // Step 2. Check the instructions are well-formed.
+        for instruction in closure.instructions() {
+            // Check the instruction opcode, operands, and destinations.
+            finalize_types.check_instruction(stack, closure.name(), instruction)?;
+        }
+
+        // Step 3. Check the outputs are well-formed.
+        for output in closure.outputs() {
+            // Retrieve the output operand type.
+            let output_type = RegisterType::from(finalize_types.get_type_from_operand(stack, output.operand())?);
+            // Ensure the output operand type matches the output register type.
+            ensure!(
+                &output_type == output.register_type(),
+                "Output '{}' in '{}' does not match the expected type '{}'",
+                output.operand(),
+                closure.name(),
+                output.register_type()
+            );
+        }
+
+        Ok(finalize_types)
+    }
 }
 
 impl<N: Network> FinalizeTypes<N> {
@@ -177,6 +224,7 @@ impl<N: Network> FinalizeTypes<N> {
             Command::Contains(contains) => self.check_contains(stack, finalize.name(), contains)?,
             Command::Get(get) => self.check_get(stack, get)?,
             Command::GetOrUse(get_or_use) => self.check_get_or_use(stack, get_or_use)?,
+            Command::Invoke(invoke) => self.check_invoke(stack, invoke)?,
             Command::RandChaCha(rand_chacha) => self.check_rand_chacha(stack, finalize.name(), rand_chacha)?,
             Command::Remove(remove) => self.check_remove(stack, finalize.name(), remove)?,
             Command::Set(set) => self.check_set(stack, finalize.name(), set)?,
@@ -312,7 +360,7 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
                 }
                 // Retrieve the mapping from the program.
-                external.get_mapping(mapping_name)?
+                Some(external.get_mapping(mapping_name)?)
             }
             MappingLocator::Resource(mapping_name) => {
                 // Ensure the declared mapping in `get` is defined in the current program.
@@ -320,14 +368,16 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
                 }
                 // Retrieve the mapping from the program.
-                stack.program().get_mapping(mapping_name)?
+                Some(stack.program().get_mapping(mapping_name)?)
+            }
+            MappingLocator::Dynamic(register, _) => {
+                // Ensure the register contains the address of the program.
+                self.check_program_address(stack, register)?;
+                // Note: The mapping is only known at runtime, and is checked against the declared types by the process.
+                None
             }
         };
 
-        // Get the mapping key type.
-        let mapping_key_type = mapping.key().plaintext_type();
-        // Get the mapping value type.
-        let mapping_value_type = mapping.value().plaintext_type();
         // Retrieve the register type of the key.
         let key_type = match self.get_type_from_operand(stack, get.key())? {
             // If the register is a plaintext type, return it.
@@ -335,16 +385,30 @@ impl<N: Network> FinalizeTypes<N> {
             // If the register is a future, throw an error.
             FinalizeType::Future(..) => bail!("A future cannot be used as a key in a `get` command"),
         };
-        // Check that the key type in the mapping matches the key type in the instruction.
-        if *mapping_key_type != key_type {
-            bail!("Key type in `get` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'.")
-        }
+        // Determine the value type.
+        let value_type = match (mapping, get.value_type()) {
+            (Some(mapping), None) => {
+                // Get the mapping key type.
+                let mapping_key_type = mapping.key().plaintext_type();
+                // Check that the key type in the mapping matches the key type in the instruction.
+                if *mapping_key_type != key_type {
+                    bail!(
+                        "Key type in `get` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
+                    )
+                }
+                // Get the mapping value type.
+                mapping.value().plaintext_type().clone()
+            }
+            (None, Some(value_type)) => value_type.clone(),
+            (Some(_), Some(_)) => bail!("A value type cannot be declared in `get` for a static mapping"),
+            (None, None) => bail!("A value type must be declared in `get` for a dynamic mapping"),
+        };
         // Get the destination register.
         let destination = get.destination().clone();
         // Ensure the destination register is a locator (and does not reference an access).
         ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
         // Insert the destination register.
-        self.add_destination(destination, FinalizeType::Plaintext(mapping_value_type.clone()))?;
+        self.add_destination(destination, FinalizeType::Plaintext(value_type))?;
         Ok(())
     }
 
@@ -378,7 +442,7 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Mapping '{mapping_name}' in '{program_id}' is not defined.")
                 }
                 // Retrieve the mapping from the program.
-                external.get_mapping(mapping_name)?
+                Some(external.get_mapping(mapping_name)?)
             }
             MappingLocator::Resource(mapping_name) => {
                 // Ensure the declared mapping in `get.or_use` is defined in the current program.
@@ -386,14 +450,16 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Mapping '{mapping_name}' in '{}' is not defined.", stack.program_id())
                 }
                 // Retrieve the mapping from the program.
-                stack.program().get_mapping(mapping_name)?
+                Some(stack.program().get_mapping(mapping_name)?)
+            }
+            MappingLocator::Dynamic(register, _) => {
+                // Ensure the register contains the address of the program.
+                self.check_program_address(stack, register)?;
+                // Note: The mapping is only known at runtime, and is checked against the default type by the process.
+                None
             }
         };
 
-        // Get the mapping key type.
-        let mapping_key_type = mapping.key().plaintext_type();
-        // Get the mapping value type.
-        let mapping_value_type = mapping.value().plaintext_type();
         // Retrieve the register type of the key.
         let key_type = match self.get_type_from_operand(stack, get_or_use.key())? {
             // If the register is a plaintext type, return it.
@@ -401,12 +467,6 @@ impl<N: Network> FinalizeTypes<N> {
             // If the register is a future, throw an error.
             FinalizeType::Future(..) => bail!("A future cannot be used as a key in a `get.or_use` command"),
         };
-        // Check that the key type in the mapping matches the key type.
-        if *mapping_key_type != key_type {
-            bail!(
-                "Key type in `get.or_use` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
-            )
-        }
         // Retrieve the register type of the default value.
         let default_value_type = match self.get_type_from_operand(stack, get_or_use.default())? {
             // If the register is a plaintext type, return it.
@@ -414,11 +474,23 @@ impl<N: Network> FinalizeTypes<N> {
             // If the register is a future, throw an error.
             FinalizeType::Future(..) => bail!("A default value cannot be a future"),
         };
-        // Check that the value type in the mapping matches the default value type.
-        if mapping_value_type != &default_value_type {
-            bail!(
-                "Default value type in `get.or_use` '{default_value_type}' does not match the value type in the mapping '{mapping_value_type}'."
-            )
+        if let Some(mapping) = mapping {
+            // Get the mapping key type.
+            let mapping_key_type = mapping.key().plaintext_type();
+            // Get the mapping value type.
+            let mapping_value_type = mapping.value().plaintext_type();
+            // Check that the key type in the mapping matches the key type.
+            if *mapping_key_type != key_type {
+                bail!(
+                    "Key type in `get.or_use` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
+                )
+            }
+            // Check that the value type in the mapping matches the default value type.
+            if mapping_value_type != &default_value_type {
+                bail!(
+                    "Default value type in `get.or_use` '{default_value_type}' does not match the value type in the mapping '{mapping_value_type}'."
+                )
+            }
         }
         // Get the destination register.
         let destination = get_or_use.destination().clone();
@@ -429,6 +501,34 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Checks that the given `invoke` command is well-formed.
+    #[inline]
+    fn check_invoke(&self, stack: &(impl StackMatches<N> + StackProgram<N>), invoke: &Invoke<N>) -> Result<()> {
+        // Ensure the register contains the address of the program.
+        self.check_program_address(stack, invoke.register())?;
+        // Ensure the operands are plaintext types.
+        // Note: The callback is only known at runtime, and its input types are checked by the process.
+        for operand in invoke.arguments() {
+            if let FinalizeType::Future(..) = self.get_type_from_operand(stack, operand)? {
+                bail!("A future cannot be passed to a callback in '{invoke}'")
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the given register contains the address of a program, for a dynamic mapping or callback.
+    #[inline]
+    fn check_program_address(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        register: &Register<N>,
+    ) -> Result<()> {
+        match self.get_type(stack, register)? {
+            FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Address)) => Ok(()),
+            finalize_type => bail!("Register '{register}' must contain a program address, found '{finalize_type}'."),
+        }
+    }
+
     /// Ensure the given `rand.chacha` command is well-formed.
     #[inline]
     fn check_rand_chacha(
@@ -667,6 +767,17 @@ impl<N: Network> FinalizeTypes<N> {
                 bail!("Fatal error: Cannot check command '{opcode}' as an instruction in 'finalize {finalize_name}'.")
             }
             Opcode::Commit(opcode) => RegisterTypes::check_commit_opcode(opcode, instruction)?,
+            Opcode::ECDSA(opcode) => match opcode {
+                "ecdsa.verify.digest" => ensure!(
+                    matches!(instruction, Instruction::ECDSAVerifyDigest(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "ecdsa.verify.keccak256" => ensure!(
+                    matches!(instruction, Instruction::ECDSAVerifyKeccak256(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
             Opcode::Hash(opcode) => RegisterTypes::check_hash_opcode(opcode, instruction)?,
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
@@ -679,6 +790,17 @@ impl<N: Network> FinalizeTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
+            Opcode::Repeat => {
+                // Retrieve the repeat operation.
+                let repeat = match instruction {
+                    Instruction::Repeat(repeat) => repeat,
+                    _ => bail!("Instruction '{instruction}' is not a repeat operation."),
+                };
+                // Retrieve the closure.
+                let closure = stack.program().get_closure(repeat.closure_name())?;
+                // Ensure the closure is well-formed in a finalize context.
+                Self::initialize_closure_types(stack, &closure)?;
+            }
             Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
@@ -20,6 +20,7 @@ use console::{
     program::{ArrayType, Identifier, LiteralType, PlaintextType, Register, RegisterType, StructType},
 };
 use synthesizer_program::{
+    Closure,
     Command,
     Finalize,
     Instruction,
@@ -52,6 +53,13 @@ impl<N: Network> FinalizeTypes<N> {
         Self::initialize_finalize_types(stack, finalize)
     }
 
+    /// Initializes a new instance of `FinalizeTypes` for the given closure, when it is repeated in finalize.
+    /// Checks that the given closure is well-formed in a finalize context for the given stack.
+    #[inline]
+    pub fn from_closure(stack: &(impl StackMatches<N> + StackProgram<N>), closure: &Closure<N>) -> Result<Self> {
+        Self::initialize_closure_types(stack, closure)
+    }
+
     /// Returns `true` if the given register exists.
     pub fn contains(&self, register: &Register<N>) -> bool {
         // Retrieve the register locator.
@@ -79,6 +87,9 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Signer => bail!("'self.signer' is not a valid operand in a finalize context."),
             Operand::Caller => bail!("'self.caller' is not a valid operand in a finalize context."),
             Operand::BlockHeight => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
+            Operand::BlockTimestamp => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::I64)),
+            Operand::NetworkID => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::U16)),
+            Operand::ProgramAddress => FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
         })
     }
 
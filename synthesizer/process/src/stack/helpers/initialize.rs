@@ -21,12 +21,15 @@ impl<N: Network> Stack<N> {
         // Construct the stack for the program.
         let mut stack = Self {
             program: program.clone(),
+            edition: N::EDITION,
             external_stacks: Default::default(),
             register_types: Default::default(),
             finalize_types: Default::default(),
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            previous_verifying_keys: Default::default(),
+            key_cache: process.key_cache().cloned(),
         };
 
         // Add all of the imports into the stack.
@@ -50,6 +53,11 @@ impl<N: Network> Stack<N> {
             // Add the function to the stack.
             stack.insert_function(function)?;
         }
+        // Add the program callbacks to the stack.
+        for callback in program.callbacks().values() {
+            // Add the callback to the stack.
+            stack.insert_callback(callback)?;
+        }
         // Return the stack.
         Ok(stack)
     }
@@ -113,4 +121,21 @@ impl<N: Network> Stack<N> {
         // Return success.
         Ok(())
     }
+
+    /// Adds the given callback name and finalize types to the stack.
+    #[inline]
+    fn insert_callback(&mut self, callback: &Callback<N>) -> Result<()> {
+        // Retrieve the callback name.
+        let name = callback.name();
+        // Ensure the callback name is not already added.
+        ensure!(!self.finalize_types.contains_key(name), "Callback '{name}' already exists");
+
+        // Compute the finalize types.
+        let finalize_types = FinalizeTypes::from_finalize(self, callback.logic())?;
+        // Add the callback name and finalize types to the stack.
+        self.finalize_types.insert(*name, finalize_types);
+
+        // Return success.
+        Ok(())
+    }
 }
@@ -27,6 +27,18 @@ impl<N: Network> Stack<N> {
             return Ok(());
         }
 
+        // Initialize the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+        // Retrieve the key cache, which does not apply to 'credits.aleo'.
+        let key_cache = self.key_cache.as_ref().filter(|_| self.program_id() != &credits_program_id);
+        // If the keys are cached, load them instead of synthesizing them.
+        // Note: An unreadable entry is treated as a cache miss, and is overwritten below.
+        if let Some(Ok(Some((proving_key, verifying_key)))) = key_cache.map(|cache| cache.load(self, function_name)) {
+            self.insert_proving_key(function_name, proving_key)?;
+            self.insert_verifying_key(function_name, verifying_key)?;
+            return Ok(());
+        }
+
         // Retrieve the program ID.
         let program_id = self.program_id();
         // Retrieve the function input types.
@@ -64,6 +76,17 @@ impl<N: Network> Stack<N> {
         ensure!(self.contains_proving_key(function_name), "Function '{function_name}' is missing a proving key.");
         // Ensure the verifying key exists.
         ensure!(self.contains_verifying_key(function_name), "Function '{function_name}' is missing a verifying key.");
+
+        // Store the keys in the cache.
+        // Note: A failure to cache the keys does not fail the synthesis.
+        if let Some(cache) = key_cache {
+            let _ = cache.store(
+                self,
+                function_name,
+                &self.get_proving_key(function_name)?,
+                &self.get_verifying_key(function_name)?,
+            );
+        }
         Ok(())
     }
 
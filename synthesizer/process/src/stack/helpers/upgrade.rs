@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Initializes the stack for an upgrade of a program that already exists in the process.
+    /// The stack is assigned the next edition, and retains the verifying keys of the previous editions.
+    #[inline]
+    pub fn upgrade(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::<N>::from_str("credits.aleo")?, "Cannot upgrade 'credits.aleo'");
+        // Retrieve the stack of the latest edition.
+        let latest = process.get_stack(program_id)?;
+        // Ensure the upgrade is compatible with the latest edition.
+        latest.check_upgrade_is_compatible(program)?;
+        // Ensure the program is well-formed.
+        Self::check_program_is_well_formed(program)?;
+        // Compute the next edition.
+        let Some(edition) = latest.edition.checked_add(1) else {
+            bail!("Program '{program_id}' has reached the maximum edition")
+        };
+
+        // Retrieve the verifying keys of the previous editions, including the latest edition.
+        let mut previous_verifying_keys = latest.previous_verifying_keys.read().clone();
+        let latest_verifying_keys = latest.verifying_keys.read().clone();
+        if !latest_verifying_keys.is_empty() {
+            previous_verifying_keys.insert(latest.edition, latest_verifying_keys);
+        }
+
+        // Initialize the stack.
+        let mut stack = Self::initialize(process, program)?;
+        stack.edition = edition;
+        stack.previous_verifying_keys = Arc::new(RwLock::new(previous_verifying_keys));
+        Ok(stack)
+    }
+
+    /// Initializes the stack for an earlier edition of a program that exists in the process,
+    /// from the deployment of the earlier edition. This is used to revert an upgrade.
+    #[inline]
+    pub fn restore(process: &Process<N>, deployment: &Deployment<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Retrieve the stack of the latest edition.
+        let latest = process.get_stack(program_id)?;
+        // Ensure the deployment is for an earlier edition.
+        ensure!(
+            deployment.edition() < latest.edition,
+            "Cannot restore edition {} of program '{program_id}' (latest edition {})",
+            deployment.edition(),
+            latest.edition
+        );
+
+        // Retrieve the verifying keys of the editions before the restored edition.
+        let previous_verifying_keys = latest
+            .previous_verifying_keys
+            .read()
+            .iter()
+            .filter(|(edition, _)| **edition < deployment.edition())
+            .map(|(edition, verifying_keys)| (*edition, verifying_keys.clone()))
+            .collect();
+
+        // Initialize the stack.
+        let mut stack = Self::initialize(process, deployment.program())?;
+        stack.edition = deployment.edition();
+        stack.previous_verifying_keys = Arc::new(RwLock::new(previous_verifying_keys));
+        // Insert the verifying keys.
+        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+            stack.insert_verifying_key(function_name, verifying_key.clone())?;
+        }
+        Ok(stack)
+    }
+
+    /// Initializes a new stack for the program of the given stack, with the latest stacks of its imports
+    /// in the process. The new stack shares the edition and keys of the given stack.
+    #[inline]
+    pub(crate) fn refresh(process: &Process<N>, stack: &Stack<N>) -> Result<Self> {
+        let mut refreshed = Self::initialize(process, &stack.program)?;
+        refreshed.edition = stack.edition;
+        refreshed.proving_keys = stack.proving_keys.clone();
+        refreshed.verifying_keys = stack.verifying_keys.clone();
+        refreshed.previous_verifying_keys = stack.previous_verifying_keys.clone();
+        Ok(refreshed)
+    }
+
+    /// Ensures the given program is a compatible upgrade of the program in the stack.
+    ///
+    /// An upgrade must preserve the storage layout and interface of the program: every existing mapping,
+    /// struct, and record is unchanged, every existing function keeps its input and output types,
+    /// and the input types of its finalize logic, and every existing callback keeps its input types.
+    /// An upgrade may change the logic of the existing functions and callbacks, and add new components.
+    pub fn check_upgrade_is_compatible(&self, program: &Program<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = self.program.id();
+        // Ensure the program ID matches.
+        ensure!(program.id() == program_id, "Cannot upgrade '{program_id}' with program '{}'", program.id());
+        // Ensure the upgrade changes the program.
+        ensure!(program != &self.program, "The upgrade of '{program_id}' does not change the program");
+
+        // Ensure every mapping is unchanged.
+        for (name, mapping) in self.program.mappings() {
+            match program.mappings().get(name) {
+                Some(upgraded) if upgraded == mapping => (),
+                Some(_) => bail!("The upgrade of '{program_id}' changes the key or value type of mapping '{name}'"),
+                None => bail!("The upgrade of '{program_id}' removes mapping '{name}'"),
+            }
+        }
+        // Ensure every struct is unchanged.
+        for (name, struct_) in self.program.structs() {
+            match program.structs().get(name) {
+                Some(upgraded) if upgraded == struct_ => (),
+                Some(_) => bail!("The upgrade of '{program_id}' changes struct '{name}'"),
+                None => bail!("The upgrade of '{program_id}' removes struct '{name}'"),
+            }
+        }
+        // Ensure every record is unchanged.
+        for (name, record) in self.program.records() {
+            match program.records().get(name) {
+                Some(upgraded) if upgraded == record => (),
+                Some(_) => bail!("The upgrade of '{program_id}' changes record '{name}'"),
+                None => bail!("The upgrade of '{program_id}' removes record '{name}'"),
+            }
+        }
+        // Ensure every function keeps its input and output types, and the input types of its finalize logic.
+        for (name, function) in self.program.functions() {
+            let Some(upgraded) = program.functions().get(name) else {
+                bail!("The upgrade of '{program_id}' removes function '{name}'")
+            };
+            ensure!(
+                upgraded.input_types() == function.input_types() && upgraded.output_types() == function.output_types(),
+                "The upgrade of '{program_id}' changes the inputs or outputs of function '{name}'"
+            );
+            // Note: The executions of a previous edition are finalized with the upgraded finalize logic.
+            ensure!(
+                upgraded.finalize_logic().map(|finalize| finalize.input_types())
+                    == function.finalize_logic().map(|finalize| finalize.input_types()),
+                "The upgrade of '{program_id}' changes the finalize inputs of function '{name}'"
+            );
+        }
+        // Ensure every callback keeps its input types, as other programs may invoke it.
+        for (name, callback) in self.program.callbacks() {
+            let Some(upgraded) = program.callbacks().get(name) else {
+                bail!("The upgrade of '{program_id}' removes callback '{name}'")
+            };
+            ensure!(
+                upgraded.logic().input_types() == callback.logic().input_types(),
+                "The upgrade of '{program_id}' changes the inputs of callback '{name}'"
+            );
+        }
+        Ok(())
+    }
+}
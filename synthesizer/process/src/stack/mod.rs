@@ -30,13 +30,16 @@ pub use register_types::*;
 mod registers;
 pub use registers::*;
 
+mod repeat;
+pub use repeat::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{traits::*, CallMetrics, Process, Trace};
+use crate::{traits::*, CallMetrics, InstructionMetrics, KeyCache, Process, Trace};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -64,8 +67,8 @@ use console::{
     types::{Field, Group},
 };
 use ledger_block::{Deployment, Transition};
-use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
-use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_program::{traits::*, CallOperator, Callback, Closure, Function, Instruction, Operand, Program};
+use synthesizer_snark::{Certificate, PreparedCheck, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
@@ -170,11 +173,13 @@ impl<N: Network> CallStack<N> {
 pub struct Stack<N: Network> {
     /// The program (record types, structs, functions).
     program: Program<N>,
+    /// The edition of the program.
+    edition: u16,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
     /// The mapping of closure and function names to their register types.
     register_types: IndexMap<Identifier<N>, RegisterTypes<N>>,
-    /// The mapping of finalize names to their register types.
+    /// The mapping of finalize and callback names to their register types.
     finalize_types: IndexMap<Identifier<N>, FinalizeTypes<N>>,
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
@@ -182,6 +187,10 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The mapping of each previous edition to its verifying keys, for executions proven against earlier editions.
+    previous_verifying_keys: Arc<RwLock<IndexMap<u16, IndexMap<Identifier<N>, VerifyingKey<N>>>>>,
+    /// The disk cache for the proving and verifying keys, if it is enabled.
+    key_cache: Option<Arc<KeyCache<N>>>,
 }
 
 impl<N: Network> Stack<N> {
@@ -192,8 +201,22 @@ impl<N: Network> Stack<N> {
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Ensure the program is well-formed.
+        Self::check_program_is_well_formed(program)?;
+
+        // Return the stack.
+        Stack::initialize(process, program)
+    }
+
+    /// Ensures the given program contains functions, and serializes and deserializes correctly.
+    #[inline]
+    fn check_program_is_well_formed(program: &Program<N>) -> Result<()> {
         // Ensure the program contains functions.
-        ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
+        ensure!(
+            !program.functions().is_empty(),
+            "No functions present in the deployment for program '{}'",
+            program.id()
+        );
 
         // Serialize the program into bytes.
         let program_bytes = program.to_bytes_le()?;
@@ -205,8 +228,7 @@ impl<N: Network> Stack<N> {
         // Ensure the program deserializes from a string correctly.
         ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
 
-        // Return the stack.
-        Stack::initialize(process, program)
+        Ok(())
     }
 }
 
@@ -353,6 +375,18 @@ impl<N: Network> StackProgramTypes<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Returns the edition of the program.
+    #[inline]
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
+    /// Returns the previous editions of the program, whose verifying keys are retained, in ascending order.
+    #[inline]
+    pub fn previous_editions(&self) -> Vec<u16> {
+        self.previous_verifying_keys.read().keys().copied().collect()
+    }
+
     /// Returns `true` if the proving key for the given function name exists.
     #[inline]
     pub fn contains_proving_key(&self, function_name: &Identifier<N>) -> bool {
@@ -387,6 +421,24 @@ impl<N: Network> Stack<N> {
         }
     }
 
+    /// Returns the verifying key for the given function name, as of the given edition of the program.
+    #[inline]
+    pub fn get_verifying_key_for_edition(
+        &self,
+        function_name: &Identifier<N>,
+        edition: u16,
+    ) -> Result<VerifyingKey<N>> {
+        // If the edition is the current edition, return the current verifying key.
+        if edition == self.edition {
+            return self.get_verifying_key(function_name);
+        }
+        // Return the verifying key of the previous edition, if it exists.
+        match self.previous_verifying_keys.read().get(&edition).and_then(|keys| keys.get(function_name)) {
+            Some(verifying_key) => Ok(verifying_key.clone()),
+            None => bail!("Verifying key not found for: {}/{function_name} (edition {edition})", self.program.id()),
+        }
+    }
+
     /// Inserts the given proving key for the given function name.
     #[inline]
     pub fn insert_proving_key(&self, function_name: &Identifier<N>, proving_key: ProvingKey<N>) -> Result<()> {
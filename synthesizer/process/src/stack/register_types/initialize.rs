@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::*;
-use synthesizer_program::CastType;
+use synthesizer_program::{CastType, Repeat};
 
 impl<N: Network> RegisterTypes<N> {
     /// Initializes a new instance of `RegisterTypes` for the given closure.
@@ -41,6 +41,8 @@ impl<N: Network> RegisterTypes<N> {
             // Check the instruction opcode, operands, and destinations.
             register_types.check_instruction(stack, closure.name(), instruction)?;
         }
+        // Ensure the closure does not unroll into too many iterations.
+        register_types.check_unrolled_iterations(stack, closure.name(), closure.instructions())?;
 
         // Step 3. Check the outputs are well-formed.
         for output in closure.outputs() {
@@ -111,6 +113,8 @@ impl<N: Network> RegisterTypes<N> {
                 _ => {}
             }
         }
+        // Ensure the function does not unroll into too many iterations.
+        register_types.check_unrolled_iterations(stack, function.name(), function.instructions())?;
 
         // Ensure the number of `async` instructions is valid.
         if function.finalize_logic().is_some() {
@@ -356,6 +360,24 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given instructions do not unroll into more than `N::MAX_REPEAT_ITERATIONS` closure iterations.
+    /// Note: The iterations of nested `repeat` instructions are multiplied, as each iteration is synthesized.
+    #[inline]
+    fn check_unrolled_iterations(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        closure_or_function_name: &Identifier<N>,
+        instructions: &[Instruction<N>],
+    ) -> Result<()> {
+        let iterations = Repeat::unrolled_iterations(stack.program(), instructions)?;
+        ensure!(
+            iterations <= u64::from(N::MAX_REPEAT_ITERATIONS),
+            "'{closure_or_function_name}' unrolls into {iterations} 'repeat' iterations, exceeding the maximum of {}",
+            N::MAX_REPEAT_ITERATIONS
+        );
+        Ok(())
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -561,6 +583,17 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Instruction '{instruction}' cannot invoke command '{opcode}'.");
             }
             Opcode::Commit(opcode) => Self::check_commit_opcode(opcode, instruction)?,
+            Opcode::ECDSA(opcode) => match opcode {
+                "ecdsa.verify.digest" => ensure!(
+                    matches!(instruction, Instruction::ECDSAVerifyDigest(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "ecdsa.verify.keccak256" => ensure!(
+                    matches!(instruction, Instruction::ECDSAVerifyKeccak256(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
             Opcode::Hash(opcode) => Self::check_hash_opcode(opcode, instruction)?,
             Opcode::Is(opcode) => match opcode {
                 "is.eq" => ensure!(
@@ -573,6 +606,27 @@ impl<N: Network> RegisterTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
+            Opcode::Repeat => {
+                // Retrieve the repeat operation.
+                let repeat = match instruction {
+                    Instruction::Repeat(repeat) => repeat,
+                    _ => bail!("Instruction '{instruction}' is not a repeat operation."),
+                };
+
+                // Retrieve the index of the repeated closure.
+                let closure_name = repeat.closure_name();
+                let Some(closure_index) = stack.program().closures().get_index_of(closure_name) else {
+                    bail!("Closure '{closure_name}' is not defined in '{}'.", stack.program_id())
+                };
+                // If the instruction is in a closure, ensure the repeated closure is declared before it.
+                // Note: This ensures a closure cannot repeat itself, directly or indirectly.
+                if let Some(index) = stack.program().closures().get_index_of(closure_or_function_name) {
+                    ensure!(
+                        closure_index < index,
+                        "Closure '{closure_or_function_name}' can only repeat a closure declared before it."
+                    );
+                }
+            }
             Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FinalizeRegisters, FinalizeTypes, Registers, RegistersCall, StackEvaluate, StackExecute};
+use aleo_std::prelude::{finish, lap, timer};
+use console::network::prelude::*;
+use synthesizer_program::{
+    FinalizeRegistersState,
+    Instruction,
+    RegistersLoad,
+    RegistersLoadCircuit,
+    RegistersSigner,
+    RegistersSignerCircuit,
+    RegistersStore,
+    RegistersStoreCircuit,
+    Repeat,
+    StackMatches,
+    StackProgram,
+};
+
+pub trait RepeatTrait<N: Network> {
+    /// Evaluates the instruction.
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()>;
+
+    /// Executes the instruction.
+    fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut (
+                 impl RegistersCall<N>
+                 + RegistersSignerCircuit<N, A>
+                 + RegistersLoadCircuit<N, A>
+                 + RegistersStoreCircuit<N, A>
+             ),
+    ) -> Result<()>;
+
+    /// Finalizes the instruction.
+    fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut FinalizeRegisters<N>,
+    ) -> Result<()>;
+}
+
+impl<N: Network> RepeatTrait<N> for Repeat<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    fn evaluate<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        let timer = timer!("Repeat::evaluate");
+
+        // Load the operands values.
+        let mut values: Vec<_> = self.operands().iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+        // Retrieve the closure.
+        let closure = stack.program().get_closure(self.closure_name())?;
+        lap!(timer, "Retrieved the closure");
+
+        // Retrieve the signer, caller, and transition view key.
+        // Note: When a closure is executed, its registers only hold the circuit values, so eject them instead.
+        use circuit::Eject;
+        let signer = registers.signer().or_else(|_| registers.signer_circuit().map(|signer| signer.eject_value()))?;
+        let caller = registers.caller().or_else(|_| registers.caller_circuit().map(|caller| caller.eject_value()))?;
+        let tvk = registers.tvk().or_else(|_| registers.tvk_circuit().map(|tvk| tvk.eject_value()))?;
+
+        // Evaluate the closure for each iteration, passing the outputs of each iteration to the next.
+        for _ in 0..**self.count() {
+            values = stack.evaluate_closure::<A>(&closure, &values, registers.call_stack(), signer, caller, tvk)?;
+        }
+        lap!(timer, "Computed outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in values.into_iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store(stack, register, output)?;
+        }
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Executes the instruction.
+    /// Note: The closure is synthesized once per iteration, so the loop is unrolled in the circuit.
+    #[inline]
+    fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
+        registers: &mut (
+                 impl RegistersCall<N>
+                 + RegistersSignerCircuit<N, A>
+                 + RegistersLoadCircuit<N, A>
+                 + RegistersStoreCircuit<N, A>
+             ),
+    ) -> Result<()> {
+        let timer = timer!("Repeat::execute");
+
+        // Load the operands values.
+        let mut values: Vec<_> =
+            self.operands().iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
+        // Retrieve the closure.
+        let closure = stack.program().get_closure(self.closure_name())?;
+        lap!(timer, "Retrieved the closure");
+
+        // Execute the closure for each iteration, passing the outputs of each iteration to the next.
+        for _ in 0..**self.count() {
+            values = stack.execute_closure(
+                &closure,
+                &values,
+                registers.call_stack(),
+                registers.signer_circuit()?,
+                registers.caller_circuit()?,
+                registers.tvk_circuit()?,
+            )?;
+        }
+        lap!(timer, "Computed outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in values.into_iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store_circuit(stack, register, output)?;
+        }
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut FinalizeRegisters<N>,
+    ) -> Result<()> {
+        let timer = timer!("Repeat::finalize");
+
+        // Load the operands values.
+        let mut values: Vec<_> = self.operands().iter().map(|operand| registers.load(stack, operand)).try_collect()?;
+        // Retrieve the closure.
+        let closure = stack.program().get_closure(self.closure_name())?;
+        // Compute the finalize types of the closure.
+        let finalize_types = FinalizeTypes::from_closure(stack, &closure)?;
+        lap!(timer, "Retrieved the closure");
+
+        // Evaluate the closure for each iteration, passing the outputs of each iteration to the next.
+        for _ in 0..**self.count() {
+            // Initialize the registers for this iteration.
+            let mut closure_registers = FinalizeRegisters::new(
+                *registers.state(),
+                *registers.transition_id(),
+                *closure.name(),
+                finalize_types.clone(),
+            );
+
+            // Store the inputs.
+            closure.inputs().iter().map(|i| i.register()).zip_eq(values).try_for_each(|(register, value)| {
+                // Assign the input value to the register.
+                closure_registers.store(stack, register, value)
+            })?;
+
+            // Evaluate the instructions.
+            for instruction in closure.instructions() {
+                // Evaluate the instruction.
+                let result = match instruction {
+                    // If the instruction is a `repeat` instruction, we need to handle it separately.
+                    Instruction::Repeat(repeat) => RepeatTrait::finalize(repeat, stack, &mut closure_registers),
+                    // Otherwise, evaluate the instruction normally.
+                    _ => instruction.finalize(stack, &mut closure_registers),
+                };
+                // If the evaluation fails, bail and return the error.
+                if let Err(error) = result {
+                    bail!("Failed to evaluate instruction ({instruction}): {error}");
+                }
+            }
+
+            // Load the outputs.
+            values =
+                closure.outputs().iter().map(|output| closure_registers.load(stack, output.operand())).try_collect()?;
+        }
+        lap!(timer, "Computed outputs");
+
+        // Assign the outputs to the destination registers.
+        for (output, register) in values.into_iter().zip_eq(&self.destinations()) {
+            // Assign the output to the register.
+            registers.store(stack, register, output)?;
+        }
+        finish!(timer);
+
+        Ok(())
+    }
+}
@@ -23,7 +23,7 @@ use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, Testnet3},
     program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
-    types::{Field, U64},
+    types::{Field, I64, U16, U64, U8},
 };
 use ledger_block::Fee;
 use ledger_query::Query;
@@ -46,7 +46,7 @@ type CurrentAleo = AleoV0;
 
 /// Samples a new finalize state.
 pub fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-    FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+    FinalizeGlobalState::from(block_height as u64, block_height, CurrentNetwork::GENESIS_TIMESTAMP, [0u8; 32])
 }
 
 /// Samples a valid fee for the given process, block store, and finalize store.
@@ -1253,7 +1253,7 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -1303,6 +1303,673 @@ finalize compute:
     assert_eq!(candidate, Value::from_str("8u64").unwrap());
 }
 
+#[test]
+fn test_process_execute_and_finalize_block_timestamp_network_id_and_self_address() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping timestamps:
+    key as u8.public;
+    value as i64.public;
+
+mapping networks:
+    key as u8.public;
+    value as u16.public;
+
+mapping addresses:
+    key as u8.public;
+    value as address.public;
+
+function store:
+    async store into r0;
+    output r0 as testing.aleo/store.future;
+
+finalize store:
+    set block.timestamp into timestamps[0u8];
+    set network.id into networks[0u8];
+    set self.address into addresses[0u8];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the function name.
+    let function_name = Identifier::from_str("store").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            function_name,
+            Vec::<Value<_>>::new().iter(),
+            rng,
+        )
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Finalize the execution, with a block timestamp after the genesis timestamp.
+    let state = FinalizeGlobalState::from(2, 2, CurrentNetwork::GENESIS_TIMESTAMP + 15, [0u8; 32]);
+    process.finalize_execution(state, &finalize_store, &execution, None).unwrap();
+
+    // Retrieve the value stored in the given mapping.
+    let get = |mapping_name: &str| {
+        finalize_store
+            .get_value_speculative(
+                *program_id,
+                Identifier::from_str(mapping_name).unwrap(),
+                &Plaintext::from(Literal::U8(U8::new(0))),
+            )
+            .unwrap()
+            .unwrap()
+    };
+
+    // Check the block timestamp, network ID, and program address.
+    let expected_timestamp = Literal::I64(I64::new(CurrentNetwork::GENESIS_TIMESTAMP + 15));
+    assert_eq!(get("timestamps"), Value::Plaintext(Plaintext::from(expected_timestamp)));
+    let expected_network_id = Literal::U16(U16::new(CurrentNetwork::ID));
+    assert_eq!(get("networks"), Value::Plaintext(Plaintext::from(expected_network_id)));
+    let expected_address = Literal::Address(program_id.to_address().unwrap());
+    assert_eq!(get("addresses"), Value::Plaintext(Plaintext::from(expected_address)));
+}
+
+#[test]
+fn test_process_execute_and_finalize_repeat() {
+    // Initialize a new program.
+    let (string, program) = Program::<CurrentNetwork>::parse(
+        r"
+program testing.aleo;
+
+mapping totals:
+    key as u8.public;
+    value as u64.public;
+
+closure step:
+    input r0 as u64;
+    input r1 as u64;
+    add r0 r1 into r2;
+    output r2 as u64;
+    output r1 as u64;
+
+closure step_twice:
+    input r0 as u64;
+    input r1 as u64;
+    repeat 2u32 step r0 r1 into r2 r3;
+    output r2 as u64;
+    output r3 as u64;
+
+function sum:
+    input r0 as u64.public;
+    repeat 4u32 step_twice r0 1u64 into r1 r2;
+    async sum r1 into r3;
+    output r1 as u64.public;
+    output r3 as testing.aleo/sum.future;
+
+finalize sum:
+    input r0 as u64.public;
+    repeat 3u32 step r0 2u64 into r1 r2;
+    set r1 into totals[0u8];
+",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Declare the program ID.
+    let program_id = program.id();
+    // Declare the function name.
+    let function_name = Identifier::from_str("sum").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Add the program to the process.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Check that the deployment verifies.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Compute the fee.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    // Finalize the deployment.
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    // Add the stack *manually* to the process.
+    process.add_stack(stack).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            function_name,
+            [Value::<CurrentNetwork>::from_str("5u64").unwrap()].iter(),
+            rng,
+        )
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Execute the request.
+    let (response, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    // Check the closure was applied 8 times, adding 1 each time.
+    assert_eq!(response.outputs()[0], Value::from_str("13u64").unwrap());
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Finalize the execution.
+    process.finalize_execution(sample_finalize_state(2), &finalize_store, &execution, None).unwrap();
+
+    // Check the closure was applied 3 times in finalize, adding 2 each time.
+    let total = finalize_store
+        .get_value_speculative(
+            *program_id,
+            Identifier::from_str("totals").unwrap(),
+            &Plaintext::from(Literal::U8(U8::new(0))),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(total, Value::from_str("19u64").unwrap());
+}
+
+#[test]
+fn test_process_execute_and_finalize_get_dynamic_mapping() {
+    // Initialize a token program.
+    let token = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async mint r0 r1 into r2;
+    output r2 as token.aleo/mint.future;
+
+finalize mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    set r1 into balances[r0];
+",
+    )
+    .unwrap();
+
+    // Initialize a program that reads the balances of a token, without importing it.
+    let reader = Program::<CurrentNetwork>::from_str(
+        r"
+program reader.aleo;
+
+mapping quotes:
+    key as u8.public;
+    value as u64.public;
+
+function quote:
+    input r0 as address.public;
+    input r1 as address.public;
+    async quote r0 r1 into r2;
+    output r2 as reader.aleo/quote.future;
+
+finalize quote:
+    input r0 as address.public;
+    input r1 as address.public;
+    get r0/balances[r1] into r2 as u64;
+    get.or_use r0/balances[r0] 5u64 into r3;
+    add r2 r3 into r4;
+    set r4 into quotes[0u8];
+
+function quote_as_u128:
+    input r0 as address.public;
+    input r1 as address.public;
+    async quote_as_u128 r0 r1 into r2;
+    output r2 as reader.aleo/quote_as_u128.future;
+
+finalize quote_as_u128:
+    input r0 as address.public;
+    input r1 as address.public;
+    get r0/balances[r1] into r2 as u128;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy the programs.
+    for (index, program) in [&token, &reader].into_iter().enumerate() {
+        let deployment = process.deploy::<CurrentAleo, _>(program, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+        let state = sample_finalize_state(1 + index as u32);
+        let (stack, _) = process.finalize_deployment(state, &finalize_store, &deployment, &fee).unwrap();
+        process.add_stack(stack).unwrap();
+    }
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller_address = Address::try_from(&caller_private_key).unwrap();
+    // Compute the address of the token program.
+    let token_address = token.id().to_address().unwrap();
+
+    // Executes and finalizes the given function.
+    let mut execute = |program: &Program<CurrentNetwork>, function_name: &str, inputs: [Value<CurrentNetwork>; 2]| {
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        trace.prepare(Query::from(block_store.clone())).unwrap();
+        let execution = trace.prove_execution::<CurrentAleo, _>(function_name, rng).unwrap();
+        process.verify_execution(&execution).unwrap();
+        process.finalize_execution(sample_finalize_state(3), &finalize_store, &execution, None)
+    };
+
+    // Mint tokens to the caller.
+    execute(&token, "mint", [
+        Value::from_str(&caller_address.to_string()).unwrap(),
+        Value::from_str("100u64").unwrap(),
+    ])
+    .unwrap();
+
+    // Read the balance of the caller, from a program address that is only known at runtime.
+    execute(&reader, "quote", [
+        Value::from_str(&token_address.to_string()).unwrap(),
+        Value::from_str(&caller_address.to_string()).unwrap(),
+    ])
+    .unwrap();
+    // Check the balance was read, and the default was used for the missing key.
+    let quote = finalize_store
+        .get_value_speculative(
+            *reader.id(),
+            Identifier::from_str("quotes").unwrap(),
+            &Plaintext::from(Literal::U8(U8::new(0))),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(quote, Value::from_str("105u64").unwrap());
+
+    // Ensure the read fails if the address is not a program.
+    assert!(
+        execute(&reader, "quote", [
+            Value::from_str(&caller_address.to_string()).unwrap(),
+            Value::from_str(&caller_address.to_string()).unwrap(),
+        ])
+        .is_err()
+    );
+    // Ensure the read fails if the declared value type does not match the mapping.
+    assert!(
+        execute(&reader, "quote_as_u128", [
+            Value::from_str(&token_address.to_string()).unwrap(),
+            Value::from_str(&caller_address.to_string()).unwrap(),
+        ])
+        .is_err()
+    );
+}
+
+#[test]
+fn test_process_execute_and_finalize_invoke_callback() {
+    // Initialize a token program, with a callback that transfers the balance of the invoking program.
+    let token = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async mint r0 r1 into r2;
+    output r2 as token.aleo/mint.future;
+
+finalize mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    set r1 into balances[r0];
+
+callback transfer:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u64.public;
+    get balances[r0] into r3;
+    sub r3 r2 into r4;
+    set r4 into balances[r0];
+    get.or_use balances[r1] 0u64 into r5;
+    add r5 r2 into r6;
+    set r6 into balances[r1];
+",
+    )
+    .unwrap();
+
+    // Initialize a program that withdraws from its balance of a token, without importing it.
+    let vault = Program::<CurrentNetwork>::from_str(
+        r"
+program vault.aleo;
+
+mapping withdrawals:
+    key as u8.public;
+    value as u64.public;
+
+function withdraw:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u64.public;
+    async withdraw r0 r1 r2 into r3;
+    output r3 as vault.aleo/withdraw.future;
+
+finalize withdraw:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u64.public;
+    invoke r0/transfer r1 r2;
+    set r2 into withdrawals[0u8];
+
+function withdraw_as_u128:
+    input r0 as address.public;
+    input r1 as address.public;
+    async withdraw_as_u128 r0 r1 into r2;
+    output r2 as vault.aleo/withdraw_as_u128.future;
+
+finalize withdraw_as_u128:
+    input r0 as address.public;
+    input r1 as address.public;
+    invoke r0/transfer r1 5u128;
+",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy the programs.
+    for (index, program) in [&token, &vault].into_iter().enumerate() {
+        let deployment = process.deploy::<CurrentAleo, _>(program, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+        let state = sample_finalize_state(1 + index as u32);
+        let (stack, _) = process.finalize_deployment(state, &finalize_store, &deployment, &fee).unwrap();
+        process.add_stack(stack).unwrap();
+    }
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller_address = Address::try_from(&caller_private_key).unwrap();
+    // Compute the addresses of the programs.
+    let token_address = token.id().to_address().unwrap();
+    let vault_address = vault.id().to_address().unwrap();
+
+    // Executes and finalizes the given function.
+    let mut execute = |program: &Program<CurrentNetwork>, function_name: &str, inputs: &[Value<CurrentNetwork>]| {
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        trace.prepare(Query::from(block_store.clone())).unwrap();
+        let execution = trace.prove_execution::<CurrentAleo, _>(function_name, rng).unwrap();
+        process.verify_execution(&execution).unwrap();
+        process.finalize_execution(sample_finalize_state(3), &finalize_store, &execution, None)
+    };
+    // Returns the token balance of the given address.
+    let balance_of = |address: &Address<CurrentNetwork>| {
+        finalize_store
+            .get_value_speculative(
+                *token.id(),
+                Identifier::from_str("balances").unwrap(),
+                &Plaintext::from(Literal::Address(*address)),
+            )
+            .unwrap()
+    };
+
+    // Mint tokens to the vault program.
+    execute(&token, "mint", &[
+        Value::from_str(&vault_address.to_string()).unwrap(),
+        Value::from_str("100u64").unwrap(),
+    ])
+    .unwrap();
+
+    // Withdraw from the vault to the caller, through a token program that is only known at runtime.
+    execute(&vault, "withdraw", &[
+        Value::from_str(&token_address.to_string()).unwrap(),
+        Value::from_str(&caller_address.to_string()).unwrap(),
+        Value::from_str("30u64").unwrap(),
+    ])
+    .unwrap();
+    // Check the callback transferred from the balance of the invoking program.
+    assert_eq!(balance_of(&vault_address), Some(Value::from_str("70u64").unwrap()));
+    assert_eq!(balance_of(&caller_address), Some(Value::from_str("30u64").unwrap()));
+    // Check the finalize logic of the caller resumed after the callback.
+    let withdrawal = finalize_store
+        .get_value_speculative(
+            *vault.id(),
+            Identifier::from_str("withdrawals").unwrap(),
+            &Plaintext::from(Literal::U8(U8::new(0))),
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(withdrawal, Value::from_str("30u64").unwrap());
+
+    // Ensure the invocation fails if the invoking program has an insufficient balance.
+    assert!(
+        execute(&vault, "withdraw", &[
+            Value::from_str(&token_address.to_string()).unwrap(),
+            Value::from_str(&caller_address.to_string()).unwrap(),
+            Value::from_str("71u64").unwrap(),
+        ])
+        .is_err()
+    );
+    // Ensure the invocation fails if the address is not a program.
+    assert!(
+        execute(&vault, "withdraw", &[
+            Value::from_str(&caller_address.to_string()).unwrap(),
+            Value::from_str(&caller_address.to_string()).unwrap(),
+            Value::from_str("1u64").unwrap(),
+        ])
+        .is_err()
+    );
+    // Ensure the invocation fails if the arguments do not match the callback inputs.
+    assert!(
+        execute(&vault, "withdraw_as_u128", &[
+            Value::from_str(&token_address.to_string()).unwrap(),
+            Value::from_str(&caller_address.to_string()).unwrap(),
+        ])
+        .is_err()
+    );
+    // Check the failed invocations did not change the balances.
+    assert_eq!(balance_of(&vault_address), Some(Value::from_str("70u64").unwrap()));
+    assert_eq!(balance_of(&caller_address), Some(Value::from_str("30u64").unwrap()));
+}
+
+#[test]
+fn test_process_repeat_rejects_ill_formed_closures() {
+    // Checks that the given program is rejected by the process.
+    let check_rejected = |program: &str| {
+        let program = Program::<CurrentNetwork>::from_str(program).unwrap();
+        assert!(Process::<CurrentNetwork>::load().unwrap().add_program(&program).is_err());
+    };
+
+    // Ensure a closure cannot change the types of its values across iterations.
+    check_rejected(
+        r"
+program testing.aleo;
+
+closure widen:
+    input r0 as u32;
+    cast r0 into r1 as u64;
+    output r1 as u64;
+
+function run:
+    input r0 as u32.public;
+    repeat 2u32 widen r0 into r1;
+    output r1 as u64.public;",
+    );
+
+    // Ensure a closure cannot repeat itself.
+    // Note: This program is rejected when it is parsed, before it reaches the process.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+closure again:
+    input r0 as u64;
+    repeat 2u32 again r0 into r1;
+    output r1 as u64;
+
+function run:
+    input r0 as u64.public;
+    repeat 2u32 again r0 into r1;
+    output r1 as u64.public;",
+    );
+    assert!(program.is_err());
+
+    // Ensure the number of iterations is bounded.
+    check_rejected(&format!(
+        r"
+program testing.aleo;
+
+closure twice:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function run:
+    input r0 as u64.public;
+    repeat {}u32 twice r0 into r1;
+    output r1 as u64.public;",
+        CurrentNetwork::MAX_REPEAT_ITERATIONS + 1
+    ));
+
+    // Ensure the iterations of nested repeats are bounded, as they are multiplied when unrolled.
+    check_rejected(
+        r"
+program testing.aleo;
+
+closure twice:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+closure many_times:
+    input r0 as u64;
+    repeat 64u32 twice r0 into r1;
+    output r1 as u64;
+
+function run:
+    input r0 as u64.public;
+    repeat 32u32 many_times r0 into r1;
+    output r1 as u64.public;",
+    );
+    // Ensure the iterations of a closure are bounded, as its nested repeats are multiplied when unrolled.
+    check_rejected(
+        r"
+program testing.aleo;
+
+closure twice:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+closure many_times:
+    input r0 as u64;
+    repeat 64u32 twice r0 into r1;
+    output r1 as u64;
+
+closure many_many_times:
+    input r0 as u64;
+    repeat 32u32 many_times r0 into r1;
+    output r1 as u64;
+
+function run:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+    );
+
+    // Ensure a closure that reads 'self.signer' cannot be repeated in finalize.
+    check_rejected(
+        r"
+program testing.aleo;
+
+closure signer_of:
+    input r0 as address;
+    is.eq self.signer r0 into r1;
+    ternary r1 r0 r0 into r2;
+    output r2 as address;
+
+function run:
+    async run self.caller into r0;
+    output r0 as testing.aleo/run.future;
+
+finalize run:
+    input r0 as address.public;
+    repeat 1u32 signer_of r0 into r1;",
+    );
+}
+
 #[test]
 fn test_process_execute_and_finalize_increment_decrement_via_get_set() {
     // Initialize a new program.
@@ -1366,7 +2033,7 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -1493,7 +2160,7 @@ finalize mint_public:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
     //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
@@ -1622,7 +2289,7 @@ finalize mint_public:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
     //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
@@ -1662,7 +2329,7 @@ finalize init:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(2), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
     //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
@@ -1780,7 +2447,7 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -2209,7 +2876,7 @@ finalize compute:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -2362,8 +3029,12 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        program_addresses: IndexMap::new(),
+        key_cache: None,
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2436,7 +3107,7 @@ function {function_name}:
     // Finalize the deployment.
     let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
     // Add the stack *manually* to the process.
-    process.add_stack(stack);
+    process.add_stack(stack).unwrap();
 
     // Initialize a new caller account.
     let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    tests::test_execute::{sample_fee, sample_finalize_state},
+    Process,
+    StackProgram,
+};
+use circuit::network::AleoV0;
+use console::{
+    account::{Address, PrivateKey},
+    network::{prelude::*, Testnet3},
+    program::{Identifier, ProgramID, Value},
+};
+use ledger_query::Query;
+use ledger_store::{
+    helpers::memory::{BlockMemory, FinalizeMemory},
+    BlockStore,
+    FinalizeStore,
+};
+use synthesizer_program::Program;
+
+use std::collections::HashMap;
+
+type CurrentNetwork = Testnet3;
+type CurrentAleo = AleoV0;
+
+/// Returns a version of the testing program, with the given `compute` instruction and additional components.
+fn sample_program(compute: &str, extra: &str) -> Program<CurrentNetwork> {
+    Program::from_str(&format!(
+        r"
+program testing.aleo;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+{extra}
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    {compute} r1 r2 into r3;
+    async compute r0 r3 into r4;
+    output r4 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+"
+    ))
+    .unwrap()
+}
+
+#[test]
+fn test_process_upgrade_program() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the process and stores.
+    let mut process = Process::load().unwrap();
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    let program_id = ProgramID::<CurrentNetwork>::from_str("testing.aleo").unwrap();
+    let function_name = Identifier::<CurrentNetwork>::from_str("compute").unwrap();
+
+    // Deploy the original edition of the program.
+    let program_0 = sample_program("add", "");
+    let deployment_0 = process.deploy::<CurrentAleo, _>(&program_0, rng).unwrap();
+    assert_eq!(deployment_0.edition(), <CurrentNetwork as Network>::EDITION);
+    process.verify_deployment::<CurrentAleo, _>(&deployment_0, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment_0, &fee).unwrap();
+    process.add_stack(stack).unwrap();
+
+    // Add a program that imports the program.
+    let dependent = Program::<CurrentNetwork>::from_str(
+        r"
+import testing.aleo;
+
+program dependent.aleo;
+
+function noop:
+    input r0 as u64.public;
+    output r0 as u64.public;
+",
+    )
+    .unwrap();
+    process.add_program(&dependent).unwrap();
+
+    // Execute the original edition of the program.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+    let inputs = [
+        Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(),
+        Value::from_str("3u64").unwrap(),
+        Value::from_str("5u64").unwrap(),
+    ];
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, inputs.iter(), rng)
+        .unwrap();
+    let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    trace.prepare(Query::from(block_store.clone())).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+
+    // Ensure incompatible upgrades are rejected.
+    let changed_mapping = sample_program("mul", "").to_string().replace("value as u64.public", "value as u128.public");
+    assert!(process.deploy::<CurrentAleo, _>(&Program::from_str(&changed_mapping).unwrap(), rng).is_err());
+    let changed_inputs =
+        sample_program("mul", "").to_string().replace("input r2 as u64.public", "input r2 as u64.private");
+    assert!(process.deploy::<CurrentAleo, _>(&Program::from_str(&changed_inputs).unwrap(), rng).is_err());
+    let changed_finalize_inputs = sample_program("mul", "")
+        .to_string()
+        .replace("async compute r0 r3 into r4;", "cast r3 into r5 as u128;\n    async compute r0 r5 into r4;")
+        .replace(
+            "input r1 as u64.public;\n    get.or_use",
+            "input r1 as u128.public;\n    cast r1 into r4 as u64;\n    get.or_use",
+        )
+        .replace("add r2 r1 into r3;", "add r2 r4 into r3;");
+    let error =
+        process.deploy::<CurrentAleo, _>(&Program::from_str(&changed_finalize_inputs).unwrap(), rng).unwrap_err();
+    assert!(error.to_string().contains("changes the finalize inputs"));
+    assert!(process.deploy::<CurrentAleo, _>(&program_0, rng).is_err());
+
+    // Upgrade the program, by changing the function logic and adding a mapping.
+    let program_1 = sample_program("mul", "\nmapping total:\n    key as boolean.public;\n    value as u64.public;\n");
+    let deployment_1 = process.deploy::<CurrentAleo, _>(&program_1, rng).unwrap();
+    assert_eq!(deployment_1.edition(), <CurrentNetwork as Network>::EDITION + 1);
+    process.verify_deployment::<CurrentAleo, _>(&deployment_1, rng).unwrap();
+    // Ensure the original deployment can no longer be verified.
+    assert!(process.verify_deployment::<CurrentAleo, _>(&deployment_0, rng).is_err());
+
+    // Finalize the upgrade.
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) =
+        process.finalize_deployment(sample_finalize_state(2), &finalize_store, &deployment_1, &fee).unwrap();
+    process.add_stack(stack).unwrap();
+
+    // Ensure the program is upgraded, and the new mapping is initialized.
+    assert_eq!(process.get_program(program_id).unwrap(), &program_1);
+    assert_eq!(process.get_stack(program_id).unwrap().edition(), deployment_1.edition());
+    assert!(finalize_store.contains_mapping_speculative(&program_id, &Identifier::from_str("total").unwrap()).unwrap());
+    assert_eq!(finalize_store.get_edition_history_confirmed(&program_id).unwrap(), vec![
+        (deployment_0.edition(), 1),
+        (deployment_1.edition(), 2)
+    ]);
+
+    // Ensure the importing program uses the upgraded edition.
+    let dependent_stack = process.get_stack(dependent.id()).unwrap();
+    assert_eq!(dependent_stack.get_external_stack(&program_id).unwrap().edition(), deployment_1.edition());
+
+    // Ensure the execution proven against the original edition only verifies against the original edition.
+    assert!(process.verify_execution(&execution).is_err());
+    let editions_0 = HashMap::from([(program_id, deployment_0.edition())]);
+    process.verify_execution_with_editions(&execution, &editions_0).unwrap();
+    let editions_1 = HashMap::from([(program_id, deployment_1.edition())]);
+    assert!(process.verify_execution_with_editions(&execution, &editions_1).is_err());
+}
@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod profile;
+pub use profile::*;
+
 use console::{
     network::Network,
-    program::{Identifier, ProgramID},
+    program::{Identifier, Locator, ProgramID},
 };
+use synthesizer_program::Opcode;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct CallMetrics<N: Network> {
     pub program_id: ProgramID<N>,
     pub function_name: Identifier<N>,
@@ -25,4 +29,51 @@ pub struct CallMetrics<N: Network> {
     pub num_request_constraints: u64,
     pub num_function_constraints: u64,
     pub num_response_constraints: u64,
+    /// The circuit metrics of each instruction in the function, in order.
+    pub instructions: Vec<InstructionMetrics<N>>,
+}
+
+/// The circuit metrics of an instruction in a function.
+/// For a function call, the metrics only cover the instruction in the caller circuit,
+/// as the callee is synthesized in its own circuit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionMetrics<N: Network> {
+    /// The index of the instruction in the function.
+    pub index: usize,
+    /// The opcode of the instruction.
+    pub opcode: Opcode,
+    /// The called function, if the instruction is a function call.
+    pub callee: Option<Locator<N>>,
+    pub num_constants: u64,
+    pub num_public: u64,
+    pub num_private: u64,
+    pub num_constraints: u64,
+    pub num_nonzeros: (u64, u64, u64),
+}
+
+impl<N: Network> InstructionMetrics<N> {
+    /// Initializes the metrics of an instruction, from the circuit counts before and after the instruction.
+    /// The counts are given as `(constants, public, private, constraints, nonzeros)`, as returned by `Environment::count`.
+    pub fn new(
+        index: usize,
+        opcode: Opcode,
+        callee: Option<Locator<N>>,
+        before: (u64, u64, u64, u64, (u64, u64, u64)),
+        after: (u64, u64, u64, u64, (u64, u64, u64)),
+    ) -> Self {
+        Self {
+            index,
+            opcode,
+            callee,
+            num_constants: after.0.saturating_sub(before.0),
+            num_public: after.1.saturating_sub(before.1),
+            num_private: after.2.saturating_sub(before.2),
+            num_constraints: after.3.saturating_sub(before.3),
+            num_nonzeros: (
+                after.4 .0.saturating_sub(before.4 .0),
+                after.4 .1.saturating_sub(before.4 .1),
+                after.4 .2.saturating_sub(before.4 .2),
+            ),
+        }
+    }
 }
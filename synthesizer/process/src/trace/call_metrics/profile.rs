@@ -0,0 +1,249 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::network::prelude::*;
+
+use indexmap::IndexMap;
+use serde_json::json;
+
+/// The label of the request verification in a function frame.
+const REQUEST_FRAME: &str = "[request]";
+/// The label of the response construction in a function frame.
+const RESPONSE_FRAME: &str = "[response]";
+
+/// A per-instruction constraint profile of an execution.
+///
+/// The profile is built from the call metrics of an execution, which are ordered with every callee before its caller,
+/// and it attributes every instruction to its call stack, from the root function down to the function that contains it.
+#[derive(Clone, Debug)]
+pub struct ConstraintProfile<N: Network> {
+    /// The profiled functions, as `(call stack, call metrics)`, with every caller before its callees.
+    frames: Vec<(Vec<Locator<N>>, CallMetrics<N>)>,
+}
+
+impl<N: Network> ConstraintProfile<N> {
+    /// Initializes a new constraint profile from the call metrics of an execution.
+    pub fn new(call_metrics: &[CallMetrics<N>]) -> Result<Self> {
+        let mut frames = Vec::with_capacity(call_metrics.len());
+        // Add the root calls, starting from the last one.
+        let mut end = call_metrics.len();
+        let mut roots = Vec::new();
+        while end > 0 {
+            let mut root_frames = Vec::new();
+            end = Self::add_frames(call_metrics, end, &mut Vec::new(), &mut root_frames)?;
+            roots.push(root_frames);
+        }
+        // Order the root calls as they were called.
+        roots.into_iter().rev().for_each(|root_frames| frames.extend(root_frames));
+        Ok(Self { frames })
+    }
+
+    /// Adds the frame of the call that ends at the given index, and the frames of its callees.
+    /// Returns the index at which the call starts.
+    fn add_frames(
+        call_metrics: &[CallMetrics<N>],
+        end: usize,
+        stack: &mut Vec<Locator<N>>,
+        frames: &mut Vec<(Vec<Locator<N>>, CallMetrics<N>)>,
+    ) -> Result<usize> {
+        // Retrieve the call, which comes after its callees.
+        let metrics = &call_metrics[end - 1];
+        stack.push(Locator::new(metrics.program_id, metrics.function_name));
+        frames.push((stack.clone(), metrics.clone()));
+
+        // Add the callees, starting from the last one.
+        let mut callees = Vec::new();
+        let mut start = end - 1;
+        for callee in metrics.instructions.iter().rev().filter_map(|instruction| instruction.callee) {
+            ensure!(start > 0, "Missing the call metrics of '{callee}', called by '{}'", stack[stack.len() - 1]);
+            let callee_metrics = &call_metrics[start - 1];
+            ensure!(
+                Locator::new(callee_metrics.program_id, callee_metrics.function_name) == callee,
+                "Expected the call metrics of '{callee}', found '{}/{}'",
+                callee_metrics.program_id,
+                callee_metrics.function_name
+            );
+            let mut callee_frames = Vec::new();
+            start = Self::add_frames(call_metrics, start, stack, &mut callee_frames)?;
+            callees.push(callee_frames);
+        }
+        // Order the callees as they were called.
+        callees.into_iter().rev().for_each(|callee_frames| frames.extend(callee_frames));
+
+        stack.pop();
+        Ok(start)
+    }
+
+    /// Returns the profile in the folded stack format, with one `stack count` line per stack,
+    /// weighted by the number of constraints, which can be rendered as a flame graph.
+    pub fn to_folded_stacks(&self) -> String {
+        let mut stacks = IndexMap::<String, u64>::new();
+        for (stack, metrics) in &self.frames {
+            let frame = stack.iter().map(|locator| locator.to_string()).collect::<Vec<_>>().join(";");
+            // Add the request, instructions, and response of the function.
+            *stacks.entry(format!("{frame};{REQUEST_FRAME}")).or_default() += metrics.num_request_constraints;
+            for instruction in &metrics.instructions {
+                *stacks.entry(format!("{frame};{}", instruction.opcode)).or_default() += instruction.num_constraints;
+            }
+            *stacks.entry(format!("{frame};{RESPONSE_FRAME}")).or_default() += metrics.num_response_constraints;
+        }
+        stacks
+            .into_iter()
+            .filter(|(_, num_constraints)| *num_constraints > 0)
+            .map(|(stack, num_constraints)| format!("{stack} {num_constraints}\n"))
+            .collect()
+    }
+
+    /// Returns the profile as a JSON table, with a row for every instruction of every call,
+    /// and a summary for every opcode, sorted by the number of constraints in descending order.
+    pub fn to_json(&self) -> serde_json::Value {
+        // Construct the rows of the instructions.
+        let mut calls = Vec::with_capacity(self.frames.len());
+        for (stack, metrics) in &self.frames {
+            let instructions = metrics
+                .instructions
+                .iter()
+                .map(|instruction| {
+                    json!({
+                        "index": instruction.index,
+                        "opcode": instruction.opcode.to_string(),
+                        "callee": instruction.callee.map(|callee| callee.to_string()),
+                        "num_constants": instruction.num_constants,
+                        "num_public": instruction.num_public,
+                        "num_private": instruction.num_private,
+                        "num_constraints": instruction.num_constraints,
+                        "num_nonzeros": [instruction.num_nonzeros.0, instruction.num_nonzeros.1, instruction.num_nonzeros.2],
+                    })
+                })
+                .collect::<Vec<_>>();
+            calls.push(json!({
+                "stack": stack.iter().map(|locator| locator.to_string()).collect::<Vec<_>>(),
+                "num_request_constraints": metrics.num_request_constraints,
+                "num_function_constraints": metrics.num_function_constraints,
+                "num_response_constraints": metrics.num_response_constraints,
+                "instructions": instructions,
+            }));
+        }
+
+        // Aggregate the metrics of each opcode.
+        let mut opcodes = IndexMap::<Opcode, (u64, u64, u64, u64, u64)>::new();
+        for instruction in self.frames.iter().flat_map(|(_, metrics)| &metrics.instructions) {
+            let entry = opcodes.entry(instruction.opcode).or_default();
+            entry.0 += 1;
+            entry.1 += instruction.num_constants;
+            entry.2 += instruction.num_public;
+            entry.3 += instruction.num_private;
+            entry.4 += instruction.num_constraints;
+        }
+        opcodes.sort_by(|_, a, _, b| b.4.cmp(&a.4));
+        let opcodes = opcodes
+            .into_iter()
+            .map(|(opcode, (count, num_constants, num_public, num_private, num_constraints))| {
+                json!({
+                    "opcode": opcode.to_string(),
+                    "count": count,
+                    "num_constants": num_constants,
+                    "num_public": num_public,
+                    "num_private": num_private,
+                    "num_constraints": num_constraints,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        json!({ "calls": calls, "opcodes": opcodes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    /// Returns the call metrics of a function, with the given `(opcode, callee, constraints)` instructions.
+    fn sample_call_metrics(locator: &str, instructions: &[(Opcode, Option<&str>, u64)]) -> CallMetrics<CurrentNetwork> {
+        let locator = Locator::<CurrentNetwork>::from_str(locator).unwrap();
+        let instructions = instructions
+            .iter()
+            .enumerate()
+            .map(|(index, (opcode, callee, num_constraints))| InstructionMetrics {
+                index,
+                opcode: *opcode,
+                callee: callee.map(|callee| Locator::from_str(callee).unwrap()),
+                num_constants: 0,
+                num_public: 0,
+                num_private: *num_constraints,
+                num_constraints: *num_constraints,
+                num_nonzeros: (0, 0, 0),
+            })
+            .collect::<Vec<_>>();
+        CallMetrics {
+            program_id: *locator.program_id(),
+            function_name: *locator.resource(),
+            num_instructions: instructions.len(),
+            num_request_constraints: 10,
+            num_function_constraints: instructions.iter().map(|instruction| instruction.num_constraints).sum(),
+            num_response_constraints: 5,
+            instructions,
+        }
+    }
+
+    #[test]
+    fn test_constraint_profile() {
+        let add = Opcode::Literal("add");
+        // The root function calls `child.aleo/first`, and then `child.aleo/second`, which calls `child.aleo/first`.
+        let call_metrics = vec![
+            sample_call_metrics("child.aleo/first", &[(add, None, 1)]),
+            sample_call_metrics("child.aleo/first", &[(add, None, 1)]),
+            sample_call_metrics("child.aleo/second", &[(Opcode::Call, Some("child.aleo/first"), 3)]),
+            sample_call_metrics("root.aleo/main", &[
+                (add, None, 2),
+                (Opcode::Call, Some("child.aleo/first"), 4),
+                (Opcode::Call, Some("child.aleo/second"), 4),
+                (add, None, 2),
+            ]),
+        ];
+
+        let profile = ConstraintProfile::new(&call_metrics).unwrap();
+        let expected = "\
+root.aleo/main;[request] 10
+root.aleo/main;add 4
+root.aleo/main;call 8
+root.aleo/main;[response] 5
+root.aleo/main;child.aleo/first;[request] 10
+root.aleo/main;child.aleo/first;add 1
+root.aleo/main;child.aleo/first;[response] 5
+root.aleo/main;child.aleo/second;[request] 10
+root.aleo/main;child.aleo/second;call 3
+root.aleo/main;child.aleo/second;[response] 5
+root.aleo/main;child.aleo/second;child.aleo/first;[request] 10
+root.aleo/main;child.aleo/second;child.aleo/first;add 1
+root.aleo/main;child.aleo/second;child.aleo/first;[response] 5
+";
+        assert_eq!(profile.to_folded_stacks(), expected);
+
+        // Ensure the opcodes are sorted by the number of constraints.
+        let json = profile.to_json();
+        assert_eq!(json["calls"].as_array().unwrap().len(), 4);
+        assert_eq!(json["opcodes"][0]["opcode"], "call");
+        assert_eq!(json["opcodes"][0]["count"], 3);
+        assert_eq!(json["opcodes"][0]["num_constraints"], 11);
+        assert_eq!(json["opcodes"][1]["opcode"], "add");
+        assert_eq!(json["opcodes"][1]["num_constraints"], 6);
+
+        // Ensure a missing callee is rejected.
+        assert!(ConstraintProfile::new(&call_metrics[1..]).is_err());
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Register, Value},
+};
+use synthesizer_program::{Command, FinalizeOperation};
+
+/// A mapping access made by a finalize command.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MappingAccess<N: Network> {
+    /// A read of the `key` in the mapping, as `(program ID, mapping name, key, value)`.
+    /// The value is `None` if the key does not exist in the mapping.
+    Read(ProgramID<N>, Identifier<N>, Plaintext<N>, Option<Value<N>>),
+    /// A write of the `value` to the `key` in the mapping, as `(program ID, mapping name, key, value)`.
+    Write(ProgramID<N>, Identifier<N>, Plaintext<N>, Value<N>),
+    /// A removal of the `key` from the mapping, as `(program ID, mapping name, key)`.
+    Remove(ProgramID<N>, Identifier<N>, Plaintext<N>),
+}
+
+/// A command that was evaluated during finalize.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeStep<N: Network> {
+    /// The transition ID.
+    transition_id: N::TransitionID,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The index of the command in the finalize logic.
+    index: usize,
+    /// The command.
+    command: Command<N>,
+    /// The resolved operand values, or `None` for an operand that failed to resolve.
+    operands: Vec<Option<Value<N>>>,
+    /// For a `branch.eq` or `branch.neq` command, `true` if the branch was taken.
+    branch_taken: Option<bool>,
+    /// The mapping access of the command, if the command accesses a mapping.
+    mapping_access: Option<MappingAccess<N>>,
+    /// The values assigned to the destination registers.
+    outputs: Vec<(Register<N>, Value<N>)>,
+    /// `true` if the command was evaluated successfully.
+    is_complete: bool,
+}
+
+impl<N: Network> FinalizeStep<N> {
+    /// Initializes a new finalize step, before the command is evaluated.
+    pub(crate) fn new(
+        transition_id: N::TransitionID,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        index: usize,
+        command: Command<N>,
+        operands: Vec<Option<Value<N>>>,
+        mapping_access: Option<MappingAccess<N>>,
+    ) -> Self {
+        // Determine if the branch is taken, if the command is a branch command.
+        let branch_taken = match (&command, operands.as_slice()) {
+            (Command::BranchEq(_), [Some(first), Some(second)]) => Some(first == second),
+            (Command::BranchNeq(_), [Some(first), Some(second)]) => Some(first != second),
+            _ => None,
+        };
+        Self {
+            transition_id,
+            program_id,
+            function_name,
+            index,
+            command,
+            operands,
+            branch_taken,
+            mapping_access,
+            outputs: Vec::new(),
+            is_complete: false,
+        }
+    }
+
+    /// Returns the transition ID.
+    pub const fn transition_id(&self) -> &N::TransitionID {
+        &self.transition_id
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the index of the command in the finalize logic.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the command.
+    pub const fn command(&self) -> &Command<N> {
+        &self.command
+    }
+
+    /// Returns the resolved operand values, or `None` for an operand that failed to resolve.
+    pub fn operands(&self) -> &[Option<Value<N>>] {
+        &self.operands
+    }
+
+    /// For a `branch.eq` or `branch.neq` command, returns `true` if the branch was taken.
+    pub const fn branch_taken(&self) -> Option<bool> {
+        self.branch_taken
+    }
+
+    /// Returns the mapping access of the command, if the command accesses a mapping.
+    pub const fn mapping_access(&self) -> Option<&MappingAccess<N>> {
+        self.mapping_access.as_ref()
+    }
+
+    /// Returns the values assigned to the destination registers.
+    pub fn outputs(&self) -> &[(Register<N>, Value<N>)] {
+        &self.outputs
+    }
+
+    /// Returns `true` if the command was evaluated successfully.
+    pub const fn is_complete(&self) -> bool {
+        self.is_complete
+    }
+}
+
+/// A trace of the commands that were evaluated during finalize, in the order they were evaluated.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FinalizeTrace<N: Network> {
+    /// The evaluated commands.
+    steps: Vec<FinalizeStep<N>>,
+    /// The finalize operations, if finalize succeeded.
+    operations: Vec<FinalizeOperation<N>>,
+    /// The reason finalize aborted, if it aborted.
+    abort_reason: Option<String>,
+}
+
+impl<N: Network> FinalizeTrace<N> {
+    /// Initializes a new finalize trace.
+    pub fn new() -> Self {
+        Self { steps: Vec::new(), operations: Vec::new(), abort_reason: None }
+    }
+
+    /// Returns the evaluated commands, in the order they were evaluated.
+    pub fn steps(&self) -> &[FinalizeStep<N>] {
+        &self.steps
+    }
+
+    /// Returns the finalize operations, if finalize succeeded.
+    pub fn operations(&self) -> &[FinalizeOperation<N>] {
+        &self.operations
+    }
+
+    /// Returns the reason finalize aborted, if it aborted.
+    pub fn abort_reason(&self) -> Option<&str> {
+        self.abort_reason.as_deref()
+    }
+
+    /// Returns the command that failed, if finalize aborted while evaluating a command.
+    pub fn failed_step(&self) -> Option<&FinalizeStep<N>> {
+        match self.abort_reason {
+            Some(_) => self.steps.last().filter(|step| !step.is_complete),
+            None => None,
+        }
+    }
+
+    /// Adds a command that is about to be evaluated.
+    pub(crate) fn begin_step(&mut self, step: FinalizeStep<N>) {
+        self.steps.push(step);
+    }
+
+    /// Marks the last command as evaluated, with the given values of its destination registers.
+    pub(crate) fn complete_step(&mut self, outputs: Vec<(Register<N>, Value<N>)>) {
+        if let Some(step) = self.steps.last_mut() {
+            step.outputs = outputs;
+            step.is_complete = true;
+        }
+    }
+
+    /// Sets the outcome of finalize.
+    pub(crate) fn set_outcome(&mut self, outcome: Result<Vec<FinalizeOperation<N>>>) {
+        match outcome {
+            Ok(operations) => self.operations = operations,
+            Err(error) => self.abort_reason = Some(error.to_string()),
+        }
+    }
+}
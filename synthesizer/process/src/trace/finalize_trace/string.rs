@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde_json::json;
+
+impl<N: Network> MappingAccess<N> {
+    /// Returns the mapping access as a JSON value.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Read(program_id, mapping_name, key, value) => json!({
+                "type": "read",
+                "program_id": program_id.to_string(),
+                "mapping_name": mapping_name.to_string(),
+                "key": key.to_string(),
+                "value": value.as_ref().map(|value| value.to_string()),
+            }),
+            Self::Write(program_id, mapping_name, key, value) => json!({
+                "type": "write",
+                "program_id": program_id.to_string(),
+                "mapping_name": mapping_name.to_string(),
+                "key": key.to_string(),
+                "value": value.to_string(),
+            }),
+            Self::Remove(program_id, mapping_name, key) => json!({
+                "type": "remove",
+                "program_id": program_id.to_string(),
+                "mapping_name": mapping_name.to_string(),
+                "key": key.to_string(),
+            }),
+        }
+    }
+}
+
+impl<N: Network> Display for MappingAccess<N> {
+    /// Prints the mapping access as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Read(program_id, mapping_name, key, Some(value)) => {
+                write!(f, "read {program_id}/{mapping_name}[{key}] = {value}")
+            }
+            Self::Read(program_id, mapping_name, key, None) => {
+                write!(f, "read {program_id}/{mapping_name}[{key}] = (none)")
+            }
+            Self::Write(program_id, mapping_name, key, value) => {
+                write!(f, "write {program_id}/{mapping_name}[{key}] = {value}")
+            }
+            Self::Remove(program_id, mapping_name, key) => write!(f, "remove {program_id}/{mapping_name}[{key}]"),
+        }
+    }
+}
+
+impl<N: Network> FinalizeStep<N> {
+    /// Returns the finalize step as a JSON value.
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "transition_id": self.transition_id.to_string(),
+            "program_id": self.program_id.to_string(),
+            "function_name": self.function_name.to_string(),
+            "index": self.index,
+            "command": self.command.to_string(),
+            "operands": self.operands.iter().map(|operand| operand.as_ref().map(|value| value.to_string())).collect::<Vec<_>>(),
+            "branch_taken": self.branch_taken,
+            "mapping_access": self.mapping_access.as_ref().map(|access| access.to_json()),
+            "outputs": self.outputs.iter().map(|(register, value)| json!({
+                "register": register.to_string(),
+                "value": value.to_string(),
+            })).collect::<Vec<_>>(),
+            "is_complete": self.is_complete,
+        })
+    }
+}
+
+impl<N: Network> Display for FinalizeStep<N> {
+    /// Prints the finalize step as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "#{} {}", self.index, self.command)?;
+        // Print the branch outcome.
+        match self.branch_taken {
+            Some(true) => write!(f, " (taken)")?,
+            Some(false) => write!(f, " (not taken)")?,
+            None => (),
+        }
+        // Print the operands.
+        if !self.operands.is_empty() {
+            let operands = self
+                .operands
+                .iter()
+                .map(|operand| match operand {
+                    Some(value) => value.to_string(),
+                    None => "(unresolved)".to_string(),
+                })
+                .collect::<Vec<_>>();
+            write!(f, "\n    operands: {}", operands.join(", "))?;
+        }
+        // Print the mapping access.
+        if let Some(access) = &self.mapping_access {
+            write!(f, "\n    {access}")?;
+        }
+        // Print the outputs.
+        for (register, value) in &self.outputs {
+            write!(f, "\n    {register} = {value}")?;
+        }
+        // Print the failure.
+        if !self.is_complete {
+            write!(f, "\n    (failed)")?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FinalizeTrace<N> {
+    /// Returns the finalize trace as a JSON value.
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "steps": self.steps.iter().map(|step| step.to_json()).collect::<Vec<_>>(),
+            "operations": self.operations.iter().map(|operation| operation.to_string()).collect::<Vec<_>>(),
+            "abort_reason": self.abort_reason,
+        })
+    }
+}
+
+impl<N: Network> Display for FinalizeTrace<N> {
+    /// Prints the finalize trace as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut current_transition = None;
+        for step in &self.steps {
+            // Print the function, whenever the evaluated transition changes.
+            if current_transition != Some(step.transition_id) {
+                writeln!(f, "{}/{} ({})", step.program_id, step.function_name, step.transition_id)?;
+                current_transition = Some(step.transition_id);
+            }
+            writeln!(f, "  {}", step.to_string().replace('\n', "\n  "))?;
+        }
+        match &self.abort_reason {
+            Some(reason) => write!(f, "Aborted: {reason}"),
+            None => write!(f, "Finished with {} finalize operation(s)", self.operations.len()),
+        }
+    }
+}
@@ -15,6 +15,9 @@
 mod call_metrics;
 pub use call_metrics::*;
 
+mod finalize_trace;
+pub use finalize_trace::*;
+
 mod inclusion;
 pub use inclusion::*;
 
@@ -25,11 +28,14 @@ use console::{
 };
 use ledger_block::{Execution, Fee, Transition};
 use ledger_query::QueryTrait;
-use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
+use synthesizer_snark::{PreparedCheck, Proof, ProvingKey, VerifyingKey};
 
 use once_cell::sync::OnceCell;
 use std::collections::HashMap;
 
+/// The locator of the fee functions, used to identify the fee proof.
+const FEE_LOCATOR: &str = "credits.aleo/fee (private or public)";
+
 #[derive(Clone, Debug, Default)]
 pub struct Trace<N: Network> {
     /// The list of transitions.
@@ -214,44 +220,51 @@ impl<N: Network> Trace<N> {
         verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         execution: &Execution<N>,
     ) -> Result<()> {
-        // Retrieve the global state root.
-        let global_state_root = execution.global_state_root();
-        // Ensure the global state root is not zero.
-        if global_state_root == N::StateRoot::default() {
-            bail!("Inclusion expected the global state root in the execution to *not* be zero")
-        }
-        // Retrieve the proof.
-        let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
+        // Construct the verifier inputs, including the inclusion verifier inputs.
+        let (verifier_inputs, proof) = Self::to_execution_verifier_inputs(verifier_inputs, execution)?;
         // Verify the execution proof.
-        match Self::verify_batch(locator, verifier_inputs, global_state_root, execution.transitions(), proof) {
-            Ok(()) => Ok(()),
-            Err(e) => bail!("Execution is invalid - {e}"),
+        match VerifyingKey::verify_batch(locator, verifier_inputs, proof) {
+            true => Ok(()),
+            false => bail!("Execution is invalid - Failed to verify proof"),
         }
     }
 
+    /// Checks the proof for the execution, up to the final pairing check, and returns the pairing check.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn prepare_execution_proof(
+        locator: &str,
+        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        execution: &Execution<N>,
+    ) -> Result<PreparedCheck<N>> {
+        // Construct the verifier inputs, including the inclusion verifier inputs.
+        let (verifier_inputs, proof) = Self::to_execution_verifier_inputs(verifier_inputs, execution)?;
+        // Prepare the pairing check of the execution proof.
+        VerifyingKey::prepare_batch(locator, verifier_inputs, proof).map_err(|e| anyhow!("Execution is invalid - {e}"))
+    }
+
     /// Checks the proof for the fee.
     /// Note: This does *not* check that the global state root exists in the ledger.
     pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
-        // Retrieve the global state root.
-        let global_state_root = fee.global_state_root();
-        // Ensure the global state root is not zero.
-        if global_state_root == N::StateRoot::default() {
-            bail!("Inclusion expected the global state root in the fee to *not* be zero")
-        }
-        // Retrieve the proof.
-        let Some(proof) = fee.proof() else { bail!("Expected the fee to contain a proof") };
+        // Construct the verifier inputs, including the inclusion verifier inputs.
+        let (verifier_inputs, proof) = Self::to_fee_verifier_inputs(verifier_inputs, fee)?;
         // Verify the fee proof.
-        match Self::verify_batch(
-            "credits.aleo/fee (private or public)",
-            vec![verifier_inputs],
-            global_state_root,
-            [fee.transition()].into_iter(),
-            proof,
-        ) {
-            Ok(()) => Ok(()),
-            Err(e) => bail!("Fee is invalid - {e}"),
+        match VerifyingKey::verify_batch(FEE_LOCATOR, verifier_inputs, proof) {
+            true => Ok(()),
+            false => bail!("Fee is invalid - Failed to verify proof"),
         }
     }
+
+    /// Checks the proof for the fee, up to the final pairing check, and returns the pairing check.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn prepare_fee_proof(
+        verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>),
+        fee: &Fee<N>,
+    ) -> Result<PreparedCheck<N>> {
+        // Construct the verifier inputs, including the inclusion verifier inputs.
+        let (verifier_inputs, proof) = Self::to_fee_verifier_inputs(verifier_inputs, fee)?;
+        // Prepare the pairing check of the fee proof.
+        VerifyingKey::prepare_batch(FEE_LOCATOR, verifier_inputs, proof).map_err(|e| anyhow!("Fee is invalid - {e}"))
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -295,15 +308,58 @@ impl<N: Network> Trace<N> {
         Ok((global_state_root, proof))
     }
 
-    /// Checks the proof for the given inputs.
-    /// Note: This does *not* check that the global state root exists in the ledger.
-    fn verify_batch<'a>(
-        locator: &str,
+    /// Returns the verifier inputs and proof of the given execution, including the inclusion verifier inputs.
+    #[allow(clippy::type_complexity)]
+    fn to_execution_verifier_inputs(
+        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        execution: &Execution<N>,
+    ) -> Result<(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Proof<N>)> {
+        // Retrieve the global state root.
+        let global_state_root = execution.global_state_root();
+        // Ensure the global state root is not zero.
+        if global_state_root == N::StateRoot::default() {
+            bail!("Inclusion expected the global state root in the execution to *not* be zero")
+        }
+        // Retrieve the proof.
+        let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
+        // Add the inclusion verifier inputs.
+        match Self::add_inclusion_verifier_inputs(verifier_inputs, global_state_root, execution.transitions()) {
+            Ok(verifier_inputs) => Ok((verifier_inputs, proof)),
+            Err(e) => bail!("Execution is invalid - {e}"),
+        }
+    }
+
+    /// Returns the verifier inputs and proof of the given fee, including the inclusion verifier inputs.
+    #[allow(clippy::type_complexity)]
+    fn to_fee_verifier_inputs(
+        verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>),
+        fee: &Fee<N>,
+    ) -> Result<(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Proof<N>)> {
+        // Retrieve the global state root.
+        let global_state_root = fee.global_state_root();
+        // Ensure the global state root is not zero.
+        if global_state_root == N::StateRoot::default() {
+            bail!("Inclusion expected the global state root in the fee to *not* be zero")
+        }
+        // Retrieve the proof.
+        let Some(proof) = fee.proof() else { bail!("Expected the fee to contain a proof") };
+        // Add the inclusion verifier inputs.
+        match Self::add_inclusion_verifier_inputs(
+            vec![verifier_inputs],
+            global_state_root,
+            [fee.transition()].into_iter(),
+        ) {
+            Ok(verifier_inputs) => Ok((verifier_inputs, proof)),
+            Err(e) => bail!("Fee is invalid - {e}"),
+        }
+    }
+
+    /// Returns the given verifier inputs, with the inclusion verifier inputs of the given transitions.
+    fn add_inclusion_verifier_inputs<'a>(
         mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
-        proof: &Proof<N>,
-    ) -> Result<()> {
+    ) -> Result<Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>> {
         // Construct the batch of inclusion verifier inputs.
         let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
         // Insert the batch of inclusion verifier inputs to the verifier inputs.
@@ -313,10 +369,6 @@ impl<N: Network> Trace<N> {
             // Insert the inclusion verifier inputs.
             verifier_inputs.push((verifying_key, batch_inclusion_inputs));
         }
-        // Verify the proof.
-        match VerifyingKey::verify_batch(locator, verifier_inputs, proof) {
-            true => Ok(()),
-            false => bail!("Failed to verify proof"),
-        }
+        Ok(verifier_inputs)
     }
 }
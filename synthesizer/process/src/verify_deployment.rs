@@ -16,6 +16,7 @@ use super::*;
 
 impl<N: Network> Process<N> {
     /// Verifies the given deployment is ordered.
+    /// If the program already exists, the deployment must be an upgrade to the next edition of the program.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -24,13 +25,9 @@ impl<N: Network> Process<N> {
     ) -> Result<()> {
         let timer = timer!("Process::verify_deployment");
 
-        // Retrieve the program ID.
-        let program_id = deployment.program().id();
-        // Ensure the program does not already exist in the process.
-        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
-
         // Ensure the program is well-formed, by computing the stack.
-        let stack = Stack::new(self, deployment.program())?;
+        // Note: If the program already exists, the deployment must be a compatible upgrade of the program.
+        let stack = self.initialize_stack(deployment.program())?;
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
@@ -40,6 +37,29 @@ impl<N: Network> Process<N> {
         finish!(timer);
         verification
     }
+
+    /// Verifies the given deployment is ordered, up to the final pairing check of each certificate,
+    /// and returns the pairing checks, to be checked with `PreparedCheck::verify_all`.
+    #[inline]
+    pub fn prepare_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<Vec<PreparedCheck<N>>> {
+        let timer = timer!("Process::prepare_deployment");
+
+        // Ensure the program is well-formed, by computing the stack.
+        // Note: If the program already exists, the deployment must be a compatible upgrade of the program.
+        let stack = self.initialize_stack(deployment.program())?;
+        lap!(timer, "Compute the stack");
+
+        // Ensure the verifying keys are well-formed, and prepare the pairing checks of the certificates.
+        let prepared = stack.prepare_deployment::<A, R>(deployment, rng);
+        lap!(timer, "Prepare the deployment");
+
+        finish!(timer);
+        prepared
+    }
 }
 
 #[cfg(test)]
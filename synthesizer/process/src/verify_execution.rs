@@ -15,12 +15,82 @@
 use super::*;
 
 impl<N: Network> Process<N> {
-    /// Verifies the given execution is valid.
+    /// Verifies the given execution is valid, against the latest editions of its programs.
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
+        self.verify_execution_with_editions(execution, &HashMap::new())
+    }
+
+    /// Verifies the given execution is valid, against the given editions of its programs.
+    /// The programs that are not in `editions` are verified against their latest edition.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_execution_with_editions(
+        &self,
+        execution: &Execution<N>,
+        editions: &HashMap<ProgramID<N>, u16>,
+    ) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
+        // Construct the verifier inputs of the execution.
+        let (locator, verifier_inputs) = self.construct_execution_verifier_inputs(execution, editions)?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Verify the execution proof.
+        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
+        lap!(timer, "Verify the proof");
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Verifies the given execution is valid, up to the final pairing check of the proof,
+    /// and returns the pairing check, to be checked with `PreparedCheck::verify_all`.
+    /// Note: The pairing check is against the latest editions of the programs.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn prepare_execution(&self, execution: &Execution<N>) -> Result<PreparedCheck<N>> {
+        self.prepare_execution_with_editions(execution, &HashMap::new())
+    }
+
+    /// Verifies the given execution is valid, up to the final pairing check of the proof,
+    /// and returns the pairing check, to be checked with `PreparedCheck::verify_all`.
+    /// The programs that are not in `editions` are checked against their latest edition.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn prepare_execution_with_editions(
+        &self,
+        execution: &Execution<N>,
+        editions: &HashMap<ProgramID<N>, u16>,
+    ) -> Result<PreparedCheck<N>> {
+        let timer = timer!("Process::prepare_execution");
+
+        // Construct the verifier inputs of the execution.
+        let (locator, verifier_inputs) = self.construct_execution_verifier_inputs(execution, editions)?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Prepare the pairing check of the execution proof.
+        let prepared = Trace::prepare_execution_proof(&locator, verifier_inputs, execution)?;
+        lap!(timer, "Prepare the proof");
+
+        finish!(timer);
+        Ok(prepared)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Verifies the transitions of the given execution, and returns the locator of the main function,
+    /// along with the verifier inputs of the execution proof.
+    /// The verifying keys of each program are as of its edition in `editions`, or its latest edition otherwise.
+    #[allow(clippy::type_complexity)]
+    fn construct_execution_verifier_inputs(
+        &self,
+        execution: &Execution<N>,
+        editions: &HashMap<ProgramID<N>, u16>,
+    ) -> Result<(String, Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>)> {
+        let timer = timer!("Process::construct_execution_verifier_inputs");
+
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
@@ -120,11 +190,17 @@ impl<N: Network> Process<N> {
             let inputs = self.to_transition_verifier_inputs(transition, parent, &call_graph, &mut transition_map)?;
             lap!(timer, "Constructed the verifier inputs for a transition of {}", function.name());
 
+            // Retrieve the verifying key, as of the edition of the program.
+            let verifying_key = match editions.get(stack.program_id()) {
+                Some(edition) => stack.get_verifying_key_for_edition(function.name(), *edition)?,
+                None => stack.get_verifying_key(function.name())?,
+            };
+
             // Save the verifying key and its inputs.
             verifier_inputs
                 .entry(Locator::new(*stack.program_id(), *function.name()))
                 // Retrieve the verifying key, if it does not already exist.
-                .or_insert((stack.get_verifying_key(function.name())?, vec![]))
+                .or_insert((verifying_key, vec![]))
                 .1
                 .push(inputs);
             lap!(timer, "Stored the verifier inputs for a transition of {}", function.name());
@@ -140,13 +216,9 @@ impl<N: Network> Process<N> {
 
         // Construct the list of verifier inputs.
         let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
-        // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
-
-        lap!(timer, "Verify the proof");
 
         finish!(timer);
-        Ok(())
+        Ok((locator, verifier_inputs))
     }
 }
 
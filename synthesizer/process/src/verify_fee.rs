@@ -21,6 +21,43 @@ impl<N: Network> Process<N> {
     pub fn verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
         let timer = timer!("Process::verify_fee");
 
+        // Construct the verifier inputs of the fee.
+        let verifier_inputs = self.construct_fee_verifier_inputs(fee, deployment_or_execution_id)?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Ensure the fee proof is valid.
+        Trace::verify_fee_proof(verifier_inputs, fee)?;
+        finish!(timer, "Verify the fee proof");
+        Ok(())
+    }
+
+    /// Verifies the given fee is valid, up to the final pairing check of the proof,
+    /// and returns the pairing check, to be checked with `PreparedCheck::verify_all`.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn prepare_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<PreparedCheck<N>> {
+        let timer = timer!("Process::prepare_fee");
+
+        // Construct the verifier inputs of the fee.
+        let verifier_inputs = self.construct_fee_verifier_inputs(fee, deployment_or_execution_id)?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Prepare the pairing check of the fee proof.
+        let prepared = Trace::prepare_fee_proof(verifier_inputs, fee)?;
+        finish!(timer, "Prepare the fee proof");
+        Ok(prepared)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Verifies the given fee transition is well-formed, and returns the verifier inputs of the fee proof.
+    fn construct_fee_verifier_inputs(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+    ) -> Result<(VerifyingKey<N>, Vec<Vec<N::Field>>)> {
+        let timer = timer!("Process::construct_fee_verifier_inputs");
+
         #[cfg(debug_assertions)]
         {
             println!("Verifying fee from {}/{}...", fee.program_id(), fee.function_name());
@@ -62,18 +99,17 @@ impl<N: Network> Process<N> {
         lap!(timer, "Verify the deployment or execution ID");
 
         // Verify the fee transition is well-formed.
-        match is_fee_private {
+        let verifier_inputs = match is_fee_private {
             true => self.verify_fee_private(&fee)?,
             false => self.verify_fee_public(&fee)?,
-        }
+        };
         finish!(timer, "Verify the fee transition");
-        Ok(())
+        Ok(verifier_inputs)
     }
-}
 
-impl<N: Network> Process<N> {
-    /// Verifies the transition for `credits.aleo/fee_private` is well-formed.
-    fn verify_fee_private(&self, fee: &&Fee<N>) -> Result<()> {
+    /// Verifies the transition for `credits.aleo/fee_private` is well-formed,
+    /// and returns the verifier inputs of the fee proof.
+    fn verify_fee_private(&self, fee: &&Fee<N>) -> Result<(VerifyingKey<N>, Vec<Vec<N::Field>>)> {
         let timer = timer!("Process::verify_fee_private");
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
@@ -135,15 +171,14 @@ impl<N: Network> Process<N> {
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        finish!(timer, "Retrieve the verifying key");
+        Ok((verifying_key, vec![inputs]))
     }
 
-    /// Verifies the transition for `credits.aleo/fee_public` is well-formed.
+    /// Verifies the transition for `credits.aleo/fee_public` is well-formed,
+    /// and returns the verifier inputs of the fee proof.
     /// Attention: This method does *not* verify the account balance is sufficient.
-    fn verify_fee_public(&self, fee: &&Fee<N>) -> Result<()> {
+    fn verify_fee_public(&self, fee: &&Fee<N>) -> Result<(VerifyingKey<N>, Vec<Vec<N::Field>>)> {
         let timer = timer!("Process::verify_fee_public");
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
@@ -205,10 +240,8 @@ impl<N: Network> Process<N> {
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        finish!(timer, "Retrieve the verifying key");
+        Ok((verifying_key, vec![inputs]))
     }
 }
 
@@ -55,6 +55,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
                 3 => program.add_closure(ClosureCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Read the function.
                 4 => program.add_function(FunctionCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                // Read the callback.
+                5 => program.add_callback(CallbackCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Invalid variant.
                 _ => return Err(error(format!("Failed to parse program. Invalid component variant '{variant}'"))),
             }
@@ -131,6 +133,15 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
                     }
                     None => return Err(error(format!("Function '{identifier}' is not defined."))),
                 },
+                ProgramDefinition::Callback => match self.callbacks.get(identifier) {
+                    Some(callback) => {
+                        // Write the variant.
+                        5u8.write_le(&mut writer)?;
+                        // Write the callback.
+                        callback.write_le(&mut writer)?;
+                    }
+                    None => return Err(error(format!("Callback '{identifier}' is not defined."))),
+                },
             }
         }
 
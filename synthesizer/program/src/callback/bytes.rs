@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Command: CommandTrait<N>> FromBytes for CallbackCore<N, Command> {
+    /// Reads the callback from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the logic of the callback.
+        let logic = FinalizeCore::read_le(&mut reader)?;
+        // Initialize the callback.
+        Self::new(logic).map_err(error)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> ToBytes for CallbackCore<N, Command> {
+    /// Writes the callback to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the logic of the callback.
+        self.logic.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Callback;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_callback_bytes() -> Result<()> {
+        let callback_string = r"
+callback deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    set r1 into balances[r0];";
+
+        let expected = Callback::<CurrentNetwork>::from_str(callback_string)?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Callback::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected.to_string(), candidate.to_string());
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use crate::{finalize::FinalizeCore, traits::CommandTrait};
+use console::{
+    network::prelude::*,
+    program::{FinalizeType, Identifier, LiteralType, PlaintextType},
+};
+
+/// A callback is finalize logic that other programs can invoke from their own finalize logic,
+/// to update the mappings of the program that declares the callback.
+/// The first input of a callback is the address of the invoking program, e.g.
+/// ```text
+/// callback deposit:
+///     input r0 as address.public;
+///     input r1 as u64.public;
+///     ...
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct CallbackCore<N: Network, Command: CommandTrait<N>> {
+    /// The logic of the callback.
+    logic: FinalizeCore<N, Command>,
+}
+
+impl<N: Network, Command: CommandTrait<N>> CallbackCore<N, Command> {
+    /// Initializes a new callback from the given logic.
+    ///
+    /// # Errors
+    /// This method will halt if the first input is not an `address`, for the invoking program.
+    /// This method will halt if an input is not a plaintext type.
+    /// This method will halt if a command invokes another callback.
+    pub fn new(logic: FinalizeCore<N, Command>) -> Result<Self> {
+        // Retrieve the callback name.
+        let name = logic.name();
+        // Ensure the first input is the address of the invoking program.
+        match logic.inputs().first().map(|input| input.finalize_type()) {
+            Some(FinalizeType::Plaintext(PlaintextType::Literal(LiteralType::Address))) => (),
+            _ => bail!("The first input of callback '{name}' must be an address, for the invoking program"),
+        }
+        // Ensure the inputs are plaintext types.
+        for input in logic.inputs() {
+            if let FinalizeType::Future(..) = input.finalize_type() {
+                bail!("Callback '{name}' cannot take a future as input")
+            }
+        }
+        // Ensure the callback does not invoke another callback.
+        ensure!(!logic.commands().iter().any(|command| command.is_invoke()), "Callback '{name}' cannot invoke a callback");
+        Ok(Self { logic })
+    }
+
+    /// Returns the name of the callback.
+    pub const fn name(&self) -> &Identifier<N> {
+        self.logic.name()
+    }
+
+    /// Returns the logic of the callback, which is evaluated as finalize logic.
+    pub const fn logic(&self) -> &FinalizeCore<N, Command> {
+        &self.logic
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> TypeName for CallbackCore<N, Command> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "callback"
+    }
+}
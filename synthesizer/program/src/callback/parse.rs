@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Command: CommandTrait<N>> Parser for CallbackCore<N, Command> {
+    /// Parses a string into a callback.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the logic of the callback from the string.
+        let (string, logic) = FinalizeCore::parse_with_keyword(Self::type_name(), string)?;
+
+        map_res(take(0usize), move |_| match Self::new(logic.clone()) {
+            Ok(callback) => Ok(callback),
+            Err(error) => {
+                eprintln!("{error}");
+                Err(error)
+            }
+        })(string)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> FromStr for CallbackCore<N, Command> {
+    type Err = Error;
+
+    /// Returns a callback from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> Debug for CallbackCore<N, Command> {
+    /// Prints the callback as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> Display for CallbackCore<N, Command> {
+    /// Prints the callback as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.logic.fmt_with_keyword(Self::type_name(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Callback;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_callback_parse() {
+        let callback = Callback::<CurrentNetwork>::parse(
+            r"
+callback deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into balances[r0];",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("deposit", callback.name().to_string());
+        assert_eq!(2, callback.logic().inputs().len());
+        assert_eq!(3, callback.logic().commands().len());
+
+        // Ensure the first input must be an address.
+        assert!(
+            Callback::<CurrentNetwork>::parse("callback deposit: input r0 as u64.public; add r0 r0 into r1;").is_err()
+        );
+        // Ensure a callback without inputs is rejected.
+        assert!(Callback::<CurrentNetwork>::parse("callback deposit: add 1u8 1u8 into r0;").is_err());
+        // Ensure a callback cannot invoke another callback.
+        let string = "callback deposit: input r0 as address.public; input r1 as address.public; invoke r1/deposit;";
+        assert!(Callback::<CurrentNetwork>::parse(string).is_err());
+    }
+
+    #[test]
+    fn test_callback_display() {
+        let expected = r"callback deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    set r1 into balances[r0];";
+        let callback = Callback::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{callback}"));
+    }
+}
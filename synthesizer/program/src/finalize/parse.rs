@@ -18,10 +18,18 @@ impl<N: Network, Command: CommandTrait<N>> Parser for FinalizeCore<N, Command> {
     /// Parses a string into finalize.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        Self::parse_with_keyword(Self::type_name(), string)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
+    /// Parses a string into finalize logic, which is declared with the given keyword.
+    #[inline]
+    pub(crate) fn parse_with_keyword<'a>(keyword: &'static str, string: &'a str) -> ParserResult<'a, Self> {
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
-        // Parse the 'finalize' keyword from the string.
-        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the keyword from the string.
+        let (string, _) = tag(keyword)(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the associated function name from the string.
@@ -79,8 +87,15 @@ impl<N: Network, Command: CommandTrait<N>> Debug for FinalizeCore<N, Command> {
 impl<N: Network, Command: CommandTrait<N>> Display for FinalizeCore<N, Command> {
     /// Prints the finalize as a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_with_keyword(Self::type_name(), f)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
+    /// Prints the finalize logic as a string, declared with the given keyword.
+    pub(crate) fn fmt_with_keyword(&self, keyword: &str, f: &mut Formatter) -> fmt::Result {
         // Write the finalize to a string.
-        write!(f, "{} {}:", Self::type_name(), self.name)?;
+        write!(f, "{keyword} {}:", self.name)?;
         self.inputs.iter().try_for_each(|input| write!(f, "\n    {input}"))?;
         self.commands.iter().try_for_each(|command| write!(f, "\n    {command}"))
     }
@@ -20,6 +20,10 @@ pub type Program<N> = crate::ProgramCore<N, Instruction<N>, Command<N>>;
 pub type Function<N> = crate::FunctionCore<N, Instruction<N>, Command<N>>;
 pub type Finalize<N> = crate::FinalizeCore<N, Command<N>>;
 pub type Closure<N> = crate::ClosureCore<N, Instruction<N>>;
+pub type Callback<N> = crate::CallbackCore<N, Command<N>>;
+
+mod callback;
+pub use callback::*;
 
 mod closure;
 pub use closure::*;
@@ -101,6 +105,8 @@ enum ProgramDefinition {
     Closure,
     /// A program function.
     Function,
+    /// A program callback.
+    Callback,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -121,6 +127,8 @@ pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: Co
     closures: IndexMap<Identifier<N>, ClosureCore<N, Instruction>>,
     /// A map of the declared functions for the program.
     functions: IndexMap<Identifier<N>, FunctionCore<N, Instruction, Command>>,
+    /// A map of the declared callbacks for the program.
+    callbacks: IndexMap<Identifier<N>, CallbackCore<N, Command>>,
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -139,6 +147,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             records: IndexMap::new(),
             closures: IndexMap::new(),
             functions: IndexMap::new(),
+            callbacks: IndexMap::new(),
         })
     }
 
@@ -183,6 +192,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.functions
     }
 
+    /// Returns the callbacks in the program.
+    pub const fn callbacks(&self) -> &IndexMap<Identifier<N>, CallbackCore<N, Command>> {
+        &self.callbacks
+    }
+
     /// Returns `true` if the program contains an import with the given program ID.
     pub fn contains_import(&self, id: &ProgramID<N>) -> bool {
         self.imports.contains_key(id)
@@ -213,6 +227,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         self.functions.contains_key(name)
     }
 
+    /// Returns `true` if the program contains a callback with the given name.
+    pub fn contains_callback(&self, name: &Identifier<N>) -> bool {
+        self.callbacks.contains_key(name)
+    }
+
     /// Returns the mapping with the given name.
     pub fn get_mapping(&self, name: &Identifier<N>) -> Result<Mapping<N>> {
         // Attempt to retrieve the mapping.
@@ -283,6 +302,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Return the function.
         Ok(function)
     }
+
+    /// Returns a reference to the callback with the given name.
+    pub fn get_callback_ref(&self, name: &Identifier<N>) -> Result<&CallbackCore<N, Command>> {
+        // Attempt to retrieve the callback.
+        let callback = self.callbacks.get(name).ok_or_else(|| anyhow!("Callback '{name}' is not defined."))?;
+        // Ensure the callback name matches.
+        ensure!(callback.name() == name, "Expected callback '{name}', but found callback '{}'", callback.name());
+        // Return the callback.
+        Ok(callback)
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -472,6 +501,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
     /// This method will halt if a destination register already exists in memory.
     /// This method will halt if an output register does not already exist.
     /// This method will halt if an output type references a non-existent definition.
+    /// This method will halt if a `repeat` instruction repeats a closure that is not declared before it.
     #[inline]
     fn add_closure(&mut self, closure: ClosureCore<N, Instruction>) -> Result<()> {
         // Retrieve the closure name.
@@ -492,6 +522,14 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         ensure!(!closure.instructions().is_empty(), "Cannot evaluate a closure without instructions");
         // Ensure the number of outputs is within the allowed range.
         ensure!(closure.outputs().len() <= N::MAX_OUTPUTS, "Closure exceeds maximum number of outputs");
+        // Ensure the closure only repeats the closures declared before it.
+        // Note: This ensures a closure cannot repeat itself, directly or indirectly.
+        for repeated_closure in closure.instructions().iter().filter_map(|instruction| instruction.repeated_closure()) {
+            ensure!(
+                self.closures.contains_key(repeated_closure),
+                "Closure '{closure_name}' can only repeat a closure declared before it."
+            );
+        }
 
         // Add the function name to the identifiers.
         if self.identifiers.insert(closure_name, ProgramDefinition::Closure).is_some() {
@@ -549,6 +587,38 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         }
         Ok(())
     }
+
+    /// Adds a new callback to the program.
+    ///
+    /// # Errors
+    /// This method will halt if the callback was previously added.
+    /// This method will halt if the callback name is already in use in the program.
+    /// This method will halt if the callback name is a reserved opcode or keyword.
+    #[inline]
+    fn add_callback(&mut self, callback: CallbackCore<N, Command>) -> Result<()> {
+        // Retrieve the callback name.
+        let callback_name = *callback.name();
+
+        // Ensure the program has not exceeded the maximum number of callbacks.
+        ensure!(self.callbacks.len() < N::MAX_FUNCTIONS, "Program exceeds the maximum number of callbacks");
+
+        // Ensure the callback name is new.
+        ensure!(self.is_unique_name(&callback_name), "'{callback_name}' is already in use.");
+        // Ensure the callback name is not a reserved opcode.
+        ensure!(!Self::is_reserved_opcode(&callback_name.to_string()), "'{callback_name}' is a reserved opcode.");
+        // Ensure the callback name is not a reserved keyword.
+        ensure!(!Self::is_reserved_keyword(&callback_name), "'{callback_name}' is a reserved keyword.");
+
+        // Add the callback name to the identifiers.
+        if self.identifiers.insert(callback_name, ProgramDefinition::Callback).is_some() {
+            bail!("'{callback_name}' already exists in the program.")
+        }
+        // Add the callback to the program.
+        if self.callbacks.insert(callback_name, callback).is_some() {
+            bail!("'{callback_name}' already exists in the program.")
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -602,6 +672,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         "value",
         "async",
         "finalize",
+        "callback",
         // Reserved (catch all)
         "global",
         "block",
@@ -749,6 +820,66 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_program_closure_repeat() -> Result<()> {
+        // Create a closure that repeats itself.
+        let closure = Closure::<CurrentNetwork>::from_str(
+            r"
+closure again:
+    input r0 as u64;
+    repeat 2u32 again r0 into r1;
+    output r1 as u64;",
+        )?;
+        // Ensure the program is rejected, before its repeats are unrolled.
+        assert!(Program::<CurrentNetwork>::from_str(&format!("program unknown.aleo; {closure}")).is_err());
+
+        // Ensure a closure cannot repeat a closure declared after it.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+
+closure first:
+    input r0 as u64;
+    repeat 2u32 second r0 into r1;
+    output r1 as u64;
+
+closure second:
+    input r0 as u64;
+    repeat 2u32 first r0 into r1;
+    output r1 as u64;",
+        );
+        assert!(program.is_err());
+
+        // Ensure a closure can repeat a closure declared before it.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+
+closure first:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+closure second:
+    input r0 as u64;
+    repeat 2u32 first r0 into r1;
+    output r1 as u64;",
+        )?;
+        // Ensure the nested iterations are unrolled.
+        let instructions = Closure::<CurrentNetwork>::from_str(
+            r"
+closure third:
+    input r0 as u64;
+    repeat 3u32 second r0 into r1;
+    output r1 as u64;",
+        )?
+        .instructions()
+        .to_vec();
+        assert_eq!(Repeat::unrolled_iterations(&program, &instructions)?, 6);
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_import() -> Result<()> {
         // Initialize a new program.
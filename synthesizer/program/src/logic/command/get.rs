@@ -19,7 +19,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Locator, Register, Value},
+    program::{Identifier, Locator, PlaintextType, ProgramID, Register, Value},
 };
 
 use std::io::{BufRead, BufReader};
@@ -31,6 +31,16 @@ pub enum MappingLocator<N: Network> {
     Locator(Locator<N>),
     /// The reference to a local mapping name.
     Resource(Identifier<N>),
+    /// The reference to a mapping name in the program whose address is stored in the register.
+    Dynamic(Register<N>, Identifier<N>),
+}
+
+impl<N: Network> MappingLocator<N> {
+    /// Returns `true` if the program of the mapping is only known at runtime.
+    #[inline]
+    pub const fn is_dynamic(&self) -> bool {
+        matches!(self, MappingLocator::Dynamic(..))
+    }
 }
 
 impl<N: Network> Parser for MappingLocator<N> {
@@ -39,6 +49,9 @@ impl<N: Network> Parser for MappingLocator<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         alt((
             map(Locator::parse, |locator| MappingLocator::Locator(locator)),
+            map(pair(terminated(Register::parse, tag("/")), Identifier::parse), |(register, identifier)| {
+                MappingLocator::Dynamic(register, identifier)
+            }),
             map(Identifier::parse, |identifier| MappingLocator::Resource(identifier)),
         ))(string)
     }
@@ -75,6 +88,7 @@ impl<N: Network> Display for MappingLocator<N> {
         match self {
             MappingLocator::Locator(locator) => Display::fmt(locator, f),
             MappingLocator::Resource(resource) => Display::fmt(resource, f),
+            MappingLocator::Dynamic(register, resource) => write!(f, "{register}/{resource}"),
         }
     }
 }
@@ -94,6 +108,7 @@ impl<N: Network> FromBytes for MappingLocator<N> {
         match variant {
             0 => Ok(MappingLocator::Locator(Locator::read_le(&mut reader)?)),
             1 => Ok(MappingLocator::Resource(Identifier::read_le(&mut reader)?)),
+            2 => Ok(MappingLocator::Dynamic(Register::read_le(&mut reader)?, Identifier::read_le(&mut reader)?)),
             _ => Err(error("Failed to read MappingLocator. Invalid variant.")),
         }
     }
@@ -119,12 +134,24 @@ impl<N: Network> ToBytes for MappingLocator<N> {
                 // Write the resource.
                 resource.write_le(&mut writer)
             }
+            MappingLocator::Dynamic(register, resource) => {
+                // Write the version.
+                0u8.write_le(&mut writer)?;
+                // Write the variant.
+                2u8.write_le(&mut writer)?;
+                // Write the register.
+                register.write_le(&mut writer)?;
+                // Write the resource.
+                resource.write_le(&mut writer)
+            }
         }
     }
 }
 
 /// A get command, e.g. `get accounts[r0] into r1;`.
 /// Gets the value stored at `operand` in `mapping` and stores the result in `destination`.
+/// If the program of the mapping is read from a register, the value type must be declared,
+/// e.g. `get r0/balances[r1] into r2 as u64;`.
 #[derive(Clone)]
 pub struct Get<N: Network> {
     /// The mapping.
@@ -134,13 +161,18 @@ pub struct Get<N: Network> {
     key: Operand<N>,
     /// The destination register.
     destination: Register<N>,
+    /// The declared value type, if the mapping is dynamic.
+    value_type: Option<PlaintextType<N>>,
 }
 
 impl<N: Network> PartialEq for Get<N> {
     /// Returns true if the two objects are equal.
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        self.mapping == other.mapping && self.key == other.key && self.destination == other.destination
+        self.mapping == other.mapping
+            && self.key == other.key
+            && self.destination == other.destination
+            && self.value_type == other.value_type
     }
 }
 
@@ -153,6 +185,7 @@ impl<N: Network> std::hash::Hash for Get<N> {
         self.mapping.hash(state);
         self.key.hash(state);
         self.destination.hash(state);
+        self.value_type.hash(state);
     }
 }
 
@@ -164,9 +197,13 @@ impl<N: Network> Get<N> {
     }
 
     /// Returns the operands in the operation.
+    /// If the mapping is dynamic, the register containing the program address is the first operand.
     #[inline]
     pub fn operands(&self) -> Vec<Operand<N>> {
-        vec![self.key.clone()]
+        match &self.mapping {
+            MappingLocator::Dynamic(register, _) => vec![Operand::Register(register.clone()), self.key.clone()],
+            _ => vec![self.key.clone()],
+        }
     }
 
     /// Returns the mapping.
@@ -186,6 +223,26 @@ impl<N: Network> Get<N> {
     pub const fn destination(&self) -> &Register<N> {
         &self.destination
     }
+
+    /// Returns the declared value type, if the mapping is dynamic.
+    #[inline]
+    pub const fn value_type(&self) -> Option<&PlaintextType<N>> {
+        self.value_type.as_ref()
+    }
+
+    /// Returns the command, with the dynamic mapping resolved to the mapping in the given program.
+    #[inline]
+    pub fn resolve(&self, program_id: ProgramID<N>) -> Result<Self> {
+        match self.mapping {
+            MappingLocator::Dynamic(_, mapping_name) => Ok(Self {
+                mapping: MappingLocator::Locator(Locator::new(program_id, mapping_name)),
+                key: self.key.clone(),
+                destination: self.destination.clone(),
+                value_type: None,
+            }),
+            _ => bail!("Cannot resolve the static mapping '{}'", self.mapping),
+        }
+    }
 }
 
 impl<N: Network> Get<N> {
@@ -201,6 +258,9 @@ impl<N: Network> Get<N> {
         let (program_id, mapping_name) = match self.mapping {
             MappingLocator::Locator(locator) => (*locator.program_id(), *locator.resource()),
             MappingLocator::Resource(mapping_name) => (*stack.program_id(), mapping_name),
+            MappingLocator::Dynamic(..) => {
+                bail!("Forbidden operation: Use 'Process' to resolve the dynamic mapping '{}'.", self.mapping)
+            }
         };
 
         // Ensure the mapping exists in storage.
@@ -260,12 +320,28 @@ impl<N: Network> Parser for Get<N> {
         // Parse the destination register from the string.
         let (string, destination) = Register::parse(string)?;
 
+        // Parse the declared value type from the string, if the mapping is dynamic.
+        let (string, value_type) = match mapping.is_dynamic() {
+            true => {
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the "as" keyword from the string.
+                let (string, _) = tag("as")(string)?;
+                // Parse the whitespace from the string.
+                let (string, _) = Sanitizer::parse_whitespaces(string)?;
+                // Parse the value type from the string.
+                let (string, value_type) = PlaintextType::parse(string)?;
+                (string, Some(value_type))
+            }
+            false => (string, None),
+        };
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the ";" from the string.
         let (string, _) = tag(";")(string)?;
 
-        Ok((string, Self { mapping, key, destination }))
+        Ok((string, Self { mapping, key, destination, value_type }))
     }
 }
 
@@ -302,7 +378,12 @@ impl<N: Network> Display for Get<N> {
         // Print the mapping and key operand.
         write!(f, "{}[{}] into ", self.mapping, self.key)?;
         // Print the destination register.
-        write!(f, "{};", self.destination)
+        write!(f, "{}", self.destination)?;
+        // Print the declared value type, if the mapping is dynamic.
+        if let Some(value_type) = &self.value_type {
+            write!(f, " as {value_type}")?;
+        }
+        write!(f, ";")
     }
 }
 
@@ -328,8 +409,13 @@ impl<N: Network> FromBytes for Get<N> {
         let key = Operand::read_le(&mut reader)?;
         // Read the destination register.
         let destination = Register::read_le(&mut reader)?;
+        // Read the declared value type, if the mapping is dynamic.
+        let value_type = match mapping.is_dynamic() {
+            true => Some(PlaintextType::read_le(&mut reader)?),
+            false => None,
+        };
         // Return the command.
-        Ok(Self { mapping, key, destination })
+        Ok(Self { mapping, key, destination, value_type })
     }
 }
 
@@ -339,13 +425,18 @@ impl<N: Network> ToBytes for Get<N> {
         // Write the mapping name.
         // TODO (howardwu): For mainnet - Write `self.mapping` directly, instead of matching on the identifier case.
         match &self.mapping {
-            MappingLocator::Locator(_) => self.mapping.write_le(&mut writer)?,
+            MappingLocator::Locator(_) | MappingLocator::Dynamic(..) => self.mapping.write_le(&mut writer)?,
             MappingLocator::Resource(identifier) => identifier.write_le(&mut writer)?,
         }
         // Write the key operand.
         self.key.write_le(&mut writer)?;
         // Write the destination register.
-        self.destination.write_le(&mut writer)
+        self.destination.write_le(&mut writer)?;
+        // Write the declared value type, if the mapping is dynamic.
+        match &self.value_type {
+            Some(value_type) => value_type.write_le(&mut writer),
+            None => Ok(()),
+        }
     }
 }
 
@@ -391,6 +482,34 @@ mod tests {
         assert_eq!(get.operands().len(), 1, "The number of operands is incorrect");
         assert_eq!(get.key, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(get.destination, Register::Locator(1), "The second operand is incorrect");
+
+        let (string, get) = Get::<CurrentNetwork>::parse("get r0/balances[r1] into r2 as u64;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get.mapping, MappingLocator::from_str("r0/balances").unwrap());
+        assert!(get.mapping.is_dynamic(), "The mapping should be dynamic");
+        assert_eq!(get.operands().len(), 2, "The number of operands is incorrect");
+        assert_eq!(get.key, Operand::Register(Register::Locator(1)), "The first operand is incorrect");
+        assert_eq!(get.destination, Register::Locator(2), "The second operand is incorrect");
+        assert_eq!(get.value_type, Some(PlaintextType::from_str("u64").unwrap()), "The value type is incorrect");
+
+        // A dynamic mapping must declare its value type, and a static mapping must not.
+        assert!(Get::<CurrentNetwork>::parse("get r0/balances[r1] into r2;").is_err());
+        assert!(Get::<CurrentNetwork>::parse("get account[r0] into r1 as u64;").is_err());
+    }
+
+    #[test]
+    fn test_dynamic_bytes() {
+        let expected = "get r0/balances[r1] into r2 as u64;";
+        let get = Get::<CurrentNetwork>::from_str(expected).unwrap();
+        assert_eq!(expected, get.to_string());
+
+        let bytes = get.to_bytes_le().unwrap();
+        assert_eq!(get, Get::<CurrentNetwork>::from_bytes_le(&bytes[..]).unwrap());
+
+        // Resolve the mapping to a static locator.
+        let resolved = get.resolve(ProgramID::from_str("token.aleo").unwrap()).unwrap();
+        assert_eq!("get token.aleo/balances[r1] into r2;", resolved.to_string());
+        assert!(resolved.resolve(ProgramID::from_str("token.aleo").unwrap()).is_err());
     }
 
     #[test]
@@ -20,7 +20,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Register, Value},
+    program::{Locator, ProgramID, Register, Value},
 };
 
 use console::program::Identifier;
@@ -29,6 +29,7 @@ use std::io::{BufRead, BufReader};
 /// A get command that uses the provided default in case of failure, e.g. `get.or_use accounts[r0] r1 into r2;`.
 /// Gets the value stored at `operand` in `mapping` and stores the result in `destination`.
 /// If the key is not present, `default` is stored in `destination`.
+/// If the program of the mapping is read from a register, the value type is the type of `default`.
 #[derive(Clone)]
 pub struct GetOrUse<N: Network> {
     /// The mapping.
@@ -72,9 +73,15 @@ impl<N: Network> GetOrUse<N> {
     }
 
     /// Returns the operands in the operation.
+    /// If the mapping is dynamic, the register containing the program address is the first operand.
     #[inline]
     pub fn operands(&self) -> Vec<Operand<N>> {
-        vec![self.key.clone(), self.default.clone()]
+        match &self.mapping {
+            MappingLocator::Dynamic(register, _) => {
+                vec![Operand::Register(register.clone()), self.key.clone(), self.default.clone()]
+            }
+            _ => vec![self.key.clone(), self.default.clone()],
+        }
     }
 
     /// Returns the mapping.
@@ -100,6 +107,20 @@ impl<N: Network> GetOrUse<N> {
     pub const fn destination(&self) -> &Register<N> {
         &self.destination
     }
+
+    /// Returns the command, with the dynamic mapping resolved to the mapping in the given program.
+    #[inline]
+    pub fn resolve(&self, program_id: ProgramID<N>) -> Result<Self> {
+        match self.mapping {
+            MappingLocator::Dynamic(_, mapping_name) => Ok(Self {
+                mapping: MappingLocator::Locator(Locator::new(program_id, mapping_name)),
+                key: self.key.clone(),
+                default: self.default.clone(),
+                destination: self.destination.clone(),
+            }),
+            _ => bail!("Cannot resolve the static mapping '{}'", self.mapping),
+        }
+    }
 }
 
 impl<N: Network> GetOrUse<N> {
@@ -115,6 +136,9 @@ impl<N: Network> GetOrUse<N> {
         let (program_id, mapping_name) = match self.mapping {
             MappingLocator::Locator(locator) => (*locator.program_id(), *locator.resource()),
             MappingLocator::Resource(mapping_name) => (*stack.program_id(), mapping_name),
+            MappingLocator::Dynamic(..) => {
+                bail!("Forbidden operation: Use 'Process' to resolve the dynamic mapping '{}'.", self.mapping)
+            }
         };
 
         // Ensure the mapping exists in storage.
@@ -260,7 +284,7 @@ impl<N: Network> ToBytes for GetOrUse<N> {
         // Write the mapping name.
         // TODO (howardwu): For mainnet - Write the `self.mapping` directly, instead of matching on the identifier case.
         match &self.mapping {
-            MappingLocator::Locator(_) => self.mapping.write_le(&mut writer)?,
+            MappingLocator::Locator(_) | MappingLocator::Dynamic(..) => self.mapping.write_le(&mut writer)?,
             MappingLocator::Resource(identifier) => identifier.write_le(&mut writer)?,
         }
         // Write the key operand.
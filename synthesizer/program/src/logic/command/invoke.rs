@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Opcode, Operand};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Register},
+};
+
+/// An invoke command, e.g. `invoke r0/deposit r1 r2;`.
+/// Invokes the callback in the program whose address is stored in the register.
+/// The callback receives the address of the invoking program, followed by the operands.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Invoke<N: Network> {
+    /// The register containing the program address.
+    register: Register<N>,
+    /// The name of the callback.
+    callback_name: Identifier<N>,
+    /// The operands.
+    operands: Vec<Operand<N>>,
+}
+
+impl<N: Network> Invoke<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("invoke")
+    }
+
+    /// Returns the register containing the program address.
+    #[inline]
+    pub const fn register(&self) -> &Register<N> {
+        &self.register
+    }
+
+    /// Returns the name of the callback.
+    #[inline]
+    pub const fn callback_name(&self) -> &Identifier<N> {
+        &self.callback_name
+    }
+
+    /// Returns the operands passed to the callback.
+    #[inline]
+    pub fn arguments(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the operands in the operation.
+    /// Note: The register containing the program address is the first operand.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        std::iter::once(Operand::Register(self.register.clone())).chain(self.operands.iter().cloned()).collect()
+    }
+}
+
+impl<N: Network> Parser for Invoke<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the register from the string.
+        let (string, register) = Register::parse(string)?;
+        // Parse the "/" from the string.
+        let (string, _) = tag("/")(string)?;
+        // Parse the callback name from the string.
+        let (string, callback_name) = Identifier::parse(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = map_res(many0(complete(parse_operand)), |operands: Vec<Operand<N>>| {
+            // Ensure the number of operands is within the bounds.
+            match operands.len() < N::MAX_INPUTS {
+                true => Ok(operands),
+                false => Err(error("Failed to parse 'invoke' command: too many operands")),
+            }
+        })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ';' from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { register, callback_name, operands }))
+    }
+}
+
+impl<N: Network> FromStr for Invoke<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Invoke<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Invoke<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() >= N::MAX_INPUTS {
+            return Err(fmt::Error);
+        }
+        // Print the command.
+        write!(f, "{} {}/{}", Self::opcode(), self.register, self.callback_name)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        write!(f, ";")
+    }
+}
+
+impl<N: Network> FromBytes for Invoke<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the register.
+        let register = Register::read_le(&mut reader)?;
+        // Read the callback name.
+        let callback_name = Identifier::read_le(&mut reader)?;
+
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within the bounds.
+        if num_operands >= N::MAX_INPUTS {
+            return Err(error(format!("The number of operands must be < {}", N::MAX_INPUTS)));
+        }
+        // Read the operands.
+        let mut operands = Vec::with_capacity(num_operands);
+        for _ in 0..num_operands {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+
+        Ok(Self { register, callback_name, operands })
+    }
+}
+
+impl<N: Network> ToBytes for Invoke<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() >= N::MAX_INPUTS {
+            return Err(error(format!("The number of operands must be < {}", N::MAX_INPUTS)));
+        }
+        // Write the register.
+        self.register.write_le(&mut writer)?;
+        // Write the callback name.
+        self.callback_name.write_le(&mut writer)?;
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, invoke) = Invoke::<CurrentNetwork>::parse("invoke r0/deposit r1 10u64;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(invoke.register(), &Register::Locator(0));
+        assert_eq!(invoke.callback_name(), &Identifier::from_str("deposit").unwrap());
+        assert_eq!(invoke.arguments(), &[Operand::from_str("r1").unwrap(), Operand::from_str("10u64").unwrap()]);
+        assert_eq!(invoke.operands().len(), 3);
+        assert_eq!(invoke.to_string(), "invoke r0/deposit r1 10u64;");
+
+        // Ensure a callback without operands is parsed.
+        let (string, invoke) = Invoke::<CurrentNetwork>::parse("invoke r2/ping;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert!(invoke.arguments().is_empty());
+
+        // Ensure the program must be given by a register.
+        assert!(Invoke::<CurrentNetwork>::parse("invoke token.aleo/deposit r1;").is_err());
+    }
+}
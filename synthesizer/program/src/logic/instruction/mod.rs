@@ -60,7 +60,7 @@ use console::{
         ToBytes,
         Write,
     },
-    program::{Register, RegisterType},
+    program::{Identifier, Register, RegisterType},
 };
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -105,6 +105,10 @@ pub enum Instruction<N: Network> {
     DivWrapped(DivWrapped<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Verifies a secp256k1 ECDSA signature on a digest, storing the outcome in `destination`.
+    ECDSAVerifyDigest(ECDSAVerifyDigest<N>),
+    /// Verifies a secp256k1 ECDSA signature on the Keccak-256 hash of a message, storing the outcome in `destination`.
+    ECDSAVerifyKeccak256(ECDSAVerifyKeccak256<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
@@ -179,6 +183,8 @@ pub enum Instruction<N: Network> {
     Rem(Rem<N>),
     /// Divides `first` by `second`, wrapping around at the boundary of the type, storing the remainder in `destination`.
     RemWrapped(RemWrapped<N>),
+    /// Repeatedly calls a closure on the operands, passing the outputs of each iteration to the next.
+    Repeat(Repeat<N>),
     /// Shifts `first` left by `second` bits, storing the outcome in `destination`.
     Shl(Shl<N>),
     /// Shifts `first` left by `second` bits, wrapping around at the boundary of the type, storing the outcome in `destination`.
@@ -300,6 +306,9 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            ECDSAVerifyDigest,
+            ECDSAVerifyKeccak256,
+            Repeat,
         }}
     };
     // A variant **without** curly braces:
@@ -379,6 +388,15 @@ impl<N: Network> InstructionTrait<N> for Instruction<N> {
         instruction!(self, |instruction| instruction.destinations())
     }
 
+    /// Returns the name of the closure repeated by the instruction, if it is a `repeat` instruction.
+    #[inline]
+    fn repeated_closure(&self) -> Option<&Identifier<N>> {
+        match self {
+            Self::Repeat(repeat) => Some(repeat.closure_name()),
+            _ => None,
+        }
+    }
+
     /// Returns `true` if the given name is a reserved opcode.
     #[inline]
     fn is_reserved_opcode(name: &str) -> bool {
@@ -469,7 +487,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            71,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -0,0 +1,406 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Computes whether the secp256k1 ECDSA `signature` is valid for the compressed `public_key` and 32-byte `digest`.
+pub type ECDSAVerifyDigest<N> = ECDSAVerify<N, { Variant::ECDSAVerifyDigest as u8 }>;
+/// Computes whether the secp256k1 ECDSA `signature` on the Keccak-256 hash of `message` recovers the Ethereum `address`.
+pub type ECDSAVerifyKeccak256<N> = ECDSAVerify<N, { Variant::ECDSAVerifyKeccak256 as u8 }>;
+
+enum Variant {
+    ECDSAVerifyDigest,
+    ECDSAVerifyKeccak256,
+}
+
+/// The number of bytes in a signature, as `r || s || v`.
+const SIGNATURE_SIZE_IN_BYTES: u32 = 65;
+
+/// Returns the expected number of bytes in the second operand, given the variant.
+const fn expected_second_length(variant: u8) -> u32 {
+    match variant {
+        // The compressed public key, as `prefix || x`.
+        0 => 33,
+        // The Ethereum address.
+        _ => 20,
+    }
+}
+
+/// Returns `true` if the register type is a `u8` array, with the given length (if specified).
+fn is_byte_array<N: Network>(register_type: &RegisterType<N>, length: Option<u32>) -> bool {
+    match register_type {
+        RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+            *array_type.next_element_type() == PlaintextType::Literal(LiteralType::U8)
+                && length.map_or(true, |length| **array_type.length() == length)
+        }
+        _ => false,
+    }
+}
+
+/// Returns the bytes of the given `u8` array.
+fn to_bytes<N: Network>(value: &Value<N>) -> Result<Vec<u8>> {
+    match value {
+        Value::Plaintext(Plaintext::Array(elements, _)) => elements
+            .iter()
+            .map(|element| match element {
+                Plaintext::Literal(Literal::U8(byte), _) => Ok(**byte),
+                _ => bail!("Expected a 'u8' element, found '{element}'"),
+            })
+            .collect(),
+        _ => bail!("Expected a 'u8' array, found '{value}'"),
+    }
+}
+
+/// Returns the bytes of the given `u8` array circuit.
+fn to_bytes_circuit<A: circuit::Aleo>(value: &circuit::Value<A>) -> Result<Vec<circuit::U8<A>>> {
+    use circuit::Eject;
+
+    match value {
+        circuit::Value::Plaintext(circuit::Plaintext::Array(elements, _)) => elements
+            .iter()
+            .map(|element| match element {
+                circuit::Plaintext::Literal(circuit::Literal::U8(byte), _) => Ok(byte.clone()),
+                _ => bail!("Expected a 'u8' element, found '{}'", element.eject_value()),
+            })
+            .collect(),
+        _ => bail!("Expected a 'u8' array, found '{}'", value.eject_value()),
+    }
+}
+
+/// Verifies a secp256k1 ECDSA signature, and stores the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ECDSAVerify<N: Network, const VARIANT: u8> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> ECDSAVerify<N, VARIANT> {
+    /// Initializes a new `ecdsa.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::ECDSA("ecdsa.verify.digest"),
+            1 => Opcode::ECDSA("ecdsa.verify.keccak256"),
+            _ => panic!("Invalid 'ecdsa.verify' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ECDSAVerify<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = to_bytes(&registers.load(stack, &self.operands[0])?)?;
+        let second = to_bytes(&registers.load(stack, &self.operands[1])?)?;
+        let third = to_bytes(&registers.load(stack, &self.operands[2])?)?;
+
+        // Verify the signature.
+        let output = match VARIANT {
+            0 => N::ecdsa_verify_digest(&signature, &second, &third)?,
+            1 => N::ecdsa_verify_keccak256(&signature, &second, &third)?,
+            _ => bail!("Invalid 'ecdsa.verify' variant: {VARIANT}"),
+        };
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Boolean(Boolean::new(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[0])?)?;
+        let second = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[1])?)?;
+        let third = to_bytes_circuit(&registers.load_circuit(stack, &self.operands[2])?)?;
+
+        // Ensure the inputs are of the expected size.
+        ensure!(signature.len() == SIGNATURE_SIZE_IN_BYTES as usize, "Invalid signature size in '{}'", Self::opcode());
+        ensure!(
+            second.len() == expected_second_length(VARIANT) as usize,
+            "Invalid second operand size in '{}'",
+            Self::opcode()
+        );
+
+        // Verify the signature.
+        let output = match VARIANT {
+            0 => {
+                ensure!(third.len() == 32, "Invalid digest size in '{}'", Self::opcode());
+                A::ecdsa_verify_digest(&signature, &second, &third)
+            }
+            1 => A::ecdsa_verify_keccak256(&signature, &second, &third),
+            _ => bail!("Invalid 'ecdsa.verify' variant: {VARIANT}"),
+        };
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Boolean(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the first operand is a signature.
+        if !is_byte_array(&input_types[0], Some(SIGNATURE_SIZE_IN_BYTES)) {
+            bail!(
+                "Instruction '{}' expects the first input to be a '[u8; {SIGNATURE_SIZE_IN_BYTES}u32]'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+
+        // Ensure the second operand is a public key (or an Ethereum address).
+        let second_length = expected_second_length(VARIANT);
+        if !is_byte_array(&input_types[1], Some(second_length)) {
+            bail!(
+                "Instruction '{}' expects the second input to be a '[u8; {second_length}u32]'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+
+        // Ensure the third operand is a digest (or a message of any length).
+        let third_length = match VARIANT {
+            0 => Some(32),
+            _ => None,
+        };
+        if !is_byte_array(&input_types[2], third_length) {
+            bail!(
+                "Instruction '{}' expects the third input to be a 'u8' array. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for ECDSAVerify<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for ECDSAVerify<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for ECDSAVerify<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for ECDSAVerify<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for ECDSAVerify<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for ECDSAVerify<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::ArrayType, types::U32};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the register type of a `u8` array with the given length.
+    fn byte_array(length: u32) -> RegisterType<CurrentNetwork> {
+        let array_type = ArrayType::new(PlaintextType::Literal(LiteralType::U8), vec![U32::new(length)]).unwrap();
+        RegisterType::Plaintext(PlaintextType::Array(array_type))
+    }
+
+    #[test]
+    fn test_parse() {
+        let (string, ecdsa) =
+            ECDSAVerifyDigest::<CurrentNetwork>::parse("ecdsa.verify.digest r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(ecdsa.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(ecdsa.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(ecdsa.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(ecdsa.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(ecdsa.destination, Register::Locator(3), "The destination register is incorrect");
+
+        let (string, ecdsa) =
+            ECDSAVerifyKeccak256::<CurrentNetwork>::parse("ecdsa.verify.keccak256 r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(ecdsa.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(ecdsa.destination, Register::Locator(3), "The destination register is incorrect");
+
+        // Ensure the opcodes are not interchangeable.
+        assert!(ECDSAVerifyDigest::<CurrentNetwork>::parse("ecdsa.verify.keccak256 r0 r1 r2 into r3").is_err());
+    }
+
+    #[test]
+    fn test_is_byte_array() {
+        assert!(is_byte_array(&byte_array(65), Some(65)));
+        assert!(is_byte_array(&byte_array(7), None));
+        assert!(!is_byte_array(&byte_array(64), Some(65)));
+        assert!(!is_byte_array::<CurrentNetwork>(
+            &RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U8)),
+            None
+        ));
+    }
+}
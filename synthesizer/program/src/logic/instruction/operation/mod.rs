@@ -27,6 +27,9 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod ecdsa_verify;
+pub use ecdsa_verify::*;
+
 mod hash;
 pub use hash::*;
 
@@ -38,6 +41,9 @@ pub use literals::*;
 
 mod macros;
 
+mod repeat;
+pub use repeat::*;
+
 mod sign_verify;
 pub use sign_verify::*;
 
@@ -0,0 +1,402 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, StackMatches, StackProgram},
+    Instruction,
+    Opcode,
+    Operand,
+    Program,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Register, RegisterType, U32},
+};
+
+/// Repeatedly calls a local closure on the operands, a compile-time constant number of times.
+/// The outputs of each iteration are the inputs of the next iteration, so the closure must
+/// return the same number and types of values that it takes as input.
+/// i.e. `repeat 8u32 double_and_add r0 r1 into r2 r3;`
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Repeat<N: Network> {
+    /// The number of iterations.
+    count: U32<N>,
+    /// The name of the closure.
+    closure: Identifier<N>,
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination registers.
+    destinations: Vec<Register<N>>,
+}
+
+impl<N: Network> Repeat<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Repeat
+    }
+
+    /// Returns the number of iterations.
+    #[inline]
+    pub const fn count(&self) -> &U32<N> {
+        &self.count
+    }
+
+    /// Returns the name of the closure.
+    #[inline]
+    pub const fn closure_name(&self) -> &Identifier<N> {
+        &self.closure
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+
+    /// Returns the destination registers.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        self.destinations.clone()
+    }
+
+    /// Returns the total number of closure iterations that the given instructions unroll into.
+    /// The iterations of a nested `repeat` are multiplied by the iterations of each `repeat` that contains it.
+    /// Note: `Program::add_closure` ensures a closure only repeats the closures declared before it.
+    pub fn unrolled_iterations(program: &Program<N>, instructions: &[Instruction<N>]) -> Result<u64> {
+        instructions.iter().try_fold(0u64, |total, instruction| {
+            // Skip the instructions that are not `repeat` instructions.
+            let Instruction::Repeat(repeat) = instruction else { return Ok(total) };
+            // Compute the iterations unrolled by each iteration of the closure.
+            let closure = program.get_closure(&repeat.closure)?;
+            let nested = Self::unrolled_iterations(program, closure.instructions())?.max(1);
+            // Compute the iterations unrolled by the instruction.
+            let iterations = u64::from(*repeat.count)
+                .checked_mul(nested)
+                .ok_or_else(|| anyhow!("The number of iterations in 'repeat' overflowed"))?;
+            total.checked_add(iterations).ok_or_else(|| anyhow!("The number of iterations in 'repeat' overflowed"))
+        })
+    }
+}
+
+impl<N: Network> Repeat<N> {
+    /// Evaluates the instruction.
+    pub fn evaluate(&self, _stack: &impl StackProgram<N>, _registers: &mut impl RegistersLoad<N>) -> Result<()> {
+        bail!("Forbidden operation: Evaluate cannot invoke a 'repeat' directly. Use 'repeat' in 'Stack' instead.")
+    }
+
+    /// Executes the instruction.
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        _stack: &impl StackProgram<N>,
+        _registers: &mut impl RegistersLoadCircuit<N, A>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Execute cannot invoke a 'repeat' directly. Use 'repeat' in 'Stack' instead.")
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut impl RegistersLoad<N>,
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke a 'repeat' directly. Use 'repeat' in 'Stack' instead.")
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of iterations is within the bounds.
+        ensure!(*self.count > 0, "The number of iterations in 'repeat' must be greater than zero");
+        ensure!(
+            *self.count <= N::MAX_REPEAT_ITERATIONS,
+            "The number of iterations in 'repeat' must be <= {}",
+            N::MAX_REPEAT_ITERATIONS
+        );
+
+        // Retrieve the closure.
+        let closure = match stack.program().get_closure(&self.closure) {
+            Ok(closure) => closure,
+            Err(_) => bail!("Cannot repeat '{}'. Only a local closure can be repeated.", self.closure),
+        };
+
+        // Ensure the number of operands matches the number of input statements.
+        if closure.inputs().len() != self.operands.len() {
+            bail!("Expected {} inputs, found {}", closure.inputs().len(), self.operands.len())
+        }
+        // Ensure the number of inputs matches the number of input statements.
+        if closure.inputs().len() != input_types.len() {
+            bail!("Expected {} input types, found {}", closure.inputs().len(), input_types.len())
+        }
+        // Ensure the number of destinations matches the number of output statements.
+        if closure.outputs().len() != self.destinations.len() {
+            bail!("Expected {} outputs, found {}", closure.outputs().len(), self.destinations.len())
+        }
+        // Ensure the number of outputs matches the number of inputs.
+        if closure.outputs().len() != closure.inputs().len() {
+            bail!(
+                "Cannot repeat '{}'. Expected {} outputs, found {}",
+                self.closure,
+                closure.inputs().len(),
+                closure.outputs().len()
+            )
+        }
+
+        // Ensure the input types match the closure, and that each output type matches its input type,
+        // so that the outputs of one iteration are valid inputs to the next iteration.
+        for ((input, output), input_type) in closure.inputs().iter().zip_eq(closure.outputs()).zip_eq(input_types) {
+            if input.register_type() != input_type {
+                bail!("Expected input type '{}', found '{input_type}'", input.register_type())
+            }
+            if output.register_type() != input.register_type() {
+                bail!(
+                    "Cannot repeat '{}'. Expected output type '{}', found '{}'",
+                    self.closure,
+                    input.register_type(),
+                    output.register_type()
+                )
+            }
+        }
+
+        // Return the output register types.
+        Ok(closure.outputs().iter().map(|output| output.register_type()).cloned().collect())
+    }
+}
+
+impl<N: Network> Parser for Repeat<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        /// Parses a destination register from the string.
+        fn parse_destination<N: Network>(string: &str) -> ParserResult<Register<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the destination from the string.
+            Register::parse(string)
+        }
+
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the number of iterations from the string.
+        let (string, count) = U32::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the name of the closure from the string.
+        let (string, closure) = Identifier::parse(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = map_res(many1(complete(parse_operand)), |operands: Vec<Operand<N>>| {
+            // Ensure the number of operands is within the bounds.
+            match operands.len() <= N::MAX_OPERANDS {
+                true => Ok(operands),
+                false => Err(error("Failed to parse 'repeat' opcode: too many operands")),
+            }
+        })(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the destinations from the string.
+        let (string, destinations) = map_res(many1(complete(parse_destination)), |destinations: Vec<Register<N>>| {
+            // Ensure the number of destinations is within the bounds.
+            match destinations.len() <= N::MAX_OPERANDS {
+                true => Ok(destinations),
+                false => Err(error("Failed to parse 'repeat' opcode: too many destinations")),
+            }
+        })(string)?;
+
+        Ok((string, Self { count, closure, operands, destinations }))
+    }
+}
+
+impl<N: Network> FromStr for Repeat<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Repeat<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Repeat<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.is_empty() || self.operands.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.is_empty() || self.destinations.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} {} {}", Self::opcode(), self.count, self.closure)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        write!(f, " into")?;
+        self.destinations.iter().try_for_each(|destination| write!(f, " {destination}"))
+    }
+}
+
+impl<N: Network> FromBytes for Repeat<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of iterations.
+        let count = U32::read_le(&mut reader)?;
+        // Read the name of the closure.
+        let closure = Identifier::read_le(&mut reader)?;
+
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of operands is within the bounds.
+        if num_operands == 0 || num_operands > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be nonzero and <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(num_operands);
+        // Read the operands.
+        for _ in 0..num_operands {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+
+        // Read the number of destination registers.
+        let num_destinations = u8::read_le(&mut reader)? as usize;
+        // Ensure the number of destinations is within the bounds.
+        if num_destinations == 0 || num_destinations > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be nonzero and <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Initialize the vector for the destinations.
+        let mut destinations = Vec::with_capacity(num_destinations);
+        // Read the destination registers.
+        for _ in 0..num_destinations {
+            destinations.push(Register::read_le(&mut reader)?);
+        }
+
+        // Return the operation.
+        Ok(Self { count, closure, operands, destinations })
+    }
+}
+
+impl<N: Network> ToBytes for Repeat<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.is_empty() || self.operands.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be nonzero and <= {}", N::MAX_OPERANDS)));
+        }
+        // Ensure the number of destinations is within the bounds.
+        if self.destinations.is_empty() || self.destinations.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of destinations must be nonzero and <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Write the number of iterations.
+        self.count.write_le(&mut writer)?;
+        // Write the name of the closure.
+        self.closure.write_le(&mut writer)?;
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the number of destination register.
+        u8::try_from(self.destinations.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the destination registers.
+        self.destinations.iter().try_for_each(|destination| destination.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        network::Testnet3,
+        program::{Literal, U64},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    const TEST_CASES: &[&str] = &[
+        "repeat 1u32 foo r0 into r1",
+        "repeat 8u32 foo r0.owner into r1",
+        "repeat 16u32 foo r0 r1 into r2 r3",
+        "repeat 256u32 foo r0 1u64 r1 into r2 r3 r4",
+    ];
+
+    #[test]
+    fn test_parse() {
+        let (string, repeat) = Repeat::<CurrentNetwork>::parse("repeat 8u32 double r0 5u64 into r1 r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(*repeat.count, 8u32, "The number of iterations is incorrect");
+        assert_eq!(repeat.closure, Identifier::from_str("double").unwrap(), "The closure is incorrect");
+        assert_eq!(repeat.operands, vec![
+            Operand::Register(Register::Locator(0)),
+            Operand::Literal(Literal::U64(U64::new(5))),
+        ]);
+        assert_eq!(repeat.destinations, vec![Register::Locator(1), Register::Locator(2)]);
+
+        // Ensure the number of iterations, operands, and destinations are required.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat double r0 into r1").is_err());
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 8u64 double r0 into r1").is_err());
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 8u32 double into r1").is_err());
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 8u32 double r0").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for expected in TEST_CASES {
+            assert_eq!(Repeat::<CurrentNetwork>::from_str(expected).unwrap().to_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        for case in TEST_CASES {
+            let expected = Repeat::<CurrentNetwork>::from_str(case).unwrap();
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, Repeat::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
@@ -27,6 +27,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
             R(RecordType<N>),
             C(ClosureCore<N, Instruction>),
             F(FunctionCore<N, Instruction, Command>),
+            B(CallbackCore<N, Command>),
         }
 
         // Parse the imports from the string.
@@ -51,6 +52,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
             map(RecordType::parse, |record| P::<N, Instruction, Command>::R(record)),
             map(ClosureCore::parse, |closure| P::<N, Instruction, Command>::C(closure)),
             map(FunctionCore::parse, |function| P::<N, Instruction, Command>::F(function)),
+            map(CallbackCore::parse, |callback| P::<N, Instruction, Command>::B(callback)),
         )))(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
@@ -73,6 +75,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     P::R(record) => program.add_record(record.clone()),
                     P::C(closure) => program.add_closure(closure.clone()),
                     P::F(function) => program.add_function(function.clone()),
+                    P::B(callback) => program.add_callback(callback.clone()),
                 };
 
                 match result {
@@ -171,6 +174,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
                     Some(function) => program.push_str(&format!("{function}\n\n")),
                     None => return Err(fmt::Error),
                 },
+                ProgramDefinition::Callback => match self.callbacks.get(identifier) {
+                    Some(callback) => program.push_str(&format!("{callback}\n\n")),
+                    None => return Err(fmt::Error),
+                },
             }
         }
         // Remove the last newline.
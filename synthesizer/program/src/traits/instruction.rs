@@ -15,12 +15,14 @@
 use console::{
     network::Network,
     prelude::{FromBytes, Parser, ToBytes},
-    program::Register,
+    program::{Identifier, Register},
 };
 
 pub trait InstructionTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     /// Returns the destination registers of the instruction.
     fn destinations(&self) -> Vec<Register<N>>;
+    /// Returns the name of the closure repeated by the instruction, if it is a `repeat` instruction.
+    fn repeated_closure(&self) -> Option<&Identifier<N>>;
     /// Returns `true` if the given name is a reserved opcode.
     fn is_reserved_opcode(name: &str) -> bool;
 }
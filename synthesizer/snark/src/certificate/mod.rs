@@ -84,6 +84,25 @@ impl<N: Network> Certificate<N> {
             }
         }
     }
+
+    /// Verifies the certificate for the given verifying key, up to the final pairing check,
+    /// and returns the pairing check, to be checked with `PreparedCheck::verify_all`.
+    pub fn prepare(
+        &self,
+        function_name: &str,
+        assignment: &circuit::Assignment<N::Field>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<PreparedCheck<N>> {
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Prepare the pairing check of the certificate.
+        match Varuna::<N>::prepare_verify_vk(universal_verifier, fiat_shamir, assignment, verifying_key, self) {
+            Ok(check) => Ok(PreparedCheck::new(check)),
+            Err(error) => bail!("Failed to verify the certificate for '{function_name}': {error}"),
+        }
+    }
 }
 
 impl<N: Network> Deref for Certificate<N> {
@@ -31,6 +31,9 @@ type Varuna<N> = varuna::VarunaSNARK<<N as Environment>::PairingCurve, FiatShami
 mod certificate;
 pub use certificate::Certificate;
 
+mod prepared_check;
+pub use prepared_check::PreparedCheck;
+
 mod proof;
 pub use proof::Proof;
 
@@ -160,4 +163,27 @@ mod test {
         println!("\nShould not verify (i.e. verifier messages should print below):");
         assert!(!verifying_key.verify("test", &[one, one + one], &proof));
     }
+
+    #[test]
+    fn test_prepared_check() {
+        let assignment = crate::test_helpers::sample_assignment();
+        let (_, verifying_key) = crate::test_helpers::sample_keys();
+        let proof = crate::test_helpers::sample_proof();
+        let certificate = crate::test_helpers::sample_certificate();
+        let rng = &mut TestRng::default();
+
+        // Prepare the checks of the proof and the certificate.
+        let one = <Circuit as Environment>::BaseField::one();
+        let prepare_proof = |input| {
+            VerifyingKey::prepare_batch("test", vec![(verifying_key.clone(), vec![vec![one, input]])], &proof).unwrap()
+        };
+        let proof_check = prepare_proof(one);
+        let certificate_check = certificate.prepare("test", &assignment, &verifying_key).unwrap();
+
+        // Ensure the checks verify together.
+        assert!(PreparedCheck::verify_all(vec![proof_check.clone(), certificate_check.clone()], rng));
+        // Ensure an invalid proof fails the combined check.
+        let invalid_check = prepare_proof(one + one);
+        assert!(!PreparedCheck::verify_all(vec![proof_check, invalid_check, certificate_check], rng));
+    }
 }
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::polycommit::sonic_pc::PreparedBatchCheck;
+
+/// The pairing check of a proof or certificate, which has been verified up to the final pairing check.
+/// The pairing checks of several proofs and certificates can be checked together with a single pairing product.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedCheck<N: Network> {
+    /// The accumulated elements of the pairing check.
+    check: PreparedBatchCheck<N::PairingCurve>,
+}
+
+impl<N: Network> PreparedCheck<N> {
+    /// Initializes a new prepared check.
+    pub(super) const fn new(check: PreparedBatchCheck<N::PairingCurve>) -> Self {
+        Self { check }
+    }
+
+    /// Returns `true` if all of the given prepared checks are valid, using a single pairing product.
+    /// If the combined check fails, at least one of the prepared checks is invalid.
+    pub fn verify_all<R: Rng + CryptoRng>(checks: Vec<PreparedCheck<N>>, rng: &mut R) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+        #[cfg(feature = "aleo-cli")]
+        let num_checks = checks.len();
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+
+        // Verify the combined pairing check.
+        match Varuna::<N>::verify_prepared(universal_verifier, checks.into_iter().map(|check| check.check), rng) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                println!(
+                    "{}",
+                    format!(" • Verified {num_checks} prepared checks (in {} ms)", timer.elapsed().as_millis())
+                        .dimmed()
+                );
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
+}
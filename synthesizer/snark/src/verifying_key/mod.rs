@@ -87,6 +87,29 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Verifies the batch proof for the given public inputs, up to the final pairing check,
+    /// and returns the pairing check, to be checked with `PreparedCheck::verify_all`.
+    #[allow(clippy::type_complexity)]
+    pub fn prepare_batch(
+        locator: &str,
+        inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        proof: &Proof<N>,
+    ) -> Result<PreparedCheck<N>> {
+        // Convert the instances.
+        let keys_to_inputs: BTreeMap<_, _> =
+            inputs.iter().map(|(verifying_key, inputs)| (verifying_key.deref(), inputs.as_slice())).collect();
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Prepare the pairing check of the batch proof.
+        match Varuna::<N>::prepare_verify_batch(universal_verifier, fiat_shamir, &keys_to_inputs, proof)? {
+            Some(check) => Ok(PreparedCheck::new(check)),
+            None => bail!("The proof for '{locator}' has an incorrect zero-knowledge mode"),
+        }
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
@@ -28,7 +28,7 @@ pub use synthesizer_program as program;
 pub use synthesizer_snark as snark;
 
 #[cfg(feature = "process")]
-pub use crate::process::{Authorization, CallMetrics, Process, Stack, Trace};
+pub use crate::process::{Authorization, CallMetrics, FinalizeTrace, Process, Stack, Trace};
 #[cfg(feature = "program")]
 pub use crate::program::{Closure, Command, Finalize, Function, Instruction, Program};
 
@@ -43,7 +43,7 @@ pub mod prelude {
     #[cfg(feature = "program")]
     pub use crate::program::{Closure, Finalize, Function, Instruction, Mapping, Program};
     #[cfg(feature = "snark")]
-    pub use crate::snark::{Certificate, Proof, ProvingKey, UniversalSRS, VerifyingKey};
+    pub use crate::snark::{Certificate, PreparedCheck, Proof, ProvingKey, UniversalSRS, VerifyingKey};
     #[cfg(all(feature = "process", feature = "program", feature = "snark"))]
     pub use crate::vm::*;
 }
@@ -719,11 +719,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 Err(e) => return Err(format!("Failed to post-ratify - {e}")),
             }
 
+            /* Record the prior state, so the block can be reverted, and the mapping history. */
+
+            if let Err(e) = store.record_revert(state.block_height()) {
+                // Note: This will abort the entire atomic batch.
+                return Err(format!("Failed to record the finalize revert - {e}"));
+            }
+            if let Err(e) = store.record_history(state.block_height()) {
+                // Note: This will abort the entire atomic batch.
+                return Err(format!("Failed to record the mapping history - {e}"));
+            }
+
             /* Start the commit process. */
 
             // Commit all of the stacks to the process.
             if !stacks.is_empty() {
-                stacks.into_iter().for_each(|stack| process.add_stack(stack))
+                // Retain the process, to roll back the stacks if a stack fails to be added.
+                let previous_process = process.clone();
+                for stack in stacks {
+                    if let Err(e) = process.add_stack(stack) {
+                        *process = previous_process;
+                        // Note: This will abort the entire atomic batch.
+                        return Err(format!("Failed to add the stack - {e}"));
+                    }
+                }
             }
 
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
@@ -956,6 +975,7 @@ mod tests {
     };
     use ledger_block::{Block, Header, Metadata, Transaction, Transition};
     use ledger_store::helpers::memory::ConsensusMemory;
+    use synthesizer_process::MappingAccess;
     use synthesizer_program::Program;
 
     use rand::distributions::DistString;
@@ -1533,6 +1553,240 @@ function ped_hash:
         }
     }
 
+    #[test]
+    fn test_simulate() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key, view key, and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Create a program that stores a value, and aborts on a zero divisor.
+        let program = Program::<CurrentNetwork>::from_str(
+            "
+program simulate.aleo;
+
+mapping quotients:
+    key as u8.public;
+    value as u8.public;
+
+function divide:
+    input r0 as u8.public;
+    async divide r0 into r1;
+    output r1 as simulate.aleo/divide.future;
+
+finalize divide:
+    input r0 as u8.public;
+    div 100u8 r0 into r1;
+    set r1 into quotients[r0];",
+        )
+        .unwrap();
+
+        // Deploy the program.
+        let credits = Some(unspent_records.pop().unwrap().decrypt(&caller_view_key).unwrap());
+        let deployment_transaction = vm.deploy(&caller_private_key, &program, credits, 10, None, rng).unwrap();
+        let deployment_block =
+            sample_next_block(&vm, &caller_private_key, &[deployment_transaction], &genesis, &mut unspent_records, rng)
+                .unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        let mapping_name = Identifier::from_str("quotients").unwrap();
+        for (divisor, expected) in [("4u8", Some("25u8")), ("0u8", None)] {
+            // Construct the execution.
+            let inputs = vec![Value::<CurrentNetwork>::from_str(divisor).unwrap()];
+            let transaction =
+                create_execution(&vm, caller_private_key, "simulate.aleo", "divide", inputs, &mut unspent_records, rng);
+
+            // Simulate the transaction.
+            let simulation = vm.simulate(&transaction, rng).unwrap();
+            assert_eq!(simulation.transaction_id(), transaction.id());
+            assert_eq!(simulation.cost(), execution_cost(&vm, transaction.execution().unwrap()).unwrap());
+            assert_eq!(simulation.fee().1, 1);
+            assert!(!simulation.operations().is_empty());
+
+            // Ensure the mapping diffs match the outcome of the finalize logic.
+            let key = Plaintext::from_str(divisor).unwrap();
+            let diffs =
+                simulation.mapping_diffs().iter().filter(|diff| diff.program_id() == program.id()).collect::<Vec<_>>();
+            match expected {
+                Some(expected) => {
+                    assert!(simulation.is_accepted());
+                    assert_eq!(diffs.len(), 1);
+                    assert_eq!(diffs[0].mapping_name(), &mapping_name);
+                    assert_eq!(diffs[0].key(), &key);
+                    assert_eq!(diffs[0].previous_value(), None);
+                    assert_eq!(diffs[0].value(), Some(&Value::from_str(expected).unwrap()));
+                }
+                None => {
+                    assert!(simulation.abort_reason().is_some());
+                    assert!(diffs.is_empty());
+                }
+            }
+            // Ensure the fee is charged in both cases.
+            assert!(simulation.mapping_diffs().iter().any(|diff| diff.program_id().to_string() == "credits.aleo"));
+
+            // Ensure the simulation did not modify the state.
+            assert!(vm.finalize_store().get_value_confirmed(*program.id(), mapping_name, &key).unwrap().is_none());
+            assert!(!vm.finalize_store().is_atomic_in_progress());
+        }
+    }
+
+    #[test]
+    fn test_trace_finalize() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key, view key, and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Create a program that branches on its input, and aborts on a zero input.
+        let program = Program::<CurrentNetwork>::from_str(
+            "
+program trace.aleo;
+
+mapping counts:
+    key as u8.public;
+    value as u8.public;
+
+function bump:
+    input r0 as u8.public;
+    async bump r0 into r1;
+    output r1 as trace.aleo/bump.future;
+
+finalize bump:
+    input r0 as u8.public;
+    get.or_use counts[r0] 0u8 into r1;
+    branch.eq r0 0u8 to end;
+    add r1 1u8 into r2;
+    set r2 into counts[r0];
+    position end;
+    div 1u8 r0 into r3;",
+        )
+        .unwrap();
+
+        // Deploy the program.
+        let credits = Some(unspent_records.pop().unwrap().decrypt(&caller_view_key).unwrap());
+        let deployment_transaction = vm.deploy(&caller_private_key, &program, credits, 10, None, rng).unwrap();
+        let deployment_block =
+            sample_next_block(&vm, &caller_private_key, &[deployment_transaction], &genesis, &mut unspent_records, rng)
+                .unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // A helper to construct an execution of `bump`.
+        let mut execute = |input: &str| {
+            let inputs = vec![Value::<CurrentNetwork>::from_str(input).unwrap()];
+            create_execution(&vm, caller_private_key, "trace.aleo", "bump", inputs, &mut unspent_records, rng)
+        };
+
+        // Trace an execution that succeeds.
+        let trace = vm.trace_finalize(&execute("3u8")).unwrap();
+        assert!(trace.abort_reason().is_none());
+        assert!(trace.failed_step().is_none());
+        assert!(!trace.operations().is_empty());
+        let steps = trace.steps().iter().filter(|step| step.program_id() == program.id()).collect::<Vec<_>>();
+        assert_eq!(steps.iter().map(|step| step.index()).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        assert!(steps.iter().all(|step| step.is_complete()));
+        // Ensure the mapping read, the branch, and the mapping write are recorded.
+        let key = Plaintext::from_str("3u8").unwrap();
+        let mapping_name = Identifier::from_str("counts").unwrap();
+        assert_eq!(
+            steps[0].mapping_access(),
+            Some(&MappingAccess::Read(*program.id(), mapping_name, key.clone(), None))
+        );
+        assert_eq!(steps[0].outputs()[0].1, Value::from_str("0u8").unwrap());
+        assert_eq!(steps[1].branch_taken(), Some(false));
+        assert_eq!(steps[1].operands(), &[
+            Some(Value::from_str("3u8").unwrap()),
+            Some(Value::from_str("0u8").unwrap())
+        ]);
+        assert_eq!(
+            steps[3].mapping_access(),
+            Some(&MappingAccess::Write(*program.id(), mapping_name, key.clone(), Value::from_str("1u8").unwrap()))
+        );
+        // Ensure the fee is traced.
+        assert!(trace.steps().iter().any(|step| step.program_id().to_string() == "credits.aleo"));
+        // Ensure the trace did not modify the state.
+        assert!(vm.finalize_store().get_value_confirmed(*program.id(), mapping_name, &key).unwrap().is_none());
+
+        // Trace an execution that aborts.
+        let trace = vm.trace_finalize(&execute("0u8")).unwrap();
+        assert!(trace.abort_reason().is_some());
+        assert!(trace.operations().is_empty());
+        let steps = trace.steps().iter().filter(|step| step.program_id() == program.id()).collect::<Vec<_>>();
+        assert_eq!(steps.iter().map(|step| step.index()).collect::<Vec<_>>(), vec![0, 1, 4, 5]);
+        assert_eq!(steps[1].branch_taken(), Some(true));
+        // Ensure the failed command is the division.
+        let failed_step = trace.failed_step().unwrap();
+        assert_eq!(failed_step.index(), 5);
+        assert!(!failed_step.is_complete());
+        assert!(!vm.finalize_store().is_atomic_in_progress());
+
+        // Confirm an execution in a block, and another execution in the next block.
+        let inputs = vec![Value::<CurrentNetwork>::from_str("3u8").unwrap()];
+        let execution =
+            create_execution(&vm, caller_private_key, "trace.aleo", "bump", inputs.clone(), &mut unspent_records, rng);
+        let block = sample_next_block(
+            &vm,
+            &caller_private_key,
+            &[execution.clone()],
+            &deployment_block,
+            &mut unspent_records,
+            rng,
+        )
+        .unwrap();
+        vm.add_next_block(&block).unwrap();
+        let next_execution =
+            create_execution(&vm, caller_private_key, "trace.aleo", "bump", inputs, &mut unspent_records, rng);
+        let next_block =
+            sample_next_block(&vm, &caller_private_key, &[next_execution], &block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&next_block).unwrap();
+        let count = Some(Value::from_str("2u8").unwrap());
+        assert_eq!(vm.finalize_store().get_value_confirmed(*program.id(), mapping_name, &key).unwrap(), count);
+
+        // Trace the confirmed execution, and ensure it is replayed against the state before its block.
+        let trace = vm.trace_finalize(&execution).unwrap();
+        assert!(trace.abort_reason().is_none());
+        assert_eq!(trace.operations(), block.transactions().finalize_operations().cloned().collect::<Vec<_>>());
+        let steps = trace.steps().iter().filter(|step| step.program_id() == program.id()).collect::<Vec<_>>();
+        assert_eq!(
+            steps[0].mapping_access(),
+            Some(&MappingAccess::Read(*program.id(), mapping_name, key.clone(), None))
+        );
+        assert_eq!(
+            steps[3].mapping_access(),
+            Some(&MappingAccess::Write(*program.id(), mapping_name, key.clone(), Value::from_str("1u8").unwrap()))
+        );
+        // Ensure the trace did not modify the state.
+        assert_eq!(vm.finalize_store().get_value_confirmed(*program.id(), mapping_name, &key).unwrap(), count);
+        assert!(!vm.finalize_store().is_atomic_in_progress());
+    }
+
     #[test]
     fn test_rejected_transaction_should_not_update_storage() {
         let rng = &mut TestRng::default();
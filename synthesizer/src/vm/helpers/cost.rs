@@ -19,16 +19,31 @@ use console::{
 };
 use ledger_block::{Deployment, Execution};
 use ledger_store::ConsensusStorage;
-use synthesizer_program::{Command, Finalize, Instruction};
+use synthesizer_program::{Command, Finalize, Instruction, Program};
 
 use std::collections::HashMap;
 
+/// The maximum cost in microcredits of the logic of a callback.
+/// Note: As the callback of an `invoke` command is only known at runtime, each `invoke` is charged this cost.
+pub const MAX_CALLBACK_COST: u64 = 1_000_000;
+
 /// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, namespace cost)).
 pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (u64, u64))> {
     // Determine the number of bytes in the deployment.
     let size_in_bytes = deployment.size_in_bytes()?;
     // Retrieve the program ID.
     let program_id = deployment.program_id();
+    // Ensure the cost of each callback is within the limit that an `invoke` command is charged.
+    // Note: This runs before the program is checked, which is safe as `Program::add_closure` ensures
+    // a closure only repeats the closures declared before it, so the repeated closures are acyclic.
+    for callback in deployment.program().callbacks().values() {
+        let cost = cost_in_microcredits(deployment.program(), callback.logic())?;
+        ensure!(
+            cost <= MAX_CALLBACK_COST,
+            "Callback '{program_id}/{}' costs {cost} microcredits, exceeding the limit of {MAX_CALLBACK_COST}",
+            callback.name()
+        );
+    }
     // Determine the number of characters in the program ID.
     let num_characters = u32::try_from(program_id.name().to_string().len())?;
 
@@ -80,7 +95,7 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
         let program = lookup.get(program_id).ok_or(anyhow!("Program '{program_id}' is missing"))?;
         // Retrieve the finalize cost.
         let cost = match program.get_function(function_name)?.finalize_logic() {
-            Some(finalize) => cost_in_microcredits(finalize)?,
+            Some(finalize) => cost_in_microcredits(program, finalize)?,
             None => continue,
         };
         // Accumulate the finalize cost.
@@ -98,9 +113,17 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
 }
 
 /// Returns the minimum number of microcredits required to run the finalize.
-pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
-    // Defines the cost of each command.
-    let cost = |command: &Command<N>| match command {
+pub fn cost_in_microcredits<N: Network>(program: &Program<N>, finalize: &Finalize<N>) -> Result<u64> {
+    finalize
+        .commands()
+        .iter()
+        .map(|command| command_cost(program, command))
+        .try_fold(0u64, |acc, res| res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed"))))
+}
+
+/// Returns the number of microcredits required to run the given command in finalize.
+fn command_cost<N: Network>(program: &Program<N>, command: &Command<N>) -> Result<u64> {
+    match command {
         Command::Instruction(Instruction::Abs(_)) => Ok(2_000),
         Command::Instruction(Instruction::AbsWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Add(_)) => Ok(2_000),
@@ -121,6 +144,8 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::Div(_)) => Ok(10_000),
         Command::Instruction(Instruction::DivWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Double(_)) => Ok(2_000),
+        Command::Instruction(Instruction::ECDSAVerifyDigest(_)) => Ok(500_000),
+        Command::Instruction(Instruction::ECDSAVerifyKeccak256(_)) => Ok(600_000),
         Command::Instruction(Instruction::GreaterThan(_)) => Ok(2_000),
         Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(2_000),
         Command::Instruction(Instruction::HashBHP256(_)) => Ok(100_000),
@@ -176,6 +201,17 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::PowWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Rem(_)) => Ok(2_000),
         Command::Instruction(Instruction::RemWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Repeat(repeat)) => {
+            // Retrieve the repeated closure.
+            let closure = program.get_closure(repeat.closure_name())?;
+            // Compute the cost of a single iteration.
+            let iteration_cost = closure.instructions().iter().try_fold(0u64, |acc, instruction| {
+                let cost = command_cost(program, &Command::Instruction(instruction.clone()))?;
+                acc.checked_add(cost).ok_or(anyhow!("Finalize cost overflowed"))
+            })?;
+            // Charge the cost of a single iteration for every iteration.
+            iteration_cost.checked_mul(u64::from(**repeat.count())).ok_or(anyhow!("Finalize cost overflowed"))
+        }
         Command::Instruction(Instruction::SignVerify(_)) => Ok(250_000),
         Command::Instruction(Instruction::Shl(_)) => Ok(2_000),
         Command::Instruction(Instruction::ShlWrapped(_)) => Ok(2_000),
@@ -191,17 +227,17 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         //  Expect these numbers to change as their usage is stabilized.
         Command::Await(_) => Ok(2_000),
         Command::Contains(_) => Ok(12_500),
+        // A read of a dynamic mapping also pays for resolving the program from its address.
+        Command::Get(get) if get.mapping().is_dynamic() => Ok(50_000),
         Command::Get(_) => Ok(25_000),
+        Command::GetOrUse(get_or_use) if get_or_use.mapping().is_dynamic() => Ok(50_000),
         Command::GetOrUse(_) => Ok(25_000),
+        // An `invoke` pays for resolving the program from its address, and for the callback at its maximum cost.
+        Command::Invoke(_) => Ok(50_000 + MAX_CALLBACK_COST),
         Command::RandChaCha(_) => Ok(25_000),
         Command::Remove(_) => Ok(10_000),
         Command::Set(_) => Ok(100_000),
         Command::BranchEq(_) | Command::BranchNeq(_) => Ok(5_000),
         Command::Position(_) => Ok(1_000),
-    };
-    finalize
-        .commands()
-        .iter()
-        .map(cost)
-        .try_fold(0u64, |acc, res| res.and_then(|x| acc.checked_add(x).ok_or(anyhow!("Finalize cost overflowed"))))
+    }
 }
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_store::MappingDiff;
+use synthesizer_program::FinalizeOperation;
+
+/// The outcome of a dry-run of a transaction against the current state of the VM.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Simulation<N: Network> {
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+    /// The finalize operations, including those of the fee.
+    operations: Vec<FinalizeOperation<N>>,
+    /// The changes to the mappings, including those of the fee.
+    mapping_diffs: Vec<MappingDiff<N>>,
+    /// The reason the finalize logic aborted, if it aborted.
+    abort_reason: Option<String>,
+    /// The minimum cost in microcredits, as `(total cost, (storage cost, finalize or namespace cost))`.
+    cost: (u64, (u64, u64)),
+    /// The base fee and priority fee in microcredits that are paid by the transaction.
+    fee: (u64, u64),
+}
+
+impl<N: Network> Simulation<N> {
+    /// Initializes a new simulation outcome.
+    pub(crate) const fn new(
+        transaction_id: N::TransactionID,
+        operations: Vec<FinalizeOperation<N>>,
+        mapping_diffs: Vec<MappingDiff<N>>,
+        abort_reason: Option<String>,
+        cost: (u64, (u64, u64)),
+        fee: (u64, u64),
+    ) -> Self {
+        Self { transaction_id, operations, mapping_diffs, abort_reason, cost, fee }
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> N::TransactionID {
+        self.transaction_id
+    }
+
+    /// Returns the finalize operations, including those of the fee.
+    pub fn operations(&self) -> &[FinalizeOperation<N>] {
+        &self.operations
+    }
+
+    /// Returns the changes to the mappings, including those of the fee.
+    pub fn mapping_diffs(&self) -> &[MappingDiff<N>] {
+        &self.mapping_diffs
+    }
+
+    /// Returns the reason the finalize logic aborted, if it aborted.
+    /// An aborted transaction is included in a block as rejected, and only its fee is finalized.
+    pub fn abort_reason(&self) -> Option<&str> {
+        self.abort_reason.as_deref()
+    }
+
+    /// Returns `true` if the transaction would be accepted.
+    pub const fn is_accepted(&self) -> bool {
+        self.abort_reason.is_none()
+    }
+
+    /// Returns the minimum cost in microcredits, as `(total cost, (storage cost, finalize cost))` for an execution,
+    /// and as `(total cost, (storage cost, namespace cost))` for a deployment.
+    pub const fn cost(&self) -> (u64, (u64, u64)) {
+        self.cost
+    }
+
+    /// Returns the base fee and priority fee in microcredits that are paid by the transaction.
+    pub const fn fee(&self) -> (u64, u64) {
+        self.fee
+    }
+}
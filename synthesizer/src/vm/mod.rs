@@ -19,6 +19,7 @@ mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod simulate;
 mod verify;
 
 use crate::{cast_mut_ref, cast_ref, process};
@@ -55,14 +56,15 @@ use ledger_store::{
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{Authorization, Process, Trace};
+use synthesizer_process::{Authorization, FinalizeTrace, Process, Trace};
 use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
+use synthesizer_snark::PreparedCheck;
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::{IndexMap, IndexSet};
 use lru::LruCache;
 use parking_lot::{Mutex, RwLock};
-use std::{num::NonZeroUsize, sync::Arc};
+use std::{collections::HashMap, num::NonZeroUsize, sync::Arc};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -98,56 +100,88 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
 
-        // A helper function to retrieve all the deployments.
+        // A helper function to retrieve all the deployments, including every edition of the program.
         fn load_deployment_and_imports<N: Network, T: TransactionStorage<N>>(
             process: &Process<N>,
             transaction_store: &TransactionStore<N, T>,
             transaction_id: N::TransactionID,
-        ) -> Result<Vec<(ProgramID<N>, Deployment<N>)>> {
-            // Retrieve the deployment from the transaction id.
-            let deployment = match transaction_store.get_deployment(&transaction_id)? {
-                Some(deployment) => deployment,
-                None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
+        ) -> Result<Vec<(Program<N>, u16, Option<Deployment<N>>)>> {
+            // Retrieve the program ID.
+            let Some(program_id) = transaction_store.get_program_id(&transaction_id)? else {
+                bail!("Deployment transaction '{transaction_id}' is not found in storage.");
             };
 
-            // Fetch the program from the deployment.
-            let program = deployment.program();
-            let program_id = program.id();
-
             // Return early if the program is already loaded.
-            if process.contains_program(program_id) {
+            if process.contains_program(&program_id) {
                 return Ok(vec![]);
             }
 
             // Prepare a vector for the deployments.
             let mut deployments = vec![];
 
-            // Iterate through the program imports.
-            for import_program_id in program.imports().keys() {
-                // Add the imports to the process if does not exist yet.
-                if !process.contains_program(import_program_id) {
-                    // Fetch the deployment transaction id.
-                    let Some(transaction_id) =
-                        transaction_store.deployment_store().find_transaction_id_from_program_id(import_program_id)?
-                    else {
-                        bail!("Transaction id for '{program_id}' is not found in storage.");
-                    };
-
-                    // Add the deployment and its imports found recursively.
-                    deployments.extend_from_slice(&load_deployment_and_imports(
-                        process,
-                        transaction_store,
-                        transaction_id,
-                    )?);
+            // Iterate through the editions of the program, so that upgrades are loaded after the original deployment.
+            for (edition, transaction_id) in transaction_store.get_edition_history(&program_id)? {
+                // Retrieve the program, and the deployment if it has not been pruned, from the transaction id.
+                let (program, deployment) = match transaction_store.is_pruned(&transaction_id)? {
+                    true => match transaction_store.get_program_for_edition(&program_id, edition)? {
+                        Some(program) => (program, None),
+                        None => bail!("Program '{program_id}' (edition {edition}) is not found in storage."),
+                    },
+                    false => match transaction_store.get_deployment(&transaction_id)? {
+                        Some(deployment) => (deployment.program().clone(), Some(deployment)),
+                        None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
+                    },
+                };
+
+                // Iterate through the program imports.
+                for import_program_id in program.imports().keys() {
+                    // Add the imports to the process if does not exist yet.
+                    if !process.contains_program(import_program_id) {
+                        // Fetch the deployment transaction id.
+                        let Some(transaction_id) = transaction_store
+                            .deployment_store()
+                            .find_transaction_id_from_program_id(import_program_id)?
+                        else {
+                            bail!("Transaction id for '{program_id}' is not found in storage.");
+                        };
+
+                        // Add the deployment and its imports found recursively.
+                        deployments.extend_from_slice(&load_deployment_and_imports(
+                            process,
+                            transaction_store,
+                            transaction_id,
+                        )?);
+                    }
                 }
-            }
 
-            // Once all the imports have been included, add the parent deployment.
-            deployments.push((*program_id, deployment));
+                // Once all the imports have been included, add the deployment of the edition.
+                deployments.push((program, edition, deployment));
+            }
 
             Ok(deployments)
         }
 
+        // A helper function to synthesize the keys of a program, whose verifying keys were pruned from storage.
+        fn synthesize_pruned_keys<N: Network>(process: &Process<N>, program: &Program<N>) -> Result<()> {
+            match N::ID {
+                console::network::Testnet3::ID => {
+                    // Cast the process and program.
+                    let process = cast_ref!(&process as Process<console::network::Testnet3>);
+                    let program = cast_ref!(&program as Program<console::network::Testnet3>);
+                    // Synthesize the proving and verifying key for each function.
+                    for function_name in program.functions().keys() {
+                        process.synthesize_key::<circuit::AleoV0, _>(
+                            program.id(),
+                            function_name,
+                            &mut rand::thread_rng(),
+                        )?;
+                    }
+                    Ok(())
+                }
+                _ => bail!("Unsupported VM configuration for network: {}", N::ID),
+            }
+        }
+
         // Retrieve the transaction store.
         let transaction_store = store.transaction_store();
         // Retrieve the list of deployment transaction IDs.
@@ -167,10 +201,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 })
                 .collect::<Result<Vec<_>>>()?;
 
-            for (program_id, deployment) in deployments.iter().flatten() {
-                // Load the deployment if it does not exist in the process yet.
-                if !process.contains_program(program_id) {
-                    process.load_deployment(deployment)?;
+            for (program, edition, deployment) in deployments.iter().flatten() {
+                // Load the deployment if the edition does not exist in the process yet.
+                let is_loaded = process.get_stack(program.id()).map_or(false, |stack| stack.edition() >= *edition);
+                if !is_loaded {
+                    match deployment {
+                        Some(deployment) => process.load_deployment(deployment)?,
+                        // If the deployment was pruned, add or upgrade the program and re-synthesize its keys.
+                        None => {
+                            match process.contains_program(program.id()) {
+                                true => process.upgrade_program(program)?,
+                                false => process.add_program(program)?,
+                            }
+                            synthesize_pruned_keys(&process, program)?;
+                        }
+                    }
                 }
             }
         }
@@ -267,6 +312,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         committee: Committee<N>,
         public_balances: IndexMap<Address<N>, u64>,
         rng: &mut R,
+    ) -> Result<Block<N>> {
+        self.genesis_quorum_inner(private_key, committee, public_balances, true, rng)
+    }
+
+    /// Returns a new genesis block for a quorum chain, whose transactions are not proven.
+    /// Note: This block is only valid for a development network, which does not verify the transactions.
+    pub fn genesis_quorum_unproven<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        committee: Committee<N>,
+        public_balances: IndexMap<Address<N>, u64>,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        self.genesis_quorum_inner(private_key, committee, public_balances, false, rng)
+    }
+
+    /// Returns a new genesis block for a quorum chain, whose transactions are proven if `prove` is set.
+    fn genesis_quorum_inner<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        committee: Committee<N>,
+        public_balances: IndexMap<Address<N>, u64>,
+        prove: bool,
+        rng: &mut R,
     ) -> Result<Block<N>> {
         // Retrieve the total stake.
         let total_stake = committee.total_stake();
@@ -292,7 +361,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let solutions = None; // The genesis block does not require solutions.
         // Prepare the transactions.
         let transactions = (0..Block::<N>::NUM_GENESIS_TRANSACTIONS)
-            .map(|_| self.execute(private_key, locator, inputs.iter(), None, 0, None, rng))
+            .map(|_| match prove {
+                true => self.execute(private_key, locator, inputs.iter(), None, 0, None, rng),
+                false => {
+                    // Authorize the call, and construct the execution from its transitions, without a proof.
+                    let authorization = self.authorize(private_key, locator.0, locator.1, inputs.iter(), rng)?;
+                    let state_root = self.block_store().current_state_root();
+                    let execution = Execution::from(authorization.transitions().into_values(), state_root, None)?;
+                    // Authorize the public fee, and construct the fee from its transition, without a proof.
+                    let (minimum_execution_cost, _) = execution_cost(self, &execution)?;
+                    let fee_authorization = self.authorize_fee_public(
+                        private_key,
+                        minimum_execution_cost,
+                        0,
+                        execution.to_execution_id()?,
+                        rng,
+                    )?;
+                    let fee_transition = fee_authorization
+                        .transitions()
+                        .into_values()
+                        .next()
+                        .ok_or_else(|| anyhow!("Missing the fee transition"))?;
+                    Transaction::from_execution(execution, Some(Fee::from(fee_transition, state_root, None)?))
+                }
+            })
             .collect::<Result<Vec<_>, _>>()?;
 
         // Construct the finalize state.
@@ -339,6 +431,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -388,6 +481,107 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
     }
+
+    /// Reverts the VM to the given block height, by removing every subsequent block (in descending order),
+    /// undoing the finalize operations of each block, and removing the programs deployed in each block.
+    /// Only the blocks within the revert depth of the storage can be reverted.
+    #[inline]
+    pub fn revert_to_height(&self, height: u32) -> Result<()> {
+        // Acquire the block lock, which is needed to ensure this function is not called concurrently.
+        // Note: This lock must be held for the entire scope of this function.
+        let _block_lock = self.block_lock.lock();
+
+        // Retrieve the latest block height.
+        let latest_height = match self.block_store().heights().max() {
+            Some(latest_height) => *latest_height,
+            None => bail!("Failed to revert to block {height} - the block store is empty"),
+        };
+        // Ensure the given height exists.
+        ensure!(height <= latest_height, "Failed to revert to block {height} - the latest block is {latest_height}");
+        // Ensure the blocks to revert are within the revert depth.
+        let revert_depth = self.finalize_store().revert_depth()?;
+        ensure!(
+            latest_height - height <= revert_depth,
+            "Failed to revert to block {height} - only the latest {revert_depth} blocks can be reverted"
+        );
+        // Ensure the finalize operations of every block to revert can be reverted.
+        // Note: This is checked upfront, as each block is reverted in its own batch, so a missing entry
+        // in the revert journal would otherwise leave the blocks partially reverted.
+        for block_height in height.saturating_add(1)..=latest_height {
+            ensure!(
+                self.finalize_store().get_revert_confirmed(block_height)?.is_some(),
+                "Failed to revert to block {height} - the finalize operations of block {block_height} cannot be reverted"
+            );
+        }
+
+        // Revert the blocks, in descending order.
+        for block_height in (height.saturating_add(1)..=latest_height).rev() {
+            // Retrieve the block hash.
+            let Some(block_hash) = self.block_store().get_block_hash(block_height)? else {
+                bail!("Failed to revert block {block_height} - missing block hash");
+            };
+            // Retrieve the block transactions.
+            let Some(transactions) = self.block_store().get_block_transactions(&block_hash)? else {
+                bail!("Failed to revert block {block_height} - missing block transactions");
+            };
+            // Retrieve the program IDs and editions of the accepted deployments.
+            let program_ids = transactions
+                .iter()
+                .filter(|confirmed| confirmed.is_accepted())
+                .filter_map(|confirmed| confirmed.transaction().deployment())
+                .map(|deployment| (*deployment.program_id(), deployment.edition()))
+                .collect::<Vec<_>>();
+
+            // Pause the atomic writes, so that both the finalize revert and block removal belong to a single batch.
+            #[cfg(feature = "rocks")]
+            self.block_store().pause_atomic_writes()?;
+
+            // First, revert the finalize operations, then remove the block.
+            match self.finalize_store().revert(block_height).and_then(|_| self.block_store().remove_last_n(1)) {
+                Ok(()) => {
+                    // Unpause the atomic writes, executing the ones queued from the revert and block removal.
+                    #[cfg(feature = "rocks")]
+                    self.block_store().unpause_atomic_writes::<false>()?;
+                }
+                Err(revert_error) => {
+                    #[cfg(feature = "rocks")]
+                    {
+                        // Clear all pending atomic operations so that unpausing the atomic writes
+                        // doesn't execute any of the queued storage operations.
+                        self.block_store().abort_atomic();
+                        self.finalize_store().abort_atomic();
+                        // Disable the atomic batch override.
+                        // Note: This call is guaranteed to succeed (without error), because `DISCARD_BATCH == true`.
+                        self.block_store().unpause_atomic_writes::<true>()?;
+                    }
+                    // Return the revert error.
+                    bail!("Failed to revert block {block_height} - {revert_error}")
+                }
+            }
+
+            // Lastly, remove the deployed programs from the process, in reverse order of deployment.
+            // If a deployment upgraded a program, the program is restored to its previous edition instead.
+            let mut process = self.process.write();
+            for (program_id, edition) in program_ids.iter().rev() {
+                match *edition > N::EDITION {
+                    true => {
+                        // Retrieve the deployment of the previous edition.
+                        let Some(transaction_id) =
+                            self.transaction_store().find_transaction_id_from_program_id(program_id)?
+                        else {
+                            bail!("Failed to revert block {block_height} - missing the previous edition of '{program_id}'")
+                        };
+                        let Some(deployment) = self.transaction_store().get_deployment(&transaction_id)? else {
+                            bail!("Failed to revert block {block_height} - missing the previous edition of '{program_id}'")
+                        };
+                        process.restore_deployment(&deployment)?;
+                    }
+                    false => process.remove_program(program_id)?,
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -400,7 +594,7 @@ pub(crate) mod test_helpers {
         types::Field,
     };
     use ledger_block::{Block, Header, Metadata, Transition};
-    use ledger_store::helpers::memory::ConsensusMemory;
+    use ledger_store::{helpers::memory::ConsensusMemory, StorageOptions, DEFAULT_REVERT_DEPTH};
     use synthesizer_program::Program;
 
     use indexmap::IndexMap;
@@ -411,7 +605,7 @@ pub(crate) mod test_helpers {
 
     /// Samples a new finalize state.
     pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-        FinalizeGlobalState::from(block_height as u64, block_height, [0u8; 32])
+        FinalizeGlobalState::from(block_height as u64, block_height, CurrentNetwork::GENESIS_TIMESTAMP, [0u8; 32])
     }
 
     pub(crate) fn sample_vm() -> VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
@@ -684,7 +878,7 @@ function compute:
 
         // Construct the new block header.
         let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) =
-            vm.speculate(sample_finalize_state(1), None, vec![], None, transactions.iter())?;
+            vm.speculate(sample_finalize_state(previous_block.height() + 1), None, vec![], None, transactions.iter())?;
         assert!(aborted_transaction_ids.is_empty());
 
         // Construct the metadata associated with the block.
@@ -989,6 +1183,148 @@ function a:
         assert!(VM::from(vm.store.clone()).is_ok());
     }
 
+    #[test]
+    fn test_pruning() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM, with a pruning depth of 2 blocks.
+        let vm = VM::from(ConsensusStore::open(StorageOptions::from(None).with_pruning(2)).unwrap()).unwrap();
+        assert_eq!(vm.block_store().pruning_depth().unwrap(), Some(2));
+        // Initialize the genesis block.
+        let genesis = vm.genesis_beacon(&caller_private_key, rng).unwrap();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Fetch the unspent record.
+        let records = genesis.transitions().cloned().flat_map(Transition::into_records).collect::<Vec<(_, _)>>();
+        let record = records[0].1.decrypt(&caller_view_key).unwrap();
+
+        // Deploy a program.
+        let program = Program::from_str(
+            r"
+program pruned_program.aleo;
+
+function c:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&caller_private_key, &program, Some(record), 0, None, rng).unwrap();
+        let deployment_block = sample_next_block(&vm, &caller_private_key, &[deployment.clone()], rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+        // Ensure no block has been pruned yet.
+        assert!(!vm.block_store().is_block_pruned(0).unwrap());
+
+        // Add a block, which prunes the genesis block.
+        let block = sample_next_block(&vm, &caller_private_key, &[], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.block_store().is_block_pruned(0).unwrap());
+        assert!(!vm.block_store().is_block_pruned(1).unwrap());
+        assert!(vm.block_store().get_block(&genesis.hash()).is_err());
+        assert_eq!(vm.block_store().get_block_header(&genesis.hash()).unwrap(), Some(*genesis.header()));
+
+        // Add a block, which prunes the deployment block.
+        let block = sample_next_block(&vm, &caller_private_key, &[], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.block_store().is_block_pruned(1).unwrap());
+        assert!(!vm.block_store().is_block_pruned(2).unwrap());
+        assert!(vm.block_store().get_block(&deployment_block.hash()).is_err());
+        assert!(vm.transaction_store().get_deployment(&deployment.id()).is_err());
+        assert!(vm.block_store().get_block(&block.hash()).unwrap().is_some());
+
+        // Ensure the VM reloads the pruned program, with the same verifying key.
+        let reloaded = VM::from(vm.store.clone()).unwrap();
+        assert!(reloaded.contains_program(program.id()));
+        let (function_name, (verifying_key, _)) = &deployment.deployment().unwrap().verifying_keys()[0];
+        assert_eq!(&reloaded.process().read().get_verifying_key(program.id(), function_name).unwrap(), verifying_key);
+
+        // Ensure the pruned blocks can be removed.
+        vm.block_store().remove_last_n(3).unwrap();
+        assert_eq!(*vm.block_store().heights().max().unwrap(), 0);
+        assert!(vm.block_store().is_block_pruned(0).unwrap());
+        assert!(!vm.block_store().is_block_pruned(1).unwrap());
+        assert!(!vm.transaction_store().contains_transaction_id(&deployment.id()).unwrap());
+    }
+
+    #[test]
+    fn test_history() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Ensure the history is disabled by default.
+        let vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None).unwrap()).unwrap();
+        assert_eq!(vm.finalize_store().history_start_height().unwrap(), None);
+
+        // Initialize the VM, with the history enabled.
+        let options = StorageOptions::from(None).with_history();
+        let vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(options).unwrap()).unwrap();
+        assert_eq!(vm.finalize_store().history_start_height().unwrap(), Some(0));
+        // Initialize the genesis block.
+        let genesis = vm.genesis_beacon(&caller_private_key, rng).unwrap();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Ensure the history records the accounts initialized in the genesis block.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let accounts = vm.finalize_store().get_mapping_confirmed(program_id, mapping_name).unwrap();
+        assert!(!accounts.is_empty());
+        for (key, value) in accounts {
+            let history = vm.finalize_store().history_iter(program_id, mapping_name, &key).unwrap().collect::<Vec<_>>();
+            assert_eq!(history, vec![(0, Some(value.clone()))]);
+            let value_at_genesis = vm.finalize_store().get_value_at_height(program_id, mapping_name, &key, 0).unwrap();
+            assert_eq!(value_at_genesis, Some(value));
+        }
+    }
+
+    #[test]
+    fn test_revert_depth() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Ensure the revert depth is capped by default.
+        let vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None).unwrap()).unwrap();
+        assert_eq!(vm.finalize_store().revert_depth().unwrap(), DEFAULT_REVERT_DEPTH);
+
+        // Initialize the VM, with a revert depth of 2 blocks.
+        let vm = VM::from(ConsensusStore::open(StorageOptions::from(None).with_revert_depth(2)).unwrap()).unwrap();
+        assert_eq!(vm.finalize_store().revert_depth().unwrap(), 2);
+        // Initialize the genesis block.
+        let genesis = vm.genesis_beacon(&caller_private_key, rng).unwrap();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Add 3 blocks.
+        for _ in 0..3 {
+            let block = sample_next_block(&vm, &caller_private_key, &[], rng).unwrap();
+            vm.add_next_block(&block).unwrap();
+        }
+        // Ensure the prior state is only retained for the latest 2 blocks.
+        for height in 0..2 {
+            assert!(vm.finalize_store().get_revert_confirmed(height).unwrap().is_none());
+        }
+        for height in 2..4 {
+            assert!(vm.finalize_store().get_revert_confirmed(height).unwrap().is_some());
+        }
+
+        // Ensure reverting beyond the revert depth fails, and leaves the VM unchanged.
+        assert!(vm.revert_to_height(1).is_err());
+        assert_eq!(*vm.block_store().heights().max().unwrap(), 3);
+        // Ensure reverting within the revert depth succeeds.
+        vm.revert_to_height(2).unwrap();
+        assert_eq!(*vm.block_store().heights().max().unwrap(), 2);
+    }
+
     #[test]
     fn test_multiple_external_calls() {
         let rng = &mut TestRng::default();
@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Performs a **dry-run** of the given transaction against the current state of the VM,
+    /// as if it were the only transaction in the next block.
+    ///
+    /// The transaction is first verified with `VM::check_transaction`. Then, its finalize logic
+    /// (and the fee) is run in an atomic batch that is aborted on completion, so no state is committed.
+    /// If the finalize logic aborts, the abort reason is returned, and only the fee is finalized,
+    /// as it would be for a rejected transaction.
+    pub fn simulate<R: CryptoRng + Rng>(&self, transaction: &Transaction<N>, rng: &mut R) -> Result<Simulation<N>> {
+        let timer = timer!("VM::simulate");
+
+        // Ensure the transaction is not a fee transaction.
+        ensure!(!transaction.is_fee(), "Cannot simulate a fee transaction");
+        // Verify the transaction.
+        self.check_transaction(transaction, None, rng)?;
+        lap!(timer, "Verify the transaction");
+
+        // Compute the cost of the transaction.
+        let cost = match transaction {
+            Transaction::Deploy(_, _, deployment, _) => deployment_cost(deployment)?,
+            Transaction::Execute(_, execution, _) => execution_cost(self, execution)?,
+            Transaction::Fee(..) => bail!("Cannot simulate a fee transaction"),
+        };
+        // Retrieve the base fee and priority fee.
+        let fee = match transaction.fee_transition() {
+            Some(fee) => (*fee.base_amount()?, *fee.priority_amount()?),
+            None => (0, 0),
+        };
+
+        // Construct the finalize state of the next block.
+        let state = self.next_finalize_state()?;
+
+        // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
+        // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
+        // Note: This lock must be held for the entire scope of the call to `atomic_finalize!`.
+        let _atomic_lock = self.atomic_lock.lock();
+
+        // Retrieve the finalize store.
+        let store = self.finalize_store();
+
+        // Perform the finalize operations, and abort the batch on completion.
+        let (operations, mapping_diffs, abort_reason) = atomic_finalize!(store, FinalizeMode::DryRun, {
+            // Acquire the write lock on the process.
+            let process = self.process.write();
+
+            // Finalize the transaction.
+            let outcome = match transaction {
+                Transaction::Deploy(_, _, deployment, fee) => {
+                    process.finalize_deployment(state, store, deployment, fee).map(|(_, operations)| operations)
+                }
+                Transaction::Execute(_, execution, fee) => {
+                    process.finalize_execution(state, store, execution, fee.as_ref())
+                }
+                Transaction::Fee(..) => return Err("Cannot simulate a fee transaction".to_string()),
+            };
+
+            // If the finalize logic aborted, finalize the fee.
+            let (operations, abort_reason) = match outcome {
+                Ok(operations) => (operations, None),
+                Err(error) => match transaction.fee_transition() {
+                    Some(fee) => match process.finalize_fee(state, store, &fee) {
+                        Ok(operations) => (operations, Some(error.to_string())),
+                        // Note: A transaction whose fee cannot be finalized is aborted from the block.
+                        Err(fee_error) => return Err(format!("Failed to finalize the fee - {fee_error}")),
+                    },
+                    None => return Err(format!("The transaction has no fee to finalize - {error}")),
+                },
+            };
+
+            // Retrieve the mapping diffs, before the batch is aborted.
+            let mapping_diffs = store.get_pending_mapping_diffs().map_err(|e| e.to_string())?;
+
+            Ok((operations, mapping_diffs, abort_reason))
+        })?;
+
+        finish!(timer, "Finished dry-run of the transaction");
+        Ok(Simulation::new(transaction.id(), operations, mapping_diffs, abort_reason, cost, fee))
+    }
+
+    /// Replays the finalize logic of the given execute transaction, and returns a trace of the evaluated commands.
+    ///
+    /// If the transaction is confirmed, it is replayed against the state before its block, by reverting
+    /// the finalize operations of its block and every subsequent block. Otherwise, it is replayed against
+    /// the current state of the VM, as if it were the only transaction in the next block.
+    ///
+    /// The transaction is **not** verified, and its finalize logic is run in an atomic batch that is
+    /// aborted on completion, so no state is committed.
+    pub fn trace_finalize(&self, transaction: &Transaction<N>) -> Result<FinalizeTrace<N>> {
+        let timer = timer!("VM::trace_finalize");
+
+        // Retrieve the execution and fee.
+        let (execution, fee) = match transaction {
+            Transaction::Execute(_, execution, fee) => (execution, fee),
+            _ => bail!("Cannot trace the finalize logic of a non-execute transaction"),
+        };
+
+        // Acquire the block lock, so that no blocks are added or reverted while the transaction is replayed.
+        let _block_lock = self.block_lock.lock();
+
+        // Determine the heights of the blocks to revert, and the finalize state to replay the transaction with.
+        let (revert_heights, state) = match self.block_store().find_block_hash(&transaction.id())? {
+            // If the transaction is confirmed, replay it against the state before its block.
+            Some(block_hash) => {
+                let Some(block_height) = self.block_store().get_block_height(&block_hash)? else {
+                    bail!("Failed to trace the transaction - missing the height of block '{block_hash}'")
+                };
+                let Some(header) = self.block_store().get_block_header(&block_hash)? else {
+                    bail!("Failed to trace the transaction - missing the header of block {block_height}")
+                };
+                let Some(previous_hash) = self.block_store().get_previous_block_hash(block_height)? else {
+                    bail!("Failed to trace the transaction - missing the previous hash of block {block_height}")
+                };
+                let Some(latest_height) = self.block_store().heights().max().map(|height| *height) else {
+                    bail!("Failed to trace the transaction - the VM has no blocks")
+                };
+                // Ensure the finalize operations of every block since the transaction can be reverted.
+                for height in block_height..=latest_height {
+                    ensure!(
+                        self.finalize_store().get_revert_confirmed(height)?.is_some(),
+                        "Failed to trace the transaction - the finalize operations of block {height} cannot be reverted"
+                    );
+                }
+                let state = FinalizeGlobalState::new::<N>(
+                    header.round(),
+                    header.height(),
+                    header.timestamp(),
+                    header.cumulative_weight(),
+                    header.cumulative_proof_target(),
+                    previous_hash,
+                )?;
+                ((block_height..=latest_height).rev().collect::<Vec<_>>(), state)
+            }
+            // Otherwise, replay it against the current state, as part of the next block.
+            None => (vec![], self.next_finalize_state()?),
+        };
+
+        // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
+        // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
+        // Note: This lock must be held for the entire scope of the call to `atomic_finalize!`.
+        let _atomic_lock = self.atomic_lock.lock();
+
+        // Retrieve the finalize store.
+        let store = self.finalize_store();
+
+        // Trace the finalize logic, and abort the batch on completion.
+        let trace = atomic_finalize!(store, FinalizeMode::DryRun, {
+            // Revert the finalize operations of the blocks, in descending order.
+            for height in revert_heights {
+                store.revert(height).map_err(|e| format!("Failed to revert block {height} - {e}"))?;
+            }
+            Ok(self.process.read().trace_finalize_execution(state, store, execution, fee.as_ref()))
+        })?;
+
+        finish!(timer, "Finished tracing the finalize logic");
+        Ok(trace)
+    }
+
+    /// Returns the finalize state of the block that follows the latest block.
+    fn next_finalize_state(&self) -> Result<FinalizeGlobalState> {
+        // Retrieve the latest block header.
+        let Some(latest_height) = self.block_store().heights().max().map(|height| *height) else {
+            bail!("Failed to simulate the transaction - the VM has no blocks")
+        };
+        let Some(latest_hash) = self.block_store().get_block_hash(latest_height)? else {
+            bail!("Failed to simulate the transaction - missing the hash of block {latest_height}")
+        };
+        let Some(latest_header) = self.block_store().get_block_header(&latest_hash)? else {
+            bail!("Failed to simulate the transaction - missing the header of block {latest_height}")
+        };
+        // Construct the finalize state.
+        FinalizeGlobalState::new::<N>(
+            latest_header.round().saturating_add(1),
+            latest_height.saturating_add(1),
+            latest_header.timestamp(),
+            latest_header.cumulative_weight(),
+            latest_header.cumulative_proof_target(),
+            latest_hash,
+        )
+    }
+}
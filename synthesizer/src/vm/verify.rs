@@ -14,6 +14,8 @@
 
 use super::*;
 
+use rand::{rngs::StdRng, SeedableRng};
+
 /// Ensures the given iterator has no duplicate elements, and that the ledger
 /// does not already contain a given item.
 macro_rules! ensure_is_unique {
@@ -39,6 +41,68 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         transaction: &Transaction<N>,
         rejected_id: Option<Field<N>>,
         rng: &mut R,
+    ) -> Result<()> {
+        self.check_transaction_inner(transaction, rejected_id, None, rng)
+    }
+
+    /// Verifies the given transactions in the VM, and checks the proofs and certificates of all of the transactions
+    /// with a single pairing product. If the combined check fails, each transaction is verified on its own,
+    /// to identify the invalid transaction. On failure, returns an error.
+    #[inline]
+    pub fn check_transactions<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[(&Transaction<N>, Option<Field<N>>)],
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("VM::check_transactions");
+
+        // Verify each transaction, up to the final pairing checks of its proofs and certificates.
+        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        let prepared_checks = cfg_iter!(transactions)
+            .zip(rngs)
+            .map(|((transaction, rejected_id), mut rng)| {
+                let mut prepared_checks = Vec::new();
+                match self.check_transaction_inner(transaction, *rejected_id, Some(&mut prepared_checks), &mut rng) {
+                    Ok(()) => Ok(prepared_checks),
+                    Err(error) => bail!("Transaction '{}' is invalid - {error}", transaction.id()),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let prepared_checks = prepared_checks.into_iter().flatten().collect::<Vec<_>>();
+        lap!(timer, "Prepare {} pairing checks", prepared_checks.len());
+
+        // Check the proofs and certificates of all of the transactions with a single pairing product.
+        if prepared_checks.is_empty() || PreparedCheck::verify_all(prepared_checks, rng) {
+            // Add the transaction IDs to the partially-verified transactions cache.
+            let mut partially_verified_transactions = self.partially_verified_transactions.write();
+            for (transaction, _) in transactions.iter().filter(|(transaction, _)| !transaction.is_fee()) {
+                partially_verified_transactions.push(transaction.id(), ());
+            }
+            finish!(timer, "Check the combined pairing product");
+            return Ok(());
+        }
+        lap!(timer, "Check the combined pairing product");
+
+        // Otherwise, verify each transaction on its own, to identify the invalid transaction.
+        for (transaction, rejected_id) in transactions {
+            if let Err(error) = self.check_transaction(transaction, *rejected_id, rng) {
+                bail!("Transaction '{}' is invalid - {error}", transaction.id())
+            }
+        }
+        finish!(timer, "Verify each transaction");
+        bail!("The combined pairing check of the transactions failed, but each transaction is valid on its own")
+    }
+
+    /// Verifies the transaction in the VM. On failure, returns an error.
+    ///
+    /// If `prepared_checks` is `Some`, the final pairing checks of the proofs and certificates are not performed,
+    /// and are added to `prepared_checks` instead, and the transaction is not added to the partially-verified cache.
+    fn check_transaction_inner<R: CryptoRng + Rng>(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        mut prepared_checks: Option<&mut Vec<PreparedCheck<N>>>,
+        rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("VM::check_transaction");
 
@@ -93,8 +157,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         lap!(timer, "Check for duplicate elements");
 
         // First, verify the fee.
-        self.check_fee(transaction, rejected_id)?;
+        self.check_fee_inner(transaction, rejected_id, prepared_checks.as_deref_mut())?;
 
+        // Determine if the final pairing checks are deferred.
+        let is_deferred = prepared_checks.is_some();
         // Check if the transaction exists in the partially-verified cache.
         let is_partially_verified = self.partially_verified_transactions.read().peek(&transaction.id()).is_some();
 
@@ -107,21 +173,41 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 };
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
-                // Ensure the edition is correct.
-                if deployment.edition() != N::EDITION {
-                    bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
-                }
-                // Ensure the program ID does not already exist in the store.
-                if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
-                }
-                // Ensure the program does not already exist in the process.
-                if self.contains_program(deployment.program_id()) {
-                    bail!("Program ID '{}' already exists", deployment.program_id());
+                // Retrieve the latest edition of the program, if it is already deployed.
+                let deployment_store = self.transaction_store().deployment_store();
+                match deployment_store.get_edition(deployment.program_id())? {
+                    // If the program is deployed, ensure this is an upgrade by the original owner.
+                    Some(latest_edition) => {
+                        // Ensure the edition is the next edition.
+                        if latest_edition.checked_add(1) != Some(deployment.edition()) {
+                            bail!(
+                                "Invalid deployment transaction '{id}' - expected edition {} for the upgrade",
+                                latest_edition.saturating_add(1)
+                            )
+                        }
+                        // Ensure the owner is the original owner of the program.
+                        // Note: Every edition is deployed by the original owner, so the latest owner is the original owner.
+                        let Some(original_owner) = deployment_store.get_owner(deployment.program_id())? else {
+                            bail!("Failed to find the owner of program '{}'", deployment.program_id())
+                        };
+                        if owner.address() != original_owner.address() {
+                            bail!("Invalid deployment transaction '{id}' - only the program owner can upgrade the program")
+                        }
+                    }
+                    None => {
+                        // Ensure the edition is correct.
+                        if deployment.edition() != N::EDITION {
+                            bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
+                        }
+                        // Ensure the program does not already exist in the process.
+                        if self.contains_program(deployment.program_id()) {
+                            bail!("Program ID '{}' already exists", deployment.program_id());
+                        }
+                    }
                 }
                 // Verify the deployment if it has not been verified before.
                 if !is_partially_verified {
-                    self.check_deployment_internal(deployment, rng)?;
+                    self.check_deployment_internal(deployment, prepared_checks, rng)?;
                 }
             }
             Transaction::Execute(id, execution, _) => {
@@ -134,14 +220,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Transaction '{id}' contains a previously rejected execution")
                 }
                 // Verify the execution.
-                self.check_execution_internal(execution, is_partially_verified)?;
+                self.check_execution_internal(execution, is_partially_verified, prepared_checks)?;
             }
             Transaction::Fee(..) => { /* no-op */ }
         }
 
-        // If the above checks have passed and this is not a fee transaction,
+        // If the above checks have passed, the proofs have been checked, and this is not a fee transaction,
         // then add the transaction ID to the partially-verified transactions cache.
-        if !matches!(transaction, Transaction::Fee(..)) && !is_partially_verified {
+        if !matches!(transaction, Transaction::Fee(..)) && !is_partially_verified && !is_deferred {
             self.partially_verified_transactions.write().push(transaction.id(), ());
         }
 
@@ -152,6 +238,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the `fee` in the given transaction. On failure, returns an error.
     #[inline]
     pub fn check_fee(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        self.check_fee_inner(transaction, rejected_id, None)
+    }
+
+    /// Verifies the `fee` in the given transaction. On failure, returns an error.
+    ///
+    /// If `prepared_checks` is `Some`, the final pairing check of the fee proof is not performed,
+    /// and is added to `prepared_checks` instead.
+    fn check_fee_inner(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        prepared_checks: Option<&mut Vec<PreparedCheck<N>>>,
+    ) -> Result<()> {
         match transaction {
             Transaction::Deploy(id, _, deployment, fee) => {
                 // Ensure the rejected ID is not present.
@@ -167,7 +266,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
                 }
                 // Verify the fee.
-                self.check_fee_internal(fee, deployment_id)?;
+                self.check_fee_internal(fee, deployment_id, prepared_checks)?;
             }
             Transaction::Execute(id, execution, fee) => {
                 // Ensure the rejected ID is not present.
@@ -195,7 +294,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         ensure!(*fee.base_amount()? == 0, "Transaction '{id}' has a non-zero base fee (execution)");
                     }
                     // Verify the fee.
-                    self.check_fee_internal(fee, execution_id)?;
+                    self.check_fee_internal(fee, execution_id, prepared_checks)?;
                 } else {
                     // Ensure the fee can be safely skipped.
                     ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
@@ -207,7 +306,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Transaction::Fee(id, fee) => {
                 // Verify the fee.
                 match rejected_id {
-                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id)?,
+                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id, prepared_checks)?,
                     None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
                 }
             }
@@ -221,14 +320,31 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///
     /// Note: This is an internal check only. To ensure all components of the deployment are checked,
     /// use `VM::check_transaction` instead.
+    ///
+    /// If `prepared_checks` is `Some`, the final pairing checks of the certificates are not performed,
+    /// and are added to `prepared_checks` instead.
     #[inline]
-    fn check_deployment_internal<R: CryptoRng + Rng>(&self, deployment: &Deployment<N>, rng: &mut R) -> Result<()> {
+    fn check_deployment_internal<R: CryptoRng + Rng>(
+        &self,
+        deployment: &Deployment<N>,
+        prepared_checks: Option<&mut Vec<PreparedCheck<N>>>,
+        rng: &mut R,
+    ) -> Result<()> {
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the deployment.
                 let deployment = cast_ref!(&deployment as Deployment<$network>);
                 // Verify the deployment.
-                $process.verify_deployment::<$aleo, _>(&deployment, rng)
+                match prepared_checks {
+                    Some(prepared_checks) => match $process.prepare_deployment::<$aleo, _>(&deployment, rng) {
+                        Ok(checks) => {
+                            prepared_checks.extend(cast_ref!(checks as Vec<PreparedCheck<N>>).iter().cloned());
+                            Ok(())
+                        }
+                        Err(error) => Err(error),
+                    },
+                    None => $process.verify_deployment::<$aleo, _>(&deployment, rng),
+                }
             }};
         }
 
@@ -243,14 +359,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///
     /// Note: This is an internal check only. To ensure all components of the execution are checked,
     /// use `VM::check_transaction` instead.
+    ///
+    /// If `prepared_checks` is `Some`, the final pairing check of the execution proof is not performed,
+    /// and is added to `prepared_checks` instead.
     #[inline]
-    fn check_execution_internal(&self, execution: &Execution<N>, is_partially_verified: bool) -> Result<()> {
+    fn check_execution_internal(
+        &self,
+        execution: &Execution<N>,
+        is_partially_verified: bool,
+        prepared_checks: Option<&mut Vec<PreparedCheck<N>>>,
+    ) -> Result<()> {
         let timer = timer!("VM::check_execution");
 
         // Verify the execution proof, if it has not been partially-verified before.
-        let verification = match is_partially_verified {
-            true => Ok(()),
-            false => self.process.read().verify_execution(execution),
+        let verification = match (is_partially_verified, prepared_checks) {
+            (true, _) => Ok(()),
+            (false, prepared_checks) => self.execution_editions(execution).and_then(|editions| {
+                let process = self.process.read();
+                match prepared_checks {
+                    Some(prepared_checks) => process
+                        .prepare_execution_with_editions(execution, &editions)
+                        .map(|check| prepared_checks.push(check)),
+                    None => process.verify_execution_with_editions(execution, &editions),
+                }
+            }),
         };
         lap!(timer, "Verify the execution");
 
@@ -268,12 +400,47 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         result
     }
 
+    /// Returns the editions of the programs in the given execution, as of the block of its global state root.
+    /// An execution may only be proven against a previous edition of a program if its global state root
+    /// is from before the program was upgraded. The programs that are not in the returned map,
+    /// such as the programs in the genesis block, are at their latest edition.
+    fn execution_editions(&self, execution: &Execution<N>) -> Result<HashMap<ProgramID<N>, u16>> {
+        // Retrieve the block height of the global state root.
+        let Some(height) = self.block_store().find_block_height_from_state_root(execution.global_state_root())? else {
+            bail!("Execution verification failed - global state root does not exist (yet)")
+        };
+
+        let mut editions = HashMap::new();
+        for transition in execution.transitions() {
+            let program_id = transition.program_id();
+            if editions.contains_key(program_id) {
+                continue;
+            }
+            // Retrieve the edition of the program that was active at the block height.
+            let edition_history = self.finalize_store().get_edition_history_confirmed(program_id)?;
+            if let Some((edition, _)) =
+                edition_history.into_iter().rev().find(|(_, upgrade_height)| *upgrade_height <= height)
+            {
+                editions.insert(*program_id, edition);
+            }
+        }
+        Ok(editions)
+    }
+
     /// Verifies the given fee. On failure, returns an error.
     ///
     /// Note: This is an internal check only. To ensure all components of the fee are checked,
     /// use `VM::check_fee` instead.
+    ///
+    /// If `prepared_checks` is `Some`, the final pairing check of the fee proof is not performed,
+    /// and is added to `prepared_checks` instead.
     #[inline]
-    fn check_fee_internal(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+    fn check_fee_internal(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        prepared_checks: Option<&mut Vec<PreparedCheck<N>>>,
+    ) -> Result<()> {
         let timer = timer!("VM::check_fee");
 
         // Ensure the fee does not exceed the limit.
@@ -281,7 +448,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ensure!(*fee_amount <= N::MAX_FEE, "Fee verification failed: fee exceeds the maximum limit");
 
         // Verify the fee.
-        let verification = self.process.read().verify_fee(fee, deployment_or_execution_id);
+        let verification = match prepared_checks {
+            Some(prepared_checks) => self
+                .process
+                .read()
+                .prepare_fee(fee, deployment_or_execution_id)
+                .map(|check| prepared_checks.push(check)),
+            None => self.process.read().verify_fee(fee, deployment_or_execution_id),
+        };
         lap!(timer, "Verify the fee");
 
         // TODO (howardwu): This check is technically insufficient. Consider moving this upstream
@@ -366,12 +540,66 @@ mod tests {
         let deployment = vm.deploy_raw(&program, rng).unwrap();
 
         // Ensure the deployment is valid.
-        vm.check_deployment_internal(&deployment, rng).unwrap();
+        vm.check_deployment_internal(&deployment, None, rng).unwrap();
 
         // Ensure that deserialization doesn't break the transaction verification.
         let serialized_deployment = deployment.to_string();
         let deployment_transaction: Deployment<CurrentNetwork> = serde_json::from_str(&serialized_deployment).unwrap();
-        vm.check_deployment_internal(&deployment_transaction, rng).unwrap();
+        vm.check_deployment_internal(&deployment_transaction, None, rng).unwrap();
+    }
+
+    #[test]
+    fn test_verify_deployment_with_self_repeating_closure() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm();
+
+        // Initialize a program, whose callback repeats a closure that repeats a prior closure.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program repeating.aleo;
+
+mapping counts:
+    key as u8.public;
+    value as u64.public;
+
+closure aaaa:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+closure bbbb:
+    input r0 as u64;
+    repeat 2u32 aaaa r0 into r1;
+    output r1 as u64;
+
+function run:
+    input r0 as u64.public;
+    repeat 2u32 bbbb r0 into r1;
+    output r1 as u64.public;
+
+callback count:
+    input r0 as address.public;
+    input r1 as u64.public;
+    repeat 2u32 bbbb r1 into r2;
+    set r2 into counts[0u8];",
+        )
+        .unwrap();
+
+        // Deploy the program.
+        let deployment = vm.deploy_raw(&program, rng).unwrap();
+        // Ensure the cost of the callback is computed.
+        assert!(deployment_cost(&deployment).is_ok());
+
+        // Rewrite the deployment, so that 'bbbb' repeats itself.
+        let mut bytes = deployment.to_bytes_le().unwrap();
+        let repeated = bytes.windows(5).enumerate().filter(|(_, window)| *window == b"\x04aaaa").nth(1).unwrap().0;
+        bytes[repeated + 1..repeated + 5].copy_from_slice(b"bbbb");
+        let string = deployment.to_string().replace("repeat 2u32 aaaa", "repeat 2u32 bbbb");
+
+        // Ensure the deployment is rejected when it is received, before its cost is computed.
+        let error = Deployment::<CurrentNetwork>::read_le(&bytes[..]).unwrap_err();
+        assert!(error.to_string().contains("can only repeat a closure declared before it"));
+        assert!(serde_json::from_str::<Deployment<CurrentNetwork>>(&string).is_err());
     }
 
     #[test]
@@ -391,13 +619,13 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(execution.proof().is_some());
                     // Verify the execution.
-                    vm.check_execution_internal(&execution, false).unwrap();
+                    vm.check_execution_internal(&execution, false, None).unwrap();
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_execution = execution.to_string();
                     let recovered_execution: Execution<CurrentNetwork> =
                         serde_json::from_str(&serialized_execution).unwrap();
-                    vm.check_execution_internal(&recovered_execution, false).unwrap();
+                    vm.check_execution_internal(&recovered_execution, false, None).unwrap();
                 }
                 _ => panic!("Expected an execution transaction"),
             }
@@ -423,12 +651,12 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(fee.proof().is_some());
                     // Verify the fee.
-                    vm.check_fee_internal(&fee, execution_id).unwrap();
+                    vm.check_fee_internal(&fee, execution_id, None).unwrap();
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_fee = fee.to_string();
                     let recovered_fee: Fee<CurrentNetwork> = serde_json::from_str(&serialized_fee).unwrap();
-                    vm.check_fee_internal(&recovered_fee, execution_id).unwrap();
+                    vm.check_fee_internal(&recovered_fee, execution_id, None).unwrap();
                 }
                 _ => panic!("Expected an execution with a fee"),
             }
@@ -459,6 +687,45 @@ mod tests {
         vm.check_transaction(&valid_transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_check_transactions() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch the transactions.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let private_fee_transaction = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let public_fee_transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+
+        // Construct a transaction with the proof of another execution of the same function.
+        let execution = public_fee_transaction.execution().unwrap();
+        let proof = private_fee_transaction.execution().unwrap().proof().cloned();
+        let invalid_execution =
+            Execution::from(execution.transitions().cloned(), execution.global_state_root(), proof).unwrap();
+        let invalid_transaction =
+            Transaction::from_execution(invalid_execution, public_fee_transaction.fee_transition()).unwrap();
+
+        // Ensure the combined check identifies the invalid transaction.
+        let error = vm
+            .check_transactions(
+                &[(&deployment_transaction, None), (&invalid_transaction, None), (&private_fee_transaction, None)],
+                rng,
+            )
+            .unwrap_err();
+        assert!(error.to_string().contains(&invalid_transaction.id().to_string()));
+        // Ensure the invalid transaction was not added to the partially-verified transactions cache.
+        assert!(vm.partially_verified_transactions.read().peek(&invalid_transaction.id()).is_none());
+
+        // Ensure the valid transactions verify together.
+        vm.check_transactions(
+            &[(&deployment_transaction, None), (&public_fee_transaction, None), (&private_fee_transaction, None)],
+            rng,
+        )
+        .unwrap();
+        // Ensure the valid transactions were added to the partially-verified transactions cache.
+        assert!(vm.partially_verified_transactions.read().peek(&public_fee_transaction.id()).is_some());
+    }
+
     #[test]
     fn test_verify_deploy_and_execute() {
         // Initialize the RNG.
@@ -555,6 +822,62 @@ mod tests {
         vm.check_transaction(&transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_verify_execution_against_edition_of_state_root() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Initialize the VM, and a VM that is not upgraded.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let stale_vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Returns the program, with the given instruction.
+        let program = |instruction: &str| {
+            Program::<CurrentNetwork>::from_str(&format!(
+                r"
+program upgradable.aleo;
+
+function compute:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    {instruction} r0 r1 into r2;
+    output r2 as u64.public;"
+            ))
+            .unwrap()
+        };
+        // Returns an execution of the program, proven against the state of the given VM.
+        let execute = |vm: &VM<_, _>, query: Option<Query<_, _>>, rng: &mut TestRng| {
+            let inputs = [Value::<CurrentNetwork>::from_str("3u64").unwrap(), Value::from_str("5u64").unwrap()];
+            vm.execute(&caller_private_key, ("upgradable.aleo", "compute"), inputs.iter(), None, 0, query, rng).unwrap()
+        };
+
+        // Deploy the original edition of the program.
+        let deployment = vm.deploy(&caller_private_key, &program("add"), None, 0, None, rng).unwrap();
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[deployment], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        stale_vm.add_next_block(&block).unwrap();
+
+        // Execute the original edition of the program.
+        let execution = execute(&vm, None, rng);
+        vm.check_transaction(&execution, None, rng).unwrap();
+
+        // Upgrade the program.
+        let upgrade = vm.deploy(&caller_private_key, &program("mul"), None, 0, None, rng).unwrap();
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[upgrade], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the execution, whose global state root is from before the upgrade, still verifies.
+        vm.check_transaction(&execution, None, rng).unwrap();
+        // Ensure an execution of the upgraded edition verifies.
+        vm.check_transaction(&execute(&vm, None, rng), None, rng).unwrap();
+
+        // Ensure an execution of the original edition, whose global state root is from after the upgrade, is rejected.
+        let stale_execution = execute(&stale_vm, Some(Query::from(vm.block_store().clone())), rng);
+        assert!(vm.check_transaction(&stale_execution, None, rng).is_err());
+    }
+
     #[test]
     fn test_failed_credits_deployment() {
         let rng = &mut TestRng::default();
@@ -518,6 +518,7 @@ fn construct_finalize_global_state<C: ConsensusStorage<CurrentNetwork>>(
     FinalizeGlobalState::new::<CurrentNetwork>(
         next_round,
         next_height,
+        CurrentNetwork::GENESIS_TIMESTAMP + 1,
         latest_cumulative_weight,
         0u128,
         latest_block.hash(),
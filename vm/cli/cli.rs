@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::cli::commands::{Build, Clean, Execute, New, Run, Update};
+use crate::cli::commands::{Account, Build, Clean, DebugFinalize, Deploy, Devnet, Execute, New, Run, Test, Update};
+
+#[cfg(not(target_family = "wasm"))]
+use crate::cli::commands::Parameters;
 
 use anstyle::{AnsiColor, Color, Style};
 use anyhow::Result;
@@ -38,16 +41,29 @@ pub struct CLI {
 
 #[derive(Debug, Parser)]
 pub enum Command {
+    #[clap(name = "account")]
+    Account(Account),
     #[clap(name = "build")]
     Build(Build),
     #[clap(name = "clean")]
     Clean(Clean),
+    #[clap(name = "debug-finalize")]
+    DebugFinalize(DebugFinalize),
+    #[clap(name = "deploy")]
+    Deploy(Deploy),
+    #[clap(name = "devnet")]
+    Devnet(Devnet),
     #[clap(name = "execute")]
     Execute(Execute),
     #[clap(name = "new")]
     New(New),
+    #[cfg(not(target_family = "wasm"))]
+    #[clap(name = "parameters")]
+    Parameters(Parameters),
     #[clap(name = "run")]
     Run(Run),
+    #[clap(name = "test")]
+    Test(Test),
     #[clap(name = "update")]
     Update(Update),
 }
@@ -56,11 +72,18 @@ impl Command {
     /// Parse the command.
     pub fn parse(self) -> Result<String> {
         match self {
+            Self::Account(command) => command.parse(),
             Self::Build(command) => command.parse(),
             Self::Clean(command) => command.parse(),
+            Self::DebugFinalize(command) => command.parse(),
+            Self::Deploy(command) => command.parse(),
+            Self::Devnet(command) => command.parse(),
             Self::Execute(command) => command.parse(),
             Self::New(command) => command.parse(),
+            #[cfg(not(target_family = "wasm"))]
+            Self::Parameters(command) => command.parse(),
             Self::Run(command) => command.parse(),
+            Self::Test(command) => command.parse(),
             Self::Update(command) => command.parse(),
         }
     }
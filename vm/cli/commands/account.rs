@@ -0,0 +1,271 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    cli::helpers::Keystore,
+    console::{
+        account::{Address, PrivateKey, Signature, ViewKey},
+        program::{Ciphertext, Record},
+    },
+};
+
+use anyhow::{bail, ensure};
+use clap::{Args, Subcommand};
+use rand::{rngs::StdRng, SeedableRng};
+use std::path::PathBuf;
+
+/// The characters of the bech32 alphabet, which an address may contain.
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Manages Aleo accounts
+#[derive(Debug, Parser)]
+pub struct Account {
+    #[clap(subcommand)]
+    command: AccountCommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum AccountCommand {
+    /// Generates a new account
+    New {
+        /// Samples the account from the specified seed, instead of from system randomness.
+        #[clap(long, conflicts_with = "vanity")]
+        seed: Option<u64>,
+        /// Samples accounts until the address starts with the specified prefix (after 'aleo1').
+        #[clap(long)]
+        vanity: Option<String>,
+        /// Writes the private key to the specified keystore file, instead of printing it.
+        #[clap(long)]
+        keystore: Option<PathBuf>,
+        /// The keystore password, which defaults to the KEYSTORE_PASSWORD environment variable.
+        #[clap(long, requires = "keystore")]
+        password: Option<String>,
+    },
+    /// Imports a private key into an encrypted keystore file
+    Import {
+        /// The private key.
+        private_key: PrivateKey<CurrentNetwork>,
+        /// Writes the private key to the specified keystore file.
+        #[clap(long)]
+        keystore: PathBuf,
+        /// The keystore password, which defaults to the KEYSTORE_PASSWORD environment variable.
+        #[clap(long)]
+        password: Option<String>,
+    },
+    /// Prints the view key of an account
+    ViewKey {
+        #[clap(flatten)]
+        key: KeyArgs,
+    },
+    /// Prints the address of an account
+    Address {
+        #[clap(flatten)]
+        key: KeyArgs,
+    },
+    /// Signs a message with the private key of an account
+    Sign {
+        /// The message.
+        message: String,
+        #[clap(flatten)]
+        key: KeyArgs,
+    },
+    /// Verifies the signature of a message for an address
+    Verify {
+        /// The address of the signer.
+        address: Address<CurrentNetwork>,
+        /// The message.
+        message: String,
+        /// The signature.
+        signature: String,
+    },
+    /// Decrypts a record ciphertext with the view key of an account
+    Decrypt {
+        /// The record ciphertext.
+        ciphertext: String,
+        /// Uses the specified view key, instead of the view key of the private key.
+        #[clap(long, conflicts_with_all = ["private_key", "keystore"])]
+        view_key: Option<ViewKey<CurrentNetwork>>,
+        #[clap(flatten)]
+        key: KeyArgs,
+    },
+}
+
+/// The source of the private key of an account.
+#[derive(Debug, Args)]
+struct KeyArgs {
+    /// Uses the specified private key, instead of the private key in the '.env' file.
+    #[clap(long, conflicts_with = "keystore")]
+    private_key: Option<PrivateKey<CurrentNetwork>>,
+    /// Uses the private key in the specified keystore file, instead of the private key in the '.env' file.
+    #[clap(long)]
+    keystore: Option<PathBuf>,
+    /// The keystore password, which defaults to the KEYSTORE_PASSWORD environment variable.
+    #[clap(long, requires = "keystore")]
+    password: Option<String>,
+}
+
+impl KeyArgs {
+    /// Returns the private key.
+    fn private_key(self) -> Result<PrivateKey<CurrentNetwork>> {
+        match (self.private_key, self.keystore) {
+            (Some(private_key), _) => Ok(private_key),
+            (None, Some(keystore)) => Keystore::open(&keystore)?.decrypt(&keystore_password(self.password)?),
+            (None, None) => crate::cli::helpers::dotenv_private_key(),
+        }
+    }
+}
+
+impl Account {
+    /// Runs the account subcommand.
+    pub fn parse(self) -> Result<String> {
+        match self.command {
+            AccountCommand::New { seed, vanity, keystore, password } => {
+                // Sample the private key.
+                let private_key = match (seed, vanity) {
+                    (Some(seed), _) => PrivateKey::new(&mut StdRng::seed_from_u64(seed))?,
+                    (None, Some(vanity)) => sample_vanity(&vanity)?,
+                    (None, None) => PrivateKey::new(&mut rand::thread_rng())?,
+                };
+                match keystore {
+                    Some(path) => {
+                        // Write the keystore.
+                        Keystore::encrypt(&private_key, &keystore_password(password)?, &mut rand::thread_rng())?
+                            .write_to(&path)?;
+                        Ok(format!(
+                            "{}\n\n✅ Stored the private key in \"{}\"",
+                            format_account(&private_key, false)?,
+                            path.display()
+                        ))
+                    }
+                    None => format_account(&private_key, true),
+                }
+            }
+            AccountCommand::Import { private_key, keystore, password } => {
+                // Write the keystore.
+                Keystore::encrypt(&private_key, &keystore_password(password)?, &mut rand::thread_rng())?
+                    .write_to(&keystore)?;
+                Ok(format!(
+                    "{}\n\n✅ Stored the private key in \"{}\"",
+                    format_account(&private_key, false)?,
+                    keystore.display()
+                ))
+            }
+            AccountCommand::ViewKey { key } => Ok(ViewKey::try_from(key.private_key()?)?.to_string()),
+            AccountCommand::Address { key } => Ok(Address::try_from(key.private_key()?)?.to_string()),
+            AccountCommand::Sign { message, key } => {
+                Ok(key.private_key()?.sign_bytes(message.as_bytes(), &mut rand::thread_rng())?.to_string())
+            }
+            AccountCommand::Verify { address, message, signature } => {
+                let signature = Signature::<CurrentNetwork>::from_str(&signature)?;
+                match signature.verify_bytes(&address, message.as_bytes()) {
+                    true => Ok(format!("✅ The signature is valid for '{}'", address.to_string().bold())),
+                    false => bail!("The signature is invalid for '{address}'"),
+                }
+            }
+            AccountCommand::Decrypt { ciphertext, view_key, key } => {
+                // Retrieve the view key.
+                let view_key = match view_key {
+                    Some(view_key) => view_key,
+                    None => ViewKey::try_from(key.private_key()?)?,
+                };
+                // Parse the record ciphertext.
+                let ciphertext = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_str(&ciphertext)?;
+                // Ensure the record belongs to the view key.
+                ensure!(ciphertext.is_owner(&view_key), "The record does not belong to the given view key");
+                Ok(ciphertext.decrypt(&view_key)?.to_string())
+            }
+        }
+    }
+}
+
+/// Returns the given keystore password, or the KEYSTORE_PASSWORD environment variable.
+fn keystore_password(password: Option<String>) -> Result<String> {
+    match password {
+        Some(password) => Ok(password),
+        None => std::env::var("KEYSTORE_PASSWORD")
+            .map_err(|_| anyhow::anyhow!("Specify the keystore password with '--password' or KEYSTORE_PASSWORD")),
+    }
+}
+
+/// Samples private keys until the address starts with 'aleo1' followed by the given prefix.
+fn sample_vanity(prefix: &str) -> Result<PrivateKey<CurrentNetwork>> {
+    // Ensure the prefix only contains bech32 characters.
+    if let Some(character) = prefix.chars().find(|c| !BECH32_CHARSET.contains(*c)) {
+        bail!("The vanity prefix contains '{character}', which is not in the address alphabet '{BECH32_CHARSET}'")
+    }
+    let address_prefix = format!("aleo1{prefix}");
+    let rng = &mut rand::thread_rng();
+    loop {
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        if Address::try_from(&private_key)?.to_string().starts_with(&address_prefix) {
+            return Ok(private_key);
+        }
+    }
+}
+
+/// Formats the keys of the account, including the private key if `with_private_key` is set.
+fn format_account(private_key: &PrivateKey<CurrentNetwork>, with_private_key: bool) -> Result<String> {
+    let mut output = String::new();
+    if with_private_key {
+        output += &format!("{:>12}  {private_key}\n", "Private Key".cyan().bold());
+    }
+    output += &format!("{:>12}  {}\n", "View Key".cyan().bold(), ViewKey::try_from(private_key)?);
+    output += &format!("{:>12}  {}", "Address".cyan().bold(), Address::try_from(private_key)?);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn test_account_new_seeded() {
+        let cli = CLI::parse_from(["snarkvm", "account", "new", "--seed", "1"]);
+        let Command::Account(account) = cli.command else { panic!("Unexpected result of clap parsing!") };
+
+        // Ensure the seeded account is deterministic.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut StdRng::seed_from_u64(1)).unwrap();
+        assert_eq!(account.parse().unwrap(), format_account(&private_key, true).unwrap());
+    }
+
+    #[test]
+    fn test_account_sign_and_verify() {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut StdRng::seed_from_u64(1)).unwrap();
+        let address = Address::try_from(&private_key).unwrap().to_string();
+
+        // Sign the message.
+        let cli = CLI::parse_from(["snarkvm", "account", "sign", "hello", "--private-key", &private_key.to_string()]);
+        let Command::Account(account) = cli.command else { panic!("Unexpected result of clap parsing!") };
+        let signature = account.parse().unwrap();
+
+        // Verify the signature.
+        let cli = CLI::parse_from(["snarkvm", "account", "verify", &address, "hello", &signature]);
+        let Command::Account(account) = cli.command else { panic!("Unexpected result of clap parsing!") };
+        assert!(account.parse().is_ok());
+
+        // Ensure the signature is invalid for another message.
+        let cli = CLI::parse_from(["snarkvm", "account", "verify", &address, "goodbye", &signature]);
+        let Command::Account(account) = cli.command else { panic!("Unexpected result of clap parsing!") };
+        assert!(account.parse().is_err());
+    }
+
+    #[test]
+    fn test_account_vanity() {
+        assert!(sample_vanity("b").is_err());
+        let private_key = sample_vanity("q").unwrap();
+        assert!(Address::try_from(&private_key).unwrap().to_string().starts_with("aleo1q"));
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    ledger::{
+        store::{helpers::memory::ConsensusMemory, ConsensusStorage},
+        Ledger,
+    },
+    prelude::{FromBytes, Network},
+    synthesizer::FinalizeTrace,
+};
+
+use anyhow::bail;
+use std::path::PathBuf;
+
+/// Replays the finalize logic of a transaction against a local ledger, and prints the trace
+#[derive(Debug, Parser)]
+pub struct DebugFinalize {
+    /// The ID of a transaction in the ledger, or the path of a transaction JSON file.
+    transaction: String,
+    /// Loads the ledger from the specified storage directory (requires the `rocks` feature).
+    #[clap(long, conflicts_with = "snapshot")]
+    storage: Option<PathBuf>,
+    /// Loads the ledger from the specified snapshot directory.
+    #[clap(long)]
+    snapshot: Option<PathBuf>,
+    /// Uses the genesis block in the specified file, instead of the genesis block of the network.
+    #[clap(long)]
+    genesis: Option<PathBuf>,
+    /// Prints the trace as JSON.
+    #[clap(long)]
+    json: bool,
+}
+
+impl DebugFinalize {
+    /// Replays the finalize logic of the transaction, and prints the trace.
+    pub fn parse(self) -> Result<String> {
+        // Load the genesis block.
+        let genesis = match &self.genesis {
+            Some(path) => Block::<CurrentNetwork>::from_bytes_le(&std::fs::read(path)?)?,
+            None => Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes())?,
+        };
+
+        // Load the ledger, and trace the transaction.
+        let trace = match (&self.storage, &self.snapshot) {
+            #[cfg(feature = "rocks")]
+            (Some(path), None) => {
+                use crate::ledger::store::helpers::rocksdb::ConsensusDB;
+                self.trace(&Ledger::<CurrentNetwork, ConsensusDB<_>>::load(genesis, path.clone())?)?
+            }
+            #[cfg(not(feature = "rocks"))]
+            (Some(_), None) => bail!("Loading the ledger from storage requires the 'rocks' feature"),
+            (None, Some(directory)) => {
+                let ledger =
+                    Ledger::<CurrentNetwork, ConsensusMemory<_>>::import_snapshot(genesis, None::<u16>, directory)?;
+                self.trace(&ledger)?
+            }
+            _ => bail!("Specify the ledger with either '--storage' or '--snapshot'"),
+        };
+
+        // Print the trace.
+        match self.json {
+            true => println!("{}", serde_json::to_string_pretty(&trace.to_json())?),
+            false => println!("{trace}\n"),
+        }
+
+        match trace.abort_reason() {
+            None => Ok(format!("✅ Finalize succeeded for '{}'", self.transaction.bold())),
+            Some(_) => Ok(format!("❌ Finalize aborted for '{}'", self.transaction.bold())),
+        }
+    }
+
+    /// Returns the trace of the finalize logic of the transaction, replayed against the state before its block
+    /// if it is confirmed, and against the latest state of the ledger otherwise.
+    fn trace<C: ConsensusStorage<CurrentNetwork>>(
+        &self,
+        ledger: &Ledger<CurrentNetwork, C>,
+    ) -> Result<FinalizeTrace<CurrentNetwork>> {
+        // Retrieve the transaction from the ledger, or read it from the file.
+        let transaction = match <CurrentNetwork as Network>::TransactionID::from_str(&self.transaction) {
+            Ok(transaction_id) => ledger.get_transaction(transaction_id)?,
+            Err(_) => Transaction::from_str(&std::fs::read_to_string(&self.transaction)?)?,
+        };
+        // Trace the finalize logic.
+        ledger.vm().trace_finalize(&transaction)
+    }
+}
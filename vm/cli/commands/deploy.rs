@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    ledger::{
+        query::Query,
+        store::{
+            helpers::memory::{BlockMemory, ConsensusMemory},
+            ConsensusStore,
+        },
+    },
+    prelude::{Plaintext, ProgramOwner, Record},
+    synthesizer::{deployment_cost, VM},
+};
+
+use anyhow::ensure;
+use std::path::PathBuf;
+
+/// Deploys an Aleo program
+#[derive(Debug, Parser)]
+pub struct Deploy {
+    /// Pays the fee privately with the specified credits record, instead of publicly.
+    #[clap(long)]
+    record: Option<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>,
+    /// The priority fee in microcredits, paid on top of the deployment cost.
+    #[clap(default_value = "0", long)]
+    priority_fee: u64,
+    /// Uses the specified endpoint to retrieve the state root for the fee.
+    #[clap(default_value = "https://api.explorer.aleo.org/v1", long)]
+    query: String,
+    /// Broadcasts the transaction to the specified endpoint.
+    #[clap(long, conflicts_with = "store")]
+    broadcast: Option<String>,
+    /// Writes the transaction to the specified file.
+    #[clap(long)]
+    store: Option<PathBuf>,
+    /// Prints the cost of the deployment, without constructing the transaction.
+    #[clap(long, conflicts_with_all = ["record", "broadcast", "store"])]
+    dry_run: bool,
+}
+
+impl Deploy {
+    /// Deploys the program in the current directory.
+    pub fn parse(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Load the package.
+        let package = Package::open(&path)?;
+
+        // Compute the deployment.
+        let deployment = package.deploy::<Aleo>(None)?;
+        // Ensure the deployment is not empty.
+        ensure!(!deployment.program().functions().is_empty(), "Attempted to deploy a program without functions");
+
+        // Compute the minimum deployment cost.
+        let (minimum_deployment_cost, (storage_cost, namespace_cost)) = deployment_cost(&deployment)?;
+        // Compute the total cost.
+        let total_cost = minimum_deployment_cost
+            .checked_add(self.priority_fee)
+            .ok_or_else(|| anyhow::anyhow!("The total cost computation overflowed for a deployment"))?;
+
+        // Log the cost breakdown.
+        use num_format::ToFormattedString;
+
+        println!("💰 Cost\n");
+        println!(" •  Storage: {} microcredits", storage_cost.to_formatted_string(LOCALE));
+        println!(" •  Namespace: {} microcredits", namespace_cost.to_formatted_string(LOCALE));
+        println!(" •  Priority: {} microcredits", self.priority_fee.to_formatted_string(LOCALE));
+        println!(" •  Total: {} microcredits\n", total_cost.to_formatted_string(LOCALE).bold());
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", path.display());
+
+        if self.dry_run {
+            return Ok(format!(
+                "✅ Estimated the cost of '{}' {}",
+                package.program_id().to_string().bold(),
+                path_string.dimmed()
+            ));
+        }
+
+        // Load the private key.
+        let private_key = crate::cli::helpers::dotenv_private_key()?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Compute the deployment ID.
+        let deployment_id = deployment.to_deployment_id()?;
+        // Construct the owner.
+        let owner = ProgramOwner::new(&private_key, deployment_id, rng)?;
+
+        // Initialize the VM, to authorize and execute the fee.
+        let vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None)?)?;
+        // Authorize the fee.
+        let authorization = match self.record {
+            Some(record) => vm.authorize_fee_private(
+                &private_key,
+                record,
+                minimum_deployment_cost,
+                self.priority_fee,
+                deployment_id,
+                rng,
+            )?,
+            None => {
+                vm.authorize_fee_public(&private_key, minimum_deployment_cost, self.priority_fee, deployment_id, rng)?
+            }
+        };
+        // Compute the fee.
+        let query = Query::<_, BlockMemory<_>>::from(self.query.as_str());
+        let fee = vm.execute_fee_authorization(authorization, Some(query), rng)?;
+
+        // Construct the transaction.
+        let transaction = Transaction::from_deployment(owner, deployment, fee)?;
+
+        match (self.broadcast, self.store) {
+            (Some(endpoint), None) => {
+                // Broadcast the transaction.
+                let response = ureq::post(&endpoint).send_json(&transaction)?;
+                ensure!(response.status() == 200, "Failed to broadcast the transaction: {}", response.into_string()?);
+                println!("📡 Broadcasted '{}' to {endpoint}\n", transaction.id());
+            }
+            (None, Some(file)) => {
+                // Write the transaction.
+                std::fs::write(&file, serde_json::to_string_pretty(&transaction)?)?;
+                println!("💾 Stored '{}' in \"{}\"\n", transaction.id(), file.display());
+            }
+            // Print the transaction.
+            _ => println!("{transaction}\n"),
+        }
+
+        Ok(format!("✅ Deployed '{}' {}", package.program_id().to_string().bold(), path_string.dimmed()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn clap_snarkvm_deploy() {
+        let arg_vec = vec!["snarkvm", "deploy", "--priority-fee", "100", "--store", "deployment.json"];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Deploy(deploy) = cli.command {
+            assert_eq!(deploy.priority_fee, 100);
+            assert_eq!(deploy.store, Some(PathBuf::from("deployment.json")));
+            assert!(deploy.record.is_none());
+            assert!(deploy.broadcast.is_none());
+            assert!(!deploy.dry_run);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn clap_snarkvm_deploy_conflicts() {
+        let arg_vec = vec!["snarkvm", "deploy", "--broadcast", "http://localhost:3030", "--store", "deployment.json"];
+        assert!(CLI::try_parse_from(arg_vec).is_err());
+
+        let arg_vec = vec!["snarkvm", "deploy", "--dry-run", "--store", "deployment.json"];
+        assert!(CLI::try_parse_from(arg_vec).is_err());
+    }
+}
@@ -0,0 +1,270 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    cli::helpers::dotenv_private_key,
+    ledger::{Devnet as Ledger, DevnetConfig},
+    prelude::{Address, Field, Network, Plaintext, PrivateKey},
+};
+
+use anyhow::{anyhow, bail};
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::Arc,
+    time::Duration,
+};
+
+/// Runs a local development network, which serves the REST endpoints used by the query
+#[derive(Debug, Parser)]
+pub struct Devnet {
+    /// The port to serve the REST endpoints on.
+    #[clap(default_value = "3030", long)]
+    port: u16,
+    /// The interval in seconds to produce blocks on, or `0` to only produce blocks on request.
+    #[clap(default_value = "0", long)]
+    interval: u64,
+    /// Skips proof generation for the genesis block, and proof verification for the transactions.
+    #[clap(long)]
+    no_proofs: bool,
+    /// Funds the account with the given public balance in microcredits, as `ADDRESS=AMOUNT`.
+    #[clap(long, value_parser = parse_allocation)]
+    balance: Vec<(Address<CurrentNetwork>, u64)>,
+    /// Adds the committee member with the given stake in microcredits, as `ADDRESS=STAKE`.
+    #[clap(long, value_parser = parse_allocation)]
+    validator: Vec<(Address<CurrentNetwork>, u64)>,
+    /// The private key of the block producer, which receives the remaining supply.
+    #[clap(long)]
+    private_key: Option<PrivateKey<CurrentNetwork>>,
+}
+
+impl Devnet {
+    /// Runs the development network, until the process is terminated.
+    pub fn parse(self) -> Result<String> {
+        // Load the private key of the block producer.
+        let private_key = match self.private_key {
+            Some(private_key) => private_key,
+            None => dotenv_private_key()?,
+        };
+
+        // Construct the configuration.
+        let mut config = DevnetConfig::new(private_key).with_proofs(!self.no_proofs);
+        for (address, stake) in self.validator {
+            config = config.with_validator(address, stake);
+        }
+        for (address, balance) in self.balance {
+            config = config.with_balance(address, balance);
+        }
+
+        // Initialize the devnet.
+        println!("⏳ Initializing the devnet...");
+        let devnet = Arc::new(Ledger::new(config, &mut rand::thread_rng())?);
+        println!("✅ Initialized the devnet with block producer '{}'", devnet.producer()?.to_string().bold());
+
+        // Spawn the block producer, if an interval is specified.
+        if self.interval > 0 {
+            devnet.spawn_block_producer(Duration::from_secs(self.interval));
+            println!("⛓  Producing a block every {} seconds", self.interval);
+        }
+
+        // Serve the REST endpoints.
+        let listener = TcpListener::bind(("127.0.0.1", self.port))?;
+        println!("🌐 Listening on http://{}/{}/", listener.local_addr()?, network_name());
+        for stream in listener.incoming() {
+            let devnet = devnet.clone();
+            std::thread::spawn(move || {
+                if let Err(error) = stream.map_err(Into::into).and_then(|stream| handle(&devnet, stream)) {
+                    eprintln!("⚠️  Failed to handle a request - {error}");
+                }
+            });
+        }
+        Ok(String::new())
+    }
+}
+
+/// Parses an allocation of the form `ADDRESS=AMOUNT`.
+fn parse_allocation(allocation: &str) -> Result<(Address<CurrentNetwork>, u64)> {
+    let (address, amount) =
+        allocation.split_once('=').ok_or_else(|| anyhow!("Expected an allocation of the form 'ADDRESS=AMOUNT'"))?;
+    Ok((Address::from_str(address)?, amount.replace('_', "").parse()?))
+}
+
+/// Returns the name of the network, as used in the REST paths.
+fn network_name() -> &'static str {
+    match CurrentNetwork::ID {
+        3 => "testnet3",
+        _ => "unknown",
+    }
+}
+
+/// Reads a single HTTP request from the given stream, and writes the response.
+fn handle(devnet: &Ledger<CurrentNetwork>, stream: TcpStream) -> Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    // Read the request line.
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => bail!("Malformed request line '{}'", request_line.trim()),
+    };
+
+    // Read the headers, to determine the length of the body.
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse()?;
+            }
+        }
+    }
+    // Read the body.
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+
+    // Route the request.
+    let (status, response) = match route(devnet, &method, &path, &String::from_utf8_lossy(&body)) {
+        Ok(Some(response)) => (200, response),
+        Ok(None) => (404, serde_json::to_string(&format!("Unknown route '{method} {path}'"))?),
+        Err(error) => (500, serde_json::to_string(&error.to_string())?),
+    };
+
+    // Write the response.
+    let reason = match status {
+        200 => "OK",
+        404 => "Not Found",
+        _ => "Internal Server Error",
+    };
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+        response.len()
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Returns the JSON response for the given request, or `None` if the route does not exist.
+fn route(devnet: &Ledger<CurrentNetwork>, method: &str, path: &str, body: &str) -> Result<Option<String>> {
+    let ledger = devnet.ledger();
+
+    // Strip the network prefix from the path.
+    let path = path.trim_end_matches('/');
+    let Some(path) = path.strip_prefix('/').and_then(|path| path.strip_prefix(network_name())) else {
+        return Ok(None);
+    };
+    let segments = path.split('/').filter(|segment| !segment.is_empty()).collect::<Vec<_>>();
+
+    let response = match (method, segments.as_slice()) {
+        ("GET", ["latest", "height"]) => serde_json::to_string(&ledger.latest_height())?,
+        ("GET", ["latest", "hash"]) => serde_json::to_string(&ledger.latest_hash())?,
+        ("GET", ["latest", "block"]) => serde_json::to_string(&ledger.latest_block())?,
+        ("GET", ["latest", "stateRoot"]) => serde_json::to_string(&ledger.latest_state_root())?,
+        ("GET", ["block", height]) => serde_json::to_string(&ledger.get_block(height.parse()?)?)?,
+        ("GET", ["transaction", id]) => serde_json::to_string(&ledger.get_transaction(FromStr::from_str(id)?)?)?,
+        ("GET", ["program", id]) => serde_json::to_string(&ledger.get_program(ProgramID::from_str(id)?)?)?,
+        ("GET", ["program", id, "mapping", name, key]) => {
+            let value = ledger.vm().finalize_store().get_value_confirmed(
+                ProgramID::from_str(id)?,
+                Identifier::from_str(name)?,
+                &Plaintext::from_str(key)?,
+            )?;
+            serde_json::to_string(&value)?
+        }
+        ("GET", ["statePath", commitment]) => {
+            serde_json::to_string(&ledger.get_state_path_for_commitment(&Field::from_str(commitment)?)?)?
+        }
+        ("POST", ["transaction", "broadcast"]) => {
+            let transaction: Transaction<CurrentNetwork> = serde_json::from_str(body)?;
+            serde_json::to_string(&devnet.submit_transaction(transaction, &mut rand::thread_rng())?)?
+        }
+        ("POST", ["devnet", "advance"]) => serde_json::to_string(&devnet.advance(&mut rand::thread_rng())?.height())?,
+        _ => return Ok(None),
+    };
+    Ok(Some(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cli::{Command, CLI},
+        utilities::TestRng,
+    };
+
+    #[test]
+    fn clap_snarkvm_devnet() {
+        let arg_vec = vec![
+            "snarkvm",
+            "devnet",
+            "--port",
+            "4040",
+            "--no-proofs",
+            "--balance",
+            "aleo1rhgdu77hgyqd3xjj8ucu3jj9r2krwz6mnzyd80gncr5fxcwlh5rsvzp9px=1_000_000",
+        ];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Devnet(devnet) = cli.command {
+            assert_eq!(devnet.port, 4040);
+            assert_eq!(devnet.interval, 0);
+            assert!(devnet.no_proofs);
+            assert_eq!(devnet.balance.len(), 1);
+            assert_eq!(devnet.balance[0].1, 1_000_000);
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+
+        // Ensure a malformed allocation is rejected.
+        assert!(CLI::try_parse_from(["snarkvm", "devnet", "--validator", "aleo1"]).is_err());
+    }
+
+    #[test]
+    fn test_devnet_routes() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the devnet, without proofs.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let devnet = Ledger::new(DevnetConfig::new(private_key).with_proofs(false), rng).unwrap();
+
+        // Ensure the routes consumed by the query are served.
+        assert_eq!(route(&devnet, "GET", "/testnet3/latest/height", "").unwrap().unwrap(), "0");
+        let state_root = route(&devnet, "GET", "/testnet3/latest/stateRoot", "").unwrap().unwrap();
+        assert_eq!(state_root, serde_json::to_string(&devnet.ledger().latest_state_root()).unwrap());
+        let program = route(&devnet, "GET", "/testnet3/program/credits.aleo", "").unwrap().unwrap();
+        assert!(program.contains("program credits.aleo;"));
+        let address = devnet.producer().unwrap();
+        let balance =
+            route(&devnet, "GET", &format!("/testnet3/program/credits.aleo/mapping/account/{address}"), "").unwrap();
+        assert!(balance.unwrap().ends_with("u64\""));
+
+        // Ensure blocks are produced on request.
+        assert_eq!(route(&devnet, "POST", "/testnet3/devnet/advance", "").unwrap().unwrap(), "1");
+        let block: Block<CurrentNetwork> =
+            serde_json::from_str(&route(&devnet, "GET", "/testnet3/block/1", "").unwrap().unwrap()).unwrap();
+        assert_eq!(block.hash(), devnet.ledger().latest_hash());
+
+        // Ensure unknown routes are not served.
+        assert!(route(&devnet, "GET", "/testnet3/unknown", "").unwrap().is_none());
+        assert!(route(&devnet, "GET", "/mainnet/latest/height", "").unwrap().is_none());
+        assert!(route(&devnet, "GET", "/testnet3/block/100", "").is_err());
+    }
+}
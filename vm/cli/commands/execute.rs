@@ -13,9 +13,24 @@
 // limitations under the License.
 
 use super::*;
+use crate::{
+    ledger::{
+        query::Query,
+        store::{
+            helpers::memory::{BlockMemory, ConsensusMemory},
+            ConsensusStore,
+        },
+    },
+    prelude::{Plaintext, Record},
+    synthesizer::{execution_cost, VM},
+};
+
+use anyhow::ensure;
+use clap::ArgGroup;
 
 /// Executes an Aleo program function locally
 #[derive(Debug, Parser)]
+#[clap(group(ArgGroup::new("payer").args(["fee_record", "public_fee"])))]
 pub struct Execute {
     /// The function name.
     function: Identifier<CurrentNetwork>,
@@ -27,6 +42,18 @@ pub struct Execute {
     /// Toggles offline mode.
     #[clap(long)]
     offline: bool,
+    /// The base fee in microcredits, which defaults to the minimum execution cost.
+    #[clap(long, requires = "payer")]
+    fee: Option<u64>,
+    /// The priority fee in microcredits, paid on top of the base fee.
+    #[clap(long, requires = "payer")]
+    priority_fee: Option<u64>,
+    /// Pays the fee privately with the specified credits record.
+    #[clap(long)]
+    fee_record: Option<Record<CurrentNetwork, Plaintext<CurrentNetwork>>>,
+    /// Pays the fee publicly from the account balance.
+    #[clap(long)]
+    public_fee: bool,
 }
 
 impl Execute {
@@ -46,10 +73,56 @@ impl Execute {
 
         // Execute the request.
         let (response, execution, metrics) =
-            package.execute::<Aleo, _>(self.endpoint, &private_key, self.function, &self.inputs, rng)?;
+            package.execute::<Aleo, _>(self.endpoint.clone(), &private_key, self.function, &self.inputs, rng)?;
+
+        // Compute the fee.
+        let fee = match self.fee_record.is_some() || self.public_fee {
+            true => {
+                // Initialize the VM, to compute the execution cost and to authorize and execute the fee.
+                let vm = VM::from(ConsensusStore::<CurrentNetwork, ConsensusMemory<_>>::open(None)?)?;
+                // Add the program and its imports to the VM.
+                {
+                    let process = vm.process();
+                    let mut process = process.write();
+                    for import_program in package.resolve_imports()? {
+                        process.add_program(&import_program)?;
+                    }
+                    process.add_program(package.program())?;
+                }
 
-        // TODO (howardwu): Include the option to execute a fee.
-        let fee = None;
+                // Compute the minimum execution cost.
+                let (minimum_execution_cost, (storage_cost, finalize_cost)) = execution_cost(&vm, &execution)?;
+                // Determine the base fee.
+                let base_fee = self.fee.unwrap_or(minimum_execution_cost);
+                ensure!(
+                    base_fee >= minimum_execution_cost,
+                    "The base fee ({base_fee} microcredits) is less than the minimum execution cost ({minimum_execution_cost} microcredits)"
+                );
+                // Determine the priority fee.
+                let priority_fee = self.priority_fee.unwrap_or(0);
+
+                // Log the cost breakdown.
+                println!("💰 Fee\n");
+                println!(" •  Storage: {} microcredits", storage_cost.to_formatted_string(LOCALE));
+                println!(" •  Finalize: {} microcredits", finalize_cost.to_formatted_string(LOCALE));
+                println!(" •  Base fee: {} microcredits", base_fee.to_formatted_string(LOCALE));
+                println!(" •  Priority fee: {} microcredits\n", priority_fee.to_formatted_string(LOCALE));
+
+                // Compute the execution ID.
+                let execution_id = execution.to_execution_id()?;
+                // Authorize the fee.
+                let authorization = match self.fee_record {
+                    Some(record) => {
+                        vm.authorize_fee_private(&private_key, record, base_fee, priority_fee, execution_id, rng)?
+                    }
+                    None => vm.authorize_fee_public(&private_key, base_fee, priority_fee, execution_id, rng)?,
+                };
+                // Execute the fee.
+                let query = Query::<_, BlockMemory<_>>::from(self.endpoint.as_str());
+                Some(vm.execute_fee_authorization(authorization, Some(query), rng)?)
+            }
+            false => None,
+        };
 
         // Construct the transaction.
         let transaction = Transaction::from_execution(execution, fee)?;
@@ -111,3 +184,36 @@ impl Execute {
         Ok(format!("✅ Executed '{}' {}", locator.to_string().bold(), path_string.dimmed()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn clap_snarkvm_execute_fee() {
+        let arg_vec = vec!["snarkvm", "execute", "hello", "1u32", "--public-fee", "--priority-fee", "100"];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Execute(execute) = cli.command {
+            assert!(execute.public_fee);
+            assert!(execute.fee_record.is_none());
+            assert_eq!(execute.fee, None);
+            assert_eq!(execute.priority_fee, Some(100));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn clap_snarkvm_execute_fee_conflicts() {
+        // The fee amounts require a payer.
+        let arg_vec = vec!["snarkvm", "execute", "hello", "1u32", "--fee", "100"];
+        assert!(CLI::try_parse_from(arg_vec).is_err());
+
+        // The fee is either private or public.
+        let record = "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, microcredits: 1u64.private, _nonce: 0group.public }";
+        let arg_vec = vec!["snarkvm", "execute", "hello", "--public-fee", "--fee-record", record];
+        assert!(CLI::try_parse_from(arg_vec).is_err());
+    }
+}
@@ -12,27 +12,47 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod account;
+pub use account::*;
+
 pub mod build;
 pub use build::*;
 
 pub mod clean;
 pub use clean::*;
 
+pub mod debug_finalize;
+pub use debug_finalize::*;
+
+pub mod deploy;
+pub use deploy::*;
+
+pub mod devnet;
+pub use devnet::*;
+
 pub mod execute;
 pub use execute::*;
 
 pub mod new;
 pub use new::*;
 
+#[cfg(not(target_family = "wasm"))]
+pub mod parameters;
+#[cfg(not(target_family = "wasm"))]
+pub use parameters::*;
+
 pub mod run;
 pub use run::*;
 
+pub mod test;
+pub use test::*;
+
 pub mod update;
 pub use update::*;
 
 use crate::{
     console::program::{Identifier, Locator, ProgramID, Value},
-    ledger::block::Transaction,
+    ledger::block::{Block, Transaction},
     package::Package,
 };
 
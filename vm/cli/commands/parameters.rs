@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::parameters::{ParameterConfig, ParameterManager, ParameterStatus};
+
+use anyhow::bail;
+use clap::Subcommand;
+use std::path::PathBuf;
+
+/// Manages the parameter files, which are otherwise fetched on first use
+#[derive(Debug, Parser)]
+pub struct Parameters {
+    /// Fetches missing files from the specified mirror, as a local directory, a file:// URL, or an HTTP(S) URL.
+    #[clap(long)]
+    mirror: Option<String>,
+    /// Fails instead of fetching missing files over the network.
+    #[clap(long)]
+    offline: bool,
+    #[clap(subcommand)]
+    command: ParametersCommand,
+}
+
+#[derive(Debug, Subcommand)]
+enum ParametersCommand {
+    /// Lists the parameter files, and whether they exist locally
+    List,
+    /// Fetches all missing parameter files
+    FetchAll,
+    /// Verifies the checksum and size of the local parameter files
+    Verify,
+    /// Exports the parameter files into a bundle directory
+    ExportBundle {
+        /// The bundle directory.
+        path: PathBuf,
+    },
+    /// Imports the parameter files from a bundle directory
+    ImportBundle {
+        /// The bundle directory.
+        path: PathBuf,
+    },
+}
+
+impl Parameters {
+    /// Manages the parameter files.
+    pub fn parse(self) -> Result<String> {
+        // Apply the mirror and offline mode, on top of the environment configuration.
+        let mut config = ParameterConfig::current();
+        if self.mirror.is_some() {
+            config = config.with_mirror(self.mirror);
+        }
+        if self.offline {
+            config = config.with_offline(true);
+        }
+        ParameterConfig::set_current(config);
+
+        let manager = ParameterManager::testnet3();
+        match self.command {
+            ParametersCommand::List => Ok(format_statuses(&manager, false)?.0),
+            ParametersCommand::FetchAll => {
+                let num_fetched = manager.fetch_all()?;
+                Ok(format!("✅ Fetched {num_fetched} parameter files into \"{}\"", manager.directory().display()))
+            }
+            ParametersCommand::Verify => match format_statuses(&manager, true)? {
+                (output, 0) => Ok(format!("{output}\n✅ Verified the parameter files")),
+                (output, num_invalid) => {
+                    println!("{output}");
+                    bail!("{num_invalid} parameter files are invalid, remove them and fetch them again")
+                }
+            },
+            ParametersCommand::ExportBundle { path } => {
+                let num_exported = manager.export_bundle(&path)?;
+                Ok(format!("✅ Exported {num_exported} parameter files into \"{}\"", path.display()))
+            }
+            ParametersCommand::ImportBundle { path } => {
+                let num_imported = manager.import_bundle(&path)?;
+                Ok(format!("✅ Imported {num_imported} parameter files into \"{}\"", manager.directory().display()))
+            }
+        }
+    }
+}
+
+/// Returns the status of each parameter file, and the number of invalid files.
+fn format_statuses(manager: &ParameterManager, verify_checksum: bool) -> Result<(String, usize)> {
+    let statuses = match verify_checksum {
+        true => manager.verify()?,
+        false => manager.list()?,
+    };
+    let mut num_invalid = 0;
+    let mut output = format!("📦 Parameter files (in \"{}\")\n\n", manager.directory().display());
+    for (file, status) in statuses {
+        let status = match status {
+            ParameterStatus::Valid => "valid".green().to_string(),
+            ParameterStatus::Missing => "missing".dimmed().to_string(),
+            ParameterStatus::Invalid(error) => {
+                num_invalid += 1;
+                format!("{} ({error})", "invalid".red())
+            }
+        };
+        output += &format!(" •  {} ({} bytes) - {status}\n", file.filename()?, file.size()?);
+    }
+    Ok((output, num_invalid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn clap_snarkvm_parameters() {
+        let arg_vec =
+            vec!["snarkvm", "parameters", "--offline", "--mirror", "file:///mirror", "export-bundle", "bundle"];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Parameters(parameters) = cli.command {
+            assert!(parameters.offline);
+            assert_eq!(parameters.mirror, Some("file:///mirror".to_string()));
+            assert!(
+                matches!(parameters.command, ParametersCommand::ExportBundle { path } if path == PathBuf::from("bundle"))
+            );
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+
+        // Ensure the subcommands are parsed.
+        for command in ["list", "fetch-all", "verify"] {
+            assert!(CLI::try_parse_from(["snarkvm", "parameters", command]).is_ok());
+        }
+        assert!(CLI::try_parse_from(["snarkvm", "parameters", "import-bundle"]).is_err());
+    }
+}
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use super::*;
+use crate::synthesizer::process::ConstraintProfile;
+
+use std::path::PathBuf;
 
 /// Runs an Aleo program function
 #[derive(Debug, Parser)]
@@ -21,6 +24,10 @@ pub struct Run {
     function: Identifier<CurrentNetwork>,
     /// The function inputs.
     inputs: Vec<Value<CurrentNetwork>>,
+    /// Writes a per-instruction constraint profile to the specified directory,
+    /// as a folded stack file for flame graphs and as a JSON table.
+    #[clap(long)]
+    profile: Option<PathBuf>,
 }
 
 impl Run {
@@ -76,6 +83,20 @@ impl Run {
             println!(" •  {function_constraints} {counter_string}",)
         }
 
+        // Write the constraint profile.
+        if let Some(directory) = &self.profile {
+            let profile = ConstraintProfile::new(&metrics)?;
+            std::fs::create_dir_all(directory)?;
+            let folded_path = directory.join(format!("{}.folded", self.function));
+            std::fs::write(&folded_path, profile.to_folded_stacks())?;
+            let json_path = directory.join(format!("{}.json", self.function));
+            std::fs::write(&json_path, serde_json::to_string_pretty(&profile.to_json())?)?;
+
+            println!("\n📊 Profile\n");
+            println!(" •  {}", folded_path.display());
+            println!(" •  {}", json_path.display());
+        }
+
         // Log the outputs.
         match response.outputs().len() {
             0 => (),
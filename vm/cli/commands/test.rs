@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use anyhow::bail;
+
+/// Runs the test cases of an Aleo program
+#[derive(Debug, Parser)]
+pub struct Test {
+    /// Only runs the test cases whose names contain the specified filter.
+    filter: Option<String>,
+    /// Also proves and verifies each execution, before it is finalized.
+    #[clap(long)]
+    prove: bool,
+}
+
+impl Test {
+    /// Runs the test cases in the tests directory of the package.
+    pub fn parse(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Load the package.
+        let package = Package::open(&path)?;
+        // Load the private key.
+        let private_key = crate::cli::helpers::dotenv_private_key()?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Run the test cases.
+        let results = package.test::<Aleo, _>(&private_key, self.filter.as_deref(), self.prove, rng)?;
+
+        // Log the results.
+        println!("🧪 Tests\n");
+        for result in &results {
+            match result.is_success() {
+                true => println!(" •  {} {}", result.name().bold(), format!("({})", result.file()).dimmed()),
+                false => {
+                    println!(
+                        " •  {} {} {}",
+                        result.name().bold(),
+                        format!("({})", result.file()).dimmed(),
+                        "FAILED".red()
+                    );
+                    for failure in result.failures() {
+                        println!("      {failure}");
+                    }
+                }
+            }
+        }
+        println!();
+
+        // Count the failed test cases.
+        let num_failed = results.iter().filter(|result| !result.is_success()).count();
+        match num_failed {
+            0 => {
+                Ok(format!("✅ Passed {} test cases for '{}'", results.len(), package.program_id().to_string().bold()))
+            }
+            _ => bail!("Failed {num_failed} of {} test cases for '{}'", results.len(), package.program_id()),
+        }
+    }
+}
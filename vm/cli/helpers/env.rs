@@ -45,6 +45,11 @@ pub fn dotenv_private_key() -> Result<PrivateKey<CurrentNetwork>> {
     } else {
         use std::str::FromStr;
         dotenv_load()?;
+        // Load the private key from the keystore, if one is specified in the environment.
+        if let Ok(keystore) = dotenvy::var("KEYSTORE") {
+            let password = dotenvy::var("KEYSTORE_PASSWORD").map_err(|e| anyhow!("Missing KEYSTORE_PASSWORD - {e}"))?;
+            return super::Keystore::open(std::path::Path::new(&keystore))?.decrypt(&password);
+        }
         // Load the private key from the environment.
         let private_key = dotenvy::var("PRIVATE_KEY").map_err(|e| anyhow!("Missing PRIVATE_KEY - {e}"))?;
         // Parse the private key.
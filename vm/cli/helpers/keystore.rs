@@ -0,0 +1,277 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    cli::CurrentNetwork,
+    console::{
+        account::PrivateKey,
+        prelude::{DeserializeExt, FromBytes, ToBytes},
+    },
+    prelude::{de, Deserialize, Deserializer, Serialize, SerializeStruct, Serializer},
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305,
+};
+use rand::{CryptoRng, Rng};
+use std::{fs::OpenOptions, io::Write, path::Path};
+
+/// The version of the keystore format.
+const KEYSTORE_VERSION: u8 = 1;
+
+/// The Argon2id memory cost of the key derivation, in KiB.
+const MEMORY_COST: u32 = 64 * 1024;
+/// The Argon2id time cost of the key derivation, in iterations.
+const TIME_COST: u32 = 3;
+/// The Argon2id degree of parallelism of the key derivation.
+const PARALLELISM: u32 = 1;
+
+/// The maximum Argon2id memory cost of a keystore file, in KiB.
+const MAX_MEMORY_COST: u32 = 1024 * 1024;
+/// The maximum Argon2id time cost of a keystore file, in iterations.
+const MAX_TIME_COST: u32 = 16;
+/// The maximum Argon2id degree of parallelism of a keystore file.
+const MAX_PARALLELISM: u32 = 16;
+
+/// The number of bytes in the salt of the key derivation.
+const SALT_LENGTH: usize = 16;
+/// The number of bytes in the nonce of the encryption.
+const NONCE_LENGTH: usize = 12;
+
+/// An encrypted private key, stored as a JSON file.
+///
+/// The encryption key is derived from the password and a random salt with Argon2id, and the private key
+/// is encrypted with ChaCha20-Poly1305. The version and key derivation parameters are authenticated with
+/// the ciphertext, so a wrong password or a modified file fails to decrypt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Keystore {
+    /// The Argon2id memory cost of the key derivation, in KiB.
+    memory_cost: u32,
+    /// The Argon2id time cost of the key derivation, in iterations.
+    time_cost: u32,
+    /// The Argon2id degree of parallelism of the key derivation.
+    parallelism: u32,
+    /// The salt of the key derivation.
+    salt: [u8; SALT_LENGTH],
+    /// The nonce of the encryption.
+    nonce: [u8; NONCE_LENGTH],
+    /// The encrypted private key, including the authentication tag.
+    ciphertext: Vec<u8>,
+}
+
+impl Keystore {
+    /// Encrypts the given private key with the given password.
+    pub fn encrypt<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<CurrentNetwork>,
+        password: &str,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Initialize the keystore, with a random salt and nonce.
+        let mut keystore = Self {
+            memory_cost: MEMORY_COST,
+            time_cost: TIME_COST,
+            parallelism: PARALLELISM,
+            salt: rng.gen(),
+            nonce: rng.gen(),
+            ciphertext: Vec::new(),
+        };
+        // Derive the encryption key.
+        let cipher = keystore.cipher(password)?;
+        // Encrypt the private key.
+        let payload = Payload { msg: &private_key.to_bytes_le()?, aad: &keystore.associated_data() };
+        keystore.ciphertext = cipher
+            .encrypt(&keystore.nonce.into(), payload)
+            .map_err(|_| anyhow!("Failed to encrypt the private key"))?;
+        Ok(keystore)
+    }
+
+    /// Decrypts the private key with the given password.
+    pub fn decrypt(&self, password: &str) -> Result<PrivateKey<CurrentNetwork>> {
+        // Derive the encryption key.
+        let cipher = self.cipher(password)?;
+        // Decrypt the private key.
+        let payload = Payload { msg: &self.ciphertext, aad: &self.associated_data() };
+        let private_key = cipher
+            .decrypt(&self.nonce.into(), payload)
+            .map_err(|_| anyhow!("Invalid password, or the keystore file is corrupted"))?;
+        PrivateKey::from_bytes_le(&private_key)
+    }
+
+    /// Loads the keystore from the given file.
+    pub fn open(path: &Path) -> Result<Self> {
+        ensure!(path.exists(), "The keystore file '{}' does not exist", path.display());
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Writes the keystore to the given file, which is only readable and writable by the owner.
+    pub fn write_to(&self, path: &Path) -> Result<()> {
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        // Create the file, ensuring it does not exist yet.
+        let mut file = options.open(path).map_err(|error| match error.kind() {
+            std::io::ErrorKind::AlreadyExists => anyhow!("The keystore file '{}' already exists", path.display()),
+            _ => anyhow!("Failed to create the keystore file '{}' - {error}", path.display()),
+        })?;
+        Ok(file.write_all(serde_json::to_string_pretty(self)?.as_bytes())?)
+    }
+
+    /// Returns the cipher, with the encryption key derived from the given password.
+    fn cipher(&self, password: &str) -> Result<ChaCha20Poly1305> {
+        ensure!(!password.is_empty(), "The keystore password must not be empty");
+        // Ensure the key derivation parameters are bounded, as they are read from the keystore file.
+        if self.memory_cost > MAX_MEMORY_COST || self.time_cost > MAX_TIME_COST || self.parallelism > MAX_PARALLELISM {
+            bail!("The keystore key derivation parameters exceed the maximum (m={MAX_MEMORY_COST}, t={MAX_TIME_COST}, p={MAX_PARALLELISM})")
+        }
+        let params = Params::new(self.memory_cost, self.time_cost, self.parallelism, Some(32))
+            .map_err(|error| anyhow!("Invalid keystore key derivation parameters - {error}"))?;
+        // Derive the encryption key.
+        let mut key = [0u8; 32];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(password.as_bytes(), &self.salt, &mut key)
+            .map_err(|error| anyhow!("Failed to derive the keystore encryption key - {error}"))?;
+        Ok(ChaCha20Poly1305::new(&key.into()))
+    }
+
+    /// Returns the associated data of the encryption, which binds the format and key derivation parameters.
+    fn associated_data(&self) -> Vec<u8> {
+        let mut associated_data = vec![KEYSTORE_VERSION];
+        associated_data.extend_from_slice(&self.memory_cost.to_le_bytes());
+        associated_data.extend_from_slice(&self.time_cost.to_le_bytes());
+        associated_data.extend_from_slice(&self.parallelism.to_le_bytes());
+        associated_data
+    }
+}
+
+impl Serialize for Keystore {
+    /// Serializes the keystore into JSON.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut keystore = serializer.serialize_struct("Keystore", 7)?;
+        keystore.serialize_field("version", &KEYSTORE_VERSION)?;
+        keystore.serialize_field("memory_cost", &self.memory_cost)?;
+        keystore.serialize_field("time_cost", &self.time_cost)?;
+        keystore.serialize_field("parallelism", &self.parallelism)?;
+        keystore.serialize_field("salt", &hex::encode(self.salt))?;
+        keystore.serialize_field("nonce", &hex::encode(self.nonce))?;
+        keystore.serialize_field("ciphertext", &hex::encode(&self.ciphertext))?;
+        keystore.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Keystore {
+    /// Deserializes the keystore from JSON.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut keystore = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the keystore version is supported.
+        let version: u8 = DeserializeExt::take_from_value::<D>(&mut keystore, "version")?;
+        if version != KEYSTORE_VERSION {
+            return Err(de::Error::custom(format!("Unsupported keystore version {version}")));
+        }
+        // Decodes the given hex field into bytes.
+        let mut take_bytes = |field: &str| {
+            let bytes: String = DeserializeExt::take_from_value::<D>(&mut keystore, field)?;
+            hex::decode(bytes).map_err(|error| de::Error::custom(format!("Invalid keystore {field} - {error}")))
+        };
+        let salt = take_bytes("salt")?;
+        let nonce = take_bytes("nonce")?;
+        let ciphertext = take_bytes("ciphertext")?;
+        Ok(Self {
+            memory_cost: DeserializeExt::take_from_value::<D>(&mut keystore, "memory_cost")?,
+            time_cost: DeserializeExt::take_from_value::<D>(&mut keystore, "time_cost")?,
+            parallelism: DeserializeExt::take_from_value::<D>(&mut keystore, "parallelism")?,
+            salt: salt.try_into().map_err(|_| de::Error::custom("Invalid keystore salt length"))?,
+            nonce: nonce.try_into().map_err(|_| de::Error::custom("Invalid keystore nonce length"))?,
+            ciphertext,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utilities::TestRng;
+
+    #[test]
+    fn test_keystore() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let keystore = Keystore::encrypt(&private_key, "password", rng)?;
+
+        // Check the serialization round trip.
+        let candidate = serde_json::from_str::<Keystore>(&serde_json::to_string(&keystore)?)?;
+        assert_eq!(keystore, candidate);
+        // Ensure the address is not stored in the clear.
+        let address = crate::console::account::Address::try_from(&private_key)?;
+        assert!(!serde_json::to_string(&keystore)?.contains(&address.to_string()));
+
+        // Check the decryption.
+        assert_eq!(candidate.decrypt("password")?, private_key);
+        assert!(candidate.decrypt("wrong password").is_err());
+        assert!(candidate.decrypt("password\0").is_err());
+        assert!(candidate.decrypt("").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_rejects_modified_files() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let keystore = Keystore::encrypt(&private_key, "password", rng)?;
+
+        // Ensure a modified ciphertext fails to decrypt.
+        let mut modified = keystore.clone();
+        modified.ciphertext[0] ^= 1;
+        assert!(modified.decrypt("password").is_err());
+
+        // Ensure modified key derivation parameters fail to decrypt.
+        let mut modified = keystore.clone();
+        modified.time_cost += 1;
+        assert!(modified.decrypt("password").is_err());
+
+        // Ensure unbounded key derivation parameters are rejected.
+        let mut modified = keystore;
+        modified.memory_cost = u32::MAX;
+        assert!(modified.decrypt("password").unwrap_err().to_string().contains("exceed the maximum"));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_keystore_file_permissions() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let rng = &mut TestRng::default();
+
+        let directory = tempfile::tempdir()?;
+        let path = directory.path().join("keystore.json");
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let keystore = Keystore::encrypt(&private_key, "password", rng)?;
+        keystore.write_to(&path)?;
+
+        // Ensure the file is only readable and writable by the owner.
+        assert_eq!(std::fs::metadata(&path)?.permissions().mode() & 0o777, 0o600);
+        // Ensure the file is not overwritten.
+        assert!(keystore.write_to(&path).is_err());
+        // Ensure the file round trips.
+        assert_eq!(Keystore::open(&path)?.decrypt("password")?, private_key);
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    prelude::{Network, ProgramID, ToBits},
+    synthesizer::Program,
+};
+
+use anyhow::{anyhow, ensure, Result};
+use core::str::FromStr;
+use indexmap::IndexMap;
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+const LOCKFILE_FILE_NAME: &str = "program.lock";
+
+pub struct Lockfile<N: Network> {
+    /// The file path.
+    path: PathBuf,
+    /// The checksums of the imported programs, in topological order.
+    checksums: IndexMap<ProgramID<N>, String>,
+}
+
+impl<N: Network> Lockfile<N> {
+    /// Opens the lockfile for reading, or initializes an empty lockfile if it does not exist.
+    pub fn open(directory: &Path) -> Result<Self> {
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The program directory does not exist: '{}'", directory.display());
+
+        // Construct the file path.
+        let path = directory.join(LOCKFILE_FILE_NAME);
+        // If the file does not exist, return an empty lockfile.
+        if !path.exists() {
+            return Ok(Self { path, checksums: IndexMap::new() });
+        }
+
+        // Read the file to a string.
+        let lockfile_string = fs::read_to_string(&path)?;
+        let json: serde_json::Value = serde_json::from_str(&lockfile_string)?;
+
+        // Retrieve the checksums.
+        let mut checksums = IndexMap::new();
+        for entry in json["programs"].as_array().ok_or_else(|| anyhow!("Programs not found in the lockfile."))? {
+            let name = entry["name"].as_str().ok_or_else(|| anyhow!("Program name not found in the lockfile."))?;
+            let checksum = entry["checksum"].as_str().ok_or_else(|| anyhow!("Checksum not found for '{name}'."))?;
+            checksums.insert(ProgramID::from_str(name)?, checksum.to_string());
+        }
+
+        Ok(Self { path, checksums })
+    }
+
+    /// Writes the lockfile, with the given checksums.
+    pub fn write(&mut self, checksums: IndexMap<ProgramID<N>, String>) -> Result<()> {
+        self.checksums = checksums;
+
+        // Construct the lockfile string.
+        let programs = self
+            .checksums
+            .iter()
+            .map(|(program_id, checksum)| serde_json::json!({ "name": program_id.to_string(), "checksum": checksum }))
+            .collect::<Vec<_>>();
+        let lockfile_string = serde_json::to_string_pretty(&serde_json::json!({ "programs": programs }))?;
+
+        // Write the file.
+        Ok(File::create(&self.path)?.write_all(format!("{lockfile_string}\n").as_bytes())?)
+    }
+
+    /// Returns the checksum of the given program.
+    pub fn checksum_of(program: &Program<N>) -> Result<String> {
+        // Hash the program string.
+        let hash = N::hash_sha3_256(&program.to_string().as_bytes().to_bits_le())?;
+        // Encode the hash in hexadecimal.
+        Ok(hash
+            .chunks(8)
+            .map(|bits| bits.iter().rev().fold(0u8, |byte, bit| (byte << 1) | *bit as u8))
+            .map(|byte| format!("{byte:02x}"))
+            .collect())
+    }
+
+    /// Returns the lockfile file name.
+    pub const fn file_name() -> &'static str {
+        LOCKFILE_FILE_NAME
+    }
+
+    /// Returns the file path.
+    pub const fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Returns the checksums of the imported programs.
+    pub const fn checksums(&self) -> &IndexMap<ProgramID<N>, String> {
+        &self.checksums
+    }
+
+    /// Returns the locked checksum of the given program ID, if it exists.
+    pub fn get_checksum(&self, program_id: &ProgramID<N>) -> Option<&String> {
+        self.checksums.get(program_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_lockfile() {
+        let directory = tempfile::tempdir().unwrap().into_path();
+
+        // Ensure a missing lockfile is empty.
+        let mut lockfile = Lockfile::<CurrentNetwork>::open(&directory).unwrap();
+        assert!(lockfile.checksums().is_empty());
+        assert!(!lockfile.path().exists());
+
+        // Write the lockfile.
+        let program = Program::<CurrentNetwork>::credits().unwrap();
+        let checksum = Lockfile::checksum_of(&program).unwrap();
+        assert_eq!(checksum.len(), 64);
+        lockfile.write(IndexMap::from([(*program.id(), checksum.clone())])).unwrap();
+
+        // Ensure the lockfile is read back.
+        let candidate = Lockfile::<CurrentNetwork>::open(&directory).unwrap();
+        assert_eq!(candidate.get_checksum(program.id()), Some(&checksum));
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
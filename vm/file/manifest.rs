@@ -17,7 +17,7 @@ use crate::{
     synthesizer::Program,
 };
 
-use anyhow::{anyhow, ensure, Result};
+use anyhow::{anyhow, bail, ensure, Result};
 use core::str::FromStr;
 use std::{
     fs::{self, File},
@@ -27,11 +27,49 @@ use std::{
 
 const MANIFEST_FILE_NAME: &str = "program.json";
 
+/// A dependency of a package, as declared in the `dependencies` section of the manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Dependency<N: Network> {
+    /// A program in a local package directory, given relative to the manifest.
+    Local(ProgramID<N>, PathBuf),
+    /// A program deployed on the network, stored in the imports directory and pinned in the lockfile.
+    Network(ProgramID<N>),
+}
+
+impl<N: Network> Dependency<N> {
+    /// Returns the program ID of the dependency.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        match self {
+            Self::Local(program_id, _) => program_id,
+            Self::Network(program_id) => program_id,
+        }
+    }
+
+    /// Parses the dependency from a manifest entry.
+    fn from_json(json: &serde_json::Value) -> Result<Self> {
+        // Retrieve the program ID.
+        let name = json["name"].as_str().ok_or_else(|| anyhow!("Dependency name not found."))?;
+        let program_id = ProgramID::from_str(name)?;
+        // Retrieve the location.
+        match json["location"].as_str() {
+            Some("local") => {
+                let path = json["path"].as_str().ok_or_else(|| anyhow!("Path not found for dependency '{name}'."))?;
+                Ok(Self::Local(program_id, PathBuf::from(path)))
+            }
+            Some("network") => Ok(Self::Network(program_id)),
+            Some(location) => bail!("Unknown location '{location}' for dependency '{name}'"),
+            None => bail!("Location not found for dependency '{name}'."),
+        }
+    }
+}
+
 pub struct Manifest<N: Network> {
     /// The file path.
     path: PathBuf,
     /// The program ID.
     program_id: ProgramID<N>,
+    /// The dependencies.
+    dependencies: Vec<Dependency<N>>,
 }
 
 impl<N: Network> Manifest<N> {
@@ -62,7 +100,7 @@ impl<N: Network> Manifest<N> {
         File::create(&path)?.write_all(manifest_string.as_bytes())?;
 
         // Return the manifest file.
-        Ok(Self { path, program_id: *id })
+        Ok(Self { path, program_id: *id, dependencies: Vec::new() })
     }
 
     /// Opens the manifest file for reading.
@@ -85,8 +123,25 @@ impl<N: Network> Manifest<N> {
         // Ensure the program name is valid.
         ensure!(!Program::is_reserved_keyword(id.name()), "Program name is invalid (reserved): {id}");
 
+        // Retrieve the dependencies.
+        let dependencies = match &json["dependencies"] {
+            serde_json::Value::Null => Vec::new(),
+            serde_json::Value::Array(entries) => {
+                entries.iter().map(Dependency::from_json).collect::<Result<Vec<_>>>()?
+            }
+            _ => bail!("Dependencies must be a list in '{}'", path.display()),
+        };
+        // Ensure the dependencies are unique.
+        for (index, dependency) in dependencies.iter().enumerate() {
+            ensure!(
+                !dependencies[..index].iter().any(|d| d.program_id() == dependency.program_id()),
+                "Dependency '{}' is declared more than once",
+                dependency.program_id()
+            );
+        }
+
         // Return the manifest file.
-        Ok(Self { path, program_id: id })
+        Ok(Self { path, program_id: id, dependencies })
     }
 
     /// Returns `true` if the manifest file exists at the given path.
@@ -111,4 +166,14 @@ impl<N: Network> Manifest<N> {
     pub const fn program_id(&self) -> &ProgramID<N> {
         &self.program_id
     }
+
+    /// Returns the dependencies.
+    pub fn dependencies(&self) -> &[Dependency<N>] {
+        &self.dependencies
+    }
+
+    /// Returns the dependency with the given program ID, if it is declared.
+    pub fn get_dependency(&self, program_id: &ProgramID<N>) -> Option<&Dependency<N>> {
+        self.dependencies.iter().find(|dependency| dependency.program_id() == program_id)
+    }
 }
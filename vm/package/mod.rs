@@ -18,9 +18,11 @@ mod deploy;
 mod execute;
 mod is_build_required;
 mod run;
+mod test;
 
 pub use build::{BuildRequest, BuildResponse};
 pub use deploy::{DeployRequest, DeployResponse};
+pub use test::{TestCase, TestResult};
 
 use crate::{
     console::{
@@ -28,7 +30,7 @@ use crate::{
         network::Network,
         program::{Identifier, Locator, ProgramID, Response, Value},
     },
-    file::{AVMFile, AleoFile, Manifest, ProverFile, VerifierFile, README},
+    file::{AVMFile, AleoFile, Dependency, Lockfile, Manifest, ProverFile, VerifierFile, README},
     ledger::{block::Execution, query::Query, store::helpers::memory::BlockMemory},
     prelude::{Deserialize, Deserializer, Serialize, SerializeStruct, Serializer},
     synthesizer::{
@@ -38,8 +40,9 @@ use crate::{
     },
 };
 
-use anyhow::{bail, ensure, Error, Result};
+use anyhow::{bail, ensure, Result};
 use core::str::FromStr;
+use indexmap::IndexMap;
 use rand::{CryptoRng, Rng};
 use std::path::{Path, PathBuf};
 
@@ -152,28 +155,109 @@ impl<N: Network> Package<N> {
         // Create the process.
         let mut process = Process::load()?;
 
-        // Prepare the imports directory.
-        let imports_directory = self.imports_directory();
+        // Add all import programs (in topological order) to the process.
+        for import_program in self.resolve_imports()? {
+            process.add_program(&import_program)?;
+        }
+
+        // Add the program to the process.
+        process.add_program(self.program())?;
+
+        Ok(process)
+    }
+
+    /// Returns the imported programs of the package, in topological order (excluding `credits.aleo`).
+    ///
+    /// Local dependencies in the manifest are resolved from their package directory, while network
+    /// dependencies and undeclared imports are resolved from the imports directory. The checksums of
+    /// the resolved programs are written to the lockfile, and must match for the programs it pins.
+    pub fn resolve_imports(&self) -> Result<Vec<Program<N>>> {
+        // Resolve the imported programs.
+        let mut resolved = IndexMap::new();
+        Self::resolve_imports_of(self.program(), &self.directory, &self.manifest_file, &mut Vec::new(), &mut resolved)?;
+
+        // Open the lockfile.
+        let mut lockfile = Lockfile::<N>::open(&self.directory)?;
+        // Compute the checksums of the imported programs.
+        let mut checksums = IndexMap::with_capacity(resolved.len());
+        for (program_id, (program, is_pinned)) in resolved.iter() {
+            let checksum = Lockfile::checksum_of(program)?;
+            // Ensure the checksum of a pinned program matches the lockfile.
+            if let Some(locked_checksum) = lockfile.get_checksum(program_id) {
+                ensure!(
+                    !is_pinned || *locked_checksum == checksum,
+                    "The checksum of '{program_id}' does not match '{}' - remove the entry to update it",
+                    Lockfile::<N>::file_name()
+                );
+            }
+            checksums.insert(*program_id, checksum);
+        }
+        // Update the lockfile, if the checksums have changed.
+        if !resolved.is_empty() && *lockfile.checksums() != checksums {
+            lockfile.write(checksums)?;
+        }
+
+        Ok(resolved.into_values().map(|(program, _)| program).collect())
+    }
 
+    /// Resolves the imports of the given program, for the package at the given directory and with the given manifest.
+    fn resolve_imports_of(
+        program: &Program<N>,
+        directory: &Path,
+        manifest: &Manifest<N>,
+        visiting: &mut Vec<ProgramID<N>>,
+        resolved: &mut IndexMap<ProgramID<N>, (Program<N>, bool)>,
+    ) -> Result<()> {
         // Initialize the 'credits.aleo' program ID.
         let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
 
-        // Add all import programs (in order) to the process.
-        self.program().imports().keys().try_for_each(|program_id| {
-            // Don't add `credits.aleo` as the process is already loaded with it.
-            if program_id != &credits_program_id {
-                // Open the Aleo program file.
-                let import_program_file = AleoFile::open(&imports_directory, program_id, false)?;
-                // Add the import program.
-                process.add_program(import_program_file.program())?;
+        for program_id in program.imports().keys() {
+            // Skip `credits.aleo` as the process is already loaded with it, and any resolved program.
+            if program_id == &credits_program_id || resolved.contains_key(program_id) {
+                continue;
+            }
+            // Ensure the imports are acyclic.
+            ensure!(!visiting.contains(program_id), "Found a cyclic import of '{program_id}'");
+            visiting.push(*program_id);
+
+            match manifest.get_dependency(program_id) {
+                Some(Dependency::Local(_, path)) => {
+                    // Open the package of the dependency.
+                    let package = Package::<N>::open(&directory.join(path))?;
+                    ensure!(
+                        package.program_id() == program_id,
+                        "The package at '{}' is '{}', but the dependency is '{program_id}'",
+                        package.directory().display(),
+                        package.program_id()
+                    );
+                    // Resolve the imports of the dependency, from its own package.
+                    Self::resolve_imports_of(
+                        package.program(),
+                        package.directory(),
+                        package.manifest_file(),
+                        visiting,
+                        resolved,
+                    )?;
+                    resolved.insert(*program_id, (package.program().clone(), false));
+                }
+                Some(Dependency::Network(_)) | None => {
+                    // Open the Aleo program file.
+                    let import_program_file = AleoFile::open(&directory.join("imports"), program_id, false)?;
+                    let import_program = import_program_file.program();
+                    ensure!(
+                        import_program.id() == program_id,
+                        "The imported program '{}' does not match '{program_id}'",
+                        import_program.id()
+                    );
+                    // Resolve the imports of the imported program, from the same imports directory.
+                    Self::resolve_imports_of(import_program, directory, manifest, visiting, resolved)?;
+                    resolved.insert(*program_id, (import_program.clone(), true));
+                }
             }
-            Ok::<_, Error>(())
-        })?;
-
-        // Add the program to the process.
-        process.add_program(self.program())?;
 
-        Ok(process)
+            visiting.pop();
+        }
+        Ok(())
     }
 }
 
@@ -579,4 +663,73 @@ function bar:
         // Proactively remove the temporary directory (to conserve space).
         std::fs::remove_dir_all(directory).unwrap();
     }
+
+    #[test]
+    fn test_resolve_imports_nested() {
+        // Samples a new package with nested imports at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_nested_package();
+
+        // Ensure the imports are resolved in topological order.
+        let imports = package.resolve_imports().unwrap();
+        let import_ids = imports.iter().map(|program| program.id().to_string()).collect::<Vec<_>>();
+        assert_eq!(import_ids, vec!["child.aleo", "parent.aleo"]);
+
+        // Ensure the lockfile pins the imported programs.
+        let lockfile = Lockfile::<CurrentNetwork>::open(&directory).unwrap();
+        assert!(lockfile.path().exists());
+        for program in &imports {
+            assert_eq!(lockfile.get_checksum(program.id()), Some(&Lockfile::checksum_of(program).unwrap()));
+        }
+
+        // Ensure a modified import no longer matches the lockfile.
+        let child_path = package.imports_directory().join("child.aleo");
+        let child_string = std::fs::read_to_string(&child_path).unwrap();
+        std::fs::write(&child_path, child_string.replace("function mint:", "function mint_v2:")).unwrap();
+        assert!(package.resolve_imports().is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_imports_local_dependency() {
+        let workspace = tempfile::tempdir().unwrap().into_path();
+
+        // Create the dependency package.
+        let token_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+        let token_package = Package::create(&workspace.join("token"), &token_id).unwrap();
+
+        // Create the main package, which imports the dependency package by path.
+        let wallet_directory = workspace.join("wallet");
+        std::fs::create_dir_all(&wallet_directory).unwrap();
+        std::fs::write(
+            wallet_directory.join("main.aleo"),
+            "import token.aleo;\n\nprogram wallet.aleo;\n\nfunction transfer:\n    input r0 as u32.public;\n    input r1 as u32.private;\n    call token.aleo/hello r0 r1 into r2;\n    output r2 as u32.private;\n",
+        )
+        .unwrap();
+        std::fs::write(
+            wallet_directory.join("program.json"),
+            r#"{
+    "program": "wallet.aleo",
+    "version": "0.0.0",
+    "description": "",
+    "license": "MIT",
+    "dependencies": [
+        { "name": "token.aleo", "location": "local", "path": "../token" }
+    ]
+}
+"#,
+        )
+        .unwrap();
+        let package = Package::<CurrentNetwork>::open(&wallet_directory).unwrap();
+        assert_eq!(package.manifest_file().dependencies(), &[Dependency::Local(token_id, PathBuf::from("../token"))]);
+
+        // Ensure the dependency is resolved without an imports directory.
+        assert!(!package.imports_directory().exists());
+        assert_eq!(package.resolve_imports().unwrap(), vec![token_package.program().clone()]);
+        assert!(package.get_process().unwrap().contains_program(&token_id));
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(workspace).unwrap();
+    }
 }
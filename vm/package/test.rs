@@ -0,0 +1,448 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    console::program::Plaintext,
+    ledger::store::{helpers::memory::ConsensusMemory, ConsensusStore},
+    synthesizer::{program::FinalizeGlobalState, VM},
+};
+
+use std::fmt;
+
+/// The extension of the test files in the tests directory.
+const TEST_FILE_EXTENSION: &str = "json";
+
+/// An expected mapping entry, as `(program ID, mapping name, key, value)`, where a `None` value is absent.
+type MappingEntry<N> = (ProgramID<N>, Identifier<N>, Plaintext<N>, Option<Value<N>>);
+
+/// A test case, which calls a function of the package program and checks the outcome.
+pub struct TestCase<N: Network> {
+    /// The name of the test case.
+    name: String,
+    /// The private key of the caller, if it differs from the default private key.
+    private_key: Option<PrivateKey<N>>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The function inputs.
+    inputs: Vec<Value<N>>,
+    /// The expected outputs, where `None` matches any output.
+    outputs: Option<Vec<Option<Value<N>>>>,
+    /// Whether the finalize logic is expected to abort.
+    aborts: bool,
+    /// The expected mapping entries after the test case.
+    mappings: Vec<MappingEntry<N>>,
+}
+
+impl<N: Network> TestCase<N> {
+    /// Parses the test case from a test file entry, for the given package program ID.
+    fn from_json(json: &serde_json::Value, program_id: &ProgramID<N>) -> Result<Self> {
+        // Retrieve the name.
+        let name = json["name"].as_str().ok_or_else(|| anyhow::anyhow!("Test case name not found."))?.to_string();
+        // A helper to parse a string field.
+        let parse_str = |value, field| field_as_str(value, field, &name);
+
+        // Retrieve the private key.
+        let private_key = match &json["private_key"] {
+            serde_json::Value::Null => None,
+            private_key => Some(PrivateKey::from_str(parse_str(private_key, "private_key")?)?),
+        };
+        // Retrieve the function name.
+        let function_name = Identifier::from_str(parse_str(&json["function"], "function")?)?;
+        // Retrieve the inputs.
+        let inputs = match &json["inputs"] {
+            serde_json::Value::Null => Vec::new(),
+            serde_json::Value::Array(inputs) => {
+                inputs.iter().map(|input| Value::from_str(parse_str(input, "inputs")?)).collect::<Result<_>>()?
+            }
+            _ => bail!("The inputs of test case '{name}' must be a list"),
+        };
+        // Retrieve the expected outputs.
+        let outputs = match &json["outputs"] {
+            serde_json::Value::Null => None,
+            serde_json::Value::Array(outputs) => Some(
+                outputs
+                    .iter()
+                    .map(|output| match parse_str(output, "outputs")? {
+                        "_" => Ok(None),
+                        output => Ok(Some(Value::from_str(output)?)),
+                    })
+                    .collect::<Result<_>>()?,
+            ),
+            _ => bail!("The outputs of test case '{name}' must be a list"),
+        };
+        // Retrieve the expected finalize outcome.
+        let aborts = json["aborts"].as_bool().unwrap_or(false);
+        // Retrieve the expected mapping entries.
+        let mappings = match &json["mappings"] {
+            serde_json::Value::Null => Vec::new(),
+            serde_json::Value::Array(mappings) => mappings
+                .iter()
+                .map(|entry| {
+                    let program_id = match &entry["program"] {
+                        serde_json::Value::Null => *program_id,
+                        program => ProgramID::from_str(parse_str(program, "program")?)?,
+                    };
+                    let mapping_name = Identifier::from_str(parse_str(&entry["mapping"], "mapping")?)?;
+                    let key = Plaintext::from_str(parse_str(&entry["key"], "key")?)?;
+                    let value = match &entry["value"] {
+                        serde_json::Value::Null => None,
+                        value => Some(Value::from_str(parse_str(value, "value")?)?),
+                    };
+                    Ok((program_id, mapping_name, key, value))
+                })
+                .collect::<Result<_>>()?,
+            _ => bail!("The mappings of test case '{name}' must be a list"),
+        };
+
+        Ok(Self { name, private_key, function_name, inputs, outputs, aborts, mappings })
+    }
+
+    /// Returns the name of the test case.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+}
+
+/// Returns the given test case field as a string.
+fn field_as_str<'a>(value: &'a serde_json::Value, field: &str, name: &str) -> Result<&'a str> {
+    value.as_str().ok_or_else(|| anyhow::anyhow!("Invalid '{field}' in test case '{name}'"))
+}
+
+/// The outcome of a test case.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestResult {
+    /// The test file, relative to the tests directory.
+    file: String,
+    /// The name of the test case.
+    name: String,
+    /// The mismatches between the expected and the actual outcome.
+    failures: Vec<String>,
+}
+
+impl TestResult {
+    /// Returns the test file, relative to the tests directory.
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Returns the name of the test case.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the mismatches between the expected and the actual outcome.
+    pub fn failures(&self) -> &[String] {
+        &self.failures
+    }
+
+    /// Returns `true` if the test case passed.
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for TestResult {
+    /// Prints the test result, with the mismatches of a failed test case.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.is_success() {
+            true => write!(f, "{} ({}) ... ok", self.name, self.file),
+            false => {
+                write!(f, "{} ({}) ... FAILED", self.name, self.file)?;
+                for failure in &self.failures {
+                    write!(f, "\n    {failure}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<N: Network> Package<N> {
+    /// Returns the tests directory.
+    pub fn tests_directory(&self) -> PathBuf {
+        self.directory.join("tests")
+    }
+
+    /// Runs the test cases in the tests directory, whose names contain the given filter.
+    ///
+    /// Each test file is run against a fresh in-memory VM, and its test cases run in order,
+    /// so a test case observes the mapping state left by the previous test cases of its file.
+    /// If `prove` is set, each execution is also proven and verified before it is finalized.
+    pub fn test<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        filter: Option<&str>,
+        prove: bool,
+        rng: &mut R,
+    ) -> Result<Vec<TestResult>> {
+        // Ensure the tests directory exists.
+        let tests_directory = self.tests_directory();
+        ensure!(tests_directory.exists(), "The tests directory does not exist: '{}'", tests_directory.display());
+
+        // Discover the test files.
+        let mut test_files = std::fs::read_dir(&tests_directory)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .filter(|path| {
+                path.is_file() && path.extension().map_or(false, |extension| extension == TEST_FILE_EXTENSION)
+            })
+            .collect::<Vec<_>>();
+        test_files.sort();
+
+        // Resolve the imported programs.
+        let imports = self.resolve_imports()?;
+
+        let mut results = Vec::new();
+        for path in test_files {
+            // Retrieve the file name.
+            let file = path.strip_prefix(&tests_directory)?.display().to_string();
+            // Parse the test cases.
+            let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+            let test_cases = json["cases"]
+                .as_array()
+                .ok_or_else(|| anyhow::anyhow!("Test cases not found in '{}'", path.display()))?
+                .iter()
+                .map(|test_case| TestCase::from_json(test_case, self.program_id()))
+                .collect::<Result<Vec<_>>>()?;
+
+            // Skip the file, if none of its test cases are selected.
+            if !test_cases.iter().any(|test_case| filter.map_or(true, |filter| test_case.name.contains(filter))) {
+                continue;
+            }
+
+            // Initialize a fresh VM with the package programs.
+            let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+            for program in imports.iter().chain(std::iter::once(self.program())) {
+                vm.process().write().add_program(program)?;
+                for mapping_name in program.mappings().keys() {
+                    vm.finalize_store().initialize_mapping(*program.id(), *mapping_name)?;
+                }
+            }
+
+            // Run the selected test cases, in order.
+            for test_case in test_cases {
+                // Note: The skipped test cases do not run, so later test cases may observe a different state.
+                if filter.map_or(false, |filter| !test_case.name.contains(filter)) {
+                    continue;
+                }
+                let private_key = test_case.private_key.as_ref().unwrap_or(private_key);
+                let failures = match self.run_test_case::<A, R>(&vm, private_key, &test_case, prove, rng) {
+                    Ok(failures) => failures,
+                    Err(error) => vec![error.to_string()],
+                };
+                results.push(TestResult { file: file.clone(), name: test_case.name, failures });
+            }
+        }
+        Ok(results)
+    }
+
+    /// Runs the test case against the given VM, and returns the mismatches with the expected outcome.
+    fn run_test_case<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        vm: &VM<N, ConsensusMemory<N>>,
+        private_key: &PrivateKey<N>,
+        test_case: &TestCase<N>,
+        prove: bool,
+        rng: &mut R,
+    ) -> Result<Vec<String>> {
+        let process = vm.process();
+        let mut failures = Vec::new();
+
+        // Authorize the function call.
+        let authorization = process.read().authorize::<A, R>(
+            private_key,
+            self.program_id(),
+            test_case.function_name,
+            test_case.inputs.iter(),
+            rng,
+        )?;
+
+        // Evaluate the function, and check the outputs.
+        let response = process.read().evaluate::<A>(authorization.replicate())?;
+        if let Some(expected_outputs) = &test_case.outputs {
+            match expected_outputs.len() == response.outputs().len() {
+                true => {
+                    for (index, (expected, output)) in expected_outputs.iter().zip(response.outputs()).enumerate() {
+                        if let Some(expected) = expected.as_ref().filter(|expected| *expected != output) {
+                            failures.push(format!("output {index}: expected '{expected}', found '{output}'"));
+                        }
+                    }
+                }
+                false => failures.push(format!(
+                    "expected {} outputs, found {}",
+                    expected_outputs.len(),
+                    response.outputs().len()
+                )),
+            }
+        }
+
+        // Construct the execution.
+        let execution = match prove {
+            true => {
+                // Prepare the locator.
+                let locator = Locator::new(*self.program_id(), test_case.function_name).to_string();
+                // Execute the function, and prove the execution.
+                let (_, mut trace) = process.read().execute::<A, R>(authorization, rng)?;
+                trace.prepare(Query::from(vm.block_store()))?;
+                let execution = trace.prove_execution::<A, R>(&locator, rng)?;
+                // Verify the execution.
+                if let Err(error) = process.read().verify_execution(&execution) {
+                    failures.push(format!("the execution proof is invalid - {error}"));
+                }
+                execution
+            }
+            false => {
+                Execution::from(authorization.transitions().into_values(), vm.block_store().current_state_root(), None)?
+            }
+        };
+
+        // Finalize the execution, and check the outcome.
+        let state = FinalizeGlobalState::new_genesis::<N>()?;
+        match (process.read().finalize_execution(state, vm.finalize_store(), &execution, None), test_case.aborts) {
+            (Ok(_), true) => failures.push("expected finalize to abort, but it succeeded".to_string()),
+            (Err(error), false) => failures.push(format!("finalize aborted - {error}")),
+            _ => (),
+        }
+
+        // Check the mapping entries.
+        for (program_id, mapping_name, key, expected) in &test_case.mappings {
+            let value = vm.finalize_store().get_value_confirmed(*program_id, *mapping_name, key)?;
+            if value != *expected {
+                let display = |value: &Option<Value<N>>| value.as_ref().map_or("none".to_string(), |v| v.to_string());
+                failures.push(format!(
+                    "{program_id}/{mapping_name}[{key}]: expected '{}', found '{}'",
+                    display(expected),
+                    display(&value)
+                ));
+            }
+        }
+
+        Ok(failures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Address, TestRng};
+
+    type CurrentNetwork = crate::prelude::Testnet3;
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    #[test]
+    fn test_package_test() {
+        let rng = &mut TestRng::default();
+
+        // Samples a new package with a mapping at a temporary directory.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program counter.aleo;
+
+mapping counts:
+    key as address.public;
+    value as u64.public;
+
+function increment:
+    input r0 as u64.public;
+    async increment self.caller r0 into r1;
+    output r0 as u64.public;
+    output r1 as counter.aleo/increment.future;
+
+finalize increment:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use counts[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into counts[r0];
+
+function check:
+    async check self.caller into r0;
+    output r0 as counter.aleo/check.future;
+
+finalize check:
+    input r0 as address.public;
+    get counts[r0] into r1;",
+        )
+        .unwrap();
+        let (directory, package) = crate::package::test_helpers::sample_package_with_program_and_imports(&program, &[]);
+
+        // Sample the callers.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Write the test file.
+        std::fs::create_dir_all(package.tests_directory()).unwrap();
+        let test_file = serde_json::json!({
+            "cases": [
+                {
+                    "name": "increment_once",
+                    "function": "increment",
+                    "inputs": ["5u64"],
+                    "outputs": ["5u64", "_"],
+                    "mappings": [{ "mapping": "counts", "key": address.to_string(), "value": "5u64" }]
+                },
+                {
+                    "name": "increment_twice",
+                    "function": "increment",
+                    "inputs": ["3u64"],
+                    "mappings": [{ "mapping": "counts", "key": address.to_string(), "value": "8u64" }]
+                },
+                {
+                    "name": "increment_wrong",
+                    "function": "increment",
+                    "inputs": ["1u64"],
+                    "outputs": ["2u64", "_"],
+                    "mappings": [{ "mapping": "counts", "key": address.to_string(), "value": "8u64" }]
+                },
+                {
+                    "name": "check_missing",
+                    "private_key": other_private_key.to_string(),
+                    "function": "check",
+                    "aborts": true
+                }
+            ]
+        });
+        std::fs::write(package.tests_directory().join("counter.json"), test_file.to_string()).unwrap();
+
+        // Run the test cases.
+        let results = package.test::<CurrentAleo, _>(&private_key, None, false, rng).unwrap();
+        let names = results.iter().map(|result| result.name()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["increment_once", "increment_twice", "increment_wrong", "check_missing"]);
+        assert!(results[0].is_success(), "{}", results[0]);
+        assert!(results[1].is_success(), "{}", results[1]);
+        assert!(results[3].is_success(), "{}", results[3]);
+
+        // Ensure the failed test case reports the mismatches.
+        assert_eq!(results[2].failures(), &[
+            "output 0: expected '2u64', found '1u64'".to_string(),
+            format!("counter.aleo/counts[{address}]: expected '8u64', found '9u64'"),
+        ]);
+
+        // Ensure the filter selects the test cases.
+        let results = package.test::<CurrentAleo, _>(&private_key, Some("twice"), false, rng).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name(), "increment_twice");
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}